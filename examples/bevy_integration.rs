@@ -0,0 +1,86 @@
+//! Renders a hexasphere in Bevy, with the 12 pentagons raised as thick tiles.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo run --example bevy_integration --features bevy
+//! ```
+
+use bevy::prelude::*;
+use geotiles::interop::bevy::BevyMeshOptions;
+use geotiles::{Hexasphere, ThickTile};
+
+/// Marker for the entity that slowly spins the whole planet.
+#[derive(Component)]
+struct Planet;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .add_systems(Update, rotate_planet)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Small tiles with a gap between them so the tile edges are visible
+    let hexasphere = Hexasphere::new(10.0, 4, 0.95);
+    let options = BevyMeshOptions {
+        scale: 0.2,
+        ..Default::default()
+    };
+
+    let surface = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.2, 0.5, 0.8),
+        perceptual_roughness: 0.8,
+        ..default()
+    });
+    let pentagon = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.9, 0.6, 0.2),
+        ..default()
+    });
+
+    commands
+        .spawn((Planet, Transform::default(), Visibility::default()))
+        .with_children(|planet| {
+            planet.spawn((
+                Mesh3d(meshes.add(hexasphere.to_bevy_mesh_with(&options))),
+                MeshMaterial3d(surface),
+            ));
+
+            // Pentagons become thick tiles sitting slightly above the surface
+            let lifted = hexasphere.create_inner_sphere(10.5);
+            for tile in lifted.tiles.iter().filter(|tile| tile.is_pentagon()) {
+                let thick_tile = ThickTile::from_surface_tile(tile, 0.5);
+                planet.spawn((
+                    Mesh3d(meshes.add(thick_tile.to_bevy_mesh_with(&options))),
+                    MeshMaterial3d(pentagon.clone()),
+                ));
+            }
+        });
+
+    commands.spawn((
+        PointLight {
+            intensity: 10_000_000.0,
+            range: 100.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(8.0, 16.0, 8.0),
+    ));
+
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 2.0, 6.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+}
+
+fn rotate_planet(time: Res<Time>, mut planets: Query<&mut Transform, With<Planet>>) {
+    for mut transform in &mut planets {
+        transform.rotate_y(0.2 * time.delta_secs());
+    }
+}
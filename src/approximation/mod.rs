@@ -1,5 +1,7 @@
-//! Regular hexagon approximation utilities.
+//! Regular hexagon and pentagon approximation utilities.
 
 pub mod regular_hexagon;
+pub mod regular_pentagon;
 
 pub use regular_hexagon::RegularHexagonParams;
+pub use regular_pentagon::RegularPentagonParams;
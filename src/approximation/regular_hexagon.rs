@@ -1,6 +1,7 @@
 //! Regular hexagon generation and parameters.
 
-use crate::geometry::Point;
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::MeshData;
 use crate::tile::TileOrientation;
 use std::f64::consts::PI;
 
@@ -93,12 +94,208 @@ impl RegularHexagonParams {
 
         vertices
     }
+
+    /// Returns the area of the hexagon, `3√3 / 2 × radius²`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Point, RegularHexagonParams, tile::TileOrientation};
+    /// let params = RegularHexagonParams {
+    ///     center: Point::new(0.0, 0.0, 0.0),
+    ///     radius: 2.0,
+    ///     orientation: TileOrientation::default(),
+    /// };
+    /// assert!((params.area() - 6.0 * 3f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn area(&self) -> f64 {
+        1.5 * 3f64.sqrt() * self.radius * self.radius
+    }
+
+    /// Returns the length of each side, which for a regular hexagon equals the radius.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Point, RegularHexagonParams, tile::TileOrientation};
+    /// let params = RegularHexagonParams {
+    ///     center: Point::new(0.0, 0.0, 0.0),
+    ///     radius: 2.0,
+    ///     orientation: TileOrientation::default(),
+    /// };
+    /// assert_eq!(params.edge_length(), 2.0);
+    /// ```
+    pub fn edge_length(&self) -> f64 {
+        self.radius
+    }
+
+    /// Generates a flat, render-ready mesh of the hexagon.
+    ///
+    /// The hexagon is triangulated as a fan around its center, like a tile in
+    /// [`Hexasphere::to_mesh`](crate::Hexasphere::to_mesh). Unlike
+    /// [`generate_vertices`](Self::generate_vertices), positions are not
+    /// rounded to three decimals.
+    ///
+    /// # Returns
+    ///
+    /// A [`MeshData`] with 7 vertices and 6 triangles:
+    /// - Vertex 0 is the center, vertices 1 to 6 the corners in the order of
+    ///   [`generate_vertices`](Self::generate_vertices)
+    /// - Every normal equals `orientation.up` and triangles are wound
+    ///   counter-clockwise when seen from that side
+    /// - UVs map the corners onto the unit circle around (0.5, 0.5)
+    /// - `tile_ranges` holds a single range covering all triangles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+    /// let mesh = tile.get_regular_hexagon_params().unwrap().generate_mesh();
+    ///
+    /// assert_eq!(mesh.positions.len(), 7);
+    /// assert_eq!(mesh.triangle_count(), 6);
+    /// ```
+    pub fn generate_mesh(&self) -> MeshData {
+        let mut mesh = MeshData::default();
+        let up = &self.orientation.up;
+        let rim = self.rim(&Vector3::from(&self.center));
+
+        mesh.push_vertex(&self.center, up, [0.5, 0.5]);
+        for (i, corner) in rim.iter().enumerate() {
+            let angle = i as f64 * PI / 3.0;
+            mesh.push_vertex(
+                corner,
+                up,
+                [0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin()],
+            );
+        }
+        for i in 0..6 {
+            let next = (i + 1) % 6;
+            self.push_triangle(&mut mesh, [0, 1 + i, 1 + next]);
+        }
+
+        mesh.tile_ranges.push(0..mesh.indices.len() as u32);
+        mesh
+    }
+
+    /// Generates a closed hexagonal prism extruded below the hexagon.
+    ///
+    /// The top face is the hexagon itself and the bottom face lies `thickness`
+    /// further along `-orientation.up`, mirroring an inward
+    /// [`ThickTile`](crate::ThickTile). Vertices are duplicated at the hard
+    /// edges so every face keeps flat normals, with the same layout as
+    /// [`ThickTile::generate_mesh`](crate::ThickTile::generate_mesh).
+    ///
+    /// # Arguments
+    ///
+    /// * `thickness` - Distance between the top and bottom faces
+    ///
+    /// # Returns
+    ///
+    /// A [`MeshData`] with 38 vertices and 24 triangles:
+    /// 1. Top center and corners (indices 0 to 6), normal `up`
+    /// 2. Bottom center and corners (indices 7 to 13), normal `-up`
+    /// 3. Four vertices per side wall: top i, top i+1, bottom i, bottom i+1,
+    ///    with the wall's outward normal
+    ///
+    /// Wall UVs are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+    /// let prism = tile.get_regular_hexagon_params().unwrap().generate_prism_mesh(0.5);
+    ///
+    /// assert_eq!(prism.positions.len(), 38);
+    /// assert_eq!(prism.triangle_count(), 24);
+    /// ```
+    pub fn generate_prism_mesh(&self, thickness: f64) -> MeshData {
+        let mut mesh = self.generate_mesh();
+        let up = &self.orientation.up;
+        let down = up * -1.0;
+
+        let bottom_center = Vector3::from(&self.center) - up * thickness;
+        let top = self.rim(&Vector3::from(&self.center));
+        let bottom = self.rim(&bottom_center);
+
+        mesh.push_vertex(&unrounded(&bottom_center), &down, [0.5, 0.5]);
+        for (i, corner) in bottom.iter().enumerate() {
+            let angle = i as f64 * PI / 3.0;
+            mesh.push_vertex(
+                corner,
+                &down,
+                [0.5 + 0.5 * angle.cos(), 0.5 - 0.5 * angle.sin()],
+            );
+        }
+        for i in 0..6 {
+            let next = (i + 1) % 6;
+            self.push_triangle(&mut mesh, [7, 8 + next, 8 + i]);
+        }
+
+        for i in 0..6 {
+            let next = (i + 1) % 6;
+            // Walls face the direction halfway between their two corners
+            let angle = (i as f64 + 0.5) * PI / 3.0;
+            let wall_normal =
+                &self.orientation.right * angle.cos() + &self.orientation.forward * angle.sin();
+
+            let start = mesh.positions.len() as u32;
+            for corner in [&top[i], &top[next], &bottom[i], &bottom[next]] {
+                mesh.push_vertex(corner, &wall_normal, [0.0, 0.0]);
+            }
+            let [top_curr, top_next, bottom_curr, bottom_next] =
+                [start, start + 1, start + 2, start + 3];
+            self.push_triangle(&mut mesh, [top_curr, bottom_curr, top_next]);
+            self.push_triangle(&mut mesh, [top_next, bottom_curr, bottom_next]);
+        }
+
+        mesh.tile_ranges[0].end = mesh.indices.len() as u32;
+        mesh
+    }
+
+    /// The six unrounded corners of the hexagon moved to `center`.
+    fn rim(&self, center: &Vector3) -> Vec<Point> {
+        (0..6)
+            .map(|i| {
+                let angle = i as f64 * PI / 3.0;
+                let offset = &self.orientation.right * (self.radius * angle.cos())
+                    + &self.orientation.forward * (self.radius * angle.sin());
+                unrounded(&(center + &offset))
+            })
+            .collect()
+    }
+
+    /// Appends a triangle given in counter-clockwise corner order.
+    ///
+    /// Corners follow `right` toward `forward`, which is clockwise seen from
+    /// `up` for right-handed tile orientations; the triangle is flipped then
+    /// so that it faces `up` regardless.
+    fn push_triangle(&self, mesh: &mut MeshData, [a, b, c]: [u32; 3]) {
+        let o = &self.orientation;
+        if o.right.cross(&o.forward).dot(&o.up) >= 0.0 {
+            mesh.indices.extend([a, b, c]);
+        } else {
+            mesh.indices.extend([a, c, b]);
+        }
+    }
+}
+
+/// Converts a vector to a point without `Point::new`'s rounding.
+fn unrounded(vector: &Vector3) -> Point {
+    Point {
+        x: vector.x,
+        y: vector.y,
+        z: vector.z,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::geometry::{Point, Vector3};
     use crate::hexasphere::core::Hexasphere;
     use crate::tile::TileOrientation;
 
@@ -443,4 +640,114 @@ mod tests {
             );
         }
     }
+
+    fn vertex(mesh: &MeshData, index: u32) -> Vector3 {
+        let [x, y, z] = mesh.positions[index as usize];
+        Vector3::new(x as f64, y as f64, z as f64)
+    }
+
+    fn normal(mesh: &MeshData, index: u32) -> Vector3 {
+        let [x, y, z] = mesh.normals[index as usize];
+        Vector3::new(x as f64, y as f64, z as f64)
+    }
+
+    fn sample_params() -> Vec<RegularHexagonParams> {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let mut params = hexasphere.get_regular_hexagon_approximations();
+        params.truncate(10);
+
+        // The default orientation is left-handed, unlike tile orientations
+        params.push(RegularHexagonParams {
+            center: Point::new(1.0, 2.0, 3.0),
+            radius: 2.0,
+            orientation: TileOrientation::default(),
+        });
+        params
+    }
+
+    #[test]
+    fn test_generate_mesh_layout_and_normals() {
+        for params in sample_params() {
+            let mesh = params.generate_mesh();
+            let up = &params.orientation.up;
+
+            assert_eq!(mesh.positions.len(), 7);
+            assert_eq!(mesh.triangle_count(), 6);
+            assert_eq!(mesh.tile_ranges.len(), 1);
+            assert_eq!(mesh.tile_ranges[0], 0..18);
+
+            for index in 0..7 {
+                assert!(normal(&mesh, index).dot(up) > 1.0 - 1e-6);
+            }
+            for (corner, expected) in params.generate_vertices().iter().enumerate() {
+                let offset = vertex(&mesh, corner as u32 + 1) - Vector3::from(expected);
+                assert!(offset.length() < 1e-3);
+            }
+
+            // Every triangle faces up
+            for triangle in mesh.indices.chunks(3) {
+                let [a, b, c] = [0, 1, 2].map(|k| vertex(&mesh, triangle[k]));
+                let face_normal = (&b - &a).cross(&(&c - &a));
+                assert!(face_normal.dot(up) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_area_and_edge_length_match_mesh() {
+        for params in sample_params() {
+            let mesh = params.generate_mesh();
+            let triangle_area: f64 = mesh
+                .indices
+                .chunks(3)
+                .map(|triangle| {
+                    let [a, b, c] = [0, 1, 2].map(|k| vertex(&mesh, triangle[k]));
+                    (&b - &a).cross(&(&c - &a)).length() / 2.0
+                })
+                .sum();
+            let analytic = 3.0 * 3f64.sqrt() / 2.0 * params.radius * params.radius;
+
+            assert!((params.area() - analytic).abs() < 1e-12);
+            assert!((triangle_area - analytic).abs() < 1e-4 * analytic);
+
+            let side = vertex(&mesh, 1).distance_to(&vertex(&mesh, 2));
+            assert!((side - params.edge_length()).abs() < 1e-4 * params.radius);
+        }
+    }
+
+    #[test]
+    fn test_generate_prism_mesh_thickness_and_winding() {
+        let thickness = 0.25;
+
+        for params in sample_params() {
+            let prism = params.generate_prism_mesh(thickness);
+            let up = &params.orientation.up;
+            let center = Vector3::from(&params.center);
+
+            assert_eq!(prism.positions.len(), 38);
+            assert_eq!(prism.triangle_count(), 24);
+            assert_eq!(prism.tile_ranges.len(), 1);
+            assert_eq!(prism.tile_ranges[0], 0..72);
+
+            // Bottom rim sits exactly one thickness below the top rim
+            for i in 0..7 {
+                let drop = vertex(&prism, i) - vertex(&prism, i + 7);
+                assert!((drop.dot(up) - thickness).abs() < 1e-5);
+                assert!(drop.reject_from(up).length() < 1e-5);
+                assert!(normal(&prism, i + 7).dot(up) < -1.0 + 1e-6);
+            }
+
+            // Walls point away from the center and triangles agree with normals
+            for index in 14..38 {
+                let outward = vertex(&prism, index) - center.clone();
+                assert!(normal(&prism, index).dot(&outward) > 0.0);
+                assert!(normal(&prism, index).dot(up).abs() < 1e-6);
+            }
+            for triangle in prism.indices.chunks(3) {
+                let [a, b, c] = [0, 1, 2].map(|k| vertex(&prism, triangle[k]));
+                let face_normal = (&b - &a).cross(&(&c - &a));
+                assert!(face_normal.dot(&normal(&prism, triangle[0])) > 0.0);
+            }
+        }
+    }
 }
@@ -0,0 +1,197 @@
+//! Regular pentagon generation and parameters.
+
+use crate::geometry::Point;
+use crate::tile::TileOrientation;
+use std::f64::consts::PI;
+
+/// Parameters defining a regular pentagon that approximates one of the 12 pentagon tiles.
+///
+/// This is the pentagon counterpart of `RegularHexagonParams`. Every geodesic
+/// sphere has exactly 12 pentagonal tiles at the original icosahedron vertices,
+/// and these parameters let you instance a perfectly regular pentagon mesh at
+/// each of them instead of special-casing them by hand.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Hexasphere, RegularPentagonParams};
+/// # let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+/// for tile in hexasphere.tiles.iter().filter(|t| t.is_pentagon()) {
+///     let pent_params = tile.get_regular_pentagon_params().unwrap();
+///     let vertices = pent_params.generate_vertices();
+///     // Use vertices for rendering, collision detection, etc.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegularPentagonParams {
+    /// Center position of the pentagon
+    pub center: Point,
+    /// Radius from center to vertices (circumradius)
+    pub radius: f64,
+    /// Orientation defining how the pentagon is rotated
+    pub orientation: TileOrientation,
+}
+
+impl RegularPentagonParams {
+    /// Generates the 5 vertices of a regular pentagon with this configuration.
+    ///
+    /// Creates vertices positioned at 72-degree intervals around the center,
+    /// with the first vertex aligned to the orientation's right vector.
+    /// All vertices lie on a circle of the specified radius.
+    ///
+    /// # Returns
+    ///
+    /// A vector of 5 `Point` objects representing the pentagon vertices in order
+    ///
+    /// # Vertex Order
+    ///
+    /// Vertices are ordered counter-clockwise when viewed from the orientation's up vector:
+    /// - Vertex 0: Aligned with right vector
+    /// - Vertex 1: 72° counter-clockwise from vertex 0
+    /// - Vertex 2: 144° counter-clockwise from vertex 0
+    /// - etc.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, RegularPentagonParams};
+    /// # let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+    /// # let tile = hexasphere.tiles.iter().find(|t| t.is_pentagon()).unwrap();
+    /// # let pent_params = tile.get_regular_pentagon_params().unwrap();
+    /// let vertices = pent_params.generate_vertices();
+    /// assert_eq!(vertices.len(), 5);
+    ///
+    /// // All vertices should be equidistant from center
+    /// for vertex in &vertices {
+    ///     let distance = pent_params.center.distance_to(vertex);
+    ///     assert!((distance - pent_params.radius).abs() < 0.001);
+    /// }
+    /// ```
+    pub fn generate_vertices(&self) -> Vec<Point> {
+        let mut vertices = Vec::with_capacity(5);
+
+        for i in 0..5 {
+            let angle = (i as f64) * 2.0 * PI / 5.0; // 72 degrees per vertex
+
+            // Calculate position in local pentagon coordinates
+            let local_x = self.radius * angle.cos();
+            let local_y = self.radius * angle.sin();
+
+            // Transform to world coordinates using orientation
+            let world_x = self.center.x
+                + local_x * self.orientation.right.x
+                + local_y * self.orientation.forward.x;
+            let world_y = self.center.y
+                + local_x * self.orientation.right.y
+                + local_y * self.orientation.forward.y;
+            let world_z = self.center.z
+                + local_x * self.orientation.right.z
+                + local_y * self.orientation.forward.z;
+
+            vertices.push(Point::new(world_x, world_y, world_z));
+        }
+
+        vertices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hexasphere::core::Hexasphere;
+    use crate::tile::TileOrientation;
+
+    #[test]
+    fn test_generate_vertices_basic() {
+        let params = RegularPentagonParams {
+            center: Point::new(0.0, 0.0, 0.0),
+            radius: 1.0,
+            orientation: TileOrientation::default(),
+        };
+
+        let vertices = params.generate_vertices();
+        assert_eq!(vertices.len(), 5);
+
+        // All vertices should be equidistant from center
+        for vertex in &vertices {
+            let distance = params.center.distance_to(vertex);
+            assert!(
+                (distance - 1.0).abs() < 0.001,
+                "Vertex distance {} should equal radius 1.0",
+                distance
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_vertices_angles() {
+        let radius = 2.0;
+        let params = RegularPentagonParams {
+            center: Point::new(0.0, 0.0, 0.0),
+            radius,
+            orientation: TileOrientation::default(),
+        };
+
+        let vertices = params.generate_vertices();
+
+        // Vertices should be at 72-degree intervals in the XY-plane
+        for (i, vertex) in vertices.iter().enumerate() {
+            let expected_angle = (i as f64) * 2.0 * PI / 5.0;
+            assert!((vertex.x - radius * expected_angle.cos()).abs() < 0.001);
+            assert!((vertex.y - radius * expected_angle.sin()).abs() < 0.001);
+            assert!(vertex.z.abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn test_generate_vertices_edge_lengths() {
+        let radius = 3.0;
+        let params = RegularPentagonParams {
+            center: Point::new(1.0, -2.0, 4.0),
+            radius,
+            orientation: TileOrientation::default(),
+        };
+
+        let vertices = params.generate_vertices();
+
+        // Side of a regular pentagon = 2r·sin(36°)
+        let expected_edge = 2.0 * radius * (PI / 5.0).sin();
+        for i in 0..5 {
+            let edge = vertices[i].distance_to(&vertices[(i + 1) % 5]);
+            assert!(
+                (edge - expected_edge).abs() < 0.002,
+                "Edge {} length {} should be {}",
+                i,
+                edge,
+                expected_edge
+            );
+        }
+    }
+
+    #[test]
+    fn test_pentagon_approximations_from_sphere() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let approximations = hexasphere.get_regular_pentagon_approximations();
+        assert_eq!(approximations.len(), 12);
+
+        for params in &approximations {
+            let vertices = params.generate_vertices();
+            for vertex in &vertices {
+                let distance = params.center.distance_to(vertex);
+                assert!((distance - params.radius).abs() < 0.002);
+            }
+        }
+
+        // Hexagons get no pentagon parameters and vice versa
+        for tile in &hexasphere.tiles {
+            assert_eq!(
+                tile.get_regular_pentagon_params().is_some(),
+                tile.is_pentagon()
+            );
+            assert_eq!(
+                tile.get_regular_hexagon_params().is_some(),
+                tile.is_hexagon()
+            );
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Crate-wide error type.
+
+use crate::geometry::Point;
+use crate::hexasphere::{BuildError, DecodeError};
+use std::fmt;
+use std::io;
+
+/// Any error produced by geotiles.
+///
+/// Operations with a narrow set of failures keep their own error types —
+/// [`BuildError`] for construction options, [`DecodeError`] for snapshots and
+/// [`io::Error`] for writers — and all of them convert into `Error`, so `?`
+/// works in functions returning [`crate::Result`].
+///
+/// The enum is non-exhaustive: new variants may be added without a major
+/// version bump.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Error, Hexasphere};
+/// fn build_and_save(path: &str) -> geotiles::Result<usize> {
+///     let hexasphere = Hexasphere::try_new(10.0, 3, 1.0)?;
+///     let mut file = std::fs::File::create(path)?;
+///     hexasphere.write_obj(&mut file)?;
+///     Ok(hexasphere.tiles.len())
+/// }
+///
+/// let error = Hexasphere::try_new(-1.0, 3, 1.0).unwrap_err();
+/// assert!(matches!(error, Error::InvalidRadius(_)));
+/// assert_eq!(error.to_string(), "radius must be finite and positive, got -1");
+/// # assert_eq!(build_and_save("error_example.obj").unwrap(), 92);
+/// # std::fs::remove_file("error_example.obj").unwrap();
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The radius is NaN, infinite, zero or negative
+    InvalidRadius(f64),
+    /// The hex size is not a finite value in `(0.0, 1.0]`
+    InvalidHexSize(f64),
+    /// A coordinate of the sphere center is NaN or infinite
+    InvalidCenter(Point),
+    /// The subdivision count exceeds the configured safety cap
+    SubdivisionTooLarge {
+        /// The requested number of subdivisions
+        requested: usize,
+        /// The largest number of subdivisions allowed
+        max: usize,
+    },
+    /// The configuration would produce more tiles than the configured limit
+    TooManyTiles {
+        /// The number of tiles the configuration would produce
+        requested: usize,
+        /// The largest number of tiles allowed
+        limit: usize,
+    },
+    /// An index is past the end of the collection it refers to
+    IndexOutOfRange {
+        /// The requested index
+        index: usize,
+        /// The length of the collection
+        len: usize,
+    },
+    /// Writing exported data failed
+    Export(io::Error),
+    /// A binary snapshot could not be decoded
+    Decode(DecodeError),
+    /// Imported JSON is malformed or does not match the expected schema
+    InvalidJson(String),
+}
+
+/// Result type using the crate-wide [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidRadius(radius) => {
+                write!(f, "radius must be finite and positive, got {}", radius)
+            }
+            Error::InvalidHexSize(hex_size) => {
+                write!(f, "hex size must be in (0.0, 1.0], got {}", hex_size)
+            }
+            Error::InvalidCenter(center) => {
+                write!(f, "center must be finite, got ({})", center)
+            }
+            Error::SubdivisionTooLarge { requested, max } => write!(
+                f,
+                "{} subdivisions requested but the limit is {}",
+                requested, max
+            ),
+            Error::TooManyTiles { requested, limit } => write!(
+                f,
+                "{} tiles requested but the limit is {}",
+                requested, limit
+            ),
+            Error::IndexOutOfRange { index, len } => {
+                write!(f, "index {} is out of range for length {}", index, len)
+            }
+            Error::Export(error) => write!(f, "export failed: {}", error),
+            Error::Decode(error) => write!(f, "invalid snapshot: {}", error),
+            Error::InvalidJson(reason) => write!(f, "invalid JSON: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Export(error) => Some(error),
+            Error::Decode(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<BuildError> for Error {
+    fn from(error: BuildError) -> Self {
+        match error {
+            BuildError::InvalidRadius(radius) => Error::InvalidRadius(radius),
+            BuildError::InvalidHexSize(hex_size) => Error::InvalidHexSize(hex_size),
+            BuildError::InvalidCenter(center) => Error::InvalidCenter(center),
+            BuildError::SubdivisionTooLarge { requested, max } => {
+                Error::SubdivisionTooLarge { requested, max }
+            }
+            BuildError::TooManyTiles { requested, limit } => {
+                Error::TooManyTiles { requested, limit }
+            }
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        Error::Decode(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Export(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::hexasphere::{BuildError, DecodeError, Hexasphere};
+    use std::error::Error as _;
+    use std::io;
+
+    #[test]
+    fn test_display_formatting() {
+        let cases = [
+            (
+                Error::InvalidRadius(-2.0),
+                "radius must be finite and positive, got -2",
+            ),
+            (
+                Error::InvalidHexSize(1.5),
+                "hex size must be in (0.0, 1.0], got 1.5",
+            ),
+            (
+                Error::SubdivisionTooLarge {
+                    requested: 300,
+                    max: 256,
+                },
+                "300 subdivisions requested but the limit is 256",
+            ),
+            (
+                Error::TooManyTiles {
+                    requested: 20,
+                    limit: 10,
+                },
+                "20 tiles requested but the limit is 10",
+            ),
+            (
+                Error::IndexOutOfRange { index: 7, len: 6 },
+                "index 7 is out of range for length 6",
+            ),
+            (
+                Error::Export(io::Error::other("disk full")),
+                "export failed: disk full",
+            ),
+            (
+                Error::Decode(DecodeError::UnexpectedEnd),
+                "invalid snapshot: snapshot data ended unexpectedly",
+            ),
+            (
+                Error::InvalidJson("expected ',' at byte 12".to_string()),
+                "invalid JSON: expected ',' at byte 12",
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_conversions_keep_details() {
+        let build: Error = BuildError::TooManyTiles {
+            requested: 5,
+            limit: 1,
+        }
+        .into();
+        assert_eq!(build.to_string(), "5 tiles requested but the limit is 1");
+        assert!(build.source().is_none());
+
+        let decode: Error = Hexasphere::from_bytes(b"junk").unwrap_err().into();
+        assert!(matches!(decode, Error::Decode(DecodeError::InvalidMagic)));
+        assert!(decode.source().is_some());
+
+        let io: Error = io::Error::other("closed").into();
+        assert!(matches!(io, Error::Export(_)));
+        assert!(io.source().is_some());
+    }
+
+    #[test]
+    fn test_try_new_error_paths() {
+        assert!(matches!(
+            Hexasphere::try_new(0.0, 2, 1.0),
+            Err(Error::InvalidRadius(_))
+        ));
+        assert!(matches!(
+            Hexasphere::try_new(f64::INFINITY, 2, 1.0),
+            Err(Error::InvalidRadius(_))
+        ));
+        assert!(matches!(
+            Hexasphere::try_new(10.0, 2, 1.01),
+            Err(Error::InvalidHexSize(_))
+        ));
+        assert!(matches!(
+            Hexasphere::try_new(10.0, 257, 1.0),
+            Err(Error::SubdivisionTooLarge {
+                requested: 257,
+                max: 256
+            })
+        ));
+        assert!(Hexasphere::try_new(10.0, 2, 1.0).is_ok());
+    }
+}
@@ -1,6 +1,7 @@
 //! Triangular faces of the geodesic polyhedron.
 
 use crate::geometry::Point;
+use std::sync::OnceLock;
 
 /// A triangular face of the geodesic polyhedron.
 ///
@@ -22,7 +23,7 @@ use crate::geometry::Point;
 /// let point1 = Point::new(0.0, 0.0, 0.0);
 /// let point2 = Point::new(1.0, 0.0, 0.0);
 /// let point3 = Point::new(0.0, 1.0, 0.0);
-/// let mut face = Face::new(0, point1, point2, point3);
+/// let face = Face::new(0, point1, point2, point3);
 ///
 /// // Check if two faces share an edge
 /// # let face1 = Face::new(0, Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
@@ -41,7 +42,7 @@ pub struct Face {
     /// The three vertices that define this triangular face
     pub points: [Point; 3],
     /// Cached centroid calculation (computed on first access)
-    centroid: Option<Point>,
+    centroid: OnceLock<Point>,
 }
 
 impl Face {
@@ -67,7 +68,7 @@ impl Face {
         Self {
             id,
             points: [p1, p2, p3],
-            centroid: None,
+            centroid: OnceLock::new(),
         }
     }
 
@@ -169,6 +170,9 @@ impl Face {
     /// after the first calculation for efficiency. In the geodesic construction,
     /// face centroids become the boundary points of tiles.
     ///
+    /// The cache is filled through a shared reference, so faces can be read
+    /// from behind `&` or `Arc`, including from several threads.
+    ///
     /// # Returns
     ///
     /// A reference to the centroid point
@@ -182,19 +186,12 @@ impl Face {
     ///
     /// ```rust
     /// # use geotiles::{Face, Point};
-    /// # let mut face = Face::new(0, Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+    /// # let face = Face::new(0, Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
     /// let centroid = face.get_centroid();
     /// // The centroid is equidistant from all three vertices
     /// ```
-    pub fn get_centroid(&mut self) -> &Point {
-        if self.centroid.is_none() {
-            let x = (self.points[0].x + self.points[1].x + self.points[2].x) / 3.0;
-            let y = (self.points[0].y + self.points[1].y + self.points[2].y) / 3.0;
-            let z = (self.points[0].z + self.points[1].z + self.points[2].z) / 3.0;
-
-            self.centroid = Some(Point::new(x, y, z));
-        }
-        self.centroid.as_ref().unwrap()
+    pub fn get_centroid(&self) -> &Point {
+        self.centroid.get_or_init(|| self.calculate_centroid())
     }
 
     /// Calculates and returns the centroid (geometric center) of the face without caching.
@@ -215,10 +212,15 @@ impl Face {
     /// // The centroid is equidistant from all three vertices
     /// ```
     pub fn calculate_centroid(&self) -> Point {
+        self.centroid_with_precision(3)
+    }
+
+    /// Like [`Face::calculate_centroid`], rounding to `decimals` places.
+    pub(crate) fn centroid_with_precision(&self, decimals: u32) -> Point {
         let x = (self.points[0].x + self.points[1].x + self.points[2].x) / 3.0;
         let y = (self.points[0].y + self.points[1].y + self.points[2].y) / 3.0;
         let z = (self.points[0].z + self.points[1].z + self.points[2].z) / 3.0;
-        Point::new(x, y, z)
+        Point::with_precision(x, y, z, decimals)
     }
 
     /// Clears the cached centroid, forcing recalculation on next access.
@@ -246,6 +248,49 @@ impl Face {
     /// let centroid2 = face.get_centroid();
     /// ```
     pub fn clear_centroid_cache(&mut self) {
-        self.centroid = None;
+        self.centroid = OnceLock::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Face;
+    use crate::geometry::Point;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_centroid_through_shared_reference() {
+        let face = Arc::new(Face::new(
+            0,
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(0.0, 3.0, 3.0),
+        ));
+
+        let shared = Arc::clone(&face);
+        let from_thread = thread::spawn(move || shared.get_centroid().clone())
+            .join()
+            .unwrap();
+
+        let face: &Face = &face;
+        assert_eq!(face.get_centroid(), &Point::new(1.0, 1.0, 1.0));
+        assert_eq!(from_thread, *face.get_centroid());
+        assert_eq!(face.calculate_centroid(), *face.get_centroid());
+    }
+
+    #[test]
+    fn test_clear_centroid_cache_recomputes() {
+        let mut face = Face::new(
+            0,
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(0.0, 3.0, 0.0),
+        );
+        assert_eq!(face.get_centroid(), &Point::new(1.0, 1.0, 0.0));
+
+        face.points[0] = Point::new(0.0, 0.0, 3.0);
+        face.clear_centroid_cache();
+        assert_eq!(face.get_centroid(), &Point::new(1.0, 1.0, 1.0));
     }
 }
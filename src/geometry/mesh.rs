@@ -0,0 +1,146 @@
+//! Triangulated geodesic sphere mesh.
+
+use crate::geometry::Point;
+use std::collections::HashSet;
+
+/// The triangulated geodesic sphere underlying a hexasphere.
+///
+/// While `Hexasphere` keeps only the Goldberg tiles (the dual polyhedron), this
+/// structure holds the subdivided icosahedron itself after projection onto the
+/// sphere. Every tile center is exactly one vertex of this mesh, and every tile
+/// boundary point is the centroid of one of its triangles.
+///
+/// # Structure
+///
+/// - **Vertices**: Deduplicated sphere points, one per tile
+/// - **Triangles**: Vertex indices of each face, in subdivision order
+///
+/// # Use Cases
+///
+/// - **Terrain rendering**: Render the smooth triangulation while tiles remain
+///   the gameplay regions
+/// - **Mesh processing**: Feed the triangulation to external geometry tools
+/// - **Topology checks**: Verify the closed-surface invariants of the sphere
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let (hexasphere, mesh) = Hexasphere::new_with_mesh(10.0, 3, 1.0);
+///
+/// // One mesh vertex per tile, and a closed surface of genus 0
+/// assert_eq!(mesh.vertices.len(), hexasphere.tiles.len());
+/// assert_eq!(mesh.euler_characteristic(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GeodesicMesh {
+    /// Unique vertices of the triangulation, projected onto the sphere
+    pub vertices: Vec<Point>,
+    /// Triangles as indices into `vertices`
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl GeodesicMesh {
+    /// Builds a mesh from projected pool vertices and the triangles indexing them.
+    ///
+    /// Only vertices used by a triangle are kept, numbered in order of first use.
+    pub(crate) fn from_indexed(points: &[Point], triangles: &[[u32; 3]]) -> Self {
+        let mut vertices = Vec::new();
+        let mut remap = vec![usize::MAX; points.len()];
+
+        let triangles = triangles
+            .iter()
+            .map(|triangle| {
+                triangle.map(|index| {
+                    let slot = &mut remap[index as usize];
+                    if *slot == usize::MAX {
+                        *slot = vertices.len();
+                        vertices.push(points[index as usize].clone());
+                    }
+                    *slot
+                })
+            })
+            .collect();
+
+        Self {
+            vertices,
+            triangles,
+        }
+    }
+
+    /// Counts the unique undirected edges of the mesh.
+    ///
+    /// # Returns
+    ///
+    /// Number of distinct vertex pairs connected by at least one triangle side
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let (_, mesh) = Hexasphere::new_with_mesh(1.0, 0, 1.0);
+    /// assert_eq!(mesh.edge_count(), 30); // Icosahedron edges
+    /// ```
+    pub fn edge_count(&self) -> usize {
+        let mut edges = HashSet::new();
+        for triangle in &self.triangles {
+            for i in 0..3 {
+                let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+                edges.insert((a.min(b), a.max(b)));
+            }
+        }
+        edges.len()
+    }
+
+    /// Calculates the Euler characteristic V − E + F of the mesh.
+    ///
+    /// For any closed triangulation of a sphere this is exactly 2, so it is a
+    /// quick check that the mesh has no holes, duplicate vertices or stray faces.
+    ///
+    /// # Returns
+    ///
+    /// The Euler characteristic as a signed integer
+    pub fn euler_characteristic(&self) -> i64 {
+        self.vertices.len() as i64 - self.edge_count() as i64 + self.triangles.len() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_geodesic_mesh_euler_formula() {
+        for divisions in 0..=5 {
+            let (_, mesh) = Hexasphere::new_with_mesh(10.0, divisions, 1.0);
+            assert_eq!(
+                mesh.euler_characteristic(),
+                2,
+                "V - E + F should be 2 for {} divisions",
+                divisions
+            );
+            assert_eq!(mesh.edge_count() * 2, mesh.triangles.len() * 3);
+        }
+    }
+
+    #[test]
+    fn test_geodesic_mesh_matches_tiles() {
+        let (hexasphere, mesh) = Hexasphere::new_with_mesh(10.0, 3, 1.0);
+
+        assert_eq!(mesh.vertices.len(), hexasphere.tiles.len());
+        let vertices: HashSet<_> = mesh.vertices.iter().collect();
+        for tile in &hexasphere.tiles {
+            assert!(vertices.contains(&tile.center_point));
+        }
+
+        for vertex in &mesh.vertices {
+            let distance = (vertex.x.powi(2) + vertex.y.powi(2) + vertex.z.powi(2)).sqrt();
+            assert!((distance - 10.0).abs() < 0.01);
+        }
+        for triangle in &mesh.triangles {
+            assert!(triangle.iter().all(|&i| i < mesh.vertices.len()));
+            assert!(triangle[0] != triangle[1] && triangle[1] != triangle[2]);
+        }
+    }
+}
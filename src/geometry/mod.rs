@@ -4,9 +4,14 @@
 //! the geotiles library.
 
 pub mod face;
+pub mod mesh;
+mod ops;
 pub mod point;
+pub mod pool;
 pub mod vector;
 
 pub use face::Face;
+pub use mesh::GeodesicMesh;
 pub use point::Point;
+pub use pool::VertexPool;
 pub use vector::Vector3;
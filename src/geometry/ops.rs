@@ -0,0 +1,289 @@
+//! Arithmetic operators and conversions for `Point` and `Vector3`.
+//!
+//! # Rounding Policy
+//!
+//! Operators on `Point` build their result with `Point::new`, so results are
+//! rounded to 3 decimal places exactly like every other point in the crate.
+//! This keeps derived points hashable and comparable with points produced
+//! during construction.
+//!
+//! Operators on `Vector3` are never rounded. Use vectors for intermediate
+//! math (directions, normals, offsets) and convert back to a `Point` only for
+//! the final position.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use geotiles::{Point, Vector3};
+//! let a = Point::new(1.0, 2.0, 3.0);
+//! let b = Point::new(0.5, 0.5, 0.5);
+//!
+//! let offset = &a - &b;
+//! assert_eq!(offset, Point::new(0.5, 1.5, 2.5));
+//!
+//! // Unrounded direction math, then back to a rounded point
+//! let direction = Vector3::from(&a).normalize();
+//! let moved = Point::from(Vector3::from(&a) + direction * 2.0);
+//! ```
+
+use crate::geometry::{Point, Vector3};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+impl Add for &Point {
+    type Output = Point;
+
+    fn add(self, other: &Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        &self + &other
+    }
+}
+
+impl Sub for &Point {
+    type Output = Point;
+
+    fn sub(self, other: &Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        &self - &other
+    }
+}
+
+impl Neg for &Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        -&self
+    }
+}
+
+impl Mul<f64> for &Point {
+    type Output = Point;
+
+    fn mul(self, scalar: f64) -> Point {
+        Point::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Mul<f64> for Point {
+    type Output = Point;
+
+    fn mul(self, scalar: f64) -> Point {
+        &self * scalar
+    }
+}
+
+impl Div<f64> for &Point {
+    type Output = Point;
+
+    fn div(self, scalar: f64) -> Point {
+        Point::new(self.x / scalar, self.y / scalar, self.z / scalar)
+    }
+}
+
+impl Div<f64> for Point {
+    type Output = Point;
+
+    fn div(self, scalar: f64) -> Point {
+        &self / scalar
+    }
+}
+
+impl Add for &Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: &Vector3) -> Vector3 {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: Vector3) -> Vector3 {
+        &self + &other
+    }
+}
+
+impl Sub for &Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: &Vector3) -> Vector3 {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Vector3) -> Vector3 {
+        &self - &other
+    }
+}
+
+impl Neg for &Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        -&self
+    }
+}
+
+impl Mul<f64> for &Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, scalar: f64) -> Vector3 {
+        Vector3::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, scalar: f64) -> Vector3 {
+        &self * scalar
+    }
+}
+
+impl Div<f64> for &Vector3 {
+    type Output = Vector3;
+
+    fn div(self, scalar: f64) -> Vector3 {
+        Vector3::new(self.x / scalar, self.y / scalar, self.z / scalar)
+    }
+}
+
+impl Div<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn div(self, scalar: f64) -> Vector3 {
+        &self / scalar
+    }
+}
+
+impl From<&Point> for Vector3 {
+    /// Treats the point as a position vector from the origin (no rounding).
+    fn from(point: &Point) -> Self {
+        Vector3::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<Point> for Vector3 {
+    fn from(point: Point) -> Self {
+        Vector3::from(&point)
+    }
+}
+
+impl From<&Vector3> for Point {
+    /// Converts a position vector to a point, rounding like `Point::new`.
+    fn from(vector: &Vector3) -> Self {
+        Point::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for Point {
+    fn from(vector: Vector3) -> Self {
+        Point::from(&vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Point, Vector3};
+
+    #[test]
+    fn test_point_add_sub() {
+        let a = Point::new(1.0, 2.0, 3.0);
+        let b = Point::new(0.5, -1.0, 2.0);
+
+        assert_eq!(&a + &b, Point::new(1.5, 1.0, 5.0));
+        assert_eq!(&a - &b, Point::new(0.5, 3.0, 1.0));
+        assert_eq!(a.clone() + b.clone(), Point::new(1.5, 1.0, 5.0));
+        assert_eq!(a - b, Point::new(0.5, 3.0, 1.0));
+    }
+
+    #[test]
+    fn test_point_neg_mul_div() {
+        let a = Point::new(1.0, -2.0, 3.0);
+
+        assert_eq!(-&a, Point::new(-1.0, 2.0, -3.0));
+        assert_eq!(&a * 2.0, Point::new(2.0, -4.0, 6.0));
+        assert_eq!(&a / 2.0, Point::new(0.5, -1.0, 1.5));
+        assert_eq!(-(a * 3.0), Point::new(-3.0, 6.0, -9.0));
+    }
+
+    #[test]
+    fn test_point_operators_round_results() {
+        let a = Point::new(1.0, 1.0, 1.0);
+
+        // 1/3 is rounded to 3 decimals just like Point::new would do
+        let third = &a / 3.0;
+        assert_eq!(third.x, 0.333);
+        assert_eq!(third, Point::new(1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0));
+
+        let scaled = &Point::new(0.001, 0.0, 0.0) * 0.4;
+        assert_eq!(scaled.x, 0.0);
+    }
+
+    #[test]
+    fn test_vector_operators() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(0.5, 0.25, -1.0);
+
+        assert_eq!(&a + &b, Vector3::new(1.5, 2.25, 2.0));
+        assert_eq!(&a - &b, Vector3::new(0.5, 1.75, 4.0));
+        assert_eq!(-&a, Vector3::new(-1.0, -2.0, -3.0));
+        assert_eq!(&a * 2.0, Vector3::new(2.0, 4.0, 6.0));
+        assert_eq!(a.clone() / 4.0, Vector3::new(0.25, 0.5, 0.75));
+        assert_eq!(a + b, Vector3::new(1.5, 2.25, 2.0));
+    }
+
+    #[test]
+    fn test_vector_operators_do_not_round() {
+        let third = Vector3::new(1.0, 1.0, 1.0) / 3.0;
+        assert_eq!(third.x, 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let point = Point::new(1.5, -2.25, 3.125);
+        let vector = Vector3::from(&point);
+        assert_eq!(vector, Vector3::new(1.5, -2.25, 3.125));
+        assert_eq!(Point::from(vector), point);
+
+        // Converting back to a point applies the rounding policy
+        let precise = Vector3::new(0.12345, 0.0, 0.0);
+        assert_eq!(Point::from(&precise).x, 0.123);
+
+        assert_eq!(point.to_array(), [1.5, -2.25, 3.125]);
+        assert_eq!(Point::from_array([1.5, -2.25, 3.125]), point);
+    }
+}
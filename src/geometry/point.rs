@@ -1,6 +1,7 @@
 //! 3D point representation and operations.
 
-use crate::utils::LatLon;
+use crate::geometry::Vector3;
+use crate::utils::{slerp, LatLon};
 
 /// A point in 3D space with coordinates (x, y, z).
 ///
@@ -38,6 +39,13 @@ pub struct Point {
 }
 
 impl Point {
+    /// The origin, `(0, 0, 0)`.
+    pub const ORIGIN: Point = Point {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
     /// Creates a new point with the specified coordinates.
     ///
     /// Coordinates are automatically rounded to 3 decimal places for consistency
@@ -59,13 +67,69 @@ impl Point {
     /// assert_eq!(point.x, 1.235); // Rounded to 3 decimal places
     /// ```
     pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self::with_precision(x, y, z, 3) // Match JS precision
+    }
+
+    /// Creates a new point rounded to the given number of decimal places.
+    ///
+    /// `Point::new` always rounds to 3 decimals, which merges distinct points
+    /// on very small spheres. Construction uses this with the precision set by
+    /// [`crate::HexasphereBuilder::vertex_precision`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate
+    /// * `y` - Y-coordinate
+    /// * `z` - Z-coordinate
+    /// * `decimals` - Number of decimal places to keep
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geotiles::geometry::Point;
+    ///
+    /// let point = Point::with_precision(0.000123456, 1.5, -2.25, 6);
+    /// assert_eq!(point.x, 0.000123);
+    ///
+    /// // Three decimals is exactly `Point::new`
+    /// assert_eq!(Point::with_precision(1.23456, 0.0, 0.0, 3), Point::new(1.23456, 0.0, 0.0));
+    /// ```
+    pub fn with_precision(x: f64, y: f64, z: f64, decimals: u32) -> Self {
         Self {
-            x: (x * 1000.0).round() / 1000.0, // Match JS precision
-            y: (y * 1000.0).round() / 1000.0,
-            z: (z * 1000.0).round() / 1000.0,
+            x: round_coordinate(x, decimals),
+            y: round_coordinate(y, decimals),
+            z: round_coordinate(z, decimals),
         }
     }
 
+    /// Creates a point from an `[x, y, z]` array, rounding like `Point::new`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::geometry::Point;
+    /// let point = Point::from_array([1.0, 2.0, 3.0]);
+    /// assert_eq!(point, Point::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn from_array(coordinates: [f64; 3]) -> Self {
+        Self::new(coordinates[0], coordinates[1], coordinates[2])
+    }
+
+    /// Returns the coordinates as an `[x, y, z]` array.
+    ///
+    /// Handy for passing points to graphics and math libraries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::geometry::Point;
+    /// let point = Point::new(1.0, 2.0, 3.0);
+    /// assert_eq!(point.to_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    pub fn to_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
     /// Calculates the Euclidean distance between this point and another point.
     ///
     /// Uses the standard 3D distance formula: √((x₂-x₁)² + (y₂-y₁)² + (z₂-z₁)²)
@@ -168,11 +232,22 @@ impl Point {
     /// assert_eq!(boundary.x, 8.0); // 80% of the way from center to edge
     /// ```
     pub fn segment(&self, other: &Point, percent: f64) -> Point {
+        self.segment_with_precision(other, percent, 3)
+    }
+
+    /// Like [`Point::segment`], rounding the result to `decimals` places.
+    pub(crate) fn segment_with_precision(
+        &self,
+        other: &Point,
+        percent: f64,
+        decimals: u32,
+    ) -> Point {
         let percent = percent.clamp(0.0, 1.0);
-        Point::new(
+        Point::with_precision(
             self.x * (1.0 - percent) + other.x * percent,
             self.y * (1.0 - percent) + other.y * percent,
             self.z * (1.0 - percent) + other.z * percent,
+            decimals,
         )
     }
 
@@ -214,6 +289,75 @@ impl Point {
         self
     }
 
+    /// Interpolates along the great circle between the directions of two points.
+    ///
+    /// Unlike [`Point::segment`], which moves along the straight chord, the
+    /// result moves at constant angular speed and always lies on the sphere of
+    /// the given radius. Built on [`crate::utils::slerp`], including its
+    /// handling of nearly identical and nearly antipodal directions.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - End point; only its direction matters
+    /// * `t` - Interpolation factor (0.0 = this direction, 1.0 = `other`'s)
+    /// * `radius` - Distance of the result from the origin
+    ///
+    /// # Returns
+    ///
+    /// The interpolated point, exactly `radius` from the origin and not
+    /// rounded to 3 decimals like [`Point::new`]. Both points must lie away
+    /// from the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Point;
+    /// let a = Point::new(1.0, 0.0, 0.0);
+    /// let b = Point::new(0.0, 0.0, 2.0);
+    ///
+    /// let mid = a.slerp(&b, 0.5, 10.0);
+    /// assert!((mid.x - mid.z).abs() < 1e-12);
+    /// assert!((mid.x.hypot(mid.z) - 10.0).abs() < 1e-12);
+    /// ```
+    pub fn slerp(&self, other: &Point, t: f64, radius: f64) -> Point {
+        let unit = slerp(&Vector3::from(self), &Vector3::from(other), t);
+        Point {
+            x: unit.x * radius,
+            y: unit.y * radius,
+            z: unit.z * radius,
+        }
+    }
+
+    /// Calculates the angle between the directions of two points from the origin.
+    ///
+    /// Uses `atan2(|a × b|, a · b)`, which stays accurate for tiny and nearly
+    /// straight angles where `acos` of the dot product loses precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other point
+    ///
+    /// # Returns
+    ///
+    /// The angle in radians in `[0, π]`; multiplied by the radius it gives the
+    /// great-circle distance. 0.0 if either point is at the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Point;
+    /// let a = Point::new(10.0, 0.0, 0.0);
+    /// let b = Point::new(0.0, 10.0, 0.0);
+    ///
+    /// let angle = a.angle_between(&b);
+    /// assert!((angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    pub fn angle_between(&self, other: &Point) -> f64 {
+        let (a, b) = (Vector3::from(self), Vector3::from(other));
+        let cross = a.cross(&b);
+        cross.dot(&cross).sqrt().atan2(a.dot(&b))
+    }
+
     /// Converts this 3D point to latitude and longitude coordinates.
     ///
     /// Assumes the point lies on a sphere centered at the origin. Uses spherical coordinate
@@ -253,6 +397,15 @@ impl Point {
     }
 }
 
+/// Rounds to `decimals` decimal places, turning `-0.0` into `0.0`.
+///
+/// Points hash by their string form, so a negative zero would make two equal
+/// points print (and hash) as `-0` and `0` and break deduplication.
+pub(crate) fn round_coordinate(value: f64, decimals: u32) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    (value * scale).round() / scale + 0.0
+}
+
 impl std::fmt::Display for Point {
     /// Formats the point as a comma-separated string of coordinates.
     ///
@@ -552,4 +705,70 @@ mod tests {
         assert!(debug_string.contains("2"));
         assert!(debug_string.contains("3"));
     }
+
+    #[test]
+    fn test_negative_zero_is_normalized() {
+        let point = Point::new(-0.0, -0.0001, 0.0);
+        let zero = Point::new(0.0, 0.0, 0.0);
+
+        assert!(point.x.is_sign_positive());
+        assert!(point.y.is_sign_positive());
+        assert_eq!(point.to_string(), zero.to_string());
+    }
+
+    #[test]
+    fn test_slerp_degenerate_directions() {
+        let a = Point::new(3.0, 0.0, 0.0);
+
+        // Identical directions stay put
+        let same = a.slerp(&Point::new(1.0, 0.0, 0.0), 0.3, 2.0);
+        assert!(same.distance_to(&Point::new(2.0, 0.0, 0.0)) < 1e-12);
+
+        // Antipodal directions take a stable half circle without NaN
+        let b = Point::new(-1.0, 0.0, 0.0);
+        let mid = a.slerp(&b, 0.5, 1.0);
+        assert!(mid.x.abs() < 1e-12);
+        assert!((mid.angle_between(&a) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(mid, a.slerp(&b, 0.5, 1.0));
+        assert!(a.slerp(&b, 1.0, 1.0).distance_to(&b) < 1e-12);
+    }
+
+    #[test]
+    fn test_angle_between_edge_cases() {
+        let a = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(a.angle_between(&a), 0.0);
+        assert_eq!(a.angle_between(&Point::new(0.0, 0.0, 0.0)), 0.0);
+        let opposite = Point::new(-2.0, -4.0, -6.0);
+        assert!((a.angle_between(&opposite) - std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    fn direction() -> impl proptest::strategy::Strategy<Value = Point> {
+        use proptest::prelude::*;
+        (-1.0..1.0f64, -1.0..1.0f64, -1.0..1.0f64)
+            .prop_filter("away from the origin", |(x, y, z)| {
+                x * x + y * y + z * z > 0.01
+            })
+            .prop_map(|(x, y, z)| Point { x, y, z })
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_slerp_endpoints_and_radius(a in direction(), b in direction(), t in 0.0..=1.0f64) {
+            let radius = 7.5;
+            let length = |p: &Point| (p.x * p.x + p.y * p.y + p.z * p.z).sqrt();
+
+            let start = a.slerp(&b, 0.0, radius);
+            let end = a.slerp(&b, 1.0, radius);
+            proptest::prop_assert!(start.angle_between(&a) < 1e-7);
+            proptest::prop_assert!(end.angle_between(&b) < 1e-7);
+            proptest::prop_assert!((length(&a.slerp(&b, t, radius)) - radius).abs() < 1e-12);
+        }
+
+        #[test]
+        fn prop_slerp_moves_at_constant_angular_speed(a in direction(), b in direction(), t in 0.0..=1.0f64) {
+            let total = a.angle_between(&b);
+            let partial = a.angle_between(&a.slerp(&b, t, 1.0));
+            proptest::prop_assert!((partial - t * total).abs() < 1e-7, "{} vs {}", partial, t * total);
+        }
+    }
 }
@@ -0,0 +1,117 @@
+//! Deduplicated vertex storage addressed by index.
+
+use crate::geometry::Point;
+use std::collections::HashMap;
+
+/// A growable list of unique points, each identified by a `u32` index.
+///
+/// Construction stores every subdivided triangle as three indices into one
+/// shared pool instead of three owned [`Point`]s. Points are deduplicated the
+/// same way as [`crate::utils::get_or_insert_point`]: by their rounded
+/// coordinates.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Point, VertexPool};
+/// let mut pool = VertexPool::new();
+///
+/// let a = pool.insert(Point::new(1.0, 0.0, 0.0));
+/// let b = pool.insert(Point::new(0.0, 1.0, 0.0));
+/// let again = pool.insert(Point::new(1.0, 0.0, 0.0));
+///
+/// assert_eq!(a, again);
+/// assert_ne!(a, b);
+/// assert_eq!(pool.len(), 2);
+/// assert_eq!(pool.get(b), &Point::new(0.0, 1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VertexPool {
+    points: Vec<Point>,
+    lookup: HashMap<Point, u32>,
+}
+
+impl VertexPool {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of `point`, adding it to the pool if it is new.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to look up or store
+    ///
+    /// # Returns
+    ///
+    /// The index of the stored point with the same rounded coordinates
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pool would grow beyond `u32::MAX` points.
+    pub fn insert(&mut self, point: Point) -> u32 {
+        if let Some(&index) = self.lookup.get(&point) {
+            return index;
+        }
+
+        let index = u32::try_from(self.points.len()).expect("vertex pool exceeds u32 indices");
+        self.lookup.insert(point.clone(), index);
+        self.points.push(point);
+        index
+    }
+
+    /// Returns the point stored at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` was not returned by this pool.
+    pub fn get(&self, index: u32) -> &Point {
+        &self.points[index as usize]
+    }
+
+    /// Returns the number of unique points.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if the pool holds no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns all points in insertion order.
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Consumes the pool, returning its points in insertion order.
+    pub fn into_points(self) -> Vec<Point> {
+        self.points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VertexPool;
+    use crate::geometry::Point;
+
+    #[test]
+    fn test_pool_deduplicates_rounded_points() {
+        let mut pool = VertexPool::new();
+        assert!(pool.is_empty());
+
+        let a = pool.insert(Point::new(1.0, 2.0, 3.0));
+        let b = pool.insert(Point::new(1.0001, 2.0, 3.0));
+        let c = pool.insert(Point::new(3.0, 2.0, 1.0));
+
+        assert_eq!(a, 0);
+        assert_eq!(b, a);
+        assert_eq!(c, 1);
+        assert_eq!(
+            pool.points(),
+            &[Point::new(1.0, 2.0, 3.0), Point::new(3.0, 2.0, 1.0)]
+        );
+        assert_eq!(pool.into_points().len(), 2);
+    }
+}
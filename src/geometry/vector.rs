@@ -1,5 +1,8 @@
 //! 3D vector operations for coordinate systems and transformations.
 
+/// Lengths at or below this have no usable direction in [`Vector3::try_normalize`]
+const NORMALIZE_EPSILON: f64 = 1e-12;
+
 /// A 3D vector with normalization and cross product operations.
 ///
 /// Used for calculating orientations, surface normals, and coordinate system transformations.
@@ -39,7 +42,8 @@ impl Vector3 {
     /// Returns a normalized (unit) vector in the same direction.
     ///
     /// A normalized vector has a magnitude of 1.0 while preserving direction.
-    /// If the vector has zero magnitude, returns a zero vector.
+    /// If the vector has zero magnitude, returns a zero vector; use
+    /// [`Vector3::try_normalize`] to detect that case.
     ///
     /// # Returns
     ///
@@ -63,6 +67,35 @@ impl Vector3 {
         }
     }
 
+    /// Normalizes the vector, or returns `None` if it has no usable direction.
+    ///
+    /// Unlike [`Vector3::normalize`], which silently turns a zero vector into
+    /// another zero vector, this reports vectors with a length of at most
+    /// `1e-12` and vectors with NaN or infinite components.
+    ///
+    /// # Returns
+    ///
+    /// A unit vector in the same direction, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// let unit = Vector3::new(0.0, 3.0, 4.0).try_normalize().unwrap();
+    /// assert!((unit.length() - 1.0).abs() < 1e-12);
+    ///
+    /// assert!(Vector3::new(0.0, 0.0, 0.0).try_normalize().is_none());
+    /// assert!(Vector3::new(1e-13, 0.0, 0.0).try_normalize().is_none());
+    /// ```
+    pub fn try_normalize(&self) -> Option<Self> {
+        let mag = self.length();
+        if mag.is_finite() && mag > NORMALIZE_EPSILON {
+            Some(Self::new(self.x / mag, self.y / mag, self.z / mag))
+        } else {
+            None
+        }
+    }
+
     /// Calculates the cross product with another vector.
     ///
     /// The cross product produces a vector perpendicular to both input vectors,
@@ -126,4 +159,241 @@ impl Vector3 {
     pub fn dot(&self, other: &Self) -> f64 {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
+
+    /// Calculates the length (magnitude) of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// assert_eq!(Vector3::new(3.0, 4.0, 0.0).length(), 5.0);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    /// Calculates the squared length of the vector.
+    ///
+    /// Cheaper than [`Vector3::length`] as it skips the square root; use it
+    /// to compare lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// assert_eq!(Vector3::new(1.0, 2.0, 2.0).length_squared(), 9.0);
+    /// ```
+    pub fn length_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Calculates the distance between the tips of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// let a = Vector3::new(1.0, 1.0, 1.0);
+    /// let b = Vector3::new(1.0, 4.0, 5.0);
+    /// assert_eq!(a.distance_to(&b), 5.0);
+    /// ```
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        (other - self).length()
+    }
+
+    /// Calculates the angle between two vectors.
+    ///
+    /// Uses `atan2(|a × b|, a · b)`, which stays accurate for tiny and nearly
+    /// straight angles where `acos` of the normalized dot product does not.
+    ///
+    /// # Returns
+    ///
+    /// The angle in radians in `[0, π]`, or 0.0 if either vector is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// let x = Vector3::new(2.0, 0.0, 0.0);
+    /// let diagonal = Vector3::new(1.0, 1.0, 0.0);
+    /// assert!((x.angle_to(&diagonal) - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    /// ```
+    pub fn angle_to(&self, other: &Self) -> f64 {
+        self.cross(other).length().atan2(self.dot(other))
+    }
+
+    /// Projects this vector onto the line along `other`.
+    ///
+    /// # Returns
+    ///
+    /// The component of `self` parallel to `other`, or the zero vector if
+    /// `other` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// let v = Vector3::new(3.0, 4.0, 5.0);
+    /// let up = Vector3::new(0.0, 2.0, 0.0);
+    /// assert_eq!(v.project_onto(&up), Vector3::new(0.0, 4.0, 0.0));
+    /// ```
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let length_squared = other.length_squared();
+        if length_squared == 0.0 {
+            return Self::new(0.0, 0.0, 0.0);
+        }
+        other * (self.dot(other) / length_squared)
+    }
+
+    /// Removes the component of this vector along `other`.
+    ///
+    /// With `other` a surface normal this is the projection into the tangent
+    /// plane, as used to measure angles around a point on the sphere.
+    ///
+    /// # Returns
+    ///
+    /// The component of `self` perpendicular to `other`; `self` unchanged if
+    /// `other` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// let v = Vector3::new(3.0, 4.0, 5.0);
+    /// let up = Vector3::new(0.0, 2.0, 0.0);
+    /// assert_eq!(v.reject_from(&up), Vector3::new(3.0, 0.0, 5.0));
+    /// ```
+    pub fn reject_from(&self, other: &Self) -> Self {
+        self - &self.project_onto(other)
+    }
+
+    /// Linearly interpolates between two vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - End vector
+    /// * `t` - Interpolation factor (0.0 = `self`, 1.0 = `other`); not clamped
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// let a = Vector3::new(0.0, 0.0, 0.0);
+    /// let b = Vector3::new(2.0, 4.0, 6.0);
+    /// assert_eq!(a.lerp(&b, 0.5), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        Self::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.z + (other.z - self.z) * t,
+        )
+    }
+
+    /// Returns true if no component is NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Vector3;
+    /// assert!(Vector3::new(1.0, 2.0, 3.0).is_finite());
+    /// assert!(!Vector3::new(f64::NAN, 0.0, 0.0).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vector3;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn zero() -> Vector3 {
+        Vector3::new(0.0, 0.0, 0.0)
+    }
+
+    #[test]
+    fn test_length_and_distance() {
+        let v = Vector3::new(2.0, -3.0, 6.0);
+        assert_eq!(v.length_squared(), 49.0);
+        assert_eq!(v.length(), 7.0);
+        assert_eq!(zero().length(), 0.0);
+        assert_eq!(v.distance_to(&v), 0.0);
+        assert_eq!(zero().distance_to(&v), 7.0);
+    }
+
+    #[test]
+    fn test_angle_to() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        assert_eq!(x.angle_to(&x), 0.0);
+        assert!((x.angle_to(&Vector3::new(0.0, 0.0, 5.0)) - FRAC_PI_2).abs() < 1e-12);
+        assert!((x.angle_to(&Vector3::new(-3.0, 0.0, 0.0)) - PI).abs() < 1e-12);
+
+        // Tiny angles keep full precision
+        let nudged = Vector3::new(1.0, 1e-9, 0.0);
+        assert!((x.angle_to(&nudged) - 1e-9).abs() < 1e-20);
+
+        assert_eq!(x.angle_to(&zero()), 0.0);
+        assert_eq!(zero().angle_to(&zero()), 0.0);
+    }
+
+    #[test]
+    fn test_project_and_reject_split_the_vector() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let axis = Vector3::new(1.0, 1.0, 0.0);
+        let parallel = v.project_onto(&axis);
+        let perpendicular = v.reject_from(&axis);
+
+        assert!(parallel.cross(&axis).length() < 1e-12);
+        assert!(perpendicular.dot(&axis).abs() < 1e-12);
+        assert!((&parallel + &perpendicular).distance_to(&v) < 1e-12);
+    }
+
+    #[test]
+    fn test_try_normalize_rejects_degenerate_vectors() {
+        let unit = Vector3::new(-2.0, 0.0, 0.0).try_normalize().unwrap();
+        assert_eq!(unit, Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(
+            Vector3::new(1e-9, 0.0, 0.0).try_normalize(),
+            Some(Vector3::new(1.0, 0.0, 0.0))
+        );
+
+        assert!(zero().try_normalize().is_none());
+        assert!(Vector3::new(1e-13, -1e-13, 0.0).try_normalize().is_none());
+        assert!(Vector3::new(f64::NAN, 1.0, 0.0).try_normalize().is_none());
+        assert!(Vector3::new(f64::INFINITY, 0.0, 0.0)
+            .try_normalize()
+            .is_none());
+    }
+
+    #[test]
+    fn test_zero_vectors_do_not_produce_nan() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.project_onto(&zero()), zero());
+        assert_eq!(v.reject_from(&zero()), v);
+        assert_eq!(zero().project_onto(&v), zero());
+        assert_eq!(zero().reject_from(&v), zero());
+        for result in [
+            v.project_onto(&zero()),
+            v.reject_from(&zero()),
+            zero().lerp(&v, 0.5),
+        ] {
+            assert!(result.is_finite());
+        }
+        assert!(v.angle_to(&zero()).is_finite());
+    }
+
+    #[test]
+    fn test_lerp_and_is_finite() {
+        let a = Vector3::new(1.0, 0.0, -1.0);
+        let b = Vector3::new(3.0, 2.0, 1.0);
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 1.5), Vector3::new(4.0, 3.0, 2.0));
+
+        assert!(!Vector3::new(0.0, f64::INFINITY, 0.0).is_finite());
+        assert!(!Vector3::new(0.0, 0.0, f64::NAN).is_finite());
+    }
 }
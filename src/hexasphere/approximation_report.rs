@@ -0,0 +1,342 @@
+//! How far regular hexagons are from the tiles they replace.
+
+use crate::geometry::Vector3;
+use crate::hexasphere::core::Hexasphere;
+use crate::tile::core::Tile;
+use std::f64::consts::PI;
+
+/// Approximation error of a single hexagonal tile.
+///
+/// See [`Hexasphere::approximation_error_report`] for how each value is
+/// measured. Lengths are in the same units as the sphere radius.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileApproximationError {
+    /// Index of the tile in `Hexasphere::tiles`
+    pub index: usize,
+    /// Rotation of the fitted hexagon's first corner away from the tile
+    /// orientation's `right` vector, in radians, counter-clockwise seen from
+    /// outside the sphere
+    pub rotation: f64,
+    /// Largest distance between a corner of the fitted regular hexagon and
+    /// the matching boundary point of the tile
+    pub max_vertex_displacement: f64,
+    /// Area of the fitted regular hexagon divided by the tile's area
+    pub area_ratio: f64,
+    /// Smallest signed gap to a hexagonal neighbor when every hexagon is
+    /// replaced by one of the uniform radius; negative values are overlaps
+    pub min_neighbor_gap: f64,
+    /// Largest signed gap to a hexagonal neighbor, see `min_neighbor_gap`
+    pub max_neighbor_gap: f64,
+}
+
+/// How well regular hexagons approximate the hexagonal tiles.
+///
+/// Produced by [`Hexasphere::approximation_error_report`]. Aggregates cover
+/// every entry of `tiles`; pentagons are not part of the report.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let report = Hexasphere::new(10.0, 4, 1.0).approximation_error_report();
+/// println!(
+///     "corners move up to {:.1}% of the radius, neighbors overlap by up to {:.4}",
+///     100.0 * report.max_vertex_displacement / report.uniform_radius,
+///     -report.min_neighbor_gap,
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApproximationReport {
+    /// One entry per hexagonal tile, in tile order
+    pub tiles: Vec<TileApproximationError>,
+    /// Radius used for the uniform hexagons ([`Hexasphere::get_uniform_hexagon_radius`])
+    pub uniform_radius: f64,
+    /// Largest corner displacement over all tiles
+    pub max_vertex_displacement: f64,
+    /// Mean of the per-tile largest corner displacements
+    pub mean_vertex_displacement: f64,
+    /// Smallest area ratio over all tiles
+    pub min_area_ratio: f64,
+    /// Largest area ratio over all tiles
+    pub max_area_ratio: f64,
+    /// Smallest signed gap between uniform hexagons; negative is the worst overlap
+    pub min_neighbor_gap: f64,
+    /// Largest signed gap between uniform hexagons
+    pub max_neighbor_gap: f64,
+    /// Mean signed gap over all pairs of neighboring hexagons
+    pub mean_neighbor_gap: f64,
+}
+
+/// A regular hexagon rotated to best match a tile, in the tile's tangent plane.
+struct FittedHexagon {
+    center: Vector3,
+    /// Tangent basis, counter-clockwise seen from outside like the boundary
+    axes: (Vector3, Vector3),
+    rotation: f64,
+}
+
+impl FittedHexagon {
+    fn new(tile: &Tile) -> Option<Self> {
+        let orientation = tile.get_orientation()?;
+        let first = orientation.right;
+        let second = orientation.up.cross(&first).normalize();
+
+        // The boundary sags below the tangent plane at the center point;
+        // lower the hexagon to the boundary's average height
+        let surface = Vector3::from(&tile.center_point);
+        let sag = tile
+            .boundary
+            .iter()
+            .map(|point| (Vector3::from(point) - surface.clone()).dot(&orientation.up))
+            .sum::<f64>()
+            / tile.boundary.len() as f64;
+        let center = &surface + &(&orientation.up * sag);
+
+        // Least-squares rotation: the argument of Σ z_k e^(-ikπ/3) for the
+        // boundary points z_k in tangent-plane complex coordinates
+        let (mut re, mut im) = (0.0, 0.0);
+        for (k, point) in tile.boundary.iter().enumerate() {
+            let offset = Vector3::from(point) - center.clone();
+            let (x, y) = (offset.dot(&first), offset.dot(&second));
+            let (sin, cos) = (k as f64 * PI / 3.0).sin_cos();
+            re += x * cos + y * sin;
+            im += y * cos - x * sin;
+        }
+
+        Some(Self {
+            center,
+            axes: (first, second),
+            rotation: im.atan2(re),
+        })
+    }
+
+    /// Corner `k` of the fitted hexagon with the given radius.
+    fn corner(&self, k: usize, radius: f64) -> Vector3 {
+        let (sin, cos) = (self.rotation + k as f64 * PI / 3.0).sin_cos();
+        let (first, second) = &self.axes;
+        &self.center + &(first * (radius * cos) + second * (radius * sin))
+    }
+
+    /// How far `target` lies beyond the hexagon's edge, within the hexagon's
+    /// plane; negative when the hexagon reaches past it.
+    fn shortfall(&self, target: &Vector3, radius: f64) -> f64 {
+        let (first, second) = &self.axes;
+        let offset = target - &self.center;
+        let (x, y) = (offset.dot(first), offset.dot(second));
+        // Angle away from the nearest apothem, which is π/6 past a corner
+        let off_apothem = (y.atan2(x) - self.rotation).rem_euclid(PI / 3.0) - PI / 6.0;
+        x.hypot(y) - radius * 3f64.sqrt() / 2.0 / off_apothem.cos()
+    }
+}
+
+impl Hexasphere {
+    /// Measures how far regular hexagons are from the hexagonal tiles.
+    ///
+    /// Rendering every hexagon as a regular one (see
+    /// [`Tile::get_regular_hexagon_params`] and
+    /// [`Hexasphere::get_uniform_hexagon_radius`]) trades accuracy for
+    /// simplicity. This report quantifies the trade.
+    ///
+    /// # Measurements
+    ///
+    /// For each hexagonal tile, a regular hexagon with the tile's average
+    /// radius is fitted parallel to the tangent plane, at the average height
+    /// of the boundary points (which lie slightly below the tile center), and
+    /// rotated by the angle that minimizes the squared distances between its
+    /// corners and the boundary points. The report then gives:
+    ///
+    /// - **Vertex displacement**: The largest corner-to-boundary-point distance
+    /// - **Area ratio**: Regular hexagon area over [`Tile::get_area`]
+    /// - **Neighbor gap**: With every hexagon at the uniform radius instead,
+    ///   how far the two hexagons on either side of a shared edge fall short
+    ///   of its midpoint, added together. Positive values are gaps, negative
+    ///   values overlaps. Only edges between two hexagons are measured, since
+    ///   pentagons cannot be replaced by hexagons.
+    ///
+    /// # Returns
+    ///
+    /// An [`ApproximationReport`] with one entry per hexagon and aggregates.
+    /// Aggregates of an empty report (no hexagons) are 0.0.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Design decisions**: Is the uniform-hexagon rendering path good enough?
+    /// - **Subdivision choice**: Absolute errors shrink as tiles get smaller
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n×m) where n = tile count, m = average boundary points
+    /// - Space complexity: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let report = hexasphere.approximation_error_report();
+    ///
+    /// assert_eq!(report.tiles.len(), hexasphere.hexagon_count());
+    /// assert!(report.min_area_ratio <= report.max_area_ratio);
+    /// assert!(report.min_neighbor_gap <= report.max_neighbor_gap);
+    /// ```
+    pub fn approximation_error_report(&self) -> ApproximationReport {
+        let uniform_radius = self.get_uniform_hexagon_radius();
+        let fits: Vec<Option<FittedHexagon>> = self
+            .tiles
+            .iter()
+            .map(|tile| {
+                if tile.is_hexagon() {
+                    FittedHexagon::new(tile)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Signed gap across every edge shared by two hexagons
+        let mut gaps: Vec<Vec<f64>> = vec![Vec::new(); self.tiles.len()];
+        let mut gap_sum = 0.0;
+        let mut gap_count = 0;
+        for (index, tile) in self.tiles.iter().enumerate() {
+            let Some(fit) = &fits[index] else { continue };
+            let sides = tile.boundary.len();
+            for (edge, &neighbor) in tile.neighbors.iter().enumerate().take(sides) {
+                let Some(Some(other)) = fits.get(neighbor).filter(|_| neighbor > index) else {
+                    continue;
+                };
+                // neighbors[edge] lies across the edge from boundary[edge]
+                let start = Vector3::from(&tile.boundary[edge]);
+                let end = Vector3::from(&tile.boundary[(edge + 1) % sides]);
+                let midpoint = (start + end) * 0.5;
+                let gap = fit.shortfall(&midpoint, uniform_radius)
+                    + other.shortfall(&midpoint, uniform_radius);
+
+                gaps[index].push(gap);
+                gaps[neighbor].push(gap);
+                gap_sum += gap;
+                gap_count += 1;
+            }
+        }
+
+        let tiles: Vec<TileApproximationError> = fits
+            .iter()
+            .enumerate()
+            .filter_map(|(index, fit)| {
+                let fit = fit.as_ref()?;
+                let tile = &self.tiles[index];
+                let radius = tile.get_average_radius();
+
+                let max_vertex_displacement = tile
+                    .boundary
+                    .iter()
+                    .enumerate()
+                    .map(|(k, point)| fit.corner(k, radius).distance_to(&Vector3::from(point)))
+                    .fold(0.0, f64::max);
+                let regular_area = 1.5 * 3f64.sqrt() * radius * radius;
+                let (min_gap, max_gap) = min_max(&gaps[index]);
+
+                Some(TileApproximationError {
+                    index,
+                    rotation: fit.rotation,
+                    max_vertex_displacement,
+                    area_ratio: regular_area / tile.get_area(),
+                    min_neighbor_gap: min_gap,
+                    max_neighbor_gap: max_gap,
+                })
+            })
+            .collect();
+
+        let displacements: Vec<f64> = tiles.iter().map(|t| t.max_vertex_displacement).collect();
+        let area_ratios: Vec<f64> = tiles.iter().map(|t| t.area_ratio).collect();
+        let all_gaps: Vec<f64> = gaps.into_iter().flatten().collect();
+        let (min_area_ratio, max_area_ratio) = min_max(&area_ratios);
+        let (min_neighbor_gap, max_neighbor_gap) = min_max(&all_gaps);
+
+        ApproximationReport {
+            uniform_radius,
+            max_vertex_displacement: min_max(&displacements).1,
+            mean_vertex_displacement: mean(&displacements),
+            min_area_ratio,
+            max_area_ratio,
+            min_neighbor_gap,
+            max_neighbor_gap,
+            mean_neighbor_gap: if gap_count == 0 {
+                0.0
+            } else {
+                gap_sum / gap_count as f64
+            },
+            tiles,
+        }
+    }
+}
+
+/// Smallest and largest value, or `(0.0, 0.0)` when empty.
+fn min_max(values: &[f64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+/// Arithmetic mean, or 0.0 when empty.
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_report_covers_every_hexagon() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let report = hexasphere.approximation_error_report();
+
+        assert_eq!(report.tiles.len(), hexasphere.hexagon_count());
+        for entry in &report.tiles {
+            assert!(hexasphere.tiles[entry.index].is_hexagon());
+            assert!(entry.max_vertex_displacement <= report.max_vertex_displacement);
+            assert!(entry.min_neighbor_gap >= report.min_neighbor_gap);
+            assert!(entry.max_neighbor_gap <= report.max_neighbor_gap);
+        }
+        assert_eq!(
+            report.uniform_radius,
+            hexasphere.get_uniform_hexagon_radius()
+        );
+    }
+
+    #[test]
+    fn test_errors_shrink_with_subdivision() {
+        let coarse = Hexasphere::new(10.0, 2, 1.0).approximation_error_report();
+        let fine = Hexasphere::new(10.0, 4, 1.0).approximation_error_report();
+
+        assert!(fine.max_vertex_displacement < coarse.max_vertex_displacement);
+        assert!(fine.mean_vertex_displacement < coarse.mean_vertex_displacement);
+        assert!(fine.mean_neighbor_gap.abs() < coarse.mean_neighbor_gap.abs());
+        assert!(
+            (fine.max_area_ratio - 1.0).abs() < (coarse.max_area_ratio - 1.0).abs(),
+            "{} vs {}",
+            fine.max_area_ratio,
+            coarse.max_area_ratio
+        );
+    }
+
+    #[test]
+    fn test_fitted_rotation_is_small_for_aligned_tiles() {
+        // get_orientation aims at the first boundary point, so the fitted
+        // rotation only corrects the spread of the remaining corners
+        let report = Hexasphere::new(10.0, 4, 1.0).approximation_error_report();
+        for entry in &report.tiles {
+            assert!(entry.rotation.abs() < 0.2, "tile {}", entry.index);
+            assert!(entry.area_ratio > 0.9 && entry.area_ratio < 1.1);
+        }
+    }
+}
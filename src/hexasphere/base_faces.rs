@@ -0,0 +1,207 @@
+//! Grouping tiles by the face of the base icosahedron they lie on.
+//!
+//! Every hexasphere is built by subdividing the 20 faces of an icosahedron
+//! whose corners become the 12 pentagons. The base faces are recovered from
+//! the pentagons alone, so the grouping is the same for spheres that were
+//! built, refined, rotated or imported.
+
+use crate::geometry::Vector3;
+use crate::hexasphere::core::Hexasphere;
+
+/// Distance from a face's edge planes within which a unit direction counts
+/// as lying on the edge; well above the rounding of pooled vertices and well
+/// below the spacing of tile centers
+const EDGE_TOLERANCE: f64 = 1e-6;
+
+impl Hexasphere {
+    /// Groups the tiles by the base icosahedron face their centers lie on.
+    ///
+    /// # Face Numbering
+    ///
+    /// Each base face has three pentagons as corners. Faces are numbered by
+    /// sorting their corners' tile ids, lowest first, and then ordering the
+    /// faces lexicographically by those triples. Since a refined sphere
+    /// keeps the pentagon centers in the same order, coarse and refined
+    /// spheres number their faces alike.
+    ///
+    /// # Tie-Breaks
+    ///
+    /// A center on the edge shared by two faces, or on a corner shared by
+    /// five faces (every pentagon), belongs to the face with the lowest id.
+    ///
+    /// # Returns
+    ///
+    /// 20 lists of tile ids in ascending order, indexed by face id; each tile
+    /// appears in exactly one list, the one given by its `base_face`. A sphere
+    /// without exactly 12 pentagons puts every tile in face 0.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Paging**: Load and unload the sphere in 20 equal chunks
+    /// - **Culling**: Skip faces pointing away from the camera
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n), reading the `base_face` assigned at construction
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let faces = hexasphere.tiles_by_base_face();
+    ///
+    /// let total: usize = faces.iter().map(Vec::len).sum();
+    /// assert_eq!(total, hexasphere.tiles.len());
+    /// assert!(faces[7].iter().all(|&id| hexasphere.tiles[id].base_face == 7));
+    /// ```
+    pub fn tiles_by_base_face(&self) -> [Vec<usize>; 20] {
+        let mut faces: [Vec<usize>; 20] = Default::default();
+        for tile in &self.tiles {
+            faces[usize::from(tile.base_face).min(19)].push(tile.id);
+        }
+        faces
+    }
+
+    /// Sets every tile's `base_face` from the positions of the pentagons.
+    pub(crate) fn assign_base_faces(&mut self) {
+        let directions: Vec<Vector3> = self
+            .pentagon_indices()
+            .iter()
+            .map(|&id| self.relative(&self.tiles[id].center_point).normalize())
+            .collect();
+        let faces = base_face_corners(&directions);
+        for tile in &mut self.tiles {
+            let direction = Vector3::from(&tile.relative(&tile.center_point)).normalize();
+            tile.base_face = base_face_containing(&faces, &direction);
+        }
+    }
+}
+
+/// Corner directions of the 20 base faces in face id order, each
+/// counter-clockwise seen from outside, given the directions of the pentagon
+/// centers in tile id order; empty without 12 pentagons.
+pub(crate) fn base_face_corners(directions: &[Vector3]) -> Vec<[Vector3; 3]> {
+    if directions.len() != 12 {
+        return Vec::new();
+    }
+
+    // On an icosahedron each corner is joined to its five nearest corners
+    let adjacent: Vec<Vec<usize>> = (0..12)
+        .map(|i| {
+            let mut others: Vec<usize> = (0..12).filter(|&j| j != i).collect();
+            others.sort_by(|&a, &b| {
+                let (da, db) = (
+                    directions[i].dot(&directions[a]),
+                    directions[i].dot(&directions[b]),
+                );
+                db.total_cmp(&da)
+            });
+            others.truncate(5);
+            others
+        })
+        .collect();
+
+    // Pentagon ids are ascending, so positions order faces like their tile ids
+    let mut faces = Vec::with_capacity(20);
+    for i in 0..12 {
+        for &j in adjacent[i].iter().filter(|&&j| j > i) {
+            for &k in adjacent[j].iter().filter(|&&k| k > j) {
+                if adjacent[i].contains(&k) {
+                    faces.push([i, j, k]);
+                }
+            }
+        }
+    }
+    faces.sort_unstable();
+
+    faces
+        .into_iter()
+        .map(|[i, j, k]| {
+            let [a, b, c] = [i, j, k].map(|index| directions[index].clone());
+            if a.cross(&b).dot(&c) < 0.0 {
+                [a, c, b]
+            } else {
+                [a, b, c]
+            }
+        })
+        .collect()
+}
+
+/// Lowest id among the faces containing `direction`, or the face it is
+/// nearest to inside of if rounding leaves it in none.
+pub(crate) fn base_face_containing(faces: &[[Vector3; 3]], direction: &Vector3) -> u8 {
+    let inside_by = |[a, b, c]: &[Vector3; 3]| {
+        [(a, b), (b, c), (c, a)]
+            .map(|(from, to)| from.cross(to).normalize().dot(direction))
+            .into_iter()
+            .fold(f64::INFINITY, f64::min)
+    };
+
+    let margins: Vec<f64> = faces.iter().map(inside_by).collect();
+    let face = margins
+        .iter()
+        .position(|&margin| margin >= -EDGE_TOLERANCE)
+        .or_else(|| (0..margins.len()).max_by(|&a, &b| margins[a].total_cmp(&margins[b])))
+        .unwrap_or(0);
+    face as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_base_faces_partition_tiles() {
+        for divisions in [1, 2, 6] {
+            let hexasphere = Hexasphere::new(10.0, divisions, 1.0);
+            let faces = hexasphere.tiles_by_base_face();
+
+            let mut seen = HashSet::new();
+            for (face, ids) in faces.iter().enumerate() {
+                for &id in ids {
+                    assert!(seen.insert(id), "tile {id} in two faces");
+                    assert_eq!(usize::from(hexasphere.tiles[id].base_face), face);
+                }
+            }
+            assert_eq!(seen.len(), hexasphere.tiles.len());
+
+            // Interior tiles split evenly; edges and corners go to lower ids
+            let interior = (divisions.saturating_sub(1)) * divisions.saturating_sub(2) / 2;
+            let edges_and_corners = 3 * divisions.saturating_sub(1) + 3;
+            for ids in &faces {
+                assert!(ids.len() >= interior, "d={divisions}: {}", ids.len());
+                assert!(ids.len() <= interior + edges_and_corners);
+            }
+            let sizes = faces.iter().map(Vec::len);
+            let spread = sizes.clone().max().unwrap() - sizes.min().unwrap();
+            assert!(spread <= edges_and_corners);
+            let pentagon_groups = hexasphere
+                .pentagon_indices()
+                .iter()
+                .map(|&id| faces.iter().filter(|ids| ids.contains(&id)).count());
+            assert!(pentagon_groups.into_iter().all(|count| count == 1));
+        }
+    }
+
+    #[test]
+    fn test_base_faces_survive_refinement_and_import() {
+        let coarse = Hexasphere::new(10.0, 3, 1.0);
+        let fine = coarse.refined(1);
+        let decoded = Hexasphere::from_bytes(&coarse.to_bytes()).unwrap();
+
+        for tile in &coarse.tiles {
+            let twin = fine
+                .tiles
+                .iter()
+                .find(|candidate| {
+                    let (a, b) = (&candidate.center_point, &tile.center_point);
+                    (a.x - b.x).abs() + (a.y - b.y).abs() + (a.z - b.z).abs() < 1e-9
+                })
+                .unwrap();
+            assert_eq!(twin.base_face, tile.base_face);
+            assert_eq!(decoded.tiles[tile.id].base_face, tile.base_face);
+        }
+    }
+}
@@ -0,0 +1,848 @@
+//! Builder for configuring and validating hexasphere construction.
+
+use crate::geometry::{GeodesicMesh, Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+use crate::utils::SubdivisionMode;
+use std::fmt;
+
+/// Default upper bound on `subdivisions` accepted by [`HexasphereBuilder::build`].
+///
+/// Tile count grows with the square of the subdivision count (10·n² + 2), so
+/// 256 subdivisions already produce about 655,000 tiles.
+pub const DEFAULT_MAX_SUBDIVISIONS: usize = 256;
+
+/// Default upper bound on the tile count accepted by [`HexasphereBuilder::build`].
+///
+/// Ten million tiles take several gigabytes while building.
+pub const DEFAULT_MAX_TILES: usize = 10_000_000;
+
+/// Default number of decimal places kept by [`HexasphereBuilder::vertex_precision`].
+///
+/// Matches the rounding of [`crate::Point::new`].
+pub const DEFAULT_VERTEX_PRECISION: u32 = 3;
+
+/// Errors returned when a hexasphere configuration is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{BuildError, HexasphereBuilder};
+/// let result = HexasphereBuilder::new().radius(-1.0).build();
+/// assert!(matches!(result, Err(BuildError::InvalidRadius(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    /// The radius is NaN, infinite, zero or negative
+    InvalidRadius(f64),
+    /// The hex size is not a finite value in `(0.0, 1.0]`
+    InvalidHexSize(f64),
+    /// A coordinate of the sphere center is NaN or infinite
+    InvalidCenter(Point),
+    /// The subdivision count exceeds the configured safety cap
+    SubdivisionTooLarge {
+        /// The requested number of subdivisions
+        requested: usize,
+        /// The largest number of subdivisions allowed
+        max: usize,
+    },
+    /// The configuration would produce more tiles than the configured limit
+    TooManyTiles {
+        /// The number of tiles the configuration would produce
+        requested: usize,
+        /// The largest number of tiles allowed
+        limit: usize,
+    },
+}
+
+/// Error returned by [`Hexasphere::try_new`], the crate-wide [`crate::Error`].
+///
+/// Every [`BuildError`] converts into it with the same variant name.
+pub type HexasphereError = crate::Error;
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::InvalidRadius(radius) => {
+                write!(f, "radius must be finite and positive, got {}", radius)
+            }
+            BuildError::InvalidHexSize(hex_size) => {
+                write!(f, "hex size must be in (0.0, 1.0], got {}", hex_size)
+            }
+            BuildError::InvalidCenter(center) => {
+                write!(f, "center must be finite, got ({})", center)
+            }
+            BuildError::SubdivisionTooLarge { requested, max } => write!(
+                f,
+                "{} subdivisions requested but the limit is {}",
+                requested, max
+            ),
+            BuildError::TooManyTiles { requested, limit } => write!(
+                f,
+                "{} tiles requested but the limit is {}",
+                requested, limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Builder for [`Hexasphere`] with named, validated options.
+///
+/// Positional arguments like `Hexasphere::new(10.0, 3, 0.9)` are easy to mix
+/// up. The builder names every option, starts from sensible defaults and
+/// reports invalid configurations as a [`BuildError`] instead of clamping them
+/// or attempting a build that would exhaust memory.
+///
+/// # Defaults
+///
+/// - **radius**: 1.0
+/// - **subdivisions**: 2 (Class I, i.e. GP(2, 0))
+/// - **subdivision_mode**: [`SubdivisionMode::Linear`]
+/// - **hex_size**: 1.0 (tiles touch)
+/// - **max_subdivisions**: [`DEFAULT_MAX_SUBDIVISIONS`]
+/// - **max_tiles**: [`DEFAULT_MAX_TILES`]
+/// - **vertex_precision**: [`DEFAULT_VERTEX_PRECISION`]
+/// - **parallel**: `true` with the `rayon` feature, otherwise `false`
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Hexasphere, HexasphereBuilder};
+/// let hexasphere = HexasphereBuilder::new()
+///     .radius(10.0)
+///     .subdivisions(3)
+///     .hex_size(0.9)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(hexasphere.radius, 10.0);
+/// assert_eq!(hexasphere.tiles.len(), 92);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HexasphereBuilder {
+    pub(crate) radius: f64,
+    pub(crate) subdivisions: usize,
+    pub(crate) goldberg_n: usize,
+    pub(crate) subdivision_mode: SubdivisionMode,
+    pub(crate) hex_size: f64,
+    pub(crate) max_subdivisions: usize,
+    pub(crate) max_tiles: usize,
+    pub(crate) vertex_precision: u32,
+    pub(crate) pentagon_at_poles: bool,
+    pub(crate) center: Point,
+    pub(crate) parallel: bool,
+}
+
+impl Default for HexasphereBuilder {
+    fn default() -> Self {
+        Self {
+            radius: 1.0,
+            subdivisions: 2,
+            goldberg_n: 0,
+            subdivision_mode: SubdivisionMode::Linear,
+            hex_size: 1.0,
+            max_subdivisions: DEFAULT_MAX_SUBDIVISIONS,
+            max_tiles: DEFAULT_MAX_TILES,
+            vertex_precision: DEFAULT_VERTEX_PRECISION,
+            pentagon_at_poles: false,
+            center: Point::ORIGIN,
+            parallel: cfg!(feature = "rayon"),
+        }
+    }
+}
+
+impl HexasphereBuilder {
+    /// Creates a builder with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the radius of the sphere the tiles approximate.
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Sets the number of subdivisions of each icosahedron edge.
+    ///
+    /// See [`Hexasphere::new`] for how this controls the tile count.
+    pub fn subdivisions(mut self, subdivisions: usize) -> Self {
+        self.subdivisions = subdivisions;
+        self
+    }
+
+    /// Selects a general Goldberg polyhedron GP(m, n).
+    ///
+    /// `goldberg(m, 0)` is the same as `subdivisions(m)`. With `n > 0` the
+    /// triangular grid is skewed relative to the icosahedron edges, giving
+    /// `10·(m² + mn + n²) + 2` tiles. See [`Hexasphere::new_goldberg`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::HexasphereBuilder;
+    /// // GP(1, 1) is the truncated icosahedron (a soccer ball)
+    /// let soccer_ball = HexasphereBuilder::new().goldberg(1, 1).build().unwrap();
+    /// assert_eq!(soccer_ball.tiles.len(), 32);
+    /// ```
+    pub fn goldberg(mut self, m: usize, n: usize) -> Self {
+        self.subdivisions = m;
+        self.goldberg_n = n;
+        self
+    }
+
+    /// Sets how new vertices are interpolated while subdividing.
+    ///
+    /// [`SubdivisionMode::Slerp`] gives noticeably more uniform tile sizes
+    /// than the default [`SubdivisionMode::Linear`]. The mode applies to
+    /// Class I subdivision; skewed Goldberg lattices (`goldberg(m, n)` with
+    /// `n > 0`) are always interpolated linearly.
+    pub fn subdivision_mode(mut self, mode: SubdivisionMode) -> Self {
+        self.subdivision_mode = mode;
+        self
+    }
+
+    /// Sets the tile boundary scale factor (1.0 = tiles touch, smaller = gaps).
+    pub fn hex_size(mut self, hex_size: f64) -> Self {
+        self.hex_size = hex_size;
+        self
+    }
+
+    /// Sets the largest subdivision count `build` will accept.
+    ///
+    /// Raise this only if you really need very dense spheres and have the
+    /// memory for them.
+    pub fn max_subdivisions(mut self, max_subdivisions: usize) -> Self {
+        self.max_subdivisions = max_subdivisions;
+        self
+    }
+
+    /// Sets the largest tile count `build` will accept.
+    ///
+    /// The tile count is computed up front from the subdivision options, so
+    /// an oversized configuration fails immediately instead of exhausting
+    /// memory partway through construction. Faces and mesh vertices scale
+    /// with it: about 2 triangles per tile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{BuildError, HexasphereBuilder};
+    /// let result = HexasphereBuilder::new().subdivisions(4).max_tiles(100).build();
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     BuildError::TooManyTiles { requested: 162, limit: 100 }
+    /// );
+    /// ```
+    pub fn max_tiles(mut self, max_tiles: usize) -> Self {
+        self.max_tiles = max_tiles;
+        self
+    }
+
+    /// Sets how many decimal places vertex coordinates keep.
+    ///
+    /// Vertices closer together than this precision are merged while
+    /// building, and tile boundaries are rounded to it. The default of 3
+    /// decimals suits radii from about 1 to 10⁴. Smaller spheres need more
+    /// decimals or neighboring vertices collapse into one; very large
+    /// spheres can use fewer (even 0) to merge vertices more coarsely.
+    ///
+    /// The precision is stored on the built sphere
+    /// ([`Hexasphere::vertex_precision`]) and reused by every operation that
+    /// computes new points afterwards, so a small sphere stays intact when it
+    /// is rescaled, rotated, displaced or relaxed. Subdivision itself runs on
+    /// a fixed-size icosahedron before projection, so it does not depend on
+    /// the radius or this setting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::HexasphereBuilder;
+    /// // A 1 mm sphere needs micrometer precision
+    /// let tiny = HexasphereBuilder::new()
+    ///     .radius(0.001)
+    ///     .subdivisions(3)
+    ///     .vertex_precision(9)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(tiny.tiles.len(), 92);
+    /// assert!(tiny.tiles.iter().all(|t| t.neighbors.len() == t.boundary.len()));
+    /// ```
+    pub fn vertex_precision(mut self, decimals: u32) -> Self {
+        self.vertex_precision = decimals;
+        self
+    }
+
+    /// Places a pentagon exactly on each pole.
+    ///
+    /// By default the icosahedron has an edge, not a vertex, crossing the Y
+    /// axis, which puts the 12 pentagons at irregular latitudes. With this
+    /// flag the icosahedron corners are turned about the Z axis before
+    /// subdivision so that one corner lies on +Y (north pole) and its
+    /// antipode on −Y (south pole). Everything else about construction is
+    /// unchanged. The applied rotation is reported by
+    /// [`HexasphereBuilder::base_rotation`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::HexasphereBuilder;
+    /// let planet = HexasphereBuilder::new()
+    ///     .radius(10.0)
+    ///     .subdivisions(4)
+    ///     .pentagon_at_poles(true)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let north = planet
+    ///     .tiles
+    ///     .iter()
+    ///     .find(|t| t.is_pentagon() && t.get_lat_lon(10.0).lat > 89.5);
+    /// assert!(north.is_some());
+    /// ```
+    pub fn pentagon_at_poles(mut self, enabled: bool) -> Self {
+        self.pentagon_at_poles = enabled;
+        self
+    }
+
+    /// Places the center of the sphere, the origin by default.
+    ///
+    /// The sphere is built around the origin as usual and every tile is then
+    /// offset by `center`, without rounding. Tiles record the center in
+    /// `sphere_center`, so normals, orientations and latitudes are measured
+    /// from it and come out the same as for a sphere at the origin. Useful
+    /// for scenes holding several spheres.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{HexasphereBuilder, Point};
+    /// let moon = HexasphereBuilder::new()
+    ///     .radius(10.0)
+    ///     .subdivisions(3)
+    ///     .center(Point::new(100.0, 0.0, 0.0))
+    ///     .build()
+    ///     .unwrap();
+    /// let planet = HexasphereBuilder::new().radius(10.0).subdivisions(3).build().unwrap();
+    ///
+    /// let (a, b) = (&moon.tiles[5], &planet.tiles[5]);
+    /// assert_eq!(a.center_point.x, b.center_point.x + 100.0);
+    /// let (moon_lat_lon, planet_lat_lon) = (a.get_lat_lon(10.0), b.get_lat_lon(10.0));
+    /// assert!((moon_lat_lon.lon - planet_lat_lon.lon).abs() < 1e-9);
+    /// ```
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Returns the rotation applied to the icosahedron before subdivision.
+    ///
+    /// The base orientation is fully determined by the builder options, so
+    /// storing the options reproduces the same tiles. The rotation can also
+    /// be reapplied to other data with [`Hexasphere::rotate`].
+    ///
+    /// # Returns
+    ///
+    /// `(axis, angle_rad)`: the Z axis and an angle of zero by default, or
+    /// `atan(1/φ)` (about 31.7°) with [`HexasphereBuilder::pentagon_at_poles`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::HexasphereBuilder;
+    /// let (_, angle) = HexasphereBuilder::new().base_rotation();
+    /// assert_eq!(angle, 0.0);
+    ///
+    /// let (_, angle) = HexasphereBuilder::new().pentagon_at_poles(true).base_rotation();
+    /// assert!((angle.to_degrees() - 31.717).abs() < 1e-3);
+    /// ```
+    pub fn base_rotation(&self) -> (Vector3, f64) {
+        let angle = if self.pentagon_at_poles {
+            // Turns the corner (1, φ, 0) onto the +Y axis
+            1.0_f64.atan2(1.61803399)
+        } else {
+            0.0
+        };
+        (Vector3::new(0.0, 0.0, 1.0), angle)
+    }
+
+    /// Enables or disables multi-threaded construction.
+    ///
+    /// Only available with the `rayon` feature, where it defaults to `true`.
+    /// Subdivision of the icosahedron faces, point projection and tile
+    /// construction are then spread across the rayon thread pool. The result
+    /// is identical to a serial build, including tile order.
+    #[cfg(feature = "rayon")]
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Checks the configuration without building anything.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if `build` would succeed, otherwise the first problem found
+    pub fn validate(&self) -> Result<(), BuildError> {
+        if !self.radius.is_finite() || self.radius <= 0.0 {
+            return Err(BuildError::InvalidRadius(self.radius));
+        }
+        if !self.hex_size.is_finite() || self.hex_size <= 0.0 || self.hex_size > 1.0 {
+            return Err(BuildError::InvalidHexSize(self.hex_size));
+        }
+        if !self.center.to_array().iter().all(|value| value.is_finite()) {
+            return Err(BuildError::InvalidCenter(self.center.clone()));
+        }
+        if self.subdivisions + self.goldberg_n > self.max_subdivisions {
+            return Err(BuildError::SubdivisionTooLarge {
+                requested: self.subdivisions + self.goldberg_n,
+                max: self.max_subdivisions,
+            });
+        }
+        self.validate_tile_count()
+    }
+
+    /// Checks only the tile count against `max_tiles`.
+    pub(crate) fn validate_tile_count(&self) -> Result<(), BuildError> {
+        let requested = self.tile_count();
+        if requested > self.max_tiles {
+            return Err(BuildError::TooManyTiles {
+                requested,
+                limit: self.max_tiles,
+            });
+        }
+        Ok(())
+    }
+
+    /// Number of tiles GP(m, n) has: `10·(m² + mn + n²) + 2`, saturating.
+    fn tile_count(&self) -> usize {
+        let (m, n) = (self.subdivisions, self.goldberg_n);
+        if m == 0 && n == 0 {
+            return 12;
+        }
+        m.saturating_mul(m)
+            .saturating_add(m.saturating_mul(n))
+            .saturating_add(n.saturating_mul(n))
+            .saturating_mul(10)
+            .saturating_add(2)
+    }
+
+    /// Validates the options and builds the hexasphere.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `Hexasphere`, or a `BuildError` describing the invalid option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{BuildError, HexasphereBuilder};
+    /// let result = HexasphereBuilder::new().subdivisions(10_000).build();
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     BuildError::SubdivisionTooLarge { requested: 10_000, max: 256 }
+    /// );
+    /// ```
+    pub fn build(&self) -> Result<Hexasphere, BuildError> {
+        self.build_with_mesh().map(|(hexasphere, _)| hexasphere)
+    }
+
+    /// Validates the options and builds the hexasphere along with its geodesic mesh.
+    ///
+    /// See [`Hexasphere::new_with_mesh`] for details on the returned mesh.
+    pub fn build_with_mesh(&self) -> Result<(Hexasphere, GeodesicMesh), BuildError> {
+        self.validate()?;
+        Ok(Hexasphere::construct(self))
+    }
+}
+
+impl Hexasphere {
+    /// Returns a [`HexasphereBuilder`] with default options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::builder().radius(5.0).subdivisions(4).build().unwrap();
+    /// assert_eq!(hexasphere.tiles.len(), 162);
+    /// ```
+    pub fn builder() -> HexasphereBuilder {
+        HexasphereBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildError, HexasphereBuilder, DEFAULT_MAX_SUBDIVISIONS, DEFAULT_MAX_TILES};
+    use crate::geometry::{Point, Vector3};
+    use crate::hexasphere::core::Hexasphere;
+    use crate::utils::SubdivisionMode;
+    use std::collections::HashSet;
+
+    fn sorted_tiles(hexasphere: &Hexasphere) -> Vec<(String, Vec<String>)> {
+        let mut tiles: Vec<_> = hexasphere
+            .tiles
+            .iter()
+            .map(|tile| {
+                let boundary = tile.boundary.iter().map(|p| p.to_string()).collect();
+                (tile.center_point.to_string(), boundary)
+            })
+            .collect();
+        tiles.sort();
+        tiles
+    }
+
+    #[test]
+    fn test_builder_matches_constructor() {
+        for (radius, subdivisions, hex_size) in [(10.0, 3, 0.9), (1.0, 0, 1.0), (5.0, 4, 0.5)] {
+            let built = HexasphereBuilder::new()
+                .radius(radius)
+                .subdivisions(subdivisions)
+                .hex_size(hex_size)
+                .build()
+                .unwrap();
+            let constructed = Hexasphere::new(radius, subdivisions, hex_size);
+
+            assert_eq!(built.radius, constructed.radius);
+            assert_eq!(sorted_tiles(&built), sorted_tiles(&constructed));
+        }
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_radius() {
+        for radius in [f64::NAN, f64::INFINITY, 0.0, -3.0] {
+            let result = HexasphereBuilder::new().radius(radius).build();
+            assert!(matches!(result, Err(BuildError::InvalidRadius(_))));
+        }
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_hex_size() {
+        for hex_size in [f64::NAN, 0.0, -0.5, 1.5] {
+            let result = HexasphereBuilder::new().hex_size(hex_size).build();
+            assert!(matches!(result, Err(BuildError::InvalidHexSize(_))));
+        }
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_center() {
+        let center = Point::new(0.0, f64::INFINITY, 0.0);
+        let result = HexasphereBuilder::new().center(center.clone()).build();
+        assert_eq!(result.unwrap_err(), BuildError::InvalidCenter(center));
+    }
+
+    #[test]
+    fn test_offset_mesh_vertices_are_tile_centers() {
+        let (hexasphere, mesh) = HexasphereBuilder::new()
+            .radius(10.0)
+            .subdivisions(4)
+            .center(Point::new(25.0, -7.5, 3.25))
+            .build_with_mesh()
+            .unwrap();
+
+        assert_eq!(mesh.vertices.len(), hexasphere.tiles.len());
+        for vertex in &mesh.vertices {
+            assert!(hexasphere
+                .tiles
+                .iter()
+                .any(|tile| tile.center_point == *vertex));
+        }
+    }
+
+    #[test]
+    fn test_builder_enforces_subdivision_cap() {
+        let result = HexasphereBuilder::new()
+            .subdivisions(DEFAULT_MAX_SUBDIVISIONS + 1)
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            BuildError::SubdivisionTooLarge {
+                requested: DEFAULT_MAX_SUBDIVISIONS + 1,
+                max: DEFAULT_MAX_SUBDIVISIONS,
+            }
+        );
+
+        // The cap is configurable in both directions
+        let lowered = HexasphereBuilder::new().subdivisions(3).max_subdivisions(2);
+        assert!(lowered.build().is_err());
+        let raised = HexasphereBuilder::new().subdivisions(3).max_subdivisions(3);
+        assert!(raised.build().is_ok());
+    }
+
+    #[test]
+    fn test_builder_enforces_tile_limit() {
+        // 10·1001² + 2 tiles, past the default limit once the subdivision cap allows it
+        let result = HexasphereBuilder::new()
+            .subdivisions(1001)
+            .max_subdivisions(2000)
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            BuildError::TooManyTiles {
+                requested: 10_020_012,
+                limit: DEFAULT_MAX_TILES,
+            }
+        );
+
+        // Goldberg lattices count both parameters
+        let result = HexasphereBuilder::new()
+            .goldberg(2, 1)
+            .max_tiles(71)
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            BuildError::TooManyTiles {
+                requested: 72,
+                limit: 71,
+            }
+        );
+        let built = HexasphereBuilder::new()
+            .goldberg(2, 1)
+            .max_tiles(72)
+            .build();
+        assert_eq!(built.unwrap().tiles.len(), 72);
+
+        // Absurd requests saturate instead of overflowing
+        let result = HexasphereBuilder::new()
+            .subdivisions(usize::MAX / 2)
+            .max_subdivisions(usize::MAX)
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuildError::TooManyTiles {
+                requested: usize::MAX,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_try_new_errors_and_success() {
+        assert!(matches!(
+            Hexasphere::try_new(f64::NAN, 2, 1.0),
+            Err(crate::Error::InvalidRadius(_))
+        ));
+        assert!(matches!(
+            Hexasphere::try_new(-1.0, 2, 1.0),
+            Err(crate::Error::InvalidRadius(_))
+        ));
+        assert!(matches!(
+            Hexasphere::try_new(10.0, 2, 0.0),
+            Err(crate::Error::InvalidHexSize(_))
+        ));
+        assert!(matches!(
+            Hexasphere::try_new(10.0, 5000, 1.0),
+            Err(crate::Error::SubdivisionTooLarge { .. })
+        ));
+
+        let hexasphere = Hexasphere::try_new(10.0, 3, 0.9).unwrap();
+        assert_eq!(
+            sorted_tiles(&hexasphere),
+            sorted_tiles(&Hexasphere::new(10.0, 3, 0.9))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "tiles requested but the limit is")]
+    fn test_new_panics_before_allocating_oversized_sphere() {
+        Hexasphere::new(10.0, 1001, 1.0);
+    }
+
+    #[test]
+    fn test_slerp_mode_reduces_size_variation() {
+        let relative_deviation = |mode| {
+            let stats = HexasphereBuilder::new()
+                .radius(10.0)
+                .subdivisions(3)
+                .subdivision_mode(mode)
+                .build()
+                .unwrap()
+                .calculate_hexagon_stats();
+            stats.radius_std_deviation / stats.average_hexagon_radius
+        };
+
+        let linear = relative_deviation(SubdivisionMode::Linear);
+        let slerp = relative_deviation(SubdivisionMode::Slerp);
+        assert!(
+            slerp < linear,
+            "slerp {} should vary less than linear {}",
+            slerp,
+            linear
+        );
+    }
+
+    #[test]
+    fn test_slerp_mode_topology() {
+        for subdivisions in 0..=5 {
+            let (hexasphere, mesh) = HexasphereBuilder::new()
+                .subdivisions(subdivisions)
+                .subdivision_mode(SubdivisionMode::Slerp)
+                .build_with_mesh()
+                .unwrap();
+            let expected = if subdivisions == 0 {
+                12
+            } else {
+                10 * subdivisions.pow(2) + 2
+            };
+
+            assert_eq!(hexasphere.tiles.len(), expected);
+            assert_eq!(mesh.euler_characteristic(), 2);
+            let pentagons = hexasphere.tiles.iter().filter(|t| t.is_pentagon()).count();
+            assert_eq!(pentagons, 12);
+        }
+    }
+
+    /// Asserts the tile count, neighbor symmetry and distinct, outward-wound boundaries
+    fn assert_well_formed(hexasphere: &Hexasphere, expected_tiles: usize) {
+        assert_eq!(hexasphere.tiles.len(), expected_tiles);
+        assert_eq!(
+            hexasphere.tiles.iter().filter(|t| t.is_pentagon()).count(),
+            12
+        );
+
+        for tile in &hexasphere.tiles {
+            assert_eq!(
+                tile.neighbors.len(),
+                tile.boundary.len(),
+                "tile {}",
+                tile.id
+            );
+            for &neighbor in &tile.neighbors {
+                assert!(hexasphere.tiles[neighbor].neighbors.contains(&tile.id));
+            }
+
+            let distinct: HashSet<String> = tile.boundary.iter().map(|p| p.to_string()).collect();
+            assert_eq!(distinct.len(), tile.boundary.len(), "tile {}", tile.id);
+
+            let [a, b, c] = [0, 1, 2].map(|i| Vector3::from(&tile.boundary[i]));
+            let normal = (&b - &a).cross(&(&c - &a));
+            assert!(normal.dot(&Vector3::from(&tile.center_point)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_vertex_precision_tiny_radius() {
+        let tiny = HexasphereBuilder::new()
+            .radius(0.001)
+            .subdivisions(3)
+            .vertex_precision(9)
+            .build()
+            .unwrap();
+        assert_well_formed(&tiny, 92);
+    }
+
+    /// The default 3 decimals collapses neighboring vertices of a 1 mm
+    /// sphere, which debug builds catch while resolving neighbors.
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "vertex precision is too coarse")
+    )]
+    fn test_default_precision_collapses_tiny_radius() {
+        let collapsed = HexasphereBuilder::new()
+            .radius(0.001)
+            .subdivisions(3)
+            .build()
+            .unwrap();
+        assert!(collapsed
+            .tiles
+            .iter()
+            .any(|t| t.neighbors.len() != t.boundary.len()));
+    }
+
+    #[test]
+    fn test_vertex_precision_huge_radius() {
+        let planet = HexasphereBuilder::new()
+            .radius(1e6)
+            .subdivisions(5)
+            .vertex_precision(0)
+            .build()
+            .unwrap();
+        assert_well_formed(&planet, 252);
+
+        for point in planet.tiles.iter().flat_map(|t| &t.boundary) {
+            assert!(point.to_array().iter().all(|c| c.fract() == 0.0));
+        }
+    }
+
+    #[test]
+    fn test_default_vertex_precision_matches_constructor() {
+        let explicit = HexasphereBuilder::new()
+            .radius(10.0)
+            .subdivisions(4)
+            .vertex_precision(super::DEFAULT_VERTEX_PRECISION)
+            .build()
+            .unwrap();
+        let constructed = Hexasphere::new(10.0, 4, 1.0);
+
+        for (a, b) in explicit.tiles.iter().zip(&constructed.tiles) {
+            assert_eq!(a.center_point, b.center_point);
+            assert_eq!(a.boundary, b.boundary);
+            assert_eq!(a.neighbors, b.neighbors);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_build_matches_serial() {
+        let build = |parallel| {
+            HexasphereBuilder::new()
+                .radius(10.0)
+                .subdivisions(4)
+                .hex_size(0.9)
+                .parallel(parallel)
+                .build()
+                .unwrap()
+        };
+        let serial = build(false);
+        let parallel = build(true);
+
+        assert_eq!(serial.tiles.len(), parallel.tiles.len());
+        assert_eq!(
+            serial.calculate_hexagon_stats(),
+            parallel.calculate_hexagon_stats()
+        );
+        for (a, b) in serial.tiles.iter().zip(&parallel.tiles) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.center_point, b.center_point);
+            assert_eq!(a.boundary, b.boundary);
+            assert_eq!(a.neighbors, b.neighbors);
+        }
+    }
+
+    #[test]
+    fn test_pentagon_at_poles() {
+        let build = |poles: bool| {
+            HexasphereBuilder::new()
+                .radius(10.0)
+                .subdivisions(4)
+                .pentagon_at_poles(poles)
+                .build()
+                .unwrap()
+        };
+        let polar_pentagons = |hexasphere: &Hexasphere, pole: f64| {
+            hexasphere
+                .tiles
+                .iter()
+                .filter(|t| t.is_pentagon() && (t.get_lat_lon(10.0).lat - pole).abs() < 0.5)
+                .count()
+        };
+
+        let polar = build(true);
+        assert_eq!(polar.tiles.len(), 162);
+        assert_eq!(polar_pentagons(&polar, 90.0), 1);
+        assert_eq!(polar_pentagons(&polar, -90.0), 1);
+        assert_eq!(polar.tiles.iter().filter(|t| t.is_pentagon()).count(), 12);
+
+        // Off is the default and matches the plain constructor exactly
+        let standard = build(false);
+        assert_eq!(polar_pentagons(&standard, 90.0), 0);
+        assert_eq!(
+            sorted_tiles(&standard),
+            sorted_tiles(&Hexasphere::new(10.0, 4, 1.0))
+        );
+        assert_eq!(HexasphereBuilder::new().base_rotation().1, 0.0);
+    }
+}
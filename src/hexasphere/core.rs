@@ -1,11 +1,19 @@
 //! Core hexasphere implementation and construction.
 
-use crate::approximation::RegularHexagonParams;
-use crate::geometry::{Face, Point};
+use crate::approximation::{RegularHexagonParams, RegularPentagonParams};
+use crate::geometry::point::round_coordinate;
+use crate::geometry::{Face, GeodesicMesh, Point, Vector3, VertexPool};
+use crate::hexasphere::base_faces::{base_face_containing, base_face_corners};
+use crate::hexasphere::builder::HexasphereBuilder;
+use crate::hexasphere::goldberg::subdivide_goldberg;
+use crate::hexasphere::parallel::{for_each_mut, map_vec};
+use crate::hexasphere::progress::BuildPhase;
 use crate::tile::core::Tile;
-use crate::tile::{ThickTile, TileOrientation};
-use crate::utils::{find_projected_point, sort_faces_around_point, subdivide_face};
+use crate::tile::{ExtrusionMode, ThickTile, TileOrientation};
+use crate::utils::math::{sort_faces_around_point_with_precision, subdivide_face_indexed};
 use std::collections::HashMap;
+use std::fmt;
+use std::ops::Index;
 
 /// The main geodesic polyhedron structure containing all tiles.
 ///
@@ -53,12 +61,24 @@ use std::collections::HashMap;
 /// # std::fs::write("sphere.obj", hexasphere.to_obj()).unwrap();
 /// ```
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Hexasphere {
     /// Radius of the sphere that the tiles approximate
     pub radius: f64,
-    /// All polygonal tiles (hexagons and pentagons) that make up the surface
+    /// All polygonal tiles (hexagons and pentagons) that make up the surface.
+    ///
+    /// Tiles are ordered by the lexicographic (x, y, z) order of their center
+    /// points, so the same parameters always produce the same tile indices.
     pub tiles: Vec<Tile>,
+    /// Indices of the 12 pentagonal tiles, in ascending order
+    pentagon_indices: Vec<usize>,
+    /// Subdivisions of each icosahedron edge, if known
+    pub(crate) subdivisions: Option<usize>,
+    /// Center of the sphere, shared with every tile's `sphere_center`
+    center: Point,
+    /// Decimal places kept by vertex coordinates, reused whenever points are
+    /// recomputed after construction
+    precision: u32,
 }
 
 impl Hexasphere {
@@ -72,12 +92,11 @@ impl Hexasphere {
     ///
     /// * `radius` - Radius of the target sphere (determines overall size)
     /// * `num_divisions` - Number of subdivision levels (detail/complexity)
-    ///   - 0: Just the icosahedron (12 tiles)
-    ///   - 1: 42 tiles
-    ///   - 2: 162 tiles  
-    ///   - 3: 642 tiles
-    ///   - 4: 2562 tiles
-    ///   - n: ~10×4^(n-1) tiles (exponential growth)
+    ///   - 0 or 1: The dodecahedron (12 tiles)
+    ///   - 2: 42 tiles
+    ///   - 3: 92 tiles
+    ///   - 4: 162 tiles
+    ///   - n: 10·n² + 2 tiles (quadratic growth, see [`crate::utils::tile_count`])
     /// * `hex_size` - Scale factor for tile boundaries (0.01 to 1.0)
     ///   - 1.0: Tiles touch at boundaries (no gaps)
     ///   - 0.9: Small gaps between tiles (10% shrinkage)
@@ -85,14 +104,9 @@ impl Hexasphere {
     ///
     /// # Performance Considerations
     ///
-    /// Construction time grows exponentially with `num_divisions`:
-    /// - 0-2: Nearly instant (< 1ms)
-    /// - 3-4: Fast (< 100ms)
-    /// - 5-6: Moderate (< 1s)
-    /// - 7+: Slow (seconds to minutes)
-    ///
-    /// Memory usage also grows exponentially. Consider caching results for
-    /// repeated use with the same parameters.
+    /// Construction time and memory grow with the tile count, so quadratically
+    /// with `num_divisions`. Consider caching results for repeated use with
+    /// the same parameters.
     ///
     /// # Mathematical Background
     ///
@@ -117,6 +131,13 @@ impl Hexasphere {
     /// 5. **Create boundaries**: Face centroids become tile boundary points
     /// 6. **Establish neighbors**: Connect adjacent tiles
     ///
+    /// # Deterministic Ordering
+    ///
+    /// Construction is fully deterministic: tiles are sorted by the (x, y, z)
+    /// coordinates of their centers, so a given set of parameters yields the
+    /// same tile indices (and neighbor indices) in every run. Per-tile data can
+    /// therefore be saved by index and loaded against a freshly built sphere.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -136,140 +157,629 @@ impl Hexasphere {
     ///
     /// # Panics
     ///
-    /// May panic if memory allocation fails for very large subdivision levels.
-    /// Consider using smaller subdivision levels and increase gradually.
+    /// Panics if the sphere would have more than
+    /// [`DEFAULT_MAX_TILES`](crate::hexasphere::builder::DEFAULT_MAX_TILES)
+    /// tiles, rather than running out of memory partway through. Use
+    /// [`Hexasphere::try_new`] to handle that as an error instead.
     pub fn new(radius: f64, num_divisions: usize, hex_size: f64) -> Self {
-        let tao = 1.61803399; // Golden ratio
+        Self::new_with_mesh(radius, num_divisions, hex_size).0
+    }
 
-        // Create icosahedron corners
-        let corners = vec![
-            Point::new(1000.0, tao * 1000.0, 0.0),
-            Point::new(-1000.0, tao * 1000.0, 0.0),
-            Point::new(1000.0, -tao * 1000.0, 0.0),
-            Point::new(-1000.0, -tao * 1000.0, 0.0),
-            Point::new(0.0, 1000.0, tao * 1000.0),
-            Point::new(0.0, -1000.0, tao * 1000.0),
-            Point::new(0.0, 1000.0, -tao * 1000.0),
-            Point::new(0.0, -1000.0, -tao * 1000.0),
-            Point::new(tao * 1000.0, 0.0, 1000.0),
-            Point::new(-tao * 1000.0, 0.0, 1000.0),
-            Point::new(tao * 1000.0, 0.0, -1000.0),
-            Point::new(-tao * 1000.0, 0.0, -1000.0),
-        ];
+    /// Creates a new hexasphere, validating the parameters first.
+    ///
+    /// The fallible counterpart of [`Hexasphere::new`]. The expected tile
+    /// count is computed before anything is allocated, so an oversized
+    /// request fails immediately. Takes the same arguments; for the other
+    /// limits and options use [`HexasphereBuilder`], which this delegates to.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the target sphere; must be finite and positive
+    /// * `num_divisions` - Number of subdivision levels, as for [`Hexasphere::new`]
+    /// * `hex_size` - Scale factor for tile boundaries, in `(0.0, 1.0]`
+    ///
+    /// # Returns
+    ///
+    /// The hexasphere, or a [`HexasphereError`](crate::HexasphereError) for an
+    /// invalid radius or hex size, or a subdivision or tile count over the
+    /// default limits
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, HexasphereError};
+    /// let hexasphere = Hexasphere::try_new(10.0, 3, 1.0).unwrap();
+    /// assert_eq!(hexasphere.tiles.len(), 92);
+    ///
+    /// let error = Hexasphere::try_new(0.0, 3, 1.0).unwrap_err();
+    /// assert!(matches!(error, HexasphereError::InvalidRadius(r) if r == 0.0));
+    /// ```
+    pub fn try_new(
+        radius: f64,
+        num_divisions: usize,
+        hex_size: f64,
+    ) -> Result<Self, crate::HexasphereError> {
+        HexasphereBuilder::new()
+            .radius(radius)
+            .subdivisions(num_divisions)
+            .hex_size(hex_size)
+            .build()
+            .map_err(crate::Error::from)
+    }
 
-        // Keep track of unique points
-        let mut points: HashMap<Point, Point> = HashMap::new();
-        for corner in &corners {
-            points.insert(corner.clone(), corner.clone());
+    /// Creates a new hexasphere and also returns its geodesic triangle mesh.
+    ///
+    /// Construction builds the triangulated geodesic sphere first and then
+    /// derives the Goldberg tiles from it as the dual. `new` discards that
+    /// triangulation; this constructor keeps it, deduplicated and indexed,
+    /// alongside the tiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the target sphere
+    /// * `num_divisions` - Number of subdivision levels, as for [`Hexasphere::new`]
+    /// * `hex_size` - Scale factor for tile boundaries, as for [`Hexasphere::new`]
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the hexasphere and the `GeodesicMesh` it was built from.
+    /// Each tile's `center_point` is one of the mesh vertices.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Terrain rendering**: Render the triangulation, use tiles for gameplay
+    /// - **Height maps**: Displace mesh vertices per tile center
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let (hexasphere, mesh) = Hexasphere::new_with_mesh(10.0, 3, 1.0);
+    ///
+    /// // Each tile center is a vertex of the triangulation
+    /// let center = &hexasphere.tiles[0].center_point;
+    /// assert!(mesh.vertices.contains(center));
+    /// println!("{} triangles", mesh.triangles.len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics for oversized spheres, like [`Hexasphere::new`].
+    pub fn new_with_mesh(radius: f64, num_divisions: usize, hex_size: f64) -> (Self, GeodesicMesh) {
+        let options = HexasphereBuilder::new()
+            .radius(radius)
+            .goldberg(num_divisions, 0)
+            .hex_size(hex_size);
+        if let Err(error) = options.validate_tile_count() {
+            panic!("{}", error);
         }
+        let (hexasphere, mesh) = Self::construct(&options);
+        debug_assert_eq!(
+            hexasphere.tiles.len(),
+            crate::utils::tile_count(num_divisions)
+        );
+        (hexasphere, mesh)
+    }
 
-        // Create initial icosahedron faces
-        let face_indices = vec![
-            [0, 1, 4],
-            [1, 9, 4],
-            [4, 9, 5],
-            [5, 9, 3],
-            [2, 3, 7],
-            [3, 2, 5],
-            [7, 10, 2],
-            [0, 8, 10],
-            [0, 4, 8],
-            [8, 2, 10],
-            [8, 4, 5],
-            [8, 5, 2],
-            [1, 0, 6],
-            [11, 1, 6],
-            [3, 9, 11],
-            [6, 10, 7],
-            [3, 11, 7],
-            [11, 6, 7],
-            [6, 0, 10],
-            [9, 1, 11],
-        ];
+    /// Creates a hexasphere from the general Goldberg polyhedron GP(m, n).
+    ///
+    /// [`Hexasphere::new`] only produces Class I polyhedra, GP(n, 0), whose
+    /// triangular grid runs parallel to the icosahedron edges. Goldberg
+    /// polyhedra are parameterized by two integers: the dual triangulation
+    /// steps `m` grid cells in one direction and `n` after a 60° turn to get
+    /// from one pentagon to the next. `n > 0` skews the grid across the
+    /// icosahedron faces and yields tile counts that Class I cannot reach.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the target sphere
+    /// * `m` - First Goldberg parameter
+    /// * `n` - Second Goldberg parameter; `n = 0` matches `Hexasphere::new(radius, m, hex_size)`
+    /// * `hex_size` - Scale factor for tile boundaries, as for [`Hexasphere::new`]
+    ///
+    /// # Returns
+    ///
+    /// A hexasphere with `10·(m² + mn + n²) + 2` tiles, exactly 12 of which are
+    /// pentagons. GP(n, m) is the mirror image of GP(m, n).
+    ///
+    /// # Classes
+    ///
+    /// - **Class I** `(m, 0)`: The classic geodesic subdivision
+    /// - **Class II** `(m, m)`: Includes the truncated icosahedron GP(1, 1)
+    /// - **Class III** `(m, n)`, `m ≠ n`, both > 0: Chiral variants
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// // The truncated icosahedron: 12 pentagons and 20 hexagons
+    /// let soccer_ball = Hexasphere::new_goldberg(10.0, 1, 1, 1.0);
+    /// assert_eq!(soccer_ball.tiles.len(), 32);
+    ///
+    /// // A Class III polyhedron between the Class I levels 4 and 5
+    /// let chiral = Hexasphere::new_goldberg(10.0, 3, 2, 1.0);
+    /// assert_eq!(chiral.tiles.len(), 192);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics for oversized spheres, like [`Hexasphere::new`].
+    pub fn new_goldberg(radius: f64, m: usize, n: usize, hex_size: f64) -> Self {
+        let options = HexasphereBuilder::new()
+            .radius(radius)
+            .goldberg(m, n)
+            .hex_size(hex_size);
+        if let Err(error) = options.validate_tile_count() {
+            panic!("{}", error);
+        }
+        Self::construct(&options).0
+    }
 
-        let faces: Vec<Face> = face_indices
-            .into_iter()
-            .enumerate()
-            .map(|(id, [i, j, k])| {
-                Face::new(
-                    id,
-                    corners[i].clone(),
-                    corners[j].clone(),
-                    corners[k].clone(),
-                )
-            })
-            .collect();
+    /// Runs the construction pipeline for already chosen options.
+    ///
+    /// Performs no validation; out-of-range values behave as they always have
+    /// with [`Hexasphere::new`] (for example `hex_size` is clamped per tile).
+    pub(crate) fn construct(options: &HexasphereBuilder) -> (Self, GeodesicMesh) {
+        Self::construct_with_progress(options, &mut |_, _| {})
+    }
 
-        // Subdivide faces
-        let mut new_faces = Vec::new();
-        let mut face_id = faces.len();
+    /// Runs the construction pipeline, reporting progress as it goes.
+    ///
+    /// See [`Hexasphere::new_with_progress`] for when `progress` is called.
+    pub(crate) fn construct_with_progress(
+        options: &HexasphereBuilder,
+        progress: &mut dyn FnMut(BuildPhase, f32),
+    ) -> (Self, GeodesicMesh) {
+        let plan = TilePlan::new(options, progress);
+        let mut mesh = GeodesicMesh::from_indexed(&plan.projected, &plan.triangles);
 
-        for face in faces {
-            let subdivided = subdivide_face(face, num_divisions, &mut points, &mut face_id);
-            new_faces.extend(subdivided);
+        // Moved by the same sums as the tiles, so centers stay exact vertices
+        let center = &options.center;
+        if *center != Point::ORIGIN {
+            for vertex in &mut mesh.vertices {
+                vertex.x += center.x;
+                vertex.y += center.y;
+                vertex.z += center.z;
+            }
         }
+        (Self::from_plan(&plan, options, progress), mesh)
+    }
 
-        // Project all points to sphere
-        let mut projected_points: HashMap<Point, Point> = HashMap::new();
-        for point in points.into_values() {
-            let mut projected = point.clone();
-            projected.project(radius, 1.0);
-            projected_points.insert(projected.clone(), projected);
+    /// Builds every planned tile, each with its neighbors resolved.
+    pub(crate) fn from_plan(
+        plan: &TilePlan,
+        options: &HexasphereBuilder,
+        progress: &mut dyn FnMut(BuildPhase, f32),
+    ) -> Self {
+        let parallel = options.parallel;
+        let TilePlan {
+            tile_vertices,
+            pentagon_indices,
+            ..
+        } = plan;
+
+        // Built in batches of about 1% so progress can be reported between them
+        progress(BuildPhase::BuildingTiles, 0.0);
+        let tile_count = tile_vertices.len();
+        let batch_size = tile_count.div_ceil(100).max(1);
+        let mut tiles = Vec::with_capacity(tile_count);
+        for batch in tile_vertices.chunks(batch_size) {
+            tiles.extend(map_vec(batch.to_vec(), parallel, |(vertex, triangles)| {
+                plan.build_tile(vertex, &triangles)
+            }));
+            progress(
+                BuildPhase::BuildingTiles,
+                tiles.len() as f32 / tile_count as f32,
+            );
         }
 
-        // Update faces to use projected vertices
-        for face in &mut new_faces {
-            for i in 0..3 {
-                if let Some(projected_point) =
-                    find_projected_point(&face.points[i], &projected_points)
-                {
-                    face.points[i] = projected_point;
-                }
+        // Tiles are built around the origin and then moved into place
+        let center = options.center.clone();
+        let moved = center != Point::ORIGIN;
+        if moved {
+            for tile in &mut tiles {
+                tile.move_sphere_to(&center);
             }
-            // Clear cached centroid since points have changed
-            face.clear_centroid_cache();
         }
 
-        // Group faces by their points to create tiles
-        let mut point_to_faces: HashMap<Point, Vec<usize>> = HashMap::new();
-        for (face_idx, face) in new_faces.iter().enumerate() {
-            for point in &face.points {
-                point_to_faces
-                    .entry(point.clone())
-                    .or_default()
-                    .push(face_idx);
-            }
+        let mut hexasphere = Self {
+            radius: options.radius,
+            tiles,
+            pentagon_indices: pentagon_indices.clone(),
+            subdivisions: (options.goldberg_n == 0).then_some(options.subdivisions),
+            center,
+            precision: options.vertex_precision,
+        };
+        if moved {
+            hexasphere.refresh_neighbor_ids();
         }
+        hexasphere
+    }
 
-        // Create tiles
-        let mut tiles = Vec::new();
-        let mut tile_lookup: HashMap<String, usize> = HashMap::new();
+    /// Assembles a hexasphere from finished tiles, locating the pentagons.
+    ///
+    /// The sphere center is taken from the first tile; `precision` is the
+    /// number of decimals the tile coordinates were rounded to.
+    pub(crate) fn from_tiles(
+        radius: f64,
+        tiles: Vec<Tile>,
+        subdivisions: Option<usize>,
+        precision: u32,
+    ) -> Self {
+        let center = tiles
+            .first()
+            .map_or(Point::ORIGIN, |tile| tile.sphere_center.clone());
+        let pentagon_indices = tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| tile.is_pentagon())
+            .map(|(id, _)| id)
+            .collect();
+        let mut hexasphere = Self {
+            radius,
+            tiles,
+            pentagon_indices,
+            subdivisions,
+            center,
+            precision,
+        };
+        hexasphere.assign_base_faces();
+        hexasphere
+    }
 
-        for (point, face_indices) in point_to_faces {
-            let mut point_faces: Vec<Face> = face_indices
-                .into_iter()
-                .map(|idx| new_faces[idx].clone())
-                .collect();
+    /// Returns the indices of the pentagonal tiles.
+    ///
+    /// Pentagons sit on the 12 corners of the original icosahedron. Their
+    /// indices are recorded during construction, so this never scans the
+    /// tiles. Operations that keep the topology, such as
+    /// [`Hexasphere::rotate`] or [`Hexasphere::relax`], keep the list valid;
+    /// it is not updated if `tiles` is edited by hand.
+    ///
+    /// # Returns
+    ///
+    /// The pentagon tile indices in ascending order, normally exactly 12
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+    ///
+    /// assert_eq!(hexasphere.pentagon_indices().len(), 12);
+    /// for &id in hexasphere.pentagon_indices() {
+    ///     assert!(hexasphere.tiles[id].is_pentagon());
+    /// }
+    /// ```
+    pub fn pentagon_indices(&self) -> &[usize] {
+        &self.pentagon_indices
+    }
+
+    /// Returns the number of pentagonal tiles, normally 12.
+    pub fn pentagon_count(&self) -> usize {
+        self.pentagon_indices.len()
+    }
+
+    /// Returns the number of hexagonal tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// assert_eq!(hexasphere.hexagon_count(), 80);
+    /// assert_eq!(hexasphere.hexagon_count() + hexasphere.pentagon_count(), 92);
+    /// ```
+    pub fn hexagon_count(&self) -> usize {
+        self.tiles.len() - self.pentagon_indices.len()
+    }
+
+    /// Returns the number of subdivisions of each icosahedron edge the
+    /// hexasphere was built with.
+    ///
+    /// This is the `num_divisions` passed to [`Hexasphere::new`], multiplied
+    /// up by [`Hexasphere::refined`], and is kept by binary snapshots and
+    /// hexasphere.js JSON written by this crate.
+    ///
+    /// # Returns
+    ///
+    /// The subdivision count, or `None` for skewed Goldberg spheres
+    /// (`goldberg(m, n)` with `n > 0`), which have no single count, and for
+    /// spheres imported from data that does not record it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, HexasphereBuilder};
+    /// assert_eq!(Hexasphere::new(10.0, 4, 1.0).subdivisions(), Some(4));
+    ///
+    /// let soccer_ball = HexasphereBuilder::new().goldberg(1, 1).build().unwrap();
+    /// assert_eq!(soccer_ball.subdivisions(), None);
+    /// ```
+    pub fn subdivisions(&self) -> Option<usize> {
+        self.subdivisions
+    }
+
+    /// Estimates the memory held by this hexasphere, in bytes.
+    ///
+    /// Adds the struct itself to the heap allocations it owns: the tile
+    /// vector, every tile's boundary, neighbor and neighbor id vectors (with
+    /// the id strings), cached measurements and the pentagon index list.
+    /// Allocations are counted by capacity; allocator overhead is not.
+    ///
+    /// # Returns
+    ///
+    /// The estimated size in bytes
+    ///
+    /// # Use Cases
+    ///
+    /// - **Budgeting**: Pick the largest subdivision that fits in memory
+    /// - **Diagnostics**: Log the footprint of loaded spheres
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = neighbors per tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let small = Hexasphere::new(10.0, 4, 1.0).memory_estimate();
+    /// let large = Hexasphere::new(10.0, 8, 1.0).memory_estimate();
+    /// assert!(large > 3 * small);
+    /// ```
+    pub fn memory_estimate(&self) -> usize {
+        let tiles: usize = self
+            .tiles
+            .iter()
+            .map(|tile| {
+                let neighbor_ids: usize = tile.neighbor_ids.iter().map(String::capacity).sum();
+                tile.boundary.capacity() * std::mem::size_of::<Point>()
+                    + tile.neighbors.capacity() * std::mem::size_of::<usize>()
+                    + tile.neighbor_ids.capacity() * std::mem::size_of::<String>()
+                    + neighbor_ids
+                    + tile.cache_size()
+            })
+            .sum();
+
+        std::mem::size_of::<Self>()
+            + self.tiles.capacity() * std::mem::size_of::<Tile>()
+            + tiles
+            + self.pentagon_indices.capacity() * std::mem::size_of::<usize>()
+    }
+
+    /// Looks up a tile by its id.
+    ///
+    /// Tile ids equal their index in `tiles`, so this is a bounds-checked
+    /// alternative to indexing that pairs naturally with `Tile::neighbors`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The tile id (index into `tiles`)
+    ///
+    /// # Returns
+    ///
+    /// The tile with that id, or `None` if the id is out of range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = hexasphere.tile(5).unwrap();
+    /// assert_eq!(tile.id, 5);
+    ///
+    /// for &neighbor_id in &tile.neighbors {
+    ///     let neighbor = hexasphere.tile(neighbor_id).unwrap();
+    ///     assert!(neighbor.neighbors.contains(&tile.id));
+    /// }
+    /// ```
+    pub fn tile(&self, id: usize) -> Option<&Tile> {
+        self.tiles.get(id)
+    }
+
+    /// Iterates over all tiles together with their indices.
+    ///
+    /// Equivalent to `tiles.iter().enumerate()`; the index equals the tile id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// for (index, tile) in hexasphere.iter() {
+    ///     assert_eq!(tile.id, index);
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Tile)> + '_ {
+        self.tiles.iter().enumerate()
+    }
+
+    /// Iterates over the hexagonal tiles together with their indices.
+    ///
+    /// Keeping the index paired with the tile avoids losing track of which
+    /// tile a per-hexagon result belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let transforms: Vec<(usize, [f64; 16])> = hexasphere
+    ///     .hexagons()
+    ///     .filter_map(|(index, tile)| {
+    ///         let orientation = tile.get_orientation()?;
+    ///         Some((index, orientation.to_transform_matrix(&tile.center_point)))
+    ///     })
+    ///     .collect();
+    /// assert_eq!(transforms.len(), hexasphere.hexagon_count());
+    /// ```
+    pub fn hexagons(&self) -> impl Iterator<Item = (usize, &Tile)> + '_ {
+        self.iter().filter(|(_, tile)| tile.is_hexagon())
+    }
 
-            // Sort faces to be ordered around the point
-            sort_faces_around_point(&mut point_faces, &point);
+    /// Iterates over the pentagonal tiles together with their indices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let ids: Vec<usize> = hexasphere.pentagons().map(|(index, _)| index).collect();
+    /// assert_eq!(ids, hexasphere.pentagon_indices());
+    /// ```
+    pub fn pentagons(&self) -> impl Iterator<Item = (usize, &Tile)> + '_ {
+        self.iter().filter(|(_, tile)| tile.is_pentagon())
+    }
 
-            let tile = Tile::new(point, &mut point_faces, hex_size);
-            let tile_id = tile.to_string();
-            tile_lookup.insert(tile_id, tiles.len());
-            tiles.push(tile);
+    /// Finds the tile whose boundary contains the direction of a point.
+    ///
+    /// The tile with the nearest center (by angle from the sphere's center) is
+    /// checked first, which resolves almost every query. Near tile borders the
+    /// nearest center is not always the containing tile, so its neighbors are
+    /// checked next with [`Tile::contains_point`].
+    ///
+    /// # Gaps
+    ///
+    /// When the hexasphere was built with `hex_size < 1.0`, the space between
+    /// shrunken tiles belongs to no tile and this returns `None`. Points on a
+    /// shared edge belong to both tiles; either id may be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to look up, e.g. a raycast hit on the sphere
+    ///
+    /// # Returns
+    ///
+    /// The id of the containing tile, or `None` for points in a gap or at the
+    /// sphere center
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n) for the nearest-center scan; use
+    ///   [`crate::TileIndex`] for many queries
+    /// - Space complexity: O(1)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = &hexasphere.tiles[7];
+    ///
+    /// assert_eq!(hexasphere.tile_containing(&tile.center_point), Some(7));
+    /// ```
+    pub fn tile_containing(&self, point: &Point) -> Option<usize> {
+        if *point == self.center {
+            return None;
         }
+        let nearest = self.nearest_tile(&self.relative(point))?;
+        self.tile_containing_near(nearest, point)
+    }
 
-        // Resolve neighbor references
-        for tile in &mut tiles {
-            tile.neighbors = tile
-                .neighbor_ids
+    /// Finds the tile whose center is at the smallest angle from `direction`,
+    /// a direction from the sphere center.
+    ///
+    /// Scans every tile; returns `None` only if there are no tiles.
+    pub(crate) fn nearest_tile(&self, direction: &Vector3) -> Option<usize> {
+        let direction = direction.normalize();
+        self.tiles
+            .iter()
+            .map(|tile| {
+                self.relative(&tile.center_point)
+                    .normalize()
+                    .dot(&direction)
+            })
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Checks the tile with the nearest center, then its neighbors, for `point`.
+    pub(crate) fn tile_containing_near(&self, nearest: usize, point: &Point) -> Option<usize> {
+        std::iter::once(nearest)
+            .chain(self.tiles[nearest].neighbors.iter().copied())
+            .find(|&id| self.tiles[id].contains_point(point))
+    }
+
+    /// Returns the center of the sphere the tiles lie on.
+    ///
+    /// The origin unless the hexasphere was built with
+    /// [`HexasphereBuilder::center`]. All tile geometry is offset by it, and
+    /// normals, latitudes and longitudes are measured from it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, Point};
+    /// let hexasphere = Hexasphere::builder()
+    ///     .radius(10.0)
+    ///     .center(Point::new(100.0, 0.0, 0.0))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(hexasphere.center(), &Point::new(100.0, 0.0, 0.0));
+    /// assert_eq!(Hexasphere::new(10.0, 2, 1.0).center(), &Point::ORIGIN);
+    /// ```
+    pub fn center(&self) -> &Point {
+        &self.center
+    }
+
+    /// Returns the number of decimal places kept by vertex coordinates.
+    ///
+    /// Set by [`HexasphereBuilder::vertex_precision`] and 3 otherwise.
+    /// Operations that compute new points later, such as
+    /// [`Hexasphere::create_inner_sphere`] or [`Hexasphere::relax`], round
+    /// to the same precision so small spheres keep their vertices distinct.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let tiny = Hexasphere::builder()
+    ///     .radius(0.001)
+    ///     .subdivisions(3)
+    ///     .vertex_precision(9)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(tiny.vertex_precision(), 9);
+    /// assert_eq!(Hexasphere::new(10.0, 2, 1.0).vertex_precision(), 3);
+    /// ```
+    pub fn vertex_precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// `point` as a vector from the sphere center.
+    pub(crate) fn relative(&self, point: &Point) -> Vector3 {
+        Vector3::new(
+            point.x - self.center.x,
+            point.y - self.center.y,
+            point.z - self.center.z,
+        )
+    }
+
+    /// The point at `offset` from the sphere center, without rounding.
+    pub(crate) fn placed(&self, offset: &Vector3) -> Point {
+        Point {
+            x: self.center.x + offset.x,
+            y: self.center.y + offset.y,
+            z: self.center.z + offset.z,
+        }
+    }
+
+    /// Re-derives every tile's `neighbor_ids` from the current neighbor centers.
+    ///
+    /// Neighbor ids are keyed by center coordinates, so this must follow any
+    /// operation that moves tile centers.
+    pub(crate) fn refresh_neighbor_ids(&mut self) {
+        for id in 0..self.tiles.len() {
+            let neighbor_ids = self.tiles[id]
+                .neighbors
                 .iter()
-                .filter_map(|id| tile_lookup.get(id).copied())
+                .map(|&neighbor| self.tiles[neighbor].center_point.to_string())
                 .collect();
+            self.tiles[id].neighbor_ids = neighbor_ids;
         }
-
-        Self { radius, tiles }
     }
 
     /// Get regular hexagon parameters for all hexagonal tiles.
@@ -337,6 +847,36 @@ impl Hexasphere {
             .collect()
     }
 
+    /// Get regular pentagon parameters for the 12 pentagonal tiles.
+    ///
+    /// The pentagon counterpart of [`Hexasphere::get_regular_hexagon_approximations`],
+    /// so regular meshes can be instanced at every tile position.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `RegularPentagonParams`, one per pentagon (always 12 for a
+    /// complete sphere)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(1.0, 2, 1.0);
+    ///
+    /// let pentagons = hexasphere.get_regular_pentagon_approximations();
+    /// assert_eq!(pentagons.len(), 12);
+    /// for pent_params in &pentagons {
+    ///     let transform = pent_params.orientation.to_transform_matrix(&pent_params.center);
+    ///     // spawn_regular_pentagon_mesh(transform, pent_params.radius);
+    /// }
+    /// ```
+    pub fn get_regular_pentagon_approximations(&self) -> Vec<RegularPentagonParams> {
+        self.tiles
+            .iter()
+            .filter_map(|tile| tile.get_regular_pentagon_params())
+            .collect()
+    }
+
     /// Get the best single radius to use for uniform regular hexagons.
     ///
     /// Calculates the optimal radius for creating uniform regular hexagons that
@@ -364,6 +904,8 @@ impl Hexasphere {
     ///
     /// - **Pros**: Consistent appearance, simple implementation, good performance
     /// - **Cons**: Some tiles will be over/under-sized, gaps or overlaps possible
+    ///   (see [`Hexasphere::uniform_hexagon_radius_for`] for gap-free or
+    ///   overlap-free radii)
     /// - **Quality**: Depends on geodesic uniformity (higher subdivision = better)
     ///
     /// # Size Distribution
@@ -469,7 +1011,7 @@ impl Hexasphere {
             .collect()
     }
 
-    /// Get orientations only for hexagonal tiles.
+    /// Get orientations only for hexagonal tiles, paired with their tile indices.
     ///
     /// Calculates orientations specifically for hexagonal tiles, filtering out
     /// pentagons and any tiles with invalid orientations. This is useful when
@@ -477,7 +1019,8 @@ impl Hexasphere {
     ///
     /// # Returns
     ///
-    /// A vector of `TileOrientation` containing only valid hexagon orientations
+    /// A vector of `(tile_index, TileOrientation)` pairs for the hexagons with a
+    /// valid orientation, in tile order
     ///
     /// # Filtering Process
     ///
@@ -495,14 +1038,13 @@ impl Hexasphere {
     ///
     /// # Index Correspondence
     ///
-    /// **Note**: The returned vector does NOT correspond to the original `tiles`
-    /// array indices. If you need index correspondence, use `get_tile_orientations()`
-    /// and filter manually.
+    /// The position of an entry in the returned vector is NOT its tile index,
+    /// since pentagons are skipped; use the index stored in each pair. Zipping
+    /// the result with `tiles` would pair orientations with the wrong tiles.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geotiles::Point;
     /// use geotiles::Hexasphere;
     /// let hexasphere = Hexasphere::new(10.0, 4, 0.9);
     /// let hex_orientations = hexasphere.get_hexagon_orientations();
@@ -510,28 +1052,53 @@ impl Hexasphere {
     ///
     /// println!("Processing {} hexagonal tiles", hex_orientations.len());
     ///
-    /// for (i, orientation) in hex_orientations.iter().enumerate() {
-    ///     // Note: 'i' here is NOT the tile index in hexasphere.tiles
-    ///     let transform = orientation.to_transform_matrix(&Point::new(0.0, 0.0, 0.0)); // placeholder center
-    ///     // spawn_regular_hexagon_mesh(transform, uniform_radius);
+    /// for (tile_index, orientation) in &hex_orientations {
+    ///     let tile = &hexasphere[*tile_index];
+    ///     let transform = orientation.to_transform_matrix(&tile.center_point);
+    ///     // spawn_hexagon_with_tile_id(transform, uniform_radius, tile_index);
     /// }
+    /// ```
+    pub fn get_hexagon_orientations(&self) -> Vec<(usize, TileOrientation)> {
+        self.hexagons()
+            .filter_map(|(index, tile)| Some((index, tile.get_orientation()?)))
+            .collect()
+    }
+
+    /// Stores each tile's orientation, average radius, edge length and area.
     ///
-    /// // If you need tile correspondence, use this instead:
-    /// for (tile_index, tile) in hexasphere.tiles.iter().enumerate() {
-    ///     if tile.is_hexagon() {
-    ///         if let Some(orientation) = tile.get_orientation() {
-    ///             let transform = orientation.to_transform_matrix(&tile.center_point);
-    ///             // spawn_hexagon_with_tile_id(transform, uniform_radius, tile_index);
-    ///         }
-    ///     }
+    /// Calls [`Tile::precompute_cache`] on every tile, so render loops that
+    /// query these values per tile per frame no longer recompute them. The
+    /// caches are cleared by [`Hexasphere::rotate`], [`Hexasphere::displace`]
+    /// and [`Hexasphere::relax`].
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = boundary points per tile
+    /// - Space complexity: One small heap allocation per tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// hexasphere.precompute_tile_caches();
+    ///
+    /// for tile in &hexasphere.tiles {
+    ///     let _orientation = tile.get_orientation(); // no recomputation
     /// }
+    /// assert!(hexasphere.tiles.iter().all(|tile| tile.is_cached()));
     /// ```
-    pub fn get_hexagon_orientations(&self) -> Vec<TileOrientation> {
-        self.tiles
-            .iter()
-            .filter(|tile| tile.is_hexagon())
-            .filter_map(|tile| tile.get_orientation())
-            .collect()
+    pub fn precompute_tile_caches(&mut self) {
+        for tile in &mut self.tiles {
+            tile.precompute_cache();
+        }
+    }
+
+    /// Discards all values stored by [`Hexasphere::precompute_tile_caches`].
+    pub fn clear_tile_caches(&mut self) {
+        for tile in &mut self.tiles {
+            tile.clear_cache();
+        }
     }
 
     /// Create a second hexasphere for thickness, ensuring tiles correspond correctly.
@@ -605,41 +1172,147 @@ impl Hexasphere {
     /// - **Fast generation**: Only requires scaling existing points
     /// - **No subdivision**: Avoids expensive icosahedron processing
     /// - **Cache friendly**: Both spheres can share mesh generation code
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `inner_radius` is not finite and positive.
+    /// A radius larger than `self.radius` is allowed and yields an enclosing
+    /// outer shell instead.
     pub fn create_inner_sphere(&self, inner_radius: f64) -> Hexasphere {
-        // Create inner sphere with same parameters but different radius
+        debug_assert!(
+            inner_radius.is_finite() && inner_radius > 0.0,
+            "inner radius must be finite and positive, got {}",
+            inner_radius
+        );
         let ratio = inner_radius / self.radius;
 
-        // Scale all points inward while maintaining topology
-        let mut inner_sphere = Hexasphere::new(inner_radius, 0, 1.0); // dummy values
+        // Scale all points toward the sphere center while maintaining topology
+        let c = &self.center;
+        let scale = |point: &Point| {
+            Point::with_precision(
+                c.x + (point.x - c.x) * ratio,
+                c.y + (point.y - c.y) * ratio,
+                c.z + (point.z - c.z) * ratio,
+                self.precision,
+            )
+        };
+        let tiles = self
+            .tiles
+            .iter()
+            .map(|tile| Tile {
+                id: tile.id,
+                center_point: scale(&tile.center_point),
+                boundary: tile.boundary.iter().map(scale).collect(),
+                neighbor_ids: tile.neighbor_ids.clone(),
+                neighbors: tile.neighbors.clone(),
+                base_face: tile.base_face,
+                sphere_center: tile.sphere_center.clone(),
+                sphere_radius: tile.sphere_radius * ratio,
+                cache: None,
+            })
+            .collect();
+
+        let mut inner = Hexasphere {
+            radius: inner_radius,
+            tiles,
+            pentagon_indices: self.pentagon_indices.clone(),
+            subdivisions: self.subdivisions,
+            center: self.center.clone(),
+            precision: self.precision,
+        };
+        if *c != Point::ORIGIN {
+            inner.refresh_neighbor_ids();
+        }
+        inner
+    }
 
-        // Replace with scaled version of current sphere
-        inner_sphere.radius = inner_radius;
-        inner_sphere.tiles = self
+    /// Rebuilds the tile boundaries at another `hex_size`, keeping centers and topology.
+    ///
+    /// Nothing about the original scale is stored. A full-size corner is the
+    /// centroid of the tile center and the centers of the two neighbors
+    /// meeting there, so every boundary is re-derived from the centers and
+    /// the counter-clockwise neighbor lists exactly as construction derives
+    /// it, then shrunk toward the center by `hex_size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `hex_size` - Scale factor for tile boundaries, as for [`Hexasphere::new`]
+    ///
+    /// # Returns
+    ///
+    /// A new hexasphere with the same radius, tile ids, centers and neighbors.
+    /// Boundary points are rounded to the sphere's
+    /// [`Hexasphere::vertex_precision`].
+    /// Tiles whose neighbor list does not match their boundary keep their
+    /// boundary unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let picking = Hexasphere::new(10.0, 3, 1.0);
+    /// let rendering = picking.with_hex_size(0.8);
+    ///
+    /// assert_eq!(rendering.tiles[5].neighbors, picking.tiles[5].neighbors);
+    /// assert!(rendering.tiles[5].get_area() < picking.tiles[5].get_area());
+    /// ```
+    ///
+    /// # Use Cases
+    ///
+    /// - **Picking and rendering**: Full-size tiles for hit tests, shrunken ones for display
+    /// - **Level of detail**: Give [`Hexasphere::refined`] spheres the usual gaps
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tile count, k = neighbors per tile
+    /// - No subdivision or projection is repeated
+    pub fn with_hex_size(&self, hex_size: f64) -> Hexasphere {
+        let tiles = self
             .tiles
             .iter()
             .map(|tile| {
-                let scaled_center = Point::new(
-                    tile.center_point.x * ratio,
-                    tile.center_point.y * ratio,
-                    tile.center_point.z * ratio,
-                );
+                let n = tile.neighbors.len();
+                if n != tile.boundary.len()
+                    || tile.neighbors.iter().any(|&id| id >= self.tiles.len())
+                {
+                    return tile.clone();
+                }
 
-                let scaled_boundary = tile
-                    .boundary
-                    .iter()
-                    .map(|point| Point::new(point.x * ratio, point.y * ratio, point.z * ratio))
+                // Corner i lies between the neighbors across edges i - 1 and i.
+                // Built around the origin like construction, then moved back.
+                let center = tile.relative(&tile.center_point);
+                let faces: Vec<Face> = (0..n)
+                    .map(|i| {
+                        let before = &self.tiles[tile.neighbors[(i + n - 1) % n]];
+                        let after = &self.tiles[tile.neighbors[i]];
+                        Face::new(
+                            i,
+                            center.clone(),
+                            tile.relative(&before.center_point),
+                            tile.relative(&after.center_point),
+                        )
+                    })
                     .collect();
 
-                Tile {
-                    center_point: scaled_center,
-                    boundary: scaled_boundary,
-                    neighbor_ids: tile.neighbor_ids.clone(),
-                    neighbors: tile.neighbors.clone(),
-                }
+                let mut rescaled = Tile::with_precision(center, &faces, hex_size, self.precision);
+                rescaled.move_sphere_to(&tile.sphere_center);
+                rescaled.id = tile.id;
+                rescaled.neighbors = tile.neighbors.clone();
+                rescaled.neighbor_ids = tile.neighbor_ids.clone();
+                rescaled.base_face = tile.base_face;
+                rescaled.sphere_radius = tile.sphere_radius;
+                rescaled
             })
             .collect();
 
-        inner_sphere
+        Hexasphere {
+            radius: self.radius,
+            tiles,
+            pentagon_indices: self.pentagon_indices.clone(),
+            subdivisions: self.subdivisions,
+            center: self.center.clone(),
+            precision: self.precision,
+        }
     }
 
     /// Create thick tiles by extruding inward with uniform thickness.
@@ -722,4 +1395,386 @@ impl Hexasphere {
             .map(|tile| ThickTile::from_surface_tile(tile, thickness))
             .collect()
     }
+
+    /// Creates thick tiles with a per-tile thickness and extrusion direction.
+    ///
+    /// The building block for heightmap-style terrain: each tile's thickness
+    /// comes from `thickness`, for example an elevation lookup, and `mode`
+    /// decides whether the slabs rise above the surface, sink below it or
+    /// straddle it.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Extrusion direction applied to every tile, see [`ExtrusionMode`]
+    /// * `thickness` - Returns the thickness for a tile, given its index and the tile
+    ///
+    /// # Returns
+    ///
+    /// One [`ThickTile`] per tile, in tile order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{ExtrusionMode, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    ///
+    /// // Raise tiles in the northern hemisphere higher than the rest
+    /// let terrain = hexasphere.create_thick_tiles_with(ExtrusionMode::Outward, |_, tile| {
+    ///     if tile.center_point.y > 0.0 { 1.0 } else { 0.2 }
+    /// });
+    /// assert_eq!(terrain.len(), hexasphere.tiles.len());
+    /// ```
+    pub fn create_thick_tiles_with<F>(&self, mode: ExtrusionMode, thickness: F) -> Vec<ThickTile>
+    where
+        F: Fn(usize, &Tile) -> f64,
+    {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(index, tile)| {
+                ThickTile::from_surface_tile_with_mode(tile, thickness(index, tile), mode)
+            })
+            .collect()
+    }
+}
+
+/// The sorted tile centers of a sphere and the triangles around each of them.
+///
+/// Holds only the compact indexed vertex and triangle arrays, so tiles can be
+/// built one at a time without materializing the whole sphere.
+pub(crate) struct TilePlan {
+    /// Vertices projected onto the sphere
+    pub(crate) projected: Vec<Point>,
+    /// Triangles of the geodesic mesh, as indices into `projected`
+    pub(crate) triangles: Vec<[u32; 3]>,
+    /// Tile center vertices in tile id order, with the triangles around each
+    pub(crate) tile_vertices: Vec<(usize, Vec<u32>)>,
+    /// Tile ids of the 12 pentagons
+    pub(crate) pentagon_indices: Vec<usize>,
+    /// Corner directions of the 20 base icosahedron faces, from the pentagons
+    base_faces: Vec<[Vector3; 3]>,
+    /// Tile id of each vertex in `projected`, `usize::MAX` for vertices
+    /// merged into another or outside every triangle
+    vertex_tiles: Vec<usize>,
+    first_face_id: usize,
+    radius: f64,
+    hex_size: f64,
+    precision: u32,
+}
+
+impl TilePlan {
+    /// Runs the construction pipeline up to, but not including, tile creation.
+    pub(crate) fn new(
+        options: &HexasphereBuilder,
+        progress: &mut dyn FnMut(BuildPhase, f32),
+    ) -> Self {
+        let num_divisions = options.subdivisions;
+        let goldberg_n = options.goldberg_n;
+        let parallel = options.parallel;
+        let tao = 1.61803399; // Golden ratio
+
+        // Create icosahedron corners, turned about the Z axis by the base rotation
+        let (_, base_angle) = options.base_rotation();
+        let (sin, cos) = base_angle.sin_cos();
+        let corner = |x: f64, y: f64, z: f64| Point::new(x * cos - y * sin, x * sin + y * cos, z);
+        let corners = vec![
+            corner(1000.0, tao * 1000.0, 0.0),
+            corner(-1000.0, tao * 1000.0, 0.0),
+            corner(1000.0, -tao * 1000.0, 0.0),
+            corner(-1000.0, -tao * 1000.0, 0.0),
+            corner(0.0, 1000.0, tao * 1000.0),
+            corner(0.0, -1000.0, tao * 1000.0),
+            corner(0.0, 1000.0, -tao * 1000.0),
+            corner(0.0, -1000.0, -tao * 1000.0),
+            corner(tao * 1000.0, 0.0, 1000.0),
+            corner(-tao * 1000.0, 0.0, 1000.0),
+            corner(tao * 1000.0, 0.0, -1000.0),
+            corner(-tao * 1000.0, 0.0, -1000.0),
+        ];
+
+        // Every vertex is stored once; triangles refer to vertices by index
+        let mut pool = VertexPool::new();
+        for corner in &corners {
+            pool.insert(corner.clone());
+        }
+
+        // Create initial icosahedron faces
+        let face_indices = vec![
+            [0, 1, 4],
+            [1, 9, 4],
+            [4, 9, 5],
+            [5, 9, 3],
+            [2, 3, 7],
+            [3, 2, 5],
+            [7, 10, 2],
+            [0, 8, 10],
+            [0, 4, 8],
+            [8, 2, 10],
+            [8, 4, 5],
+            [8, 5, 2],
+            [1, 0, 6],
+            [11, 1, 6],
+            [3, 9, 11],
+            [6, 10, 7],
+            [3, 11, 7],
+            [11, 6, 7],
+            [6, 0, 10],
+            [9, 1, 11],
+        ];
+
+        // Subdivide faces
+        progress(BuildPhase::Subdividing, 0.0);
+        let triangles: Vec<[u32; 3]> = if goldberg_n == 0 {
+            // Each base face gets its own pool, merged in face order so the
+            // result matches a single sequential pass
+            let subdivided = map_vec(face_indices.clone(), parallel, |face| {
+                let mut local = VertexPool::new();
+                let face_corners = face.map(|corner| local.insert(corners[corner].clone()));
+                let triangles = subdivide_face_indexed(
+                    face_corners,
+                    num_divisions,
+                    options.subdivision_mode,
+                    &mut local,
+                );
+                (triangles, local)
+            });
+
+            let mut triangles = Vec::new();
+            let face_count = subdivided.len();
+            for (face, (local_triangles, local)) in subdivided.into_iter().enumerate() {
+                let remap: Vec<u32> = local
+                    .into_points()
+                    .into_iter()
+                    .map(|point| pool.insert(point))
+                    .collect();
+                triangles.extend(
+                    local_triangles
+                        .into_iter()
+                        .map(|triangle| triangle.map(|index| remap[index as usize])),
+                );
+                progress(
+                    BuildPhase::Subdividing,
+                    (face + 1) as f32 / face_count as f32,
+                );
+            }
+            triangles
+        } else {
+            let triangles = subdivide_goldberg(
+                &corners,
+                &face_indices,
+                num_divisions,
+                goldberg_n,
+                &mut pool,
+            );
+            progress(BuildPhase::Subdividing, 1.0);
+            triangles
+        };
+
+        // Unsubdivided faces keep the icosahedron face ids; new faces follow them
+        let first_face_id = if goldberg_n == 0 && num_divisions == 0 {
+            0
+        } else {
+            face_indices.len()
+        };
+
+        Self::from_pool(pool, triangles, first_face_id, options, progress)
+    }
+
+    /// Projects pooled vertices onto the sphere and groups the triangles around them.
+    ///
+    /// Faces are numbered from `first_face_id`; `options` supplies the radius,
+    /// `hex_size`, vertex precision and parallelism.
+    pub(crate) fn from_pool(
+        pool: VertexPool,
+        mut triangles: Vec<[u32; 3]>,
+        first_face_id: usize,
+        options: &HexasphereBuilder,
+        progress: &mut dyn FnMut(BuildPhase, f32),
+    ) -> Self {
+        let radius = options.radius;
+        let parallel = options.parallel;
+        let precision = options.vertex_precision;
+
+        // Project all points to sphere
+        progress(BuildPhase::Projecting, 0.0);
+        let projected = map_vec(pool.into_points(), parallel, |mut point| {
+            point.project(radius, 1.0);
+            point
+        });
+
+        // Points that round to the same position on the sphere become one
+        // vertex; only the lookup key is rounded, not the stored coordinates
+        let mut canonical: HashMap<[u64; 3], u32> = HashMap::new();
+        let remap: Vec<u32> = projected
+            .iter()
+            .enumerate()
+            .map(|(index, point)| {
+                let key = point
+                    .to_array()
+                    .map(|coordinate| round_coordinate(coordinate, precision).to_bits());
+                *canonical.entry(key).or_insert(index as u32)
+            })
+            .collect();
+        drop(canonical);
+        for_each_mut(&mut triangles, parallel, |triangle| {
+            *triangle = triangle.map(|index| remap[index as usize]);
+        });
+        drop(remap);
+        progress(BuildPhase::Projecting, 1.0);
+
+        progress(BuildPhase::GroupingFaces, 0.0);
+
+        // Group triangles by their vertices to create tiles
+        let mut vertex_triangles: Vec<Vec<u32>> = vec![Vec::new(); projected.len()];
+        for (index, triangle) in triangles.iter().enumerate() {
+            for &vertex in triangle {
+                vertex_triangles[vertex as usize].push(index as u32);
+            }
+        }
+
+        // Sort by coordinates so tile indices are identical across runs
+        let mut tile_vertices: Vec<(usize, Vec<u32>)> = vertex_triangles
+            .into_iter()
+            .enumerate()
+            .filter(|(_, triangle_indices)| !triangle_indices.is_empty())
+            .collect();
+        tile_vertices.sort_by(|(a, _), (b, _)| {
+            let (a, b) = (&projected[*a], &projected[*b]);
+            a.x.total_cmp(&b.x)
+                .then(a.y.total_cmp(&b.y))
+                .then(a.z.total_cmp(&b.z))
+        });
+
+        // Only the icosahedron corners are surrounded by five triangles
+        let pentagon_indices: Vec<usize> = tile_vertices
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, triangle_indices))| triangle_indices.len() == 5)
+            .map(|(id, _)| id)
+            .collect();
+        let pentagon_directions: Vec<Vector3> = pentagon_indices
+            .iter()
+            .map(|&id| Vector3::from(&projected[tile_vertices[id].0]).normalize())
+            .collect();
+        let base_faces = base_face_corners(&pentagon_directions);
+
+        let mut vertex_tiles = vec![usize::MAX; projected.len()];
+        for (id, (vertex, _)) in tile_vertices.iter().enumerate() {
+            vertex_tiles[*vertex] = id;
+        }
+
+        progress(BuildPhase::GroupingFaces, 1.0);
+
+        Self {
+            projected,
+            triangles,
+            tile_vertices,
+            pentagon_indices,
+            base_faces,
+            vertex_tiles,
+            first_face_id,
+            radius,
+            hex_size: options.hex_size,
+            precision,
+        }
+    }
+
+    /// Builds the tile centered on `vertex`, materializing only its faces.
+    ///
+    /// Neighbors are found through the mesh's vertex indices rather than
+    /// by position, so every edge shared with another triangle resolves.
+    pub(crate) fn build_tile(&self, vertex: usize, triangle_indices: &[u32]) -> Tile {
+        let point = self.projected[vertex].clone();
+        let base_face = base_face_containing(&self.base_faces, &Vector3::from(&point).normalize());
+        let mut point_faces: Vec<Face> = triangle_indices
+            .iter()
+            .map(|&index| {
+                let [a, b, c] =
+                    self.triangles[index as usize].map(|i| self.projected[i as usize].clone());
+                Face::new(self.first_face_id + index as usize, a, b, c)
+            })
+            .collect();
+
+        // Sort faces to be ordered around the point
+        sort_faces_around_point_with_precision(&mut point_faces, &point, self.precision);
+
+        // The tile across the edge between faces i and i + 1 is centered on
+        // the other vertex the two triangles share. Every vertex of a
+        // triangle is a tile center, so the lookup cannot fail unless a
+        // vertex precision too coarse for the radius collapsed the triangles
+        // and scrambled their order.
+        let triangle = |face: &Face| self.triangles[face.id - self.first_face_id];
+        let mut neighbors = Vec::with_capacity(point_faces.len());
+        for i in 0..point_faces.len() {
+            let next = triangle(&point_faces[(i + 1) % point_faces.len()]);
+            let shared = triangle(&point_faces[i])
+                .into_iter()
+                .find(|&other| other as usize != vertex && next.contains(&other));
+            if let Some(other) = shared {
+                let id = self.vertex_tiles[other as usize];
+                debug_assert_ne!(id, usize::MAX, "vertex {other} has no tile");
+                if !neighbors.contains(&id) {
+                    neighbors.push(id);
+                }
+            }
+        }
+        debug_assert_eq!(
+            neighbors.len(),
+            point_faces.len(),
+            "tile at vertex {vertex} lost neighbors; the vertex precision is too coarse for the radius"
+        );
+
+        let mut tile = Tile::with_neighbors(
+            point,
+            &point_faces,
+            self.hex_size,
+            self.precision,
+            neighbors,
+        );
+        tile.id = self.vertex_tiles[vertex];
+        tile.sphere_radius = self.radius;
+        tile.base_face = base_face;
+        tile
+    }
+}
+
+impl fmt::Display for Hexasphere {
+    /// Summarizes the sphere on one line, without listing tiles.
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// assert_eq!(
+    ///     hexasphere.to_string(),
+    ///     "Hexasphere { radius: 10, tiles: 162 (150 hex / 12 pent), subdivision: 4 }"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Hexasphere {{ radius: {}, tiles: {} ({} hex / {} pent)",
+            self.radius,
+            self.tiles.len(),
+            self.hexagon_count(),
+            self.pentagon_count()
+        )?;
+        if let Some(subdivisions) = self.subdivisions {
+            write!(f, ", subdivision: {}", subdivisions)?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl Index<usize> for Hexasphere {
+    type Output = Tile;
+
+    /// Returns the tile at `index`, like `tiles[index]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range; see [`Hexasphere::tile`] for a
+    /// checked lookup.
+    fn index(&self, index: usize) -> &Tile {
+        &self.tiles[index]
+    }
 }
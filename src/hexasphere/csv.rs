@@ -0,0 +1,143 @@
+//! CSV export of per-tile summary data.
+//!
+//! One row per tile with the columns:
+//!
+//! | Column             | Notes                                         |
+//! |--------------------|-----------------------------------------------|
+//! | `index`            | Tile id                                       |
+//! | `center_x/y/z`     | Tile center                                   |
+//! | `lat`, `lon`       | Center latitude and longitude in degrees      |
+//! | `is_pentagon`      | `true` or `false`                             |
+//! | `boundary_count`   | Number of boundary points                     |
+//! | `avg_radius`       | [`crate::Tile::get_average_radius`]           |
+//! | `avg_edge_length`  | [`crate::Tile::get_average_edge_length`]      |
+//! | `area`             | [`crate::Tile::get_area`]                     |
+//! | `neighbor_indices` | Neighbor tile ids, separated by `;`           |
+
+use crate::hexasphere::core::Hexasphere;
+use std::io::{self, Write};
+
+/// The mandatory first row of the CSV export.
+const HEADER: &str = "index,center_x,center_y,center_z,lat,lon,is_pentagon,boundary_count,\
+                      avg_radius,avg_edge_length,area,neighbor_indices";
+
+impl Hexasphere {
+    /// Exports per-tile summary data as CSV.
+    ///
+    /// See the [module documentation](self) for the columns. Numbers are
+    /// written with 6 decimal places and a `.` separator regardless of
+    /// locale, so the output loads directly into pandas, R or a spreadsheet.
+    ///
+    /// # Returns
+    ///
+    /// A header row followed by one row per tile, each terminated by `\n`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let csv = hexasphere.to_csv();
+    ///
+    /// let mut lines = csv.lines();
+    /// assert!(lines.next().unwrap().starts_with("index,center_x"));
+    /// assert_eq!(lines.count(), hexasphere.tiles.len());
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut buffer = Vec::new();
+        self.write_csv(&mut buffer)
+            .expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(buffer).expect("CSV output is valid UTF-8")
+    }
+
+    /// Streams the CSV produced by [`Hexasphere::to_csv`] into a writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination, such as a `File` or `BufWriter`. Writers are
+    ///   called once per field, so wrap unbuffered files in a `BufWriter`
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or the first error returned by `writer`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let mut buffer = Vec::new();
+    /// hexasphere.write_csv(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(buffer, hexasphere.to_csv().into_bytes());
+    /// ```
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "{HEADER}")?;
+
+        for tile in &self.tiles {
+            let center = &tile.center_point;
+            let lat_lon = tile.get_lat_lon(self.radius);
+            write!(
+                writer,
+                "{},{:.6},{:.6},{:.6},{:.6},{:.6},{},{},{:.6},{:.6},{:.6},",
+                tile.id,
+                center.x,
+                center.y,
+                center.z,
+                lat_lon.lat,
+                lat_lon.lon,
+                tile.is_pentagon(),
+                tile.boundary.len(),
+                tile.get_average_radius(),
+                tile.get_average_edge_length(),
+                tile.get_area(),
+            )?;
+            for (i, neighbor) in tile.neighbors.iter().enumerate() {
+                if i > 0 {
+                    write!(writer, ";")?;
+                }
+                write!(writer, "{neighbor}")?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HEADER;
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_to_csv_parses_back() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let csv = hexasphere.to_csv();
+        let rows: Vec<Vec<&str>> = csv.lines().map(|line| line.split(',').collect()).collect();
+
+        assert_eq!(rows.len(), hexasphere.tiles.len() + 1);
+        assert_eq!(rows[0].join(","), HEADER);
+        assert!(rows.iter().all(|row| row.len() == 12));
+
+        let body = &rows[1..];
+        let pentagons = body.iter().filter(|row| row[6] == "true").count();
+        assert_eq!(pentagons, 12);
+
+        for (id, row) in body.iter().enumerate() {
+            assert_eq!(row[0].parse::<usize>().unwrap(), id);
+            for field in &row[1..6] {
+                let value: f64 = field.parse().unwrap();
+                assert!(value.is_finite());
+                assert_eq!(field.split('.').nth(1).unwrap().len(), 6);
+            }
+            assert!(row[8..11]
+                .iter()
+                .all(|field| field.parse::<f64>().unwrap() > 0.0));
+
+            let neighbors: Vec<usize> = row[11].split(';').map(|n| n.parse().unwrap()).collect();
+            assert_eq!(neighbors.len(), row[7].parse::<usize>().unwrap());
+            assert!(neighbors.iter().all(|&n| n < body.len()));
+        }
+    }
+}
@@ -0,0 +1,667 @@
+//! Unique edges between adjacent tiles.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+use std::collections::HashSet;
+
+/// One shared border between two adjacent tiles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+/// let edge = &hexasphere.edges()[0];
+///
+/// let (a, b) = edge.tiles;
+/// assert!(a < b);
+/// assert!(hexasphere.tiles[a].neighbors.contains(&b));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileEdge {
+    /// Ids of the two tiles sharing the edge, lower id first
+    pub tiles: (usize, usize),
+    /// Boundary vertices at either end of the edge, counter-clockwise around `tiles.0`
+    pub endpoints: (Point, Point),
+}
+
+/// The gap between the facing boundary edges of two adjacent tiles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let hexasphere = Hexasphere::new(10.0, 2, 0.9);
+/// let gap = &hexasphere.gap_widths()[0];
+/// assert!(gap.width > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeGap {
+    /// Ids of the two tiles facing each other, lower id first
+    pub tiles: (usize, usize),
+    /// Shortest distance between the two boundary edges
+    pub width: f64,
+}
+
+/// Aggregate gap widths over all edges, as computed by [`Hexasphere::gap_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GapStats {
+    /// Narrowest gap
+    pub min_width: f64,
+    /// Mean gap over all edges
+    pub mean_width: f64,
+    /// Widest gap
+    pub max_width: f64,
+}
+
+impl Hexasphere {
+    /// Lists every edge of the Goldberg polyhedron exactly once.
+    ///
+    /// Each pair of neighboring tiles contributes one [`TileEdge`]. The
+    /// endpoints are the two boundary vertices of `tiles.0` on either side of
+    /// the neighbor, relying on the counter-clockwise neighbor order (see
+    /// [`crate::Tile::neighbors`]). With `hex_size = 1.0` adjacent tiles share
+    /// these vertices exactly; with smaller sizes the tiles no longer touch and
+    /// the endpoints lie on the shrunken boundary of `tiles.0`.
+    ///
+    /// # Returns
+    ///
+    /// All edges, ordered by `tiles.0` and then counter-clockwise around it
+    ///
+    /// # Use Cases
+    ///
+    /// - **Borders**: Draw each tile border once instead of twice
+    /// - **Edge mechanics**: Attach walls, rivers or roads to tile borders
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = neighbors per tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let edges = hexasphere.edges();
+    ///
+    /// // Every edge is shared by two tiles: E = (6·hexagons + 5·pentagons) / 2
+    /// let hexagons = hexasphere.tiles.len() - 12;
+    /// assert_eq!(edges.len(), (6 * hexagons + 5 * 12) / 2);
+    /// ```
+    pub fn edges(&self) -> Vec<TileEdge> {
+        let mut edges = Vec::new();
+
+        for tile in &self.tiles {
+            let n = tile.boundary.len();
+            if tile.neighbors.len() != n {
+                continue;
+            }
+
+            for (i, &neighbor) in tile.neighbors.iter().enumerate() {
+                if neighbor <= tile.id {
+                    continue;
+                }
+                edges.push(TileEdge {
+                    tiles: (tile.id, neighbor),
+                    endpoints: (tile.boundary[i].clone(), tile.boundary[(i + 1) % n].clone()),
+                });
+            }
+        }
+
+        edges
+    }
+
+    /// Returns the boundary edge between two adjacent tiles.
+    ///
+    /// The edge is found through `a`'s neighbor order (see
+    /// [`crate::Tile::neighbors`]), so it works for any `hex_size`; the
+    /// endpoints are the corners of `a` on either side of `b`, matching
+    /// [`TileEdge::endpoints`].
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Index of the tile whose boundary and winding are used
+    /// * `b` - Index of the neighboring tile
+    ///
+    /// # Returns
+    ///
+    /// The two corners counter-clockwise around `a`, or `None` if either
+    /// index is out of range or the tiles are not neighbors
+    ///
+    /// # Use Cases
+    ///
+    /// - **Gates and bridges**: Place an object on the border between two tiles
+    /// - **Borders**: Draw the segment separating two specific tiles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let neighbor = hexasphere.tiles[0].neighbors[2];
+    ///
+    /// let (start, end) = hexasphere.shared_edge(0, neighbor).unwrap();
+    /// assert!(hexasphere.tiles[neighbor].boundary.contains(&start));
+    /// assert!(hexasphere.tiles[neighbor].boundary.contains(&end));
+    ///
+    /// assert_eq!(hexasphere.shared_edge(0, 0), None);
+    /// ```
+    pub fn shared_edge(&self, a: usize, b: usize) -> Option<(Point, Point)> {
+        let tile = self.tiles.get(a)?;
+        if b >= self.tiles.len() {
+            return None;
+        }
+        let n = tile.boundary.len();
+        let edge = (0..n).find(|&edge| tile.neighbor_across_edge(edge) == Some(b))?;
+        Some((
+            tile.boundary[edge].clone(),
+            tile.boundary[(edge + 1) % n].clone(),
+        ))
+    }
+
+    /// Traces the outline of a set of tiles as closed loops.
+    ///
+    /// An edge is on the border if exactly one of its two tiles is in the
+    /// set. Border edges are chained into loops by walking around each corner
+    /// through the tiles of the set, using neighbor indices rather than point
+    /// equality, so the result is well-formed for any `hex_size`.
+    ///
+    /// Loops follow the counter-clockwise tile winding seen from outside the
+    /// sphere: an outer border runs counter-clockwise around the region and
+    /// the border of a hole runs clockwise. Every point is the boundary
+    /// vertex of the region tile at the start of a border edge; with
+    /// `hex_size = 1.0` these are exactly the shared tile corners.
+    ///
+    /// # Arguments
+    ///
+    /// * `tiles` - Ids of the region's tiles; duplicates and out-of-range ids
+    ///   are ignored, and the tiles need not be connected
+    ///
+    /// # Returns
+    ///
+    /// One loop of points per outline, each without repeating its first
+    /// point at the end. Empty if the set is empty or covers the whole sphere.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Territory borders**: Outline countries or player regions
+    /// - **Coastlines**: Trace land masses and lakes
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n + b × k) where n = tiles, b = border edges,
+    ///   k = neighbors per tile
+    /// - Space complexity: O(n) for the membership flags
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    ///
+    /// // A tile and its neighbors form a single blob with one outline
+    /// let center = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+    /// let mut region = center.neighbors.clone();
+    /// region.push(center.id);
+    ///
+    /// let border = hexasphere.region_border(&region);
+    /// assert_eq!(border.len(), 1);
+    /// ```
+    pub fn region_border(&self, tiles: &[usize]) -> Vec<Vec<Point>> {
+        let mut member = vec![false; self.tiles.len()];
+        for &id in tiles {
+            if let Some(flag) = member.get_mut(id) {
+                *flag = true;
+            }
+        }
+        let is_member = |id: usize| member.get(id).copied().unwrap_or(false);
+
+        // Border edges as (tile, edge index), edge i running boundary[i] → boundary[i + 1]
+        let mut visited: HashSet<(usize, usize)> = HashSet::new();
+        let mut loops = Vec::new();
+
+        for tile in self.tiles.iter().filter(|tile| member[tile.id]) {
+            let n = tile.boundary.len();
+            if tile.neighbors.len() != n {
+                continue;
+            }
+
+            for start in 0..n {
+                if is_member(tile.neighbors[start]) || visited.contains(&(tile.id, start)) {
+                    continue;
+                }
+
+                let mut points = Vec::new();
+                let mut current = (tile.id, start);
+                while visited.insert(current) {
+                    points.push(self.tiles[current.0].boundary[current.1].clone());
+                    match self.next_border_edge(current, &is_member) {
+                        Some(next) => current = next,
+                        None => break,
+                    }
+                }
+                loops.push(points);
+            }
+        }
+
+        loops
+    }
+
+    /// Finds the border edge following `(tile, edge)` around the region.
+    ///
+    /// Rotates around the corner at the end of the edge, stepping into every
+    /// member tile that shares it, until an edge facing a non-member is found.
+    fn next_border_edge(
+        &self,
+        (mut tile, mut edge): (usize, usize),
+        is_member: &impl Fn(usize) -> bool,
+    ) -> Option<(usize, usize)> {
+        // A corner is shared by at most three tiles; allow for any valence
+        for _ in 0..self.tiles.len() {
+            let current = &self.tiles[tile];
+            let n = current.neighbors.len();
+            if n != current.boundary.len() {
+                return None;
+            }
+
+            let candidate = (edge + 1) % n;
+            let across = current.neighbors[candidate];
+            if !is_member(across) {
+                return Some((tile, candidate));
+            }
+
+            // The shared edge runs the other way in the neighbor, so its end
+            // corner there is the corner we are rotating around
+            edge = self.tiles[across]
+                .neighbors
+                .iter()
+                .position(|&back| back == tile)?;
+            tile = across;
+        }
+        None
+    }
+
+    /// Measures the gap between adjacent tiles across every edge.
+    ///
+    /// With `hex_size < 1.0` each tile shrinks toward its center, so the two
+    /// boundary edges facing each other across a shared border separate. The
+    /// width is the shortest distance between those two segments; with
+    /// `hex_size = 1.0` the tiles share their corners and every width is 0.
+    ///
+    /// # Returns
+    ///
+    /// One [`EdgeGap`] per edge, in the same order as [`Hexasphere::edges`]
+    ///
+    /// # Use Cases
+    ///
+    /// - **Borders**: Size rendered tile borders to fill the gaps
+    /// - **Colliders**: Check that shrunken tiles leave room between colliders
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = neighbors per tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let touching = Hexasphere::new(10.0, 3, 1.0);
+    /// assert!(touching.gap_widths().iter().all(|gap| gap.width == 0.0));
+    ///
+    /// let spaced = Hexasphere::new(10.0, 3, 0.9);
+    /// assert!(spaced.gap_widths().iter().all(|gap| gap.width > 0.0));
+    /// ```
+    pub fn gap_widths(&self) -> Vec<EdgeGap> {
+        self.edges()
+            .into_iter()
+            .filter_map(|edge| {
+                let (a, b) = edge.tiles;
+                let (start, end) = self.shared_edge(b, a)?;
+                let width = segment_distance(
+                    [&edge.endpoints.0, &edge.endpoints.1].map(Vector3::from),
+                    [&start, &end].map(Vector3::from),
+                );
+                Some(EdgeGap {
+                    tiles: edge.tiles,
+                    width,
+                })
+            })
+            .collect()
+    }
+
+    /// Summarizes [`Hexasphere::gap_widths`] as minimum, mean and maximum.
+    ///
+    /// # Returns
+    ///
+    /// The aggregate widths, all 0 for a sphere without edges
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let stats = Hexasphere::new(10.0, 4, 0.9).gap_stats();
+    /// assert!(0.0 < stats.min_width && stats.min_width <= stats.mean_width);
+    /// assert!(stats.mean_width <= stats.max_width);
+    /// ```
+    pub fn gap_stats(&self) -> GapStats {
+        let widths: Vec<f64> = self.gap_widths().iter().map(|gap| gap.width).collect();
+        if widths.is_empty() {
+            return GapStats {
+                min_width: 0.0,
+                mean_width: 0.0,
+                max_width: 0.0,
+            };
+        }
+
+        GapStats {
+            min_width: widths.iter().copied().fold(f64::INFINITY, f64::min),
+            mean_width: widths.iter().sum::<f64>() / widths.len() as f64,
+            max_width: widths.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+/// Shortest distance between the segments `p` and `q`, given by their endpoints.
+fn segment_distance(p: [Vector3; 2], q: [Vector3; 2]) -> f64 {
+    let [p0, p1] = p;
+    let [q0, q1] = q;
+    let d1 = &p1 - &p0;
+    let d2 = &q1 - &q0;
+    let r = &p0 - &q0;
+    let (a, e, f) = (d1.dot(&d1), d2.dot(&d2), d2.dot(&r));
+
+    // Parameters of the closest points along each segment
+    let (s, t) = if a <= f64::EPSILON && e <= f64::EPSILON {
+        (0.0, 0.0)
+    } else if a <= f64::EPSILON {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = d1.dot(&r);
+        if e <= f64::EPSILON {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(&d2);
+            let denominator = a * e - b * b;
+            let s = if denominator > 0.0 {
+                ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    (&p0 + &(&d1 * s)).distance_to(&(&q0 + &(&d2 * t)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::segment_distance;
+    use crate::geometry::{Point, Vector3};
+    use crate::hexasphere::core::Hexasphere;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_edges_satisfy_euler_formula() {
+        for hexasphere in [
+            Hexasphere::new(10.0, 1, 1.0),
+            Hexasphere::new(10.0, 4, 1.0),
+            Hexasphere::new_goldberg(10.0, 2, 1, 1.0),
+        ] {
+            let edges = hexasphere.edges();
+            let pentagons = hexasphere.tiles.iter().filter(|t| t.is_pentagon()).count();
+            let hexagons = hexasphere.tiles.iter().filter(|t| t.is_hexagon()).count();
+            assert_eq!(edges.len(), (6 * hexagons + 5 * pentagons) / 2);
+
+            let vertices: HashSet<String> = hexasphere
+                .tiles
+                .iter()
+                .flat_map(|t| t.boundary.iter().map(|p| p.to_string()))
+                .collect();
+            let euler = vertices.len() as i64 - edges.len() as i64 + hexasphere.tiles.len() as i64;
+            assert_eq!(euler, 2);
+        }
+    }
+
+    #[test]
+    fn test_edges_are_unique_and_shared() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let edges = hexasphere.edges();
+
+        let pairs: HashSet<(usize, usize)> = edges.iter().map(|e| e.tiles).collect();
+        assert_eq!(pairs.len(), edges.len());
+
+        for edge in &edges {
+            let (a, b) = edge.tiles;
+            assert!(a < b);
+            for tile in [a, b] {
+                let boundary = &hexasphere.tiles[tile].boundary;
+                assert!(boundary.contains(&edge.endpoints.0));
+                assert!(boundary.contains(&edge.endpoints.1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shared_edge_between_every_adjacent_pair() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+        for a in &hexasphere.tiles {
+            for b in &hexasphere.tiles {
+                let shared = hexasphere.shared_edge(a.id, b.id);
+                if !a.neighbors.contains(&b.id) {
+                    assert_eq!(shared, None);
+                    // A tile trivially shares every edge with itself
+                    if a.id != b.id {
+                        assert_eq!(a.shared_edge_with(b), None);
+                    }
+                    continue;
+                }
+
+                let (start, end) = shared.expect("adjacent tiles share an edge");
+                assert!(a.boundary.contains(&start) && a.boundary.contains(&end));
+                assert!(b.boundary.contains(&start) && b.boundary.contains(&end));
+                assert_eq!(a.shared_edge_with(b), Some((start.clone(), end.clone())));
+                // The same edge runs the other way around the neighbor
+                assert_eq!(hexasphere.shared_edge(b.id, a.id), Some((end, start)));
+            }
+        }
+
+        assert_eq!(hexasphere.shared_edge(0, hexasphere.tiles.len()), None);
+        assert_eq!(hexasphere.shared_edge(hexasphere.tiles.len(), 0), None);
+    }
+
+    #[test]
+    fn test_adjacent_tiles_share_identical_corners() {
+        for precision in [3, 9, 15] {
+            let hexasphere = Hexasphere::builder()
+                .radius(10.0)
+                .subdivisions(3)
+                .vertex_precision(precision)
+                .build()
+                .unwrap();
+
+            for (a, tile) in hexasphere.iter() {
+                for &b in &tile.neighbors {
+                    let (start, end) = hexasphere.shared_edge(a, b).unwrap();
+                    let other = &hexasphere.tiles[b].boundary;
+                    // Exact comparison of the coordinates, not the rounded hash
+                    let same = |p: &Point| {
+                        other.iter().any(|q| {
+                            p.x.to_bits() == q.x.to_bits()
+                                && p.y.to_bits() == q.y.to_bits()
+                                && p.z.to_bits() == q.z.to_bits()
+                        })
+                    };
+                    assert!(same(&start) && same(&end), "tiles {a} and {b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shared_edge_with_gaps() {
+        let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+        let tile = &hexasphere.tiles[0];
+        let neighbor = &hexasphere.tiles[tile.neighbors[0]];
+
+        assert_eq!(tile.shared_edge_with(neighbor), None);
+        assert_eq!(
+            hexasphere.shared_edge(tile.id, neighbor.id),
+            Some((tile.boundary[0].clone(), tile.boundary[1].clone()))
+        );
+    }
+
+    #[test]
+    fn test_edges_with_gaps_use_lower_tile_boundary() {
+        let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+        let edges = hexasphere.edges();
+
+        assert_eq!(edges.len(), Hexasphere::new(10.0, 2, 1.0).edges().len());
+        for edge in &edges {
+            let boundary = &hexasphere.tiles[edge.tiles.0].boundary;
+            assert!(boundary.contains(&edge.endpoints.0));
+            assert!(boundary.contains(&edge.endpoints.1));
+        }
+    }
+
+    /// Whether `a` is `b` rotated by some offset.
+    fn is_rotation(a: &[Point], b: &[Point]) -> bool {
+        a.len() == b.len()
+            && (0..b.len()).any(|k| (0..a.len()).all(|i| a[i] == b[(i + k) % b.len()]))
+    }
+
+    #[test]
+    fn test_single_tile_border_is_its_boundary() {
+        for hex_size in [1.0, 0.8] {
+            let hexasphere = Hexasphere::new(10.0, 4, hex_size);
+            for id in [0, 17, hexasphere.pentagon_indices()[3]] {
+                let border = hexasphere.region_border(&[id]);
+                assert_eq!(border.len(), 1);
+                assert!(is_rotation(&border[0], &hexasphere.tiles[id].boundary));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tile_with_neighbors_has_one_outer_loop() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+        for center in [
+            &hexasphere.tiles[40],
+            &hexasphere.tiles[hexasphere.pentagon_indices()[0]],
+        ] {
+            let mut region = center.neighbors.clone();
+            region.push(center.id);
+
+            let outer_edges: usize = region
+                .iter()
+                .map(|&id| {
+                    let tile = &hexasphere.tiles[id];
+                    tile.neighbors
+                        .iter()
+                        .filter(|n| !region.contains(n))
+                        .count()
+                })
+                .sum();
+
+            let border = hexasphere.region_border(&region);
+            assert_eq!(border.len(), 1);
+            assert_eq!(border[0].len(), outer_edges);
+
+            let unique: HashSet<String> = border[0].iter().map(|p| p.to_string()).collect();
+            assert_eq!(unique.len(), outer_edges);
+        }
+    }
+
+    #[test]
+    fn test_region_border_holes_and_degenerate_sets() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        assert!(hexasphere.region_border(&[]).is_empty());
+
+        let all: Vec<usize> = (0..hexasphere.tiles.len()).collect();
+        assert!(hexasphere.region_border(&all).is_empty());
+
+        // Everything except one tile: a single loop around the hole
+        let hole = 25;
+        let mut rest: Vec<usize> = all.iter().copied().filter(|&id| id != hole).collect();
+        rest.push(10_000);
+        let border = hexasphere.region_border(&rest);
+        assert_eq!(border.len(), 1);
+        let mut reversed = hexasphere.tiles[hole].boundary.clone();
+        reversed.reverse();
+        assert!(
+            is_rotation(&border[0], &reversed),
+            "hole border winds clockwise"
+        );
+
+        // Two separate tiles give two loops
+        let far = hexasphere
+            .tiles
+            .iter()
+            .position(|t| !t.neighbors.contains(&0) && t.id != 0)
+            .unwrap();
+        assert_eq!(hexasphere.region_border(&[0, far, 0]).len(), 2);
+    }
+
+    #[test]
+    fn test_gap_widths_vanish_for_full_size_tiles() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+        let gaps = hexasphere.gap_widths();
+
+        assert_eq!(gaps.len(), hexasphere.edges().len());
+        assert!(gaps.iter().all(|gap| gap.width < 1e-12));
+        assert_eq!(hexasphere.gap_stats().max_width, 0.0);
+    }
+
+    #[test]
+    fn test_gap_widths_scale_with_tile_size() {
+        let full = Hexasphere::new(10.0, 8, 1.0);
+        let shrunk = Hexasphere::new(10.0, 8, 0.9);
+
+        // Each tile gives up 10% of its apothem, about 0.1·(√3/2)·radius
+        for gap in shrunk.gap_widths() {
+            let (a, b) = gap.tiles;
+            assert!(full.tiles[a].neighbors.contains(&b));
+            let size = full.tiles[a].get_average_radius() + full.tiles[b].get_average_radius();
+            let ratio = gap.width / size;
+            assert!(
+                (0.075..0.1).contains(&ratio),
+                "{:?}: ratio {ratio}",
+                gap.tiles
+            );
+        }
+
+        let stats = shrunk.gap_stats();
+        assert!(stats.min_width > 0.0);
+        assert!(stats.min_width <= stats.mean_width && stats.mean_width <= stats.max_width);
+    }
+
+    #[test]
+    fn test_segment_distance() {
+        let v = |x: f64, y: f64, z: f64| Vector3::new(x, y, z);
+
+        // Parallel segments, crossing segments and a degenerate segment
+        let parallel = segment_distance(
+            [v(0.0, 0.0, 0.0), v(1.0, 0.0, 0.0)],
+            [v(0.5, 2.0, 0.0), v(3.0, 2.0, 0.0)],
+        );
+        assert!((parallel - 2.0).abs() < 1e-12);
+        let crossing = segment_distance(
+            [v(-1.0, 0.0, 0.0), v(1.0, 0.0, 0.0)],
+            [v(0.0, -1.0, 1.0), v(0.0, 1.0, 1.0)],
+        );
+        assert!((crossing - 1.0).abs() < 1e-12);
+        let point = segment_distance(
+            [v(3.0, 4.0, 0.0), v(3.0, 4.0, 0.0)],
+            [v(0.0, 0.0, 0.0), v(0.0, 0.0, 0.0)],
+        );
+        assert!((point - 5.0).abs() < 1e-12);
+    }
+}
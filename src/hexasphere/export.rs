@@ -1,7 +1,38 @@
 //! Export functionality for hexasphere data.
 
+use crate::geometry::{Point, Vector3};
 use crate::hexasphere::core::Hexasphere;
-use std::collections::HashMap;
+use crate::tile::Tile;
+use crate::utils::{slerp, AxisConvention};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{self, Write};
+
+/// OBJ geometry together with its MTL material library.
+///
+/// Returned by [`Hexasphere::to_obj_with_groups`] and
+/// [`Hexasphere::to_obj_with_custom_groups`]. The OBJ references the library
+/// through its `mtllib` statement, so both files must be saved side by side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjExport {
+    /// Wavefront OBJ file content
+    pub obj: String,
+    /// MTL material library content, one material per group
+    pub mtl: String,
+}
+
+/// Diffuse colors for OBJ group materials, assigned in group name order.
+///
+/// The first two match the SVG export, since `hexagons` sorts before `pentagons`.
+const GROUP_PALETTE: [[f64; 3]; 8] = [
+    [0.29, 0.565, 0.851],
+    [0.878, 0.482, 0.224],
+    [0.416, 0.686, 0.29],
+    [0.58, 0.404, 0.741],
+    [0.929, 0.788, 0.282],
+    [0.502, 0.502, 0.502],
+    [0.839, 0.263, 0.29],
+    [0.275, 0.725, 0.706],
+];
 
 impl Hexasphere {
     /// Exports the hexasphere as a JSON string.
@@ -19,10 +50,13 @@ impl Hexasphere {
     /// ```json
     /// {
     ///   "radius": 10.0,
+    ///   "subdivisions": 16,
     ///   "tile_count": 2562
     /// }
     /// ```
     ///
+    /// `subdivisions` is `null` when [`Hexasphere::subdivisions`] is unknown.
+    ///
     /// # Future Enhancement
     ///
     /// For production use, consider implementing full serde serialization:
@@ -50,11 +84,43 @@ impl Hexasphere {
     /// # }
     /// ```
     pub fn to_json(&self) -> String {
+        let mut buffer = Vec::new();
+        self.write_json(&mut buffer)
+            .expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(buffer).expect("JSON output is valid UTF-8")
+    }
+
+    /// Streams the JSON produced by [`Hexasphere::to_json`] into a writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination, such as a `File` or `BufWriter`
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or the first error returned by `writer`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+    /// let mut buffer = Vec::new();
+    /// hexasphere.write_json(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(buffer, hexasphere.to_json().into_bytes());
+    /// ```
+    pub fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()> {
         // This would require serde for proper JSON serialization
-        // For now, return a simple string representation
-        format!(
-            "{{\"radius\": {}, \"tile_count\": {}}}",
+        // For now, write a simple representation
+        let subdivisions = self
+            .subdivisions()
+            .map_or_else(|| "null".to_string(), |d| d.to_string());
+        write!(
+            writer,
+            "{{\"radius\": {}, \"subdivisions\": {}, \"tile_count\": {}}}",
             self.radius,
+            subdivisions,
             self.tiles.len()
         )
     }
@@ -126,8 +192,260 @@ impl Hexasphere {
     /// - Generation time: O(n) where n is number of tiles
     /// - Memory usage: Temporary during generation, then just the string
     /// - File size: ~100 bytes per tile (varies with precision)
+    /// - For large spheres prefer [`Hexasphere::write_obj`], which never holds
+    ///   the whole file in memory
     pub fn to_obj(&self) -> String {
-        let mut obj_text = String::from("# vertices\n");
+        let mut buffer = Vec::new();
+        self.write_obj(&mut buffer)
+            .expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(buffer).expect("OBJ output is valid UTF-8")
+    }
+
+    /// Streams the OBJ produced by [`Hexasphere::to_obj`] into a writer.
+    ///
+    /// Vertices and faces are formatted straight into `writer`, so exporting a
+    /// large sphere to disk does not first build the whole file as a `String`.
+    /// Only the deduplicated vertex list and face indices are held in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination, such as a `File` or `BufWriter`. Writers are
+    ///   called once per record, so wrap unbuffered files in a `BufWriter`
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or the first error returned by `writer`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # use std::fs::File;
+    /// # use std::io::BufWriter;
+    /// # fn save_obj_example() -> std::io::Result<()> {
+    /// let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+    ///
+    /// let mut writer = BufWriter::new(File::create("large_sphere.obj")?);
+    /// hexasphere.write_obj(&mut writer)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_obj<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_obj_with_axes(writer, AxisConvention::YUp)
+    }
+
+    /// Exports the OBJ of [`Hexasphere::to_obj`] in the given axis convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - [`AxisConvention::ZUp`] for Blender and other Z-up tools
+    ///
+    /// # Returns
+    ///
+    /// A string containing the OBJ file content
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{AxisConvention, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let obj = hexasphere.to_obj_with_axes(AxisConvention::ZUp);
+    ///
+    /// // Same faces, rotated vertices
+    /// assert_eq!(obj.lines().count(), hexasphere.to_obj().lines().count());
+    /// ```
+    pub fn to_obj_with_axes(&self, axes: AxisConvention) -> String {
+        let mut buffer = Vec::new();
+        self.write_obj_with_axes(&mut buffer, axes)
+            .expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(buffer).expect("OBJ output is valid UTF-8")
+    }
+
+    /// Streams the OBJ of [`Hexasphere::to_obj_with_axes`] into a writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination, such as a `File` or `BufWriter`
+    /// * `axes` - Axis convention of the written vertices
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or the first error returned by `writer`
+    pub fn write_obj_with_axes<W: Write>(
+        &self,
+        writer: &mut W,
+        axes: AxisConvention,
+    ) -> io::Result<()> {
+        let (vertices, faces) = self.obj_polygons();
+
+        writeln!(writer, "# vertices")?;
+        write_obj_vertices(writer, &vertices, axes)?;
+
+        writeln!(writer, "\n# faces")?;
+        for face in &faces {
+            write_obj_face(writer, face)?;
+        }
+
+        Ok(())
+    }
+
+    /// Exports the hexasphere as an OBJ file with pentagon and hexagon groups.
+    ///
+    /// Works like [`Hexasphere::to_obj`] but puts the faces into a `pentagons`
+    /// and a `hexagons` group, each with a `usemtl` statement referencing a
+    /// material of the same name. The matching material library is returned
+    /// alongside, so the 12 pentagons can be selected and colored separately
+    /// right after import.
+    ///
+    /// # Arguments
+    ///
+    /// * `mtl_file` - File name of the material library, written to the
+    ///   `mtllib` statement; save [`ObjExport::mtl`] under this name next to the OBJ
+    ///
+    /// # Returns
+    ///
+    /// An [`ObjExport`] with the OBJ and MTL file contents
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # fn save_obj_example() -> std::io::Result<()> {
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let export = hexasphere.to_obj_with_groups("sphere.mtl");
+    ///
+    /// std::fs::write("sphere.obj", &export.obj)?;
+    /// std::fs::write("sphere.mtl", &export.mtl)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_obj_with_groups(&self, mtl_file: &str) -> ObjExport {
+        self.to_obj_with_groups_with_axes(mtl_file, AxisConvention::YUp)
+    }
+
+    /// Exports the grouped OBJ of [`Hexasphere::to_obj_with_groups`] in the
+    /// given axis convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `mtl_file` - File name of the material library for the `mtllib` statement
+    /// * `axes` - [`AxisConvention::ZUp`] for Blender and other Z-up tools
+    ///
+    /// # Returns
+    ///
+    /// An [`ObjExport`] with the OBJ and MTL file contents
+    pub fn to_obj_with_groups_with_axes(&self, mtl_file: &str, axes: AxisConvention) -> ObjExport {
+        self.to_obj_with_custom_groups_with_axes(
+            mtl_file,
+            |_, tile| {
+                if tile.is_pentagon() {
+                    "pentagons".to_string()
+                } else {
+                    "hexagons".to_string()
+                }
+            },
+            axes,
+        )
+    }
+
+    /// Exports the hexasphere as an OBJ file with caller-defined groups.
+    ///
+    /// Every tile is assigned to the group returned by `group` for it, and
+    /// each group gets a material of the same name. This allows data-driven
+    /// coloring, for example one group per biome or per player.
+    ///
+    /// # Arguments
+    ///
+    /// * `mtl_file` - File name of the material library for the `mtllib` statement
+    /// * `group` - Returns the group name for a tile, given its index and the tile.
+    ///   Whitespace in names is replaced with `_`, since OBJ names are single tokens
+    ///
+    /// # Returns
+    ///
+    /// An [`ObjExport`] with the OBJ and MTL file contents
+    ///
+    /// # Output Layout
+    ///
+    /// - **Vertices**: Identical to [`Hexasphere::to_obj`]
+    /// - **Groups**: Sorted by name, each written as `g name`, `usemtl name`
+    ///   and then its faces, so every face belongs to exactly one group
+    /// - **Materials**: Diffuse colors cycle through a fixed palette in group
+    ///   order; `hexagons` and `pentagons` match the SVG export colors
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    ///
+    /// // Split the sphere into northern and southern hemispheres
+    /// let export = hexasphere.to_obj_with_custom_groups("hemispheres.mtl", |_, tile| {
+    ///     if tile.center_point.y >= 0.0 { "north" } else { "south" }.to_string()
+    /// });
+    ///
+    /// assert!(export.obj.contains("g north\nusemtl north\n"));
+    /// assert!(export.mtl.contains("newmtl south\n"));
+    /// ```
+    pub fn to_obj_with_custom_groups<F>(&self, mtl_file: &str, group: F) -> ObjExport
+    where
+        F: Fn(usize, &Tile) -> String,
+    {
+        self.to_obj_with_custom_groups_with_axes(mtl_file, group, AxisConvention::YUp)
+    }
+
+    /// Exports the grouped OBJ of [`Hexasphere::to_obj_with_custom_groups`]
+    /// in the given axis convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `mtl_file` - File name of the material library for the `mtllib` statement
+    /// * `group` - Returns the group name for a tile, given its index and the tile
+    /// * `axes` - [`AxisConvention::ZUp`] for Blender and other Z-up tools
+    ///
+    /// # Returns
+    ///
+    /// An [`ObjExport`] with the OBJ and MTL file contents
+    pub fn to_obj_with_custom_groups_with_axes<F>(
+        &self,
+        mtl_file: &str,
+        group: F,
+        axes: AxisConvention,
+    ) -> ObjExport
+    where
+        F: Fn(usize, &Tile) -> String,
+    {
+        let (vertices, faces) = self.obj_polygons();
+
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (index, tile) in self.tiles.iter().enumerate() {
+            let name: String = group(index, tile)
+                .chars()
+                .map(|c| if c.is_whitespace() { '_' } else { c })
+                .collect();
+            groups.entry(name).or_default().push(index);
+        }
+
+        let mut obj = Vec::new();
+        write_grouped_obj(&mut obj, mtl_file, &vertices, &faces, &groups, axes)
+            .expect("writing to a Vec<u8> cannot fail");
+
+        let mut mtl = String::new();
+        for (number, name) in groups.keys().enumerate() {
+            let [r, g, b] = GROUP_PALETTE[number % GROUP_PALETTE.len()];
+            if number > 0 {
+                mtl.push('\n');
+            }
+            mtl.push_str(&format!("newmtl {}\nKd {} {} {}\n", name, r, g, b));
+        }
+
+        ObjExport {
+            obj: String::from_utf8(obj).expect("OBJ output is valid UTF-8"),
+            mtl,
+        }
+    }
+
+    /// Deduplicated boundary vertices and the 1-based vertex indices of every tile.
+    fn obj_polygons(&self) -> (Vec<Point>, Vec<Vec<usize>>) {
         let mut vertices = Vec::new();
         let mut vertex_map = HashMap::new();
         let mut faces = Vec::new();
@@ -151,28 +469,524 @@ impl Hexasphere {
             faces.push(face_indices);
         }
 
-        // Write vertices
-        for vertex in &vertices {
-            obj_text.push_str(&format!("v {} {} {}\n", vertex.x, vertex.y, vertex.z));
+        (vertices, faces)
+    }
+
+    /// Exports only the tile borders as OBJ line elements.
+    ///
+    /// Vertices are deduplicated exactly like [`Hexasphere::to_obj`], and
+    /// every border is written once as an `l i j` record, even though it
+    /// belongs to two tiles. Load the result on top of a globe to overlay the
+    /// tile grid.
+    ///
+    /// # Returns
+    ///
+    /// A string containing `v` records followed by one `l` record per unique edge
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let obj = hexasphere.to_obj_wireframe();
+    ///
+    /// let lines = obj.lines().filter(|line| line.starts_with("l ")).count();
+    /// assert_eq!(lines, hexasphere.edges().len());
+    /// ```
+    pub fn to_obj_wireframe(&self) -> String {
+        self.to_obj_wireframe_arcs(0)
+    }
+
+    /// Exports the tile borders as curved OBJ polylines following the sphere.
+    ///
+    /// Works like [`Hexasphere::to_obj_wireframe`], but the corners are
+    /// projected onto the sphere and each border becomes a polyline through
+    /// `samples_per_edge` extra points on the great-circle arc between its
+    /// corners, like [`crate::Tile::boundary_arc_points`]. Straight borders
+    /// cut beneath the surface of a smooth globe; arcs stay on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples_per_edge` - Points inserted along each border; 0 writes the
+    ///   plain straight-edged wireframe with unprojected corners
+    ///
+    /// # Returns
+    ///
+    /// A string containing `v` records followed by one `l` record per unique
+    /// edge, each listing `samples_per_edge + 2` vertices
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n + e × s) where n = corners, e = edges,
+    ///   s = samples per edge
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let obj = hexasphere.to_obj_wireframe_arcs(4);
+    ///
+    /// let first_line = obj.lines().find(|line| line.starts_with("l ")).unwrap();
+    /// assert_eq!(first_line.split(' ').count(), 1 + 6);
+    /// ```
+    pub fn to_obj_wireframe_arcs(&self, samples_per_edge: usize) -> String {
+        self.to_obj_wireframe_arcs_with_axes(samples_per_edge, AxisConvention::YUp)
+    }
+
+    /// Exports the wireframe of [`Hexasphere::to_obj_wireframe_arcs`] in the
+    /// given axis convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples_per_edge` - Points inserted along each border; 0 writes the
+    ///   plain straight-edged wireframe
+    /// * `axes` - [`AxisConvention::ZUp`] for Blender and other Z-up tools
+    ///
+    /// # Returns
+    ///
+    /// A string containing `v` records followed by one `l` record per unique edge
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{AxisConvention, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let obj = hexasphere.to_obj_wireframe_arcs_with_axes(2, AxisConvention::ZUp);
+    ///
+    /// // Same polylines, rotated vertices
+    /// assert_eq!(obj.lines().count(), hexasphere.to_obj_wireframe_arcs(2).lines().count());
+    /// ```
+    pub fn to_obj_wireframe_arcs_with_axes(
+        &self,
+        samples_per_edge: usize,
+        axes: AxisConvention,
+    ) -> String {
+        let (mut vertices, faces) = self.obj_polygons();
+
+        let mut seen = HashSet::new();
+        let mut edges = Vec::new();
+        for face in &faces {
+            for (i, &start) in face.iter().enumerate() {
+                let end = face[(i + 1) % face.len()];
+                if seen.insert((start.min(end), start.max(end))) {
+                    edges.push((start, end));
+                }
+            }
+        }
+
+        let mut lines: Vec<Vec<usize>> = Vec::with_capacity(edges.len());
+        if samples_per_edge == 0 {
+            lines.extend(edges.iter().map(|&(start, end)| vec![start, end]));
+        } else {
+            for vertex in &mut vertices {
+                let unit = self.relative(vertex).normalize();
+                *vertex = self.placed(&(unit * self.radius));
+            }
+            let steps = samples_per_edge + 1;
+            for &(start, end) in &edges {
+                let from = self.relative(&vertices[start - 1]);
+                let to = self.relative(&vertices[end - 1]);
+                let mut line = vec![start];
+                for step in 1..steps {
+                    let unit = slerp(&from, &to, step as f64 / steps as f64);
+                    vertices.push(self.placed(&(unit * self.radius)));
+                    line.push(vertices.len());
+                }
+                line.push(end);
+                lines.push(line);
+            }
+        }
+
+        let mut buffer = Vec::new();
+        write_obj_wireframe(&mut buffer, &vertices, &lines, axes)
+            .expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(buffer).expect("OBJ output is valid UTF-8")
+    }
+
+    /// Exports the hexasphere as an OBJ file with vertex normals.
+    ///
+    /// Works like [`Hexasphere::to_obj`] but also writes `vn` records and
+    /// references them from every face using the `f v//vn` syntax, so the mesh
+    /// shades correctly without recomputing normals in the target tool.
+    ///
+    /// # Arguments
+    ///
+    /// * `smooth` - Normal mode
+    ///   - `true`: Each vertex uses its normalized position (the true sphere
+    ///     normal), giving smooth shading across tile edges
+    ///   - `false`: Each tile uses its flat polygon normal, giving faceted shading
+    ///
+    /// # Returns
+    ///
+    /// A string containing the complete OBJ file content
+    ///
+    /// # Features
+    ///
+    /// - **Normal deduplication**: Identical normals are written once and shared,
+    ///   just like vertices
+    /// - **Outward normals**: Normals follow the counter-clockwise tile winding
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # fn save_obj_example() -> std::io::Result<()> {
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    ///
+    /// // Smooth sphere for rendering
+    /// std::fs::write("smooth.obj", hexasphere.to_obj_with_normals(true))?;
+    ///
+    /// // Faceted look that highlights individual tiles
+    /// std::fs::write("faceted.obj", hexasphere.to_obj_with_normals(false))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Performance Notes
+    ///
+    /// - Generation time: O(n) where n is number of tiles
+    pub fn to_obj_with_normals(&self, smooth: bool) -> String {
+        self.to_obj_with_normals_with_axes(smooth, AxisConvention::YUp)
+    }
+
+    /// Exports the OBJ of [`Hexasphere::to_obj_with_normals`] in the given
+    /// axis convention, converting both vertices and normals.
+    ///
+    /// # Arguments
+    ///
+    /// * `smooth` - Normal mode, as for [`Hexasphere::to_obj_with_normals`]
+    /// * `axes` - [`AxisConvention::ZUp`] for Blender and other Z-up tools
+    ///
+    /// # Returns
+    ///
+    /// A string containing the complete OBJ file content
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{AxisConvention, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let obj = hexasphere.to_obj_with_normals_with_axes(true, AxisConvention::ZUp);
+    ///
+    /// assert!(obj.lines().any(|line| line.starts_with("vn ")));
+    /// ```
+    pub fn to_obj_with_normals_with_axes(&self, smooth: bool, axes: AxisConvention) -> String {
+        let mut vertices = Vec::new();
+        let mut vertex_map = HashMap::new();
+        let mut normals: Vec<Vector3> = Vec::new();
+        let mut normal_map = HashMap::new();
+        let mut faces = Vec::new();
+
+        let mut normal_index = |normal: Vector3| {
+            let key = format!("{:.6} {:.6} {:.6}", normal.x, normal.y, normal.z);
+            *normal_map.entry(key).or_insert_with(|| {
+                normals.push(normal);
+                normals.len() // OBJ uses 1-based indexing
+            })
+        };
+
+        for tile in &self.tiles {
+            let flat_normal = (!smooth).then(|| polygon_normal(&tile.boundary));
+            let mut face_indices = Vec::new();
+
+            for boundary_point in &tile.boundary {
+                let key = boundary_point.to_string();
+                let index = if let Some(&existing_index) = vertex_map.get(&key) {
+                    existing_index
+                } else {
+                    let new_index = vertices.len() + 1;
+                    vertices.push(boundary_point.clone());
+                    vertex_map.insert(key, new_index);
+                    new_index
+                };
+
+                let normal = match &flat_normal {
+                    Some(normal) => normal.clone(),
+                    None => self.relative(boundary_point).normalize(),
+                };
+                face_indices.push((index, normal_index(axes.convert_vector(&normal))));
+            }
+
+            faces.push(face_indices);
+        }
+
+        let mut buffer = Vec::new();
+        write_obj_vertices(&mut buffer, &vertices, axes).expect("writing to a Vec<u8> cannot fail");
+        let mut obj_text = String::from("# vertices\n");
+        obj_text.push_str(&String::from_utf8(buffer).expect("OBJ output is valid UTF-8"));
+
+        obj_text.push_str("\n# normals\n");
+        for normal in &normals {
+            obj_text.push_str(&format!(
+                "vn {:.6} {:.6} {:.6}\n",
+                normal.x, normal.y, normal.z
+            ));
         }
 
-        // Write faces
         obj_text.push_str("\n# faces\n");
         for face in &faces {
             obj_text.push('f');
-            for &index in face {
-                obj_text.push_str(&format!(" {}", index));
+            for &(vertex_index, normal_index) in face {
+                obj_text.push_str(&format!(" {}//{}", vertex_index, normal_index));
             }
             obj_text.push('\n');
         }
 
         obj_text
     }
+
+    /// Exports the tiles as a flat SVG map using the equirectangular projection.
+    ///
+    /// Each tile boundary is converted to latitude/longitude with
+    /// `Tile::get_boundary_lat_lon` and drawn as one `<polygon>`, with longitude
+    /// mapped linearly to x and latitude to y. Pentagons and hexagons get
+    /// different classes and fills so the 12 pentagons stand out.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the image in pixels (covers 360° of longitude)
+    /// * `height` - Height of the image in pixels (covers 180° of latitude)
+    ///
+    /// # Returns
+    ///
+    /// A complete SVG document as a string
+    ///
+    /// # Projection Details
+    ///
+    /// - **Antimeridian**: Tiles straddling ±180° are drawn with continuous
+    ///   longitudes so they extend past the image edge, and a `<use>` copy
+    ///   shifted by one full map width fills in the part that wraps around
+    /// - **Poles**: Tiles containing a pole are unrolled into a band that
+    ///   spans the full width and runs up (or down) to the image edge
+    /// - **Degenerate tiles**: Tiles with fewer than 3 boundary points are skipped
+    ///
+    /// # Use Cases
+    ///
+    /// - **Debugging**: Inspect tile layouts without a 3D viewer
+    /// - **Documentation**: Embed a picture of the tiling in docs or READMEs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # fn save_svg_example() -> std::io::Result<()> {
+    /// let hexasphere = Hexasphere::new(10.0, 3, 0.95);
+    /// let svg = hexasphere.to_svg_equirectangular(1024, 512);
+    /// std::fs::write("tiles.svg", svg)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Performance Notes
+    ///
+    /// - Generation time: O(n) where n is number of tiles
+    /// - File size: ~150 bytes per tile
+    pub fn to_svg_equirectangular(&self, width: u32, height: u32) -> String {
+        let to_x = |lon: f64| (lon + 180.0) / 360.0 * width as f64;
+        let to_y = |lat: f64| (90.0 - lat) / 180.0 * height as f64;
+
+        let mut svg = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        svg.push_str(&format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+             xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+             width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+            width, height
+        ));
+        svg.push_str(&format!(
+            "<rect width=\"{}\" height=\"{}\" fill=\"#ffffff\"/>\n",
+            width, height
+        ));
+
+        for (index, tile) in self.tiles.iter().enumerate() {
+            if tile.boundary.len() < 3 {
+                continue;
+            }
+
+            let lat_lons: Vec<_> = (0..tile.boundary.len())
+                .filter_map(|i| tile.get_boundary_lat_lon(self.radius, i))
+                .collect();
+
+            // Unwrap longitudes so consecutive points never jump by more than 180°
+            let mut points = vec![(lat_lons[0].lon, lat_lons[0].lat)];
+            for lat_lon in &lat_lons[1..] {
+                let previous = points[points.len() - 1].0;
+                let mut lon = lat_lon.lon;
+                while lon - previous > 180.0 {
+                    lon -= 360.0;
+                }
+                while lon - previous < -180.0 {
+                    lon += 360.0;
+                }
+                points.push((lon, lat_lon.lat));
+            }
+
+            // A boundary that winds once around the axis encloses a pole
+            let wrap = |delta: f64| (delta + 180.0).rem_euclid(360.0) - 180.0;
+            let (first_lon, last_lon) = (points[0].0, points[points.len() - 1].0);
+            let winding = (last_lon - first_lon) + wrap(first_lon - last_lon);
+
+            if winding.abs() > 180.0 {
+                let pole_lat = if tile.relative(&tile.center_point).y >= 0.0 {
+                    90.0
+                } else {
+                    -90.0
+                };
+                points = lat_lons.iter().map(|ll| (ll.lon, ll.lat)).collect();
+                points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                // Interpolate where the boundary crosses the antimeridian
+                let (west, east) = (points[0], points[points.len() - 1]);
+                let t = (180.0 - east.0) / (west.0 + 360.0 - east.0);
+                let edge_lat = east.1 + t * (west.1 - east.1);
+
+                points.insert(0, (-180.0, edge_lat));
+                points.push((180.0, edge_lat));
+                points.push((180.0, pole_lat));
+                points.push((-180.0, pole_lat));
+            } else {
+                // Shift the whole polygon so its mean longitude is on the map
+                let mean = points.iter().map(|p| p.0).sum::<f64>() / points.len() as f64;
+                let shift = if mean > 180.0 {
+                    -360.0
+                } else if mean < -180.0 {
+                    360.0
+                } else {
+                    0.0
+                };
+                for point in &mut points {
+                    point.0 += shift;
+                }
+            }
+
+            let class = if tile.is_pentagon() {
+                "pentagon"
+            } else {
+                "hexagon"
+            };
+            let fill = if tile.is_pentagon() {
+                "#e07b39"
+            } else {
+                "#4a90d9"
+            };
+            let coordinates: Vec<String> = points
+                .iter()
+                .map(|&(lon, lat)| format!("{:.2},{:.2}", to_x(lon), to_y(lat)))
+                .collect();
+            svg.push_str(&format!(
+                "<polygon id=\"tile-{}\" class=\"{}\" fill=\"{}\" stroke=\"#333333\" \
+                 stroke-width=\"0.5\" points=\"{}\"/>\n",
+                index,
+                class,
+                fill,
+                coordinates.join(" ")
+            ));
+
+            // Draw the wrapped-around part of tiles that cross the antimeridian
+            let min_lon = points.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+            let max_lon = points.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+            let wrap_offset = if max_lon > 180.0 {
+                -(width as f64)
+            } else if min_lon < -180.0 {
+                width as f64
+            } else {
+                0.0
+            };
+            if wrap_offset != 0.0 {
+                svg.push_str(&format!(
+                    "<use xlink:href=\"#tile-{}\" transform=\"translate({:.2},0)\"/>\n",
+                    index, wrap_offset
+                ));
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Writes one `v x y z` line per vertex, converted to `axes`.
+fn write_obj_vertices<W: Write>(
+    writer: &mut W,
+    vertices: &[Point],
+    axes: AxisConvention,
+) -> io::Result<()> {
+    for vertex in vertices {
+        let vertex = axes.convert(vertex);
+        writeln!(writer, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+    }
+    Ok(())
+}
+
+/// Writes one `f` line with 1-based vertex indices.
+fn write_obj_face<W: Write>(writer: &mut W, face: &[usize]) -> io::Result<()> {
+    write!(writer, "f")?;
+    for &index in face {
+        write!(writer, " {}", index)?;
+    }
+    writeln!(writer)
+}
+
+/// Writes vertices followed by one `l` polyline per entry of `lines`.
+fn write_obj_wireframe<W: Write>(
+    writer: &mut W,
+    vertices: &[Point],
+    lines: &[Vec<usize>],
+    axes: AxisConvention,
+) -> io::Result<()> {
+    writeln!(writer, "# vertices")?;
+    write_obj_vertices(writer, vertices, axes)?;
+
+    writeln!(writer, "\n# edges")?;
+    for line in lines {
+        write!(writer, "l")?;
+        for &index in line {
+            write!(writer, " {}", index)?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Writes an OBJ whose faces are split into `g`/`usemtl` groups.
+fn write_grouped_obj<W: Write>(
+    writer: &mut W,
+    mtl_file: &str,
+    vertices: &[Point],
+    faces: &[Vec<usize>],
+    groups: &BTreeMap<String, Vec<usize>>,
+    axes: AxisConvention,
+) -> io::Result<()> {
+    writeln!(writer, "mtllib {}\n\n# vertices", mtl_file)?;
+    write_obj_vertices(writer, vertices, axes)?;
+
+    for (name, members) in groups {
+        writeln!(writer, "\ng {}\nusemtl {}", name, name)?;
+        for &index in members {
+            write_obj_face(writer, &faces[index])?;
+        }
+    }
+    Ok(())
+}
+
+/// Unit normal of a polygon using Newell's method (robust for non-planar rings).
+pub(crate) fn polygon_normal(boundary: &[Point]) -> Vector3 {
+    let mut normal = Vector3::new(0.0, 0.0, 0.0);
+    for (i, current) in boundary.iter().enumerate() {
+        let next = &boundary[(i + 1) % boundary.len()];
+        normal.x += (current.y - next.y) * (current.z + next.z);
+        normal.y += (current.z - next.z) * (current.x + next.x);
+        normal.z += (current.x - next.x) * (current.y + next.y);
+    }
+    normal.normalize()
 }
 
 #[cfg(test)]
 mod tests {
+    use super::polygon_normal;
+    use crate::geometry::{Point, Vector3};
     use crate::hexasphere::core::Hexasphere;
+    use crate::utils::AxisConvention;
 
     #[test]
     fn test_to_json_basic() {
@@ -191,6 +1005,7 @@ mod tests {
         let json = hexasphere.to_json();
 
         assert!(json.contains("\"radius\": 5"));
+        assert!(json.contains("\"subdivisions\": 2"));
         assert!(json.contains("\"tile_count\":"));
 
         // Should have more tiles for higher subdivision
@@ -293,6 +1108,142 @@ mod tests {
         assert!(vertex_count < face_count * 6); // Should be deduplicated
     }
 
+    #[test]
+    fn test_to_obj_shares_every_tile_corner() {
+        // Each corner is shared by three tiles and written once
+        for divisions in 1..=4 {
+            let obj = Hexasphere::new(10.0, divisions, 1.0).to_obj();
+            let vertices = obj.lines().filter(|line| line.starts_with("v ")).count();
+            assert_eq!(vertices, crate::utils::vertex_count(divisions));
+        }
+
+        // Shrunken tiles no longer touch, so every tile keeps its own corners
+        let hexasphere = Hexasphere::new(10.0, 3, 0.9);
+        let obj = hexasphere.to_obj();
+        let vertices = obj.lines().filter(|line| line.starts_with("v ")).count();
+        let corners: usize = hexasphere.tiles.iter().map(|t| t.boundary.len()).sum();
+        assert_eq!(vertices, corners);
+    }
+
+    #[test]
+    fn test_to_obj_z_up_round_trips_and_keeps_winding() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let parse = |obj: &str| -> Vec<Point> {
+            obj.lines()
+                .filter_map(|line| line.strip_prefix("v "))
+                .map(|line| {
+                    let c: Vec<f64> = line.split(' ').map(|v| v.parse().unwrap()).collect();
+                    Point {
+                        x: c[0],
+                        y: c[1],
+                        z: c[2],
+                    }
+                })
+                .collect()
+        };
+        let y_up = hexasphere.to_obj();
+        let z_up = hexasphere.to_obj_with_axes(AxisConvention::ZUp);
+
+        let original = parse(&y_up);
+        let converted = parse(&z_up);
+        let restored: Vec<Point> = converted
+            .iter()
+            .map(|point| AxisConvention::ZUp.restore(point))
+            .collect();
+        assert_eq!(restored, original);
+
+        // Faces are unchanged and still face outward in the new space
+        let faces = |obj: &str| -> Vec<String> {
+            obj.lines()
+                .filter(|line| line.starts_with("f "))
+                .map(String::from)
+                .collect()
+        };
+        assert_eq!(faces(&z_up), faces(&y_up));
+        for face in faces(&z_up) {
+            let ring: Vec<Point> = face[2..]
+                .split(' ')
+                .map(|index| converted[index.parse::<usize>().unwrap() - 1].clone())
+                .collect();
+            let centroid = ring.iter().fold(Vector3::new(0.0, 0.0, 0.0), |sum, p| {
+                &sum + &Vector3::from(p)
+            });
+            assert!(polygon_normal(&ring).dot(&centroid) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_every_obj_export_honors_z_up() {
+        // Records starting with `prefix`, restored from Z-up, plus all other lines
+        let split = |obj: &str, prefix: &str| -> (Vec<Point>, Vec<String>) {
+            let mut points = Vec::new();
+            let mut rest = Vec::new();
+            for line in obj.lines() {
+                match line.strip_prefix(prefix) {
+                    Some(values) => {
+                        let c: Vec<f64> = values.split(' ').map(|v| v.parse().unwrap()).collect();
+                        points.push(Point {
+                            x: c[0],
+                            y: c[1],
+                            z: c[2],
+                        });
+                    }
+                    None => rest.push(line.to_string()),
+                }
+            }
+            (points, rest)
+        };
+        let assert_rotated = |y_up: &str, z_up: &str, prefix: &str, tolerance: f64| {
+            let (original, y_rest) = split(y_up, prefix);
+            let (converted, z_rest) = split(z_up, prefix);
+            assert_eq!(z_rest, y_rest);
+            assert_eq!(converted.len(), original.len());
+            assert!(!original.is_empty());
+            for (point, expected) in converted.iter().zip(&original) {
+                let restored = AxisConvention::ZUp.restore(point);
+                assert!(
+                    restored.distance_to(expected) <= tolerance,
+                    "{restored} vs {expected}"
+                );
+            }
+        };
+
+        let hexasphere = Hexasphere::new(10.0, 3, 0.9);
+        let z_up = AxisConvention::ZUp;
+
+        let groups = hexasphere.to_obj_with_groups("sphere.mtl");
+        let z_groups = hexasphere.to_obj_with_groups_with_axes("sphere.mtl", z_up);
+        assert_eq!(z_groups.mtl, groups.mtl);
+        assert_rotated(&groups.obj, &z_groups.obj, "v ", 0.0);
+
+        let wireframe = hexasphere.to_obj_wireframe_arcs(3);
+        let z_wireframe = hexasphere.to_obj_wireframe_arcs_with_axes(3, z_up);
+        assert_rotated(&wireframe, &z_wireframe, "v ", 0.0);
+
+        for smooth in [true, false] {
+            let normals = hexasphere.to_obj_with_normals(smooth);
+            let z_normals = hexasphere.to_obj_with_normals_with_axes(smooth, z_up);
+            let without = |obj: &str, prefix: &str| -> String {
+                obj.lines()
+                    .filter(|line| !line.starts_with(prefix))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            assert_rotated(
+                &without(&normals, "vn "),
+                &without(&z_normals, "vn "),
+                "v ",
+                0.0,
+            );
+            assert_rotated(
+                &without(&normals, "v "),
+                &without(&z_normals, "v "),
+                "vn ",
+                1e-6,
+            );
+        }
+    }
+
     #[test]
     fn test_to_obj_pentagon_hexagon_mix() {
         let hexasphere = Hexasphere::new(1.0, 2, 1.0);
@@ -318,4 +1269,324 @@ mod tests {
         assert!(hexagon_count > 0);
         assert_eq!(pentagon_count + hexagon_count, hexasphere.tiles.len());
     }
+
+    #[test]
+    fn test_write_obj_matches_to_obj() {
+        let hexasphere = Hexasphere::new(10.0, 3, 0.9);
+
+        let mut buffer = Vec::new();
+        hexasphere.write_obj(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), hexasphere.to_obj());
+
+        let mut buffer = Vec::new();
+        hexasphere.write_json(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), hexasphere.to_json());
+    }
+
+    /// Accepts a fixed number of bytes, then fails every write
+    struct FailingWriter {
+        remaining: usize,
+    }
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.remaining == 0 {
+                return Err(std::io::Error::other("disk full"));
+            }
+            let written = buf.len().min(self.remaining);
+            self.remaining -= written;
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_obj_propagates_errors() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+        for remaining in [0, 100, 2000] {
+            let mut writer = FailingWriter { remaining };
+            let error = hexasphere.write_obj(&mut writer).unwrap_err();
+            assert_eq!(error.to_string(), "disk full");
+        }
+
+        let mut writer = FailingWriter { remaining: 0 };
+        assert!(hexasphere.write_json(&mut writer).is_err());
+    }
+
+    /// Face lines of each `g` group, in file order
+    fn obj_groups(obj: &str) -> Vec<(String, Vec<String>)> {
+        let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+        for line in obj.lines() {
+            if let Some(name) = line.strip_prefix("g ") {
+                groups.push((name.to_string(), Vec::new()));
+            } else if line.starts_with("f ") {
+                let (_, faces) = groups.last_mut().expect("face outside of a group");
+                faces.push(line.to_string());
+            }
+        }
+        groups
+    }
+
+    #[test]
+    fn test_to_obj_with_groups() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let export = hexasphere.to_obj_with_groups("sphere.mtl");
+
+        assert!(export.obj.starts_with("mtllib sphere.mtl\n"));
+        let groups = obj_groups(&export.obj);
+        let names: Vec<&str> = groups.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["hexagons", "pentagons"]);
+
+        assert_eq!(groups[1].1.len(), 12);
+        assert!(groups[1]
+            .1
+            .iter()
+            .all(|f| f.split_whitespace().count() == 6));
+        assert!(groups[0]
+            .1
+            .iter()
+            .all(|f| f.split_whitespace().count() == 7));
+
+        // Every face appears in exactly one group, with the same vertices as to_obj
+        let mut grouped: Vec<&String> = groups.iter().flat_map(|(_, faces)| faces).collect();
+        let plain = hexasphere.to_obj();
+        let mut ungrouped: Vec<String> = plain
+            .lines()
+            .filter(|l| l.starts_with("f "))
+            .map(str::to_string)
+            .collect();
+        grouped.sort();
+        ungrouped.sort();
+        assert_eq!(grouped, ungrouped.iter().collect::<Vec<_>>());
+        assert_eq!(
+            export.obj.lines().filter(|l| l.starts_with("v ")).count(),
+            plain.lines().filter(|l| l.starts_with("v ")).count()
+        );
+
+        // Each group selects the material of the same name, which the MTL defines
+        for name in names {
+            assert!(export
+                .obj
+                .contains(&format!("g {}\nusemtl {}\n", name, name)));
+            assert!(export.mtl.contains(&format!("newmtl {}\nKd ", name)));
+        }
+    }
+
+    #[test]
+    fn test_to_obj_with_custom_groups() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let export = hexasphere
+            .to_obj_with_custom_groups("bands.mtl", |index, _| format!("band {}", index % 3));
+
+        let groups = obj_groups(&export.obj);
+        let names: Vec<&str> = groups.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["band_0", "band_1", "band_2"]);
+
+        let total: usize = groups.iter().map(|(_, faces)| faces.len()).sum();
+        assert_eq!(total, hexasphere.tiles.len());
+        assert_eq!(groups[0].1.len(), hexasphere.tiles.len().div_ceil(3));
+        assert_eq!(export.mtl.matches("newmtl ").count(), 3);
+    }
+
+    /// Vertex/normal index pairs of each `f v//vn` face line
+    type ObjFaces = Vec<Vec<(usize, usize)>>;
+
+    fn parse_obj_with_normals(obj: &str) -> (usize, Vec<[f64; 3]>, ObjFaces) {
+        let vertex_count = obj.lines().filter(|l| l.starts_with("v ")).count();
+        let normals = obj
+            .lines()
+            .filter_map(|l| l.strip_prefix("vn "))
+            .map(|l| {
+                let c: Vec<f64> = l.split_whitespace().map(|v| v.parse().unwrap()).collect();
+                [c[0], c[1], c[2]]
+            })
+            .collect();
+        let faces = obj
+            .lines()
+            .filter_map(|l| l.strip_prefix("f "))
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|pair| {
+                        let (v, vn) = pair.split_once("//").expect("face should use v//vn");
+                        (v.parse().unwrap(), vn.parse().unwrap())
+                    })
+                    .collect()
+            })
+            .collect();
+        (vertex_count, normals, faces)
+    }
+
+    #[test]
+    fn test_to_obj_with_smooth_normals() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let obj = hexasphere.to_obj_with_normals(true);
+        let (vertex_count, normals, faces) = parse_obj_with_normals(&obj);
+
+        // Every vertex has its own radial normal
+        assert_eq!(normals.len(), vertex_count);
+        assert_eq!(faces.len(), hexasphere.tiles.len());
+        for face in &faces {
+            for &(v, vn) in face {
+                assert!(v >= 1 && v <= vertex_count);
+                assert!(vn >= 1 && vn <= normals.len());
+            }
+        }
+        for normal in &normals {
+            let length = (normal[0].powi(2) + normal[1].powi(2) + normal[2].powi(2)).sqrt();
+            assert!((length - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_to_obj_with_flat_normals() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let obj = hexasphere.to_obj_with_normals(false);
+        let (vertex_count, normals, faces) = parse_obj_with_normals(&obj);
+
+        // One shared normal per tile
+        assert_eq!(normals.len(), hexasphere.tiles.len());
+        for (face, tile) in faces.iter().zip(&hexasphere.tiles) {
+            let normal_index = face[0].1;
+            assert!(face
+                .iter()
+                .all(|&(v, vn)| v <= vertex_count && vn == normal_index));
+
+            let normal = normals[normal_index - 1];
+            let center = &tile.center_point;
+            let dot = normal[0] * center.x + normal[1] * center.y + normal[2] * center.z;
+            assert!(dot > 0.0, "Flat normal should point outward");
+        }
+    }
+
+    /// Checks that every opened tag is closed in the right order.
+    fn assert_well_formed_xml(document: &str) {
+        let mut stack: Vec<String> = Vec::new();
+        let mut rest = document;
+
+        while let Some(start) = rest.find('<') {
+            let end = rest[start..].find('>').expect("unterminated tag") + start;
+            let tag = &rest[start + 1..end];
+            rest = &rest[end + 1..];
+
+            if tag.starts_with('?') {
+                assert!(tag.ends_with('?'));
+            } else if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(stack.pop().as_deref(), Some(name.trim()));
+            } else if !tag.ends_with('/') {
+                let name = tag.split_whitespace().next().unwrap();
+                stack.push(name.to_string());
+            }
+            assert_eq!(
+                tag.matches('"').count() % 2,
+                0,
+                "unbalanced quotes in <{}>",
+                tag
+            );
+        }
+
+        assert!(stack.is_empty(), "unclosed tags: {:?}", stack);
+    }
+
+    #[test]
+    fn test_to_svg_equirectangular_structure() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let svg = hexasphere.to_svg_equirectangular(800, 400);
+
+        assert_well_formed_xml(&svg);
+        assert!(svg.contains("viewBox=\"0 0 800 400\""));
+        assert_eq!(svg.matches("<polygon").count(), hexasphere.tiles.len());
+        assert_eq!(svg.matches("class=\"pentagon\"").count(), 12);
+        assert_eq!(
+            svg.matches("class=\"hexagon\"").count(),
+            hexasphere.tiles.len() - 12
+        );
+
+        for line in svg
+            .lines()
+            .filter(|line| line.contains("class=\"pentagon\""))
+        {
+            assert!(line.contains("fill=\"#e07b39\""));
+        }
+    }
+
+    #[test]
+    fn test_to_svg_equirectangular_no_smearing() {
+        for divisions in 2..=5 {
+            let hexasphere = Hexasphere::new(10.0, divisions, 1.0);
+            let svg = hexasphere.to_svg_equirectangular(720, 360);
+
+            let mut wide_polygons = 0;
+            for line in svg.lines().filter(|line| line.starts_with("<polygon")) {
+                let points = line.split("points=\"").nth(1).unwrap();
+                let xs: Vec<f64> = points
+                    .trim_end_matches("\"/>")
+                    .split(' ')
+                    .map(|pair| pair.split(',').next().unwrap().parse().unwrap())
+                    .collect();
+                let min = xs.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = xs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+                // Antimeridian tiles may overhang the edge but never span the map
+                assert!(min >= -360.0 && max <= 1080.0);
+                if max - min > 360.0 {
+                    wide_polygons += 1;
+                }
+            }
+
+            // Only the tiles containing the poles are unrolled across the full width
+            assert_eq!(wide_polygons, 2, "divisions = {}", divisions);
+        }
+    }
+
+    #[test]
+    fn test_to_obj_wireframe_writes_each_edge_once() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let obj = hexasphere.to_obj_wireframe();
+
+        let vertices = obj.lines().filter(|line| line.starts_with("v ")).count();
+        let edges: Vec<(usize, usize)> = obj
+            .lines()
+            .filter_map(|line| line.strip_prefix("l "))
+            .map(|line| {
+                let ids: Vec<usize> = line.split(' ').map(|id| id.parse().unwrap()).collect();
+                assert_eq!(ids.len(), 2);
+                (ids[0].min(ids[1]), ids[0].max(ids[1]))
+            })
+            .collect();
+
+        // Euler's formula: V - E + F = 2
+        let faces = hexasphere.tiles.len();
+        assert_eq!(edges.len(), vertices + faces - 2);
+
+        let unique: std::collections::HashSet<_> = edges.iter().collect();
+        assert_eq!(unique.len(), edges.len());
+        assert!(edges
+            .iter()
+            .all(|&(a, b)| a >= 1 && b <= vertices && a != b));
+    }
+
+    #[test]
+    fn test_to_obj_wireframe_arcs_stay_on_sphere() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let straight = hexasphere.to_obj_wireframe();
+        let curved = hexasphere.to_obj_wireframe_arcs(3);
+
+        let count = |obj: &str, prefix: &str| obj.lines().filter(|l| l.starts_with(prefix)).count();
+        let (corners, edges) = (count(&straight, "v "), count(&straight, "l "));
+        assert_eq!(count(&curved, "l "), edges);
+        assert_eq!(count(&curved, "v "), corners + 3 * edges);
+
+        for line in curved.lines().filter_map(|line| line.strip_prefix("v ")) {
+            let xyz: Vec<f64> = line.split(' ').map(|v| v.parse().unwrap()).collect();
+            let length = (xyz[0].powi(2) + xyz[1].powi(2) + xyz[2].powi(2)).sqrt();
+            assert!((length - 10.0).abs() < 1e-9);
+        }
+        for line in curved.lines().filter_map(|line| line.strip_prefix("l ")) {
+            assert_eq!(line.split(' ').count(), 5);
+        }
+    }
 }
@@ -0,0 +1,862 @@
+//! Queries in geographic (latitude/longitude) terms.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+use crate::hexasphere::index::TileIndex;
+use crate::tile::Tile;
+use crate::utils::{ring_contains, slerp, LatLon};
+use std::collections::VecDeque;
+use std::f64::consts::FRAC_PI_2;
+
+/// Endpoints whose directions have a dot product below this are treated as
+/// antipodal, where the great circle between them is not unique.
+const ANTIPODAL_DOT: f64 = -1.0 + 1e-12;
+
+/// Which tiles [`Hexasphere::tiles_in_bbox_with`] counts as inside a box.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{BboxMode, Hexasphere};
+/// let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+/// let centers = hexasphere.tiles_in_bbox_with(-5.0, 5.0, 0.0, 30.0, BboxMode::Center);
+/// let touching = hexasphere.tiles_in_bbox_with(-5.0, 5.0, 0.0, 30.0, BboxMode::Overlap);
+/// assert!(centers.len() <= touching.len());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BboxMode {
+    /// Tiles whose center lies inside the box
+    #[default]
+    Center,
+    /// Tiles whose polygon overlaps the box at all
+    Overlap,
+}
+
+impl Hexasphere {
+    /// Lists the tiles a great-circle arc passes through, in order.
+    ///
+    /// The shorter arc between the two coordinates is sampled with steps of
+    /// half the smallest tile radius, so no tile it crosses is skipped. Each
+    /// sample is located starting from the previous tile, which keeps the walk
+    /// local. Whenever the arc clips a corner so that two consecutive tiles
+    /// only share a vertex, the tile between them closest to the arc is
+    /// inserted, so consecutive entries are always neighbors.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Start of the path
+    /// * `to` - End of the path
+    ///
+    /// # Returns
+    ///
+    /// Tile ids starting with the tile under `from` and ending with the tile
+    /// under `to`, without consecutive repeats. Empty if there are no tiles.
+    ///
+    /// # Special Cases
+    ///
+    /// - **Gaps**: With `hex_size < 1.0` samples between tiles are assigned to
+    ///   the tile with the nearest center
+    /// - **Antipodal points**: Every great circle through them is equally
+    ///   short; the path heads north first, matching
+    ///   [`LatLon::initial_bearing_to`]
+    ///
+    /// # Use Cases
+    ///
+    /// - **Routes**: Flight paths, trade routes or cables between two places
+    /// - **Line of sight**: Check the tiles between two units for obstacles
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n) to find the start tile and step size, plus
+    ///   O(s × k) for s samples along the arc
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, LatLon};
+    /// let hexasphere = Hexasphere::new(6371.0, 8, 1.0);
+    /// let london = LatLon::new(51.5074, -0.1278);
+    /// let new_york = LatLon::new(40.7128, -74.0060);
+    ///
+    /// let route = hexasphere.tiles_along_arc(&london, &new_york);
+    /// for pair in route.windows(2) {
+    ///     assert!(hexasphere.tiles[pair[0]].neighbors.contains(&pair[1]));
+    /// }
+    /// ```
+    pub fn tiles_along_arc(&self, from: &LatLon, to: &LatLon) -> Vec<usize> {
+        if self.tiles.is_empty() {
+            return Vec::new();
+        }
+
+        let start = Vector3::from(&from.to_point(self.radius)).normalize();
+        let end = Vector3::from(&to.to_point(self.radius)).normalize();
+        if start.dot(&end) < ANTIPODAL_DOT {
+            let middle = from.destination(0.0, FRAC_PI_2 * self.radius, self.radius);
+            let mut path = self.tiles_along_arc(from, &middle);
+            for id in self.tiles_along_arc(&middle, to) {
+                self.push_connected(&mut path, id, &start.cross(&end));
+            }
+            return path;
+        }
+
+        let normal = start.cross(&end);
+        let angle = normal.dot(&normal).sqrt().atan2(start.dot(&end));
+        let step = self.smallest_tile_angle() / 2.0;
+        let samples = if step > 0.0 && step.is_finite() {
+            (angle / step).ceil().max(1.0) as usize
+        } else {
+            1
+        };
+
+        let first = self
+            .tile_containing(&self.placed(&start))
+            .unwrap_or_else(|| self.nearest_tile_scan(&start));
+        let mut path = vec![first];
+        let mut current = first;
+
+        for i in 1..=samples {
+            let sample = slerp(&start, &end, i as f64 / samples as f64);
+            let next = self.locate_near(current, &sample);
+            if next != current {
+                self.push_connected(&mut path, next, &normal);
+                current = next;
+            }
+        }
+
+        path
+    }
+
+    /// Finds the tiles whose centers lie inside a latitude/longitude box.
+    ///
+    /// Shorthand for [`Hexasphere::tiles_in_bbox_with`] with [`BboxMode::Center`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let world = hexasphere.tiles_in_bbox(-90.0, 90.0, -180.0, 180.0);
+    /// assert_eq!(world.len(), hexasphere.tiles.len());
+    /// ```
+    pub fn tiles_in_bbox(
+        &self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+    ) -> Vec<usize> {
+        self.tiles_in_bbox_with(min_lat, max_lat, min_lon, max_lon, BboxMode::Center)
+    }
+
+    /// Finds the tiles inside a latitude/longitude box.
+    ///
+    /// Latitudes and longitudes follow [`crate::Point::to_lat_lon`] and are in
+    /// degrees. The box covers `min_lat..=max_lat` and, going east,
+    /// `min_lon..=max_lon`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lat`, `max_lat` - Southern and northern edge of the box
+    /// * `min_lon`, `max_lon` - Western and eastern edge; `min_lon > max_lon`
+    ///   describes a box crossing the antimeridian, e.g. `170.0` to `-170.0`
+    /// * `mode` - Whether a tile's center must be inside or any part of it
+    ///
+    /// # Returns
+    ///
+    /// Matching tile ids in ascending order
+    ///
+    /// # Poles
+    ///
+    /// All longitudes meet at a pole, so a box reaching latitude ±90 contains
+    /// that pole whatever its longitude range. The tile containing the pole is
+    /// then included in either mode.
+    ///
+    /// # Overlap Accuracy
+    ///
+    /// [`BboxMode::Overlap`] treats tile edges as straight lines in
+    /// latitude/longitude, which is accurate for all but very coarse tiles.
+    ///
+    /// # Use Cases
+    ///
+    /// - **GIS import**: Select the tiles a bounding box of data covers
+    /// - **Map views**: Find tiles visible in a rectangular map window
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = boundary points per tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{BboxMode, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+    ///
+    /// // A box around the antimeridian picks up tiles on both sides
+    /// let pacific = hexasphere.tiles_in_bbox_with(-20.0, 20.0, 160.0, -160.0, BboxMode::Overlap);
+    /// let east = pacific.iter().any(|&id| hexasphere.tiles[id].get_lat_lon(10.0).lon > 160.0);
+    /// let west = pacific.iter().any(|&id| hexasphere.tiles[id].get_lat_lon(10.0).lon < -160.0);
+    /// assert!(east && west);
+    /// ```
+    pub fn tiles_in_bbox_with(
+        &self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        mode: BboxMode,
+    ) -> Vec<usize> {
+        // Longitude spans as plain intervals; a wrapping box becomes two
+        let spans: Vec<(f64, f64)> = if min_lon <= max_lon {
+            vec![(min_lon, max_lon)]
+        } else {
+            vec![(min_lon, 180.0), (-180.0, max_lon)]
+        };
+        let inside = |lat_lon: &LatLon| {
+            (min_lat..=max_lat).contains(&lat_lon.lat)
+                && spans.iter().any(|&(a, b)| (a..=b).contains(&lat_lon.lon))
+        };
+
+        let poles: Vec<Point> = [(90.0, max_lat >= 90.0), (-90.0, min_lat <= -90.0)]
+            .into_iter()
+            .filter(|&(_, reached)| reached && min_lat <= max_lat)
+            .map(|(lat, _)| {
+                self.placed(&Vector3::from(&LatLon::new(lat, 0.0).to_point(self.radius)))
+            })
+            .collect();
+
+        self.tiles
+            .iter()
+            .filter(|tile| {
+                if poles.iter().any(|pole| tile.contains_point(pole)) {
+                    return true;
+                }
+                let center = direction_lat_lon(&tile.relative(&tile.center_point));
+                match mode {
+                    BboxMode::Center => inside(&center),
+                    BboxMode::Overlap => {
+                        inside(&center) || {
+                            let polygon = unwrapped_polygon(tile, center.lon);
+                            spans.iter().any(|&(a, b)| {
+                                [-360.0, 0.0, 360.0].iter().any(|shift| {
+                                    polygon_overlaps_rect(
+                                        &polygon,
+                                        (a + shift, min_lat),
+                                        (b + shift, max_lat),
+                                    )
+                                })
+                            })
+                        }
+                    }
+                }
+            })
+            .map(|tile| tile.id)
+            .collect()
+    }
+
+    /// Finds the tiles whose centers lie inside a polygon on the sphere.
+    ///
+    /// Uses [`crate::utils::point_in_spherical_polygon`], so edges are
+    /// great-circle arcs, the ring may run either way, and rings crossing the
+    /// antimeridian or enclosing a pole work as expected.
+    ///
+    /// # Arguments
+    ///
+    /// * `ring` - Polygon vertices in degrees, covering less than a hemisphere
+    ///
+    /// # Returns
+    ///
+    /// Matching tile ids in ascending order; empty for rings with fewer than
+    /// three vertices
+    ///
+    /// # Use Cases
+    ///
+    /// - **GIS import**: Stamp country borders or biome outlines onto tiles
+    /// - **Level design**: Paint hand-drawn regions onto the planet
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × m) where n = tiles, m = ring vertices
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, LatLon};
+    /// let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+    /// let ring = [
+    ///     LatLon::new(-10.0, 170.0),
+    ///     LatLon::new(-10.0, -170.0),
+    ///     LatLon::new(10.0, -170.0),
+    ///     LatLon::new(10.0, 170.0),
+    /// ];
+    ///
+    /// // The square straddles the antimeridian
+    /// let inside = hexasphere.tiles_in_polygon(&ring);
+    /// assert!(!inside.is_empty());
+    /// assert!(inside
+    ///     .iter()
+    ///     .all(|&id| hexasphere.tiles[id].get_lat_lon(10.0).lon.abs() >= 169.0));
+    /// ```
+    pub fn tiles_in_polygon(&self, ring: &[LatLon]) -> Vec<usize> {
+        let vertices: Vec<Vector3> = ring
+            .iter()
+            .map(|lat_lon| Vector3::from(&lat_lon.to_point(1.0)))
+            .collect();
+
+        self.tiles
+            .iter()
+            .filter(|tile| ring_contains(&self.relative(&tile.center_point), &vertices))
+            .map(|tile| tile.id)
+            .collect()
+    }
+
+    /// Averages an equirectangular raster onto the tiles.
+    ///
+    /// Every raster cell is assigned to the tile containing its center, and
+    /// each tile gets the mean of its cells. Cells shrink toward the poles, so
+    /// samples are weighted by the cosine of their latitude; this makes the
+    /// mean an area average rather than a per-pixel one.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Number of columns, spanning longitude -180° to 180° going east
+    /// * `height` - Number of rows, spanning latitude 90° (first row) to -90°
+    /// * `data` - Row-major cell values, `width * height` long
+    ///
+    /// # Returns
+    ///
+    /// The mean value of every tile, indexed like `tiles`. Tiles smaller than a
+    /// raster cell may contain no cell center; they take the value of the cell
+    /// under their own center instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is 0 or `data.len() != width * height`.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Elevation import**: Give every tile a height from a DEM grid
+    /// - **Climate data**: Per-tile temperature or rainfall from global grids
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n log n + c log n) where n = tiles, c = raster cells
+    /// - Builds a [`crate::TileIndex`] internally
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    ///
+    /// // 2° cells; the value is the cell's row
+    /// let (width, height) = (180, 90);
+    /// let data: Vec<f64> = (0..width * height).map(|cell| (cell / width) as f64).collect();
+    /// let means = hexasphere.aggregate_raster_mean(width, height, &data);
+    ///
+    /// assert_eq!(means.len(), hexasphere.tiles.len());
+    /// assert!(means.iter().all(|mean| (0.0..90.0).contains(mean)));
+    /// ```
+    pub fn aggregate_raster_mean(&self, width: usize, height: usize, data: &[f64]) -> Vec<f64> {
+        assert!(width > 0 && height > 0, "raster must not be empty");
+        assert_eq!(
+            data.len(),
+            width * height,
+            "raster data must be width * height long"
+        );
+
+        let index = TileIndex::new(self);
+        let (cell_lon, cell_lat) = (360.0 / width as f64, 180.0 / height as f64);
+        let mut sums = vec![(0.0, 0.0); self.tiles.len()];
+
+        for row in 0..height {
+            let lat = 90.0 - (row as f64 + 0.5) * cell_lat;
+            let weight = lat.to_radians().cos();
+            for col in 0..width {
+                let lat_lon = LatLon::new(lat, -180.0 + (col as f64 + 0.5) * cell_lon);
+                let tile = index
+                    .tile_at_lat_lon(&lat_lon)
+                    .unwrap_or_else(|| index.nearest(&Vector3::from(&lat_lon.to_point(1.0))));
+                sums[tile].0 += weight * data[row * width + col];
+                sums[tile].1 += weight;
+            }
+        }
+
+        self.tiles
+            .iter()
+            .zip(sums)
+            .map(|(tile, (sum, weight))| {
+                if weight > 0.0 {
+                    sum / weight
+                } else {
+                    let center = direction_lat_lon(&tile.relative(&tile.center_point));
+                    let row = (((90.0 - center.lat) / cell_lat) as usize).min(height - 1);
+                    let col = (((center.lon + 180.0) / cell_lon) as usize).min(width - 1);
+                    data[row * width + col]
+                }
+            })
+            .collect()
+    }
+
+    /// Smallest angle between a tile center and one of its boundary points.
+    fn smallest_tile_angle(&self) -> f64 {
+        self.tiles
+            .iter()
+            .flat_map(|tile| {
+                let center = self.relative(&tile.center_point).normalize();
+                tile.boundary.iter().map(move |point| {
+                    let corner = self.relative(point).normalize();
+                    let cross = center.cross(&corner);
+                    cross.dot(&cross).sqrt().atan2(center.dot(&corner))
+                })
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Finds the tile containing `direction`, searching outward from `hint`.
+    ///
+    /// Falls back to the nearest center, found by walking toward it through
+    /// neighbors, when no nearby tile contains the direction (e.g. in a gap).
+    fn locate_near(&self, hint: usize, direction: &Vector3) -> usize {
+        let point = self.placed(direction);
+        let tile = &self.tiles[hint];
+        if tile.contains_point(&point) {
+            return hint;
+        }
+        if let Some(&neighbor) = tile
+            .neighbors
+            .iter()
+            .find(|&&neighbor| self.tiles[neighbor].contains_point(&point))
+        {
+            return neighbor;
+        }
+
+        let closeness = |id: usize| self.relative(&self.tiles[id].center_point).dot(direction);
+        let mut current = hint;
+        loop {
+            let best = self.tiles[current]
+                .neighbors
+                .iter()
+                .copied()
+                .max_by(|&a, &b| closeness(a).total_cmp(&closeness(b)));
+            match best {
+                Some(next) if closeness(next) > closeness(current) => current = next,
+                _ => return self.tile_containing(&point).unwrap_or(current),
+            }
+        }
+    }
+
+    /// Tile with the center nearest to `direction`, by scanning every tile.
+    fn nearest_tile_scan(&self, direction: &Vector3) -> usize {
+        (0..self.tiles.len())
+            .max_by(|&a, &b| {
+                let a = self.relative(&self.tiles[a].center_point).normalize();
+                let b = self.relative(&self.tiles[b].center_point).normalize();
+                a.dot(direction).total_cmp(&b.dot(direction))
+            })
+            .unwrap_or(0)
+    }
+
+    /// Appends `next` to `path`, inserting tiles in between if it is not a neighbor.
+    ///
+    /// A shared neighbor nearest the arc's plane (given by `normal`) is
+    /// preferred; otherwise the shortest neighbor chain is used.
+    fn push_connected(&self, path: &mut Vec<usize>, next: usize, normal: &Vector3) {
+        let Some(&last) = path.last() else {
+            path.push(next);
+            return;
+        };
+        if last == next {
+            return;
+        }
+        if self.tiles[last].neighbors.contains(&next) {
+            path.push(next);
+            return;
+        }
+
+        let off_arc = |id: usize| {
+            self.relative(&self.tiles[id].center_point)
+                .normalize()
+                .dot(normal)
+                .abs()
+        };
+        let bridge = self.tiles[last]
+            .neighbors
+            .iter()
+            .copied()
+            .filter(|id| self.tiles[next].neighbors.contains(id))
+            .min_by(|&a, &b| off_arc(a).total_cmp(&off_arc(b)));
+
+        match bridge {
+            Some(bridge) => path.extend([bridge, next]),
+            None => path.extend(self.neighbor_chain(last, next).into_iter().skip(1)),
+        }
+    }
+
+    /// Shortest chain of neighboring tiles from `from` to `to`, inclusive.
+    fn neighbor_chain(&self, from: usize, to: usize) -> Vec<usize> {
+        let mut previous = vec![usize::MAX; self.tiles.len()];
+        previous[from] = from;
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(id) = queue.pop_front() {
+            if id == to {
+                break;
+            }
+            for &neighbor in &self.tiles[id].neighbors {
+                if previous[neighbor] == usize::MAX {
+                    previous[neighbor] = id;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        if previous[to] == usize::MAX {
+            return vec![from, to];
+        }
+        let mut chain = vec![to];
+        while let Some(&id) = chain.last() {
+            if id == from {
+                break;
+            }
+            chain.push(previous[id]);
+        }
+        chain.reverse();
+        chain
+    }
+}
+
+/// Latitude and longitude of a direction given as a point, whatever its length.
+fn direction_lat_lon(point: &Point) -> LatLon {
+    let length = (point.x * point.x + point.y * point.y + point.z * point.z).sqrt();
+    point.to_lat_lon(length)
+}
+
+/// A tile's corners as `(lon, lat)`, with longitudes unwrapped to within 180° of `center_lon`.
+fn unwrapped_polygon(tile: &Tile, center_lon: f64) -> Vec<(f64, f64)> {
+    tile.boundary
+        .iter()
+        .map(|point| {
+            let corner = direction_lat_lon(&tile.relative(point));
+            let lon = center_lon + (corner.lon - center_lon + 180.0).rem_euclid(360.0) - 180.0;
+            (lon, corner.lat)
+        })
+        .collect()
+}
+
+/// Whether a planar polygon and an axis-aligned rectangle share any point.
+fn polygon_overlaps_rect(polygon: &[(f64, f64)], min: (f64, f64), max: (f64, f64)) -> bool {
+    let in_rect = |&(x, y): &(f64, f64)| x >= min.0 && x <= max.0 && y >= min.1 && y <= max.1;
+    if polygon.iter().any(in_rect) {
+        return true;
+    }
+
+    let corners = [min, (max.0, min.1), max, (min.0, max.1)];
+    let n = polygon.len();
+    let edges = || (0..n).map(|i| (polygon[i], polygon[(i + 1) % n]));
+
+    // A rectangle corner inside the polygon (crossing-number test)
+    let in_polygon = |&(x, y): &(f64, f64)| {
+        edges()
+            .filter(|&((x1, y1), (x2, y2))| {
+                (y1 > y) != (y2 > y) && x < x1 + (y - y1) * (x2 - x1) / (y2 - y1)
+            })
+            .count()
+            % 2
+            == 1
+    };
+    if corners.iter().any(in_polygon) {
+        return true;
+    }
+
+    edges().any(|(p, q)| (0..4).any(|i| segments_intersect(p, q, corners[i], corners[(i + 1) % 4])))
+}
+
+/// Whether two closed segments intersect, including touching at an end.
+fn segments_intersect(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> bool {
+    let orient = |p: (f64, f64), q: (f64, f64), r: (f64, f64)| {
+        (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+    };
+    let on_segment = |p: (f64, f64), q: (f64, f64), r: (f64, f64)| {
+        r.0 >= p.0.min(q.0) && r.0 <= p.0.max(q.0) && r.1 >= p.1.min(q.1) && r.1 <= p.1.max(q.1)
+    };
+
+    let (d1, d2) = (orient(c, d, a), orient(c, d, b));
+    let (d3, d4) = (orient(a, b, c), orient(a, b, d));
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(c, d, a))
+        || (d2 == 0.0 && on_segment(c, d, b))
+        || (d3 == 0.0 && on_segment(a, b, c))
+        || (d4 == 0.0 && on_segment(a, b, d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BboxMode;
+    use crate::hexasphere::core::Hexasphere;
+    use crate::utils::LatLon;
+
+    fn assert_connected(hexasphere: &Hexasphere, path: &[usize]) {
+        for pair in path.windows(2) {
+            assert!(
+                hexasphere.tiles[pair[0]].neighbors.contains(&pair[1]),
+                "{} and {} are not neighbors",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_arc_between_neighbors_is_two_tiles() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+
+        for tile in hexasphere.tiles.iter().step_by(7) {
+            for &neighbor in &tile.neighbors {
+                let path = hexasphere.tiles_along_arc(
+                    &tile.get_lat_lon(10.0),
+                    &hexasphere.tiles[neighbor].get_lat_lon(10.0),
+                );
+                assert_eq!(path, vec![tile.id, neighbor]);
+            }
+        }
+
+        let here = hexasphere.tiles[5].get_lat_lon(10.0);
+        assert_eq!(hexasphere.tiles_along_arc(&here, &here), vec![5]);
+    }
+
+    #[test]
+    fn test_equatorial_loop_returns_to_start() {
+        let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+        let stops = [0.0, 120.0, -120.0, 0.0].map(|lon| LatLon::new(0.0, lon));
+
+        let mut path: Vec<usize> = Vec::new();
+        for leg in stops.windows(2) {
+            let segment = hexasphere.tiles_along_arc(&leg[0], &leg[1]);
+            if let Some(&last) = path.last() {
+                assert_eq!(segment[0], last);
+            }
+            path.extend(segment.into_iter().skip(usize::from(!path.is_empty())));
+        }
+
+        assert_connected(&hexasphere, &path);
+        assert_eq!(path.first(), path.last());
+        assert!(path.len() > 20);
+    }
+
+    #[test]
+    fn test_arc_paths_are_connected() {
+        for hexasphere in [Hexasphere::new(10.0, 5, 1.0), Hexasphere::new(10.0, 5, 0.6)] {
+            let places = [
+                LatLon::new(51.5, -0.1),
+                LatLon::new(40.7, -74.0),
+                LatLon::new(-33.9, 151.2),
+                LatLon::new(89.0, 10.0),
+                LatLon::new(-51.5, 179.9),
+                LatLon::new(0.0, 179.5),
+                LatLon::new(0.0, -179.5),
+            ];
+
+            for from in &places {
+                for to in &places {
+                    let path = hexasphere.tiles_along_arc(from, to);
+                    assert_connected(&hexasphere, &path);
+
+                    // Endpoints on a shared edge may belong to either tile
+                    let (start, end) = (from.to_point(10.0), to.to_point(10.0));
+                    if hexasphere.tile_containing(&start).is_some() {
+                        assert!(hexasphere.tiles[path[0]].contains_point(&start));
+                    }
+                    if hexasphere.tile_containing(&end).is_some() {
+                        let last = *path.last().unwrap();
+                        assert!(hexasphere.tiles[last].contains_point(&end));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bbox_whole_world_returns_every_tile() {
+        let hexasphere = Hexasphere::new(10.0, 5, 0.8);
+        for mode in [BboxMode::Center, BboxMode::Overlap] {
+            let all = hexasphere.tiles_in_bbox_with(-90.0, 90.0, -180.0, 180.0, mode);
+            assert_eq!(all, (0..hexasphere.tiles.len()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_bbox_equatorial_band_scales_with_subdivision() {
+        let band = |divisions| {
+            let hexasphere = Hexasphere::new(10.0, divisions, 1.0);
+            let ring = hexasphere.tiles_in_bbox_with(-0.1, 0.1, -180.0, 180.0, BboxMode::Overlap);
+
+            // Every tile straddles or touches the equator, and the ring is closed
+            for &id in &ring {
+                let lats: Vec<f64> = (0..hexasphere.tiles[id].boundary.len())
+                    .map(|i| {
+                        hexasphere.tiles[id]
+                            .get_boundary_lat_lon(10.0, i)
+                            .unwrap()
+                            .lat
+                    })
+                    .collect();
+                assert!(lats.iter().any(|&l| l <= 0.1) && lats.iter().any(|&l| l >= -0.1));
+            }
+            let components = hexasphere.connected_components(|id| ring.contains(&id));
+            assert_eq!(components.len(), 1);
+            ring.len()
+        };
+
+        let (coarse, fine) = (band(4), band(8));
+        let ratio = fine as f64 / coarse as f64;
+        assert!((1.5..2.5).contains(&ratio), "{} -> {}", coarse, fine);
+    }
+
+    #[test]
+    fn test_bbox_across_antimeridian() {
+        let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+        let lon = |id: usize| hexasphere.tiles[id].get_lat_lon(10.0).lon;
+
+        let pacific = hexasphere.tiles_in_bbox(-30.0, 30.0, 165.0, -165.0);
+        assert!(pacific.iter().any(|&id| lon(id) >= 165.0));
+        assert!(pacific.iter().any(|&id| lon(id) <= -165.0));
+        assert!(pacific.iter().all(|&id| lon(id).abs() >= 165.0));
+
+        // Both halves together equal the wrapped box
+        let mut halves = hexasphere.tiles_in_bbox(-30.0, 30.0, 165.0, 180.0);
+        halves.extend(hexasphere.tiles_in_bbox(-30.0, 30.0, -180.0, -165.0));
+        halves.sort();
+        halves.dedup();
+        assert_eq!(halves, pacific);
+    }
+
+    #[test]
+    fn test_bbox_reaching_pole_includes_polar_tile() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+        let north = LatLon::new(90.0, 0.0).to_point(10.0);
+        let polar = hexasphere.tile_containing(&north).unwrap();
+
+        for mode in [BboxMode::Center, BboxMode::Overlap] {
+            let cap = hexasphere.tiles_in_bbox_with(89.0, 90.0, 10.0, 11.0, mode);
+            assert!(cap.contains(&polar));
+        }
+
+        // Small boxes away from any tile corner still find the tile around them
+        let inner = hexasphere.tiles_in_bbox_with(10.0, 10.01, 20.0, 20.01, BboxMode::Overlap);
+        let point = LatLon::new(10.005, 20.005).to_point(10.0);
+        assert!(inner.contains(&hexasphere.tile_containing(&point).unwrap()));
+    }
+
+    #[test]
+    fn test_aggregate_raster_mean_constant_raster() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+
+        // Coarse enough that some tiles contain no cell center
+        for (width, height) in [(360, 180), (8, 4)] {
+            let data = vec![2.5; width * height];
+            let means = hexasphere.aggregate_raster_mean(width, height, &data);
+            assert_eq!(means.len(), hexasphere.tiles.len());
+            assert!(means.iter().all(|&mean| (mean - 2.5).abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_aggregate_raster_mean_hemispheres() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+        let (width, height) = (360, 180);
+        let data: Vec<f64> = (0..width * height)
+            .map(|cell| if cell / width < height / 2 { 1.0 } else { 0.0 })
+            .collect();
+        let means = hexasphere.aggregate_raster_mean(width, height, &data);
+
+        for (tile, mean) in hexasphere.tiles.iter().zip(&means) {
+            let lat = tile.get_lat_lon(10.0).lat;
+            if lat > 10.0 {
+                assert!((mean - 1.0).abs() < 1e-12, "tile at {lat} has {mean}");
+            } else if lat < -10.0 {
+                assert!(mean.abs() < 1e-12, "tile at {lat} has {mean}");
+            } else {
+                assert!((0.0..=1.0).contains(mean));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aggregate_raster_mean_rejects_wrong_length() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        hexasphere.aggregate_raster_mean(4, 4, &[0.0; 15]);
+    }
+
+    fn square(min_lat: f64, max_lat: f64, min_lon: f64, max_lon: f64) -> Vec<LatLon> {
+        vec![
+            LatLon::new(min_lat, min_lon),
+            LatLon::new(min_lat, max_lon),
+            LatLon::new(max_lat, max_lon),
+            LatLon::new(max_lat, min_lon),
+        ]
+    }
+
+    #[test]
+    fn test_tiles_in_polygon_equatorial_square() {
+        let hexasphere = Hexasphere::new(10.0, 10, 1.0);
+        let ring = square(-15.0, 15.0, -15.0, 15.0);
+        let inside = hexasphere.tiles_in_polygon(&ring);
+
+        for tile in &hexasphere.tiles {
+            let lat_lon = tile.get_lat_lon(10.0);
+            let (lat, lon) = (lat_lon.lat.abs(), lat_lon.lon.abs());
+            if lat < 14.0 && lon < 14.0 {
+                assert!(inside.contains(&tile.id), "{lat_lon:?} should be inside");
+            } else if lat > 16.0 || lon > 16.0 {
+                assert!(!inside.contains(&tile.id), "{lat_lon:?} should be outside");
+            }
+        }
+
+        // Orientation does not matter, nor does a repeated closing vertex
+        let mut reversed = ring.clone();
+        reversed.reverse();
+        assert_eq!(hexasphere.tiles_in_polygon(&reversed), inside);
+        let mut closed = ring.clone();
+        closed.push(ring[0]);
+        assert_eq!(hexasphere.tiles_in_polygon(&closed), inside);
+    }
+
+    #[test]
+    fn test_tiles_in_polygon_polar_cap() {
+        let hexasphere = Hexasphere::new(10.0, 10, 1.0);
+        let ring: Vec<LatLon> = (0..36)
+            .map(|i| LatLon::new(-60.0, i as f64 * 10.0))
+            .collect();
+        let inside = hexasphere.tiles_in_polygon(&ring);
+
+        let north = LatLon::new(90.0, 0.0).to_point(10.0);
+        let south = LatLon::new(-90.0, 0.0).to_point(10.0);
+        assert!(inside.contains(&hexasphere.tile_containing(&south).unwrap()));
+        assert!(!inside.contains(&hexasphere.tile_containing(&north).unwrap()));
+
+        for tile in &hexasphere.tiles {
+            let lat = tile.get_lat_lon(10.0).lat;
+            assert_eq!(inside.contains(&tile.id), lat < -60.0, "tile at {lat}");
+        }
+    }
+
+    #[test]
+    fn test_tiles_in_polygon_across_antimeridian() {
+        let hexasphere = Hexasphere::new(10.0, 10, 1.0);
+        let inside = hexasphere.tiles_in_polygon(&square(-20.0, 20.0, 160.0, -160.0));
+
+        let lon = |id: usize| hexasphere.tiles[id].get_lat_lon(10.0).lon;
+        assert!(inside.iter().any(|&id| lon(id) > 165.0));
+        assert!(inside.iter().any(|&id| lon(id) < -165.0));
+        assert!(inside.iter().all(|&id| lon(id).abs() > 159.0));
+
+        // The same box matches by center
+        let bbox = hexasphere.tiles_in_bbox(-18.0, 18.0, 162.0, -162.0);
+        assert!(bbox.iter().all(|id| inside.contains(id)));
+    }
+}
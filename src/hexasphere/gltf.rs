@@ -0,0 +1,485 @@
+//! glTF 2.0 binary (GLB) export.
+//!
+//! The exported files are self-contained: the JSON chunk describes the scene
+//! and the BIN chunk holds every vertex and index buffer, so they can be
+//! dropped straight into three.js, Babylon.js, Blender or any other glTF
+//! viewer. No extra dependencies are needed; the container is written by hand.
+
+use crate::geometry::Point;
+use crate::hexasphere::core::Hexasphere;
+use crate::hexasphere::mesh_data::{MeshData, MeshOptions};
+use crate::tile::TileOrientation;
+use crate::utils::AxisConvention;
+use std::f64::consts::TAU;
+
+/// `"glTF"` in little-endian, the first four bytes of every GLB file.
+const GLB_MAGIC: u32 = 0x4654_6C67;
+/// Chunk type of the JSON chunk (`"JSON"`).
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+/// Chunk type of the binary buffer chunk (`"BIN\0"`).
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+/// Accessor component types and buffer view targets from the glTF spec.
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// How [`Hexasphere::to_gltf_with`] lays out the exported scene.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{GltfMode, Hexasphere};
+/// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+///
+/// let exact = hexasphere.to_gltf_with(GltfMode::Combined);
+/// let instanced = hexasphere.to_gltf_with(GltfMode::Instanced);
+/// assert_eq!(&exact[..4], b"glTF");
+/// assert_eq!(&instanced[..4], b"glTF");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GltfMode {
+    /// One mesh holding the exact geometry of every tile, as produced by
+    /// [`Hexasphere::to_mesh`] with default options
+    #[default]
+    Combined,
+    /// One node per tile, each referencing a shared unit hexagon or unit
+    /// pentagon mesh through its transform matrix. Tiles are approximated by
+    /// their best-fit regular polygons, like
+    /// [`crate::Tile::get_regular_hexagon_params`]; engines can draw all of
+    /// them with two instanced draw calls.
+    Instanced,
+}
+
+impl Hexasphere {
+    /// Exports the hexasphere as a self-contained glTF 2.0 binary (GLB).
+    ///
+    /// Shorthand for [`Hexasphere::to_gltf_with`] with [`GltfMode::Combined`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # fn save_glb_example() -> std::io::Result<()> {
+    /// let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+    /// std::fs::write("hexasphere.glb", hexasphere.to_gltf())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_gltf(&self) -> Vec<u8> {
+        self.to_gltf_with(GltfMode::Combined)
+    }
+
+    /// Exports the hexasphere as a self-contained glTF 2.0 binary (GLB).
+    ///
+    /// Every mesh has `POSITION`, `NORMAL` and `TEXCOORD_0` attributes and a
+    /// `u32` index buffer, with triangles wound counter-clockwise seen from
+    /// outside the sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Exact combined geometry or per-tile instanced nodes
+    ///
+    /// # Returns
+    ///
+    /// The GLB file content: a 12-byte header, a JSON chunk and a BIN chunk
+    ///
+    /// # Use Cases
+    ///
+    /// - **Web viewers**: Load directly with three.js `GLTFLoader` or Babylon.js
+    /// - **DCC tools**: Import into Blender with normals and UVs intact
+    /// - **GPU instancing**: [`GltfMode::Instanced`] keeps one small mesh per
+    ///   tile shape and a transform per tile
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n) where n = total boundary points
+    /// - Size: about 32 bytes per vertex plus 12 per triangle in combined
+    ///   mode, about 100 bytes of JSON per tile in instanced mode
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{GltfMode, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let glb = hexasphere.to_gltf_with(GltfMode::Instanced);
+    ///
+    /// // The header records the total length
+    /// let length = u32::from_le_bytes(glb[8..12].try_into().unwrap());
+    /// assert_eq!(length as usize, glb.len());
+    /// ```
+    pub fn to_gltf_with(&self, mode: GltfMode) -> Vec<u8> {
+        self.to_gltf_with_axes(mode, AxisConvention::YUp)
+    }
+
+    /// Exports the GLB of [`Hexasphere::to_gltf_with`] in the given axis convention.
+    ///
+    /// glTF itself is Y-up, and importers such as Blender's convert from it on
+    /// their own, so [`AxisConvention::YUp`] is right for almost every viewer.
+    /// Use [`AxisConvention::ZUp`] only for pipelines that read the raw
+    /// coordinates without that conversion.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Exact combined geometry or per-tile instanced nodes
+    /// * `axes` - Axis convention of positions, normals and node transforms
+    ///
+    /// # Returns
+    ///
+    /// The GLB file content
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{AxisConvention, GltfMode, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let glb = hexasphere.to_gltf_with_axes(GltfMode::Combined, AxisConvention::ZUp);
+    /// assert_eq!(&glb[..4], b"glTF");
+    /// ```
+    pub fn to_gltf_with_axes(&self, mode: GltfMode, axes: AxisConvention) -> Vec<u8> {
+        let mut builder = GlbBuilder::default();
+
+        let (meshes, nodes) = match mode {
+            GltfMode::Combined => {
+                let mesh = builder.push_mesh(&self.to_mesh(MeshOptions {
+                    axes,
+                    ..MeshOptions::default()
+                }));
+                (vec![mesh], vec!["{\"mesh\":0}".to_string()])
+            }
+            GltfMode::Instanced => {
+                let meshes = vec![
+                    builder.push_mesh(&unit_polygon(6)),
+                    builder.push_mesh(&unit_polygon(5)),
+                ];
+                let nodes = self
+                    .tiles
+                    .iter()
+                    .filter_map(|tile| {
+                        let (mesh, center, radius, orientation) = if tile.is_pentagon() {
+                            let params = tile.get_regular_pentagon_params()?;
+                            (1, params.center, params.radius, params.orientation)
+                        } else {
+                            let params = tile.get_regular_hexagon_params()?;
+                            (0, params.center, params.radius, params.orientation)
+                        };
+                        let matrix = node_matrix(&orientation, &center, radius, axes);
+                        Some(format!(
+                            "{{\"name\":\"tile_{}\",\"mesh\":{mesh},\"matrix\":{}}}",
+                            tile.id,
+                            json_array(&matrix)
+                        ))
+                    })
+                    .collect();
+                (meshes, nodes)
+            }
+        };
+
+        builder.finish(&meshes, &nodes)
+    }
+}
+
+/// A regular polygon with circumradius 1 in the local XZ plane, facing +Y.
+///
+/// Vertex `i` sits at angle `i × 360° / sides` from +X toward +Z, matching
+/// [`crate::RegularHexagonParams::generate_vertices`] once transformed by the
+/// tile's orientation (X = right, Y = up, Z = forward).
+fn unit_polygon(sides: usize) -> MeshData {
+    let mut mesh = MeshData::default();
+    mesh.positions.push([0.0, 0.0, 0.0]);
+    mesh.uvs.push([0.5, 0.5]);
+
+    for i in 0..sides {
+        let angle = i as f64 * TAU / sides as f64;
+        let (x, z) = (angle.cos() as f32, angle.sin() as f32);
+        mesh.positions.push([x, 0.0, z]);
+        mesh.uvs.push([0.5 + 0.5 * x, 0.5 + 0.5 * z]);
+    }
+    mesh.normals = vec![[0.0, 1.0, 0.0]; sides + 1];
+
+    for i in 0..sides as u32 {
+        // Reversed corner order turns the triangles toward +Y
+        let next = (i + 1) % sides as u32;
+        mesh.indices.extend_from_slice(&[0, next + 1, i + 1]);
+    }
+    mesh.tile_ranges.push(0..mesh.indices.len() as u32);
+    mesh
+}
+
+/// A glTF node matrix (column-major) placing a unit polygon on a tile.
+fn node_matrix(
+    orientation: &TileOrientation,
+    center: &Point,
+    radius: f64,
+    axes: AxisConvention,
+) -> [f64; 16] {
+    let mut columns = orientation.to_transform_matrix_column_major(center);
+    // Scale the rotation columns only; the translation column stays as is
+    for value in &mut columns[..12] {
+        *value *= radius;
+    }
+    // Converting every column applies the axis change after the transform
+    for column in columns.chunks_mut(4) {
+        let [x, y, z] = axes.convert_xyz([column[0], column[1], column[2]]);
+        column[..3].copy_from_slice(&[x, y, z]);
+    }
+    columns
+}
+
+/// Formats numbers as a JSON array.
+fn json_array<T: ToString>(values: &[T]) -> String {
+    let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+    format!("[{}]", values.join(","))
+}
+
+/// Accumulates the BIN chunk and the JSON describing its contents.
+#[derive(Default)]
+struct GlbBuilder {
+    bin: Vec<u8>,
+    buffer_views: Vec<String>,
+    accessors: Vec<String>,
+}
+
+impl GlbBuilder {
+    /// Adds a mesh's buffers and returns the JSON of the mesh referencing them.
+    fn push_mesh(&mut self, mesh: &MeshData) -> String {
+        let (min, max) = bounds(&mesh.positions);
+        let bounds = format!(",\"min\":{},\"max\":{}", json_array(&min), json_array(&max));
+
+        let position = self.push_accessor(
+            f32_bytes(mesh.positions.iter().flatten()),
+            mesh.positions.len(),
+            FLOAT,
+            "VEC3",
+            ARRAY_BUFFER,
+            &bounds,
+        );
+        let normal = self.push_accessor(
+            f32_bytes(mesh.normals.iter().flatten()),
+            mesh.normals.len(),
+            FLOAT,
+            "VEC3",
+            ARRAY_BUFFER,
+            "",
+        );
+        let uv = self.push_accessor(
+            f32_bytes(mesh.uvs.iter().flatten()),
+            mesh.uvs.len(),
+            FLOAT,
+            "VEC2",
+            ARRAY_BUFFER,
+            "",
+        );
+        let indices = self.push_accessor(
+            mesh.indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+            mesh.indices.len(),
+            UNSIGNED_INT,
+            "SCALAR",
+            ELEMENT_ARRAY_BUFFER,
+            "",
+        );
+
+        format!(
+            "{{\"primitives\":[{{\"attributes\":{{\"POSITION\":{position},\"NORMAL\":{normal},\
+             \"TEXCOORD_0\":{uv}}},\"indices\":{indices},\"mode\":4}}]}}"
+        )
+    }
+
+    /// Appends one buffer view with a single accessor and returns the accessor id.
+    fn push_accessor(
+        &mut self,
+        bytes: Vec<u8>,
+        count: usize,
+        component_type: u32,
+        kind: &str,
+        target: u32,
+        extra: &str,
+    ) -> usize {
+        let view = self.buffer_views.len();
+        self.buffer_views.push(format!(
+            "{{\"buffer\":0,\"byteOffset\":{},\"byteLength\":{},\"target\":{target}}}",
+            self.bin.len(),
+            bytes.len()
+        ));
+        // Every component type used here is 4 bytes, so views stay aligned
+        self.bin.extend_from_slice(&bytes);
+
+        self.accessors.push(format!(
+            "{{\"bufferView\":{view},\"componentType\":{component_type},\"count\":{count},\
+             \"type\":\"{kind}\"{extra}}}"
+        ));
+        self.accessors.len() - 1
+    }
+
+    /// Assembles the GLB container.
+    fn finish(self, meshes: &[String], nodes: &[String]) -> Vec<u8> {
+        let scene_nodes: Vec<usize> = (0..nodes.len()).collect();
+        let mut json = format!(
+            "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"geotiles\"}},\"scene\":0,\
+             \"scenes\":[{{\"nodes\":{}}}],\"nodes\":[{}],\"meshes\":[{}],\
+             \"accessors\":[{}],\"bufferViews\":[{}],\"buffers\":[{{\"byteLength\":{}}}]}}",
+            json_array(&scene_nodes),
+            nodes.join(","),
+            meshes.join(","),
+            self.accessors.join(","),
+            self.buffer_views.join(","),
+            self.bin.len()
+        )
+        .into_bytes();
+        let mut bin = self.bin;
+
+        // Chunks are 4-byte aligned: JSON pads with spaces, BIN with zeros
+        json.resize(json.len().next_multiple_of(4), b' ');
+        bin.resize(bin.len().next_multiple_of(4), 0);
+
+        let total = 12 + 8 + json.len() + 8 + bin.len();
+        let mut glb = Vec::with_capacity(total);
+        for word in [GLB_MAGIC, 2, total as u32] {
+            glb.extend_from_slice(&word.to_le_bytes());
+        }
+        for (kind, data) in [(CHUNK_JSON, json), (CHUNK_BIN, bin)] {
+            glb.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            glb.extend_from_slice(&kind.to_le_bytes());
+            glb.extend_from_slice(&data);
+        }
+        glb
+    }
+}
+
+/// Little-endian bytes of a sequence of floats.
+fn f32_bytes<'a>(values: impl Iterator<Item = &'a f32>) -> Vec<u8> {
+    values.flat_map(|value| value.to_le_bytes()).collect()
+}
+
+/// Component-wise minimum and maximum of the positions, required by glTF.
+fn bounds(positions: &[[f32; 3]]) -> ([f32; 3], [f32; 3]) {
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for position in positions {
+        for axis in 0..3 {
+            min[axis] = min[axis].min(position[axis]);
+            max[axis] = max[axis].max(position[axis]);
+        }
+    }
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GltfMode, CHUNK_BIN, CHUNK_JSON, GLB_MAGIC};
+    use crate::hexasphere::core::Hexasphere;
+    use crate::hexasphere::mesh_data::MeshOptions;
+    use crate::utils::AxisConvention;
+
+    fn word(bytes: &[u8], offset: usize) -> usize {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize
+    }
+
+    /// Checks the container layout and returns the JSON text and BIN chunk.
+    fn split_glb(glb: &[u8]) -> (String, &[u8]) {
+        assert_eq!(word(glb, 0), GLB_MAGIC as usize);
+        assert_eq!(word(glb, 4), 2);
+        assert_eq!(word(glb, 8), glb.len());
+
+        let json_length = word(glb, 12);
+        assert_eq!(word(glb, 16), CHUNK_JSON as usize);
+        assert_eq!(json_length % 4, 0);
+        let json = String::from_utf8(glb[20..20 + json_length].to_vec()).unwrap();
+
+        let bin_start = 20 + json_length;
+        let bin_length = word(glb, bin_start);
+        assert_eq!(word(glb, bin_start + 4), CHUNK_BIN as usize);
+        assert_eq!(bin_length % 4, 0);
+        assert_eq!(bin_start + 8 + bin_length, glb.len());
+
+        (json, &glb[bin_start + 8..])
+    }
+
+    /// Every integer that follows `"key":` in the JSON, in order.
+    fn values(json: &str, key: &str) -> Vec<usize> {
+        let pattern = format!("\"{key}\":");
+        json.match_indices(&pattern)
+            .map(|(start, _)| {
+                let rest = &json[start + pattern.len()..];
+                let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap();
+                rest[..end].parse().unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_to_gltf_combined_layout() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let mesh = hexasphere.to_mesh(MeshOptions::default());
+        let glb = hexasphere.to_gltf();
+        let (json, bin) = split_glb(&glb);
+
+        assert!(json.contains("\"version\":\"2.0\""));
+        assert!(json.contains("\"POSITION\":0,\"NORMAL\":1,\"TEXCOORD_0\":2"));
+
+        let counts = values(&json, "count");
+        let vertices = mesh.positions.len();
+        assert_eq!(
+            counts,
+            vec![vertices, vertices, vertices, mesh.indices.len()]
+        );
+
+        // Views tile the buffer back to back, sized by their accessors
+        let lengths = values(&json, "byteLength");
+        let (views, buffer) = (&lengths[..4], lengths[4]);
+        assert_eq!(
+            views,
+            &[vertices * 12, vertices * 12, vertices * 8, counts[3] * 4]
+        );
+        assert_eq!(
+            values(&json, "byteOffset"),
+            vec![0, views[0], views[0] + views[1], 2 * views[0] + views[2]]
+        );
+        assert_eq!(buffer, views.iter().sum::<usize>());
+        assert_eq!(buffer, bin.len());
+
+        // Indices round-trip through the BIN chunk
+        let first_index = word(bin, buffer - counts[3] * 4);
+        assert_eq!(first_index, mesh.indices[0] as usize);
+    }
+
+    #[test]
+    fn test_to_gltf_instanced_nodes() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let glb = hexasphere.to_gltf_with(GltfMode::Instanced);
+        let (json, bin) = split_glb(&glb);
+
+        let meshes = values(&json, "mesh");
+        assert_eq!(meshes.len(), hexasphere.tiles.len());
+        assert_eq!(meshes.iter().filter(|&&mesh| mesh == 1).count(), 12);
+        assert_eq!(json.matches("\"matrix\":").count(), hexasphere.tiles.len());
+
+        // A hexagon and a pentagon, each a fan around its center
+        assert_eq!(values(&json, "count"), vec![7, 7, 7, 18, 6, 6, 6, 15]);
+        assert_eq!(*values(&json, "byteLength").last().unwrap(), bin.len());
+    }
+
+    #[test]
+    fn test_instanced_matrix_places_unit_polygon_on_tile() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let tile = hexasphere
+            .tiles
+            .iter()
+            .find(|tile| tile.is_hexagon())
+            .unwrap();
+        let params = tile.get_regular_hexagon_params().unwrap();
+        let expected = &params.generate_vertices()[0];
+
+        for axes in [AxisConvention::YUp, AxisConvention::ZUp] {
+            let m = super::node_matrix(&params.orientation, &params.center, params.radius, axes);
+
+            // Column-major: the first vertex (1, 0, 0) maps to column 0 + column 3
+            let first = [m[0] + m[12], m[1] + m[13], m[2] + m[14]];
+            let expected = axes.convert(expected);
+            assert!((first[0] - expected.x).abs() < 1e-3);
+            assert!((first[1] - expected.y).abs() < 1e-3);
+            assert!((first[2] - expected.z).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Subdivision of the icosahedron on a skewed Goldberg (m, n) lattice.
+//!
+//! Class I subdivision (n = 0) splits each icosahedron face along its own
+//! edges and is handled by [`crate::utils::subdivide_face_indexed`]. For n > 0 the
+//! triangular lattice is rotated relative to the faces, so many small
+//! triangles straddle an icosahedron edge. This module works in integer
+//! lattice coordinates to place every lattice triangle in exactly one face.
+//!
+//! # Lattice Coordinates
+//!
+//! A lattice point `(i, j)` sits at `i·e₁ + j·e₂` with `e₁ = (1, 0)` and
+//! `e₂ = (½, √3/2)`. Each face `(A, B, C)` is laid out with `A = (0, 0)`,
+//! `B = (m, n)` and `C = (-n, m + n)`, which is `B` rotated by 60°. Barycentric
+//! coordinates of lattice points are then integers divided by
+//! `T = m² + mn + n²`, so inside/outside tests are exact.
+
+use crate::geometry::{Point, VertexPool};
+use std::collections::HashMap;
+
+/// Barycentric weights multiplied by `T`, paired with global corner indices.
+type Weights = [(i64, usize); 3];
+
+/// Subdivides every icosahedron face on the Goldberg (m, n) lattice.
+///
+/// # Arguments
+///
+/// * `corners` - The 12 icosahedron corners
+/// * `face_indices` - Corner indices of the 20 faces, wound consistently
+/// * `m`, `n` - Goldberg parameters, with `m + n > 0`
+/// * `pool` - Vertex pool for deduplication and storage
+///
+/// # Returns
+///
+/// The `20·T` lattice triangles as pool indices, before projection onto the sphere
+pub(crate) fn subdivide_goldberg(
+    corners: &[Point],
+    face_indices: &[[usize; 3]],
+    m: usize,
+    n: usize,
+    pool: &mut VertexPool,
+) -> Vec<[u32; 3]> {
+    let (m, n) = (m as i64, n as i64);
+    let t = m * m + m * n + n * n;
+    assert!(t > 0, "Goldberg parameters must not both be zero");
+
+    // Third corner of the face across each directed edge
+    let mut across_edge = HashMap::new();
+    for (face, &[a, b, c]) in face_indices.iter().enumerate() {
+        for (p, q, opposite) in [(a, b, c), (b, c, a), (c, a, b)] {
+            across_edge.insert((q, p), (face, opposite));
+        }
+    }
+
+    let mut triangles = Vec::new();
+    for (face, &[a, b, c]) in face_indices.iter().enumerate() {
+        let weights = |i: i64, j: i64| -> Weights {
+            let wb = i * (m + n) + j * n;
+            let wc = j * m - i * n;
+            [(t - wb - wc, a), (wb, b), (wc, c)]
+        };
+
+        for i in (-n - 1)..=(m + 1) {
+            for j in -1..=(m + n + 1) {
+                let candidates = [
+                    [(i, j), (i + 1, j), (i, j + 1)],
+                    [(i + 1, j), (i + 1, j + 1), (i, j + 1)],
+                ];
+                for triangle in candidates {
+                    let vertices = triangle.map(|(i, j)| weights(i, j));
+                    if !centroid_in_face(face, &vertices, &across_edge) {
+                        continue;
+                    }
+
+                    triangles.push(vertices.map(|w| {
+                        let w = unfold(w, &across_edge);
+                        pool.insert(lattice_point(&w, corners, t))
+                    }));
+                }
+            }
+        }
+    }
+
+    triangles
+}
+
+/// Decides whether a lattice triangle belongs to `face`.
+///
+/// The triangle belongs to the face containing its centroid. Centroids on an
+/// icosahedron edge (which happens for m = n) go to the face with the lower
+/// index so that shared triangles are emitted once.
+fn centroid_in_face(
+    face: usize,
+    vertices: &[Weights; 3],
+    across_edge: &HashMap<(usize, usize), (usize, usize)>,
+) -> bool {
+    let sums: [i64; 3] = std::array::from_fn(|k| vertices.iter().map(|w| w[k].0).sum());
+    if sums.iter().any(|&s| s < 0) {
+        return false;
+    }
+
+    let corners = vertices[0].map(|(_, corner)| corner);
+    (0..3).filter(|&k| sums[k] == 0).all(|k| {
+        // Zero weight on corner k: the centroid lies on the opposite edge
+        let edge = (corners[(k + 1) % 3], corners[(k + 2) % 3]);
+        across_edge[&edge].0 > face
+    })
+}
+
+/// Re-expresses weights that fall outside their face in the face across the crossed edge.
+///
+/// Unfolding the neighbor `(P, Q, D)` across edge `PQ` into the plane of
+/// `(P, Q, R)` places `D` at `P + Q - R`, so a point `αP + βQ + γR` with
+/// `γ < 0` becomes `(α + γ)P + (β + γ)Q - γD`.
+fn unfold(weights: Weights, across_edge: &HashMap<(usize, usize), (usize, usize)>) -> Weights {
+    let Some(k) = weights.iter().position(|&(w, _)| w < 0) else {
+        return weights;
+    };
+
+    let (gamma, _) = weights[k];
+    let (p, q) = (weights[(k + 1) % 3], weights[(k + 2) % 3]);
+    let (_, opposite) = across_edge[&(p.1, q.1)];
+    let unfolded = [(p.0 + gamma, p.1), (q.0 + gamma, q.1), (-gamma, opposite)];
+
+    debug_assert!(
+        unfolded.iter().all(|&(w, _)| w >= 0),
+        "lattice vertex lies beyond a neighboring face"
+    );
+    unfolded
+}
+
+/// Interpolates the flat icosahedron position of a lattice point.
+///
+/// Terms are summed in corner order so that every face sharing the point
+/// computes bit-identical coordinates, which keeps deduplication exact.
+fn lattice_point(weights: &Weights, corners: &[Point], t: i64) -> Point {
+    let mut terms = *weights;
+    terms.sort_by_key(|&(_, corner)| corner);
+
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    for (weight, corner) in terms {
+        let weight = weight as f64 / t as f64;
+        x += weight * corners[corner].x;
+        y += weight * corners[corner].y;
+        z += weight * corners[corner].z;
+    }
+    Point::new(x, y, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+
+    fn tile_count(m: usize, n: usize) -> usize {
+        10 * (m * m + m * n + n * n) + 2
+    }
+
+    #[test]
+    fn test_truncated_icosahedron() {
+        let hexasphere = Hexasphere::new_goldberg(10.0, 1, 1, 1.0);
+
+        assert_eq!(hexasphere.tiles.len(), 32);
+        assert_eq!(
+            hexasphere.tiles.iter().filter(|t| t.is_pentagon()).count(),
+            12
+        );
+        assert_eq!(
+            hexasphere.tiles.iter().filter(|t| t.is_hexagon()).count(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_goldberg_tile_counts() {
+        for (m, n) in [(1, 1), (2, 1), (1, 2), (2, 2), (3, 1), (0, 2), (4, 3)] {
+            let (hexasphere, mesh) = Hexasphere::builder()
+                .radius(10.0)
+                .goldberg(m, n)
+                .build_with_mesh()
+                .unwrap();
+
+            assert_eq!(hexasphere.tiles.len(), tile_count(m, n), "GP({}, {})", m, n);
+            assert_eq!(mesh.euler_characteristic(), 2, "GP({}, {})", m, n);
+
+            let pentagons = hexasphere.tiles.iter().filter(|t| t.is_pentagon()).count();
+            let hexagons = hexasphere.tiles.iter().filter(|t| t.is_hexagon()).count();
+            assert_eq!(pentagons, 12, "GP({}, {})", m, n);
+            assert_eq!(pentagons + hexagons, hexasphere.tiles.len());
+        }
+    }
+
+    #[test]
+    fn test_goldberg_neighbors_are_symmetric() {
+        let hexasphere = Hexasphere::new_goldberg(10.0, 2, 1, 1.0);
+
+        for tile in &hexasphere.tiles {
+            assert_eq!(tile.neighbors.len(), tile.boundary.len());
+            for &neighbor in &tile.neighbors {
+                assert!(hexasphere.tiles[neighbor].neighbors.contains(&tile.id));
+            }
+        }
+    }
+
+    #[test]
+    fn test_class_one_matches_new() {
+        let goldberg = Hexasphere::new_goldberg(10.0, 2, 0, 1.0);
+        let classic = Hexasphere::new(10.0, 2, 1.0);
+
+        assert_eq!(goldberg.tiles.len(), classic.tiles.len());
+        for (a, b) in goldberg.tiles.iter().zip(&classic.tiles) {
+            assert_eq!(a.center_point, b.center_point);
+            assert_eq!(a.boundary, b.boundary);
+            assert_eq!(a.neighbors, b.neighbors);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! The tile adjacency graph in plain, dependency-free forms.
+//!
+//! With the `petgraph` feature enabled, `Hexasphere::to_graph` additionally
+//! builds a [petgraph](https://docs.rs/petgraph) graph for its algorithms.
+
+use crate::hexasphere::core::Hexasphere;
+use std::fmt::Write as _;
+
+impl Hexasphere {
+    /// Returns the neighbors of every tile as an adjacency list.
+    ///
+    /// `adjacency_list()[i]` holds the ids of tile `i`'s neighbors in the same
+    /// counter-clockwise order as [`crate::Tile::neighbors`], which makes the
+    /// tile graph easy to hand to other graph code without borrowing tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let adjacency = hexasphere.adjacency_list();
+    ///
+    /// assert_eq!(adjacency.len(), hexasphere.tiles.len());
+    /// assert!(adjacency.iter().all(|neighbors| neighbors.len() >= 5));
+    /// ```
+    pub fn adjacency_list(&self) -> Vec<Vec<usize>> {
+        self.tiles
+            .iter()
+            .map(|tile| tile.neighbors.clone())
+            .collect()
+    }
+
+    /// Writes the tile adjacency graph in Graphviz DOT format.
+    ///
+    /// Every tile is a node named by its id, with pentagons drawn as
+    /// pentagons, and every pair of neighbors is joined by one undirected
+    /// edge. Meant for debugging small spheres; the layout of thousands of
+    /// nodes is unreadable.
+    ///
+    /// # Returns
+    ///
+    /// A `graph hexasphere { ... }` document, one statement per line
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # fn save_dot_example() -> std::io::Result<()> {
+    /// let hexasphere = Hexasphere::new(10.0, 1, 1.0);
+    /// std::fs::write("icosahedron.dot", hexasphere.to_dot())?;
+    /// // Render with: neato -Tsvg icosahedron.dot > icosahedron.svg
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph hexasphere {\n");
+
+        for tile in &self.tiles {
+            let shape = if tile.is_pentagon() {
+                "pentagon"
+            } else {
+                "hexagon"
+            };
+            writeln!(dot, "  {} [shape={}];", tile.id, shape).unwrap();
+        }
+        for tile in &self.tiles {
+            for &neighbor in tile.neighbors.iter().filter(|&&n| n > tile.id) {
+                writeln!(dot, "  {} -- {};", tile.id, neighbor).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_adjacency_list_is_symmetric() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let adjacency = hexasphere.adjacency_list();
+
+        for (id, neighbors) in adjacency.iter().enumerate() {
+            assert_eq!(neighbors, &hexasphere.tiles[id].neighbors);
+            assert!(neighbors.iter().all(|&n| adjacency[n].contains(&id)));
+        }
+    }
+
+    #[test]
+    fn test_to_dot_level_one() {
+        let hexasphere = Hexasphere::new(10.0, 1, 1.0);
+        let dot = hexasphere.to_dot();
+        let lines: Vec<&str> = dot.lines().collect();
+
+        assert_eq!(lines.first(), Some(&"graph hexasphere {"));
+        assert_eq!(lines.last(), Some(&"}"));
+
+        let nodes: Vec<&str> = lines
+            .iter()
+            .filter(|l| l.ends_with("[shape=pentagon];"))
+            .copied()
+            .collect();
+        assert_eq!(nodes.len(), 12);
+
+        let mut edges: Vec<(usize, usize)> = lines
+            .iter()
+            .filter_map(|line| line.trim().strip_suffix(';')?.split_once(" -- "))
+            .map(|(a, b)| (a.parse().unwrap(), b.parse().unwrap()))
+            .collect();
+        assert_eq!(edges.len(), 30);
+        assert!(edges.iter().all(|&(a, b)| a < b && b < 12));
+        edges.dedup();
+        assert_eq!(edges.len(), 30);
+    }
+}
@@ -0,0 +1,621 @@
+//! Save files compatible with the JavaScript hexasphere.js library.
+//!
+//! hexasphere.js serializes a sphere with `Hexasphere.toJson()`:
+//!
+//! ```json
+//! {
+//!   "radius": 10,
+//!   "tiles": [
+//!     {
+//!       "centerPoint": {"x": "0.000", "y": "9.511", "z": "3.090"},
+//!       "boundary": [{"x": "0.567", "y": "9.407", "z": "3.021"}, ...]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Point coordinates are strings because the JavaScript `Point` stores
+//! `toFixed(3)` results. The export here writes the same layout, plus `lat`
+//! and `lon` numbers per tile (in degrees, from [`crate::Tile::get_lat_lon`])
+//! and a top-level `subdivisions` number when
+//! [`Hexasphere::subdivisions`](crate::Hexasphere::subdivisions) is known
+//! and a `center` point when [`Hexasphere::center`](crate::Hexasphere::center)
+//! is not the origin, which hexasphere.js front-ends can read and otherwise
+//! ignore.
+
+use crate::geometry::Point;
+use crate::hexasphere::builder::DEFAULT_VERTEX_PRECISION;
+use crate::hexasphere::core::Hexasphere;
+use crate::tile::core::Tile;
+use crate::{Error, Result};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+impl Hexasphere {
+    /// Exports the hexasphere in the JSON layout of hexasphere.js.
+    ///
+    /// See the [module documentation](self) for the schema. Coordinates are
+    /// written as 3-decimal strings exactly like the JavaScript library, so
+    /// existing save files and front-end code keep working.
+    ///
+    /// # Returns
+    ///
+    /// A compact JSON string with `radius` and a `tiles` array
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let json = hexasphere.to_hexasphere_js_json();
+    ///
+    /// assert!(json.starts_with("{\"radius\":10,\"tiles\":[{\"centerPoint\":{\"x\":\""));
+    /// ```
+    pub fn to_hexasphere_js_json(&self) -> String {
+        let mut json = String::new();
+        write!(json, "{{\"radius\":{},\"tiles\":[", self.radius).unwrap();
+
+        for (i, tile) in self.tiles.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"centerPoint\":");
+            write_point(&mut json, &tile.center_point);
+            json.push_str(",\"boundary\":[");
+            for (j, point) in tile.boundary.iter().enumerate() {
+                if j > 0 {
+                    json.push(',');
+                }
+                write_point(&mut json, point);
+            }
+            let lat_lon = tile.get_lat_lon(self.radius);
+            write!(
+                json,
+                "],\"lat\":{:.3},\"lon\":{:.3}}}",
+                lat_lon.lat, lat_lon.lon
+            )
+            .unwrap();
+        }
+
+        json.push(']');
+        if let Some(subdivisions) = self.subdivisions() {
+            write!(json, ",\"subdivisions\":{}", subdivisions).unwrap();
+        }
+        if *self.center() != Point::ORIGIN {
+            json.push_str(",\"center\":");
+            write_point(&mut json, self.center());
+        }
+        json.push('}');
+        json
+    }
+
+    /// Imports a sphere saved by hexasphere.js or [`Hexasphere::to_hexasphere_js_json`].
+    ///
+    /// The JavaScript format stores no adjacency, so neighbors are rebuilt from
+    /// boundary edges shared by two tiles, in the usual counter-clockwise order.
+    /// Coordinates may be strings or plain numbers; unknown fields are ignored.
+    /// An optional `subdivisions` number restores [`Hexasphere::subdivisions`]
+    /// and an optional `center` point [`Hexasphere::center`].
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The saved JSON text
+    ///
+    /// # Returns
+    ///
+    /// The imported hexasphere, or [`Error::InvalidJson`] if the text is not
+    /// valid JSON or lacks `radius`, `tiles`, `centerPoint` or `boundary`
+    ///
+    /// # Shrunken Tiles
+    ///
+    /// Files written with a hexSize below 1 have gaps between tiles, so no
+    /// edges are shared. Tiles whose edges do not all match a neighbor are
+    /// imported with an empty neighbor list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let original = Hexasphere::new(10.0, 3, 1.0);
+    /// let json = original.to_hexasphere_js_json();
+    ///
+    /// let imported = Hexasphere::from_hexasphere_js_json(&json).unwrap();
+    /// assert_eq!(imported.tiles.len(), original.tiles.len());
+    /// assert_eq!(imported.tiles[5].neighbors, original.tiles[5].neighbors);
+    /// ```
+    pub fn from_hexasphere_js_json(json: &str) -> Result<Hexasphere> {
+        let root = Parser::parse(json)?;
+        let radius = root.field("radius")?.number()?;
+        let subdivisions = match root.field("subdivisions") {
+            Ok(value) => {
+                let number = value.number()?;
+                if number < 0.0 || number.fract() != 0.0 {
+                    return Err(Error::InvalidJson(format!(
+                        "expected a whole number of subdivisions, got {number}"
+                    )));
+                }
+                Some(number as usize)
+            }
+            Err(_) => None,
+        };
+        let sphere_center = match root.field("center") {
+            Ok(value) => value.point()?,
+            Err(_) => Point::ORIGIN,
+        };
+        let tiles = root
+            .field("tiles")?
+            .array()?
+            .iter()
+            .enumerate()
+            .map(|(id, tile)| {
+                let boundary = tile
+                    .field("boundary")?
+                    .array()?
+                    .iter()
+                    .map(Value::point)
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Tile {
+                    id,
+                    center_point: tile.field("centerPoint")?.point()?,
+                    boundary,
+                    neighbor_ids: Vec::new(),
+                    neighbors: Vec::new(),
+                    base_face: 0,
+                    sphere_center: sphere_center.clone(),
+                    sphere_radius: radius,
+                    cache: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut hexasphere =
+            Hexasphere::from_tiles(radius, tiles, subdivisions, DEFAULT_VERTEX_PRECISION);
+        hexasphere.link_shared_edges();
+        hexasphere.refresh_neighbor_ids();
+        Ok(hexasphere)
+    }
+
+    /// Sets `neighbors` from boundary edges shared between tiles.
+    fn link_shared_edges(&mut self) {
+        let mut edges: HashMap<(&Point, &Point), usize> = HashMap::new();
+        for tile in &self.tiles {
+            let n = tile.boundary.len();
+            for i in 0..n {
+                edges.insert((&tile.boundary[i], &tile.boundary[(i + 1) % n]), tile.id);
+            }
+        }
+
+        let neighbors: Vec<Vec<usize>> = self
+            .tiles
+            .iter()
+            .map(|tile| {
+                let n = tile.boundary.len();
+                // The neighbor walks the shared edge in the opposite direction
+                (0..n)
+                    .map(|i| edges.get(&(&tile.boundary[(i + 1) % n], &tile.boundary[i])))
+                    .collect::<Option<Vec<_>>>()
+                    .map(|ids| ids.into_iter().copied().collect())
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        for (tile, neighbors) in self.tiles.iter_mut().zip(neighbors) {
+            tile.neighbors = neighbors;
+        }
+    }
+}
+
+/// Appends a point as hexasphere.js writes it, with coordinates as strings.
+fn write_point(json: &mut String, point: &Point) {
+    write!(
+        json,
+        "{{\"x\":\"{:.3}\",\"y\":\"{:.3}\",\"z\":\"{:.3}\"}}",
+        point.x, point.y, point.z
+    )
+    .unwrap();
+}
+
+/// A parsed JSON value; just enough of JSON to read save files.
+#[derive(Debug, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn field(&self, name: &str) -> Result<&Value> {
+        match self {
+            Value::Object(fields) => fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+                .ok_or_else(|| Error::InvalidJson(format!("missing field `{name}`"))),
+            _ => Err(Error::InvalidJson(format!(
+                "expected an object with field `{name}`"
+            ))),
+        }
+    }
+
+    fn array(&self) -> Result<&[Value]> {
+        match self {
+            Value::Array(values) => Ok(values),
+            _ => Err(Error::InvalidJson("expected an array".to_string())),
+        }
+    }
+
+    /// A number, also accepting the numeric strings hexasphere.js writes.
+    fn number(&self) -> Result<f64> {
+        let number = match self {
+            Value::Number(number) => Some(*number),
+            Value::String(text) => text.trim().parse().ok(),
+            _ => None,
+        };
+        number
+            .filter(|number| number.is_finite())
+            .ok_or_else(|| Error::InvalidJson(format!("expected a number, got {self:?}")))
+    }
+
+    fn point(&self) -> Result<Point> {
+        Ok(Point::new(
+            self.field("x")?.number()?,
+            self.field("y")?.number()?,
+            self.field("z")?.number()?,
+        ))
+    }
+}
+
+/// Deepest nesting of arrays and objects the parser accepts. hexasphere.js
+/// output nests 5 levels; the limit keeps hostile input from overflowing the
+/// stack.
+const MAX_DEPTH: usize = 64;
+
+/// Recursive-descent JSON parser over the input bytes.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    /// Arrays and objects currently open around `position`
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses a complete JSON document.
+    fn parse(json: &'a str) -> Result<Value> {
+        let mut parser = Parser {
+            bytes: json.as_bytes(),
+            position: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position < parser.bytes.len() {
+            return Err(parser.error("trailing characters"));
+        }
+        Ok(value)
+    }
+
+    fn error(&self, reason: &str) -> Error {
+        Error::InvalidJson(format!("{reason} at byte {}", self.position))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.position) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<()> {
+        if self.peek() != Some(byte) {
+            return Err(self.error(&format!("expected '{}'", byte as char)));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn literal(&mut self, text: &str, value: Value) -> Result<Value> {
+        if !self.bytes[self.position..].starts_with(text.as_bytes()) {
+            return Err(self.error("invalid literal"));
+        }
+        self.position += text.len();
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        match self.peek() {
+            Some(b'{') => self.nested(Self::object),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    /// Runs `parse` one nesting level deeper, failing beyond [`MAX_DEPTH`].
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value>) -> Result<Value> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Value> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected a field name"));
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.push((key, self.value()?));
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Value::Object(fields));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Value::Array(values));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect(b'"')?;
+        let mut text = String::new();
+        loop {
+            let start = self.position;
+            while let Some(&byte) = self.bytes.get(self.position) {
+                if byte == b'"' || byte == b'\\' {
+                    break;
+                }
+                self.position += 1;
+            }
+            // Splitting only at ASCII bytes keeps the UTF-8 input valid
+            text.push_str(std::str::from_utf8(&self.bytes[start..self.position]).unwrap());
+
+            match self.bytes.get(self.position) {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    let escape = self.bytes.get(self.position + 1).copied();
+                    self.position += 2;
+                    let character = match escape {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => self.unicode_escape()?,
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    text.push(character);
+                }
+                _ => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    /// Decodes the four hex digits after `\u`; lone surrogates become U+FFFD.
+    fn unicode_escape(&mut self) -> Result<char> {
+        let digits = self
+            .bytes
+            .get(self.position..self.position + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.position += 4;
+        Ok(char::from_u32(digits).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn number(&mut self) -> Result<Value> {
+        let start = self.position;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') =
+            self.bytes.get(self.position)
+        {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .map(Value::Number)
+            .ok_or_else(|| self.error("invalid number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parser, Value, MAX_DEPTH};
+    use crate::geometry::Point;
+    use crate::hexasphere::builder::HexasphereBuilder;
+    use crate::hexasphere::core::Hexasphere;
+    use crate::Error;
+
+    /// Two hand-written tiles in the layout of hexasphere.js `toJson()`.
+    const SAMPLE: &str = r#"{"radius":10,"tiles":[
+        {"centerPoint":{"x":"0.000","y":"10.000","z":"0.000"},
+         "boundary":[{"x":"1.000","y":"9.950","z":"0.000"},{"x":"0.000","y":"9.950","z":"1.000"},
+                     {"x":"-1.000","y":"9.950","z":"0.000"}]},
+        {"centerPoint":{"x":"0.000","y":"-10.000","z":"0.000"},"boundary":[]}
+    ]}"#;
+
+    #[test]
+    fn test_round_trip_preserves_tiles_and_adjacency() {
+        let original = Hexasphere::new(10.0, 4, 1.0);
+        let json = original.to_hexasphere_js_json();
+        let imported = Hexasphere::from_hexasphere_js_json(&json).unwrap();
+
+        assert_eq!(imported.radius, original.radius);
+        assert_eq!(imported.tiles.len(), original.tiles.len());
+        assert_eq!(imported.pentagon_count(), 12);
+        for (a, b) in imported.tiles.iter().zip(&original.tiles) {
+            // Centers are stored unrounded but saved with 3 decimals
+            assert!(a.center_point.distance_to(&b.center_point) < 1e-3);
+            assert_eq!(a.boundary, b.boundary);
+            assert_eq!(a.neighbors, b.neighbors);
+            assert_eq!(a.neighbor_ids.len(), a.neighbors.len());
+        }
+        assert!(imported.validate().is_ok());
+        assert_eq!(imported.subdivisions(), Some(4));
+    }
+
+    #[test]
+    fn test_round_trip_preserves_center() {
+        let center = Point::new(100.0, -20.0, 5.0);
+        let original = HexasphereBuilder::new()
+            .radius(10.0)
+            .subdivisions(2)
+            .center(center.clone())
+            .build()
+            .unwrap();
+        let imported =
+            Hexasphere::from_hexasphere_js_json(&original.to_hexasphere_js_json()).unwrap();
+
+        assert_eq!(imported.center(), &center);
+        for (a, b) in imported.tiles.iter().zip(&original.tiles) {
+            assert_eq!(a.sphere_center, center);
+            assert!(a.center_point.distance_to(&b.center_point) < 1e-3);
+            assert_eq!(a.neighbors, b.neighbors);
+        }
+        assert!(imported.validate().is_ok());
+
+        // Spheres at the origin keep the plain hexasphere.js layout
+        let plain = Hexasphere::new(10.0, 2, 1.0).to_hexasphere_js_json();
+        assert!(!plain.contains("\"center\""));
+    }
+
+    #[test]
+    fn test_export_matches_hexasphere_js_layout() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let root = Parser::parse(&hexasphere.to_hexasphere_js_json()).unwrap();
+
+        assert_eq!(root.field("radius").unwrap(), &Value::Number(10.0));
+        let tiles = root.field("tiles").unwrap().array().unwrap();
+        assert_eq!(tiles.len(), hexasphere.tiles.len());
+
+        for (value, tile) in tiles.iter().zip(&hexasphere.tiles) {
+            let center = value.field("centerPoint").unwrap();
+            for (axis, expected) in [("x", tile.center_point.x), ("y", tile.center_point.y)] {
+                let Value::String(text) = center.field(axis).unwrap() else {
+                    panic!("coordinates are strings in hexasphere.js");
+                };
+                assert_eq!(text, &format!("{expected:.3}"));
+                assert_eq!(text.split('.').nth(1).unwrap().len(), 3);
+            }
+            let boundary = value.field("boundary").unwrap().array().unwrap();
+            assert_eq!(boundary.len(), tile.boundary.len());
+
+            let lat = value.field("lat").unwrap().number().unwrap();
+            assert!((lat - tile.get_lat_lon(10.0).lat).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_import_hexasphere_js_sample() {
+        let imported = Hexasphere::from_hexasphere_js_json(SAMPLE).unwrap();
+
+        assert_eq!(imported.radius, 10.0);
+        assert_eq!(imported.tiles.len(), 2);
+        assert_eq!(imported.tiles[0].center_point.y, 10.0);
+        assert_eq!(imported.tiles[0].boundary[1].z, 1.0);
+        assert_eq!(imported.tiles[1].id, 1);
+        // No shared edges in the sample, so no adjacency
+        assert!(imported.tiles.iter().all(|tile| tile.neighbors.is_empty()));
+        assert_eq!(imported.subdivisions(), None);
+    }
+
+    #[test]
+    fn test_import_rejects_malformed_json() {
+        let cases = [
+            "",
+            "{\"radius\":10",
+            "{\"radius\":10,\"tiles\":[]} extra",
+            "{\"tiles\":[]}",
+            "{\"radius\":\"ten\",\"tiles\":[]}",
+            "{\"radius\":10,\"tiles\":[{\"boundary\":[]}]}",
+            "{\"radius\":10,\"tiles\":[{\"centerPoint\":{\"x\":1,\"y\":2},\"boundary\":[]}]}",
+            "{\"radius\":10,\"tiles\":[],\"subdivisions\":-2}",
+            "{\"radius\":10,\"tiles\":[],\"subdivisions\":2.5}",
+        ];
+        for json in cases {
+            let result = Hexasphere::from_hexasphere_js_json(json);
+            assert!(matches!(result, Err(Error::InvalidJson(_))), "{json:?}");
+        }
+    }
+
+    #[test]
+    fn test_parser_limits_nesting_depth() {
+        let deepest = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+        assert!(Parser::parse(&deepest).is_ok());
+
+        let too_deep = format!("{}{}", "[".repeat(MAX_DEPTH + 1), "]".repeat(MAX_DEPTH + 1));
+        assert!(matches!(
+            Parser::parse(&too_deep),
+            Err(Error::InvalidJson(_))
+        ));
+
+        // Unbalanced hostile input fails fast instead of overflowing the stack
+        let hostile = "[".repeat(100_000);
+        let result = Hexasphere::from_hexasphere_js_json(&hostile);
+        assert!(matches!(result, Err(Error::InvalidJson(_))));
+    }
+
+    #[test]
+    fn test_parser_handles_escapes_and_literals() {
+        let value = Parser::parse(r#" {"a\"é": [true, false, null, -1.5e2, "\n"]} "#).unwrap();
+        let items = value.field("a\"é").unwrap().array().unwrap();
+        assert_eq!(
+            items,
+            &[
+                Value::Bool(true),
+                Value::Bool(false),
+                Value::Null,
+                Value::Number(-150.0),
+                Value::String("\n".to_string()),
+            ]
+        );
+    }
+}
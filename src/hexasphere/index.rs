@@ -0,0 +1,253 @@
+//! Spatial index for fast repeated tile lookups.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+use crate::utils::LatLon;
+
+/// A kd-tree over tile center directions for fast nearest-tile queries.
+///
+/// [`Hexasphere::tile_containing`] scans every tile, which is fine for the
+/// occasional click but too slow for thousands of lookups per frame. Build a
+/// `TileIndex` once and query it instead; it borrows the hexasphere, so the
+/// tiles cannot change while the index is alive.
+///
+/// # Performance
+///
+/// - Build: O(n log n) time, O(n) space
+/// - Query: O(log n) on average
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Hexasphere, TileIndex, Vector3};
+/// let hexasphere = Hexasphere::new(10.0, 16, 1.0);
+/// let index = TileIndex::new(&hexasphere);
+///
+/// let tile = &hexasphere.tiles[123];
+/// assert_eq!(index.nearest(&Vector3::from(&tile.center_point)), 123);
+/// assert_eq!(index.tile_containing(&tile.center_point), Some(123));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TileIndex<'a> {
+    hexasphere: &'a Hexasphere,
+    /// Unit center direction of every tile, by tile id
+    directions: Vec<[f64; 3]>,
+    /// Tile ids laid out as an implicit kd-tree: the median of every range is
+    /// its node, split on axis `depth % 3`
+    nodes: Vec<usize>,
+}
+
+impl<'a> TileIndex<'a> {
+    /// Builds the index for all tiles of `hexasphere`.
+    pub fn new(hexasphere: &'a Hexasphere) -> Self {
+        let directions: Vec<[f64; 3]> = hexasphere
+            .tiles
+            .iter()
+            .map(|tile| {
+                let direction = hexasphere.relative(&tile.center_point).normalize();
+                [direction.x, direction.y, direction.z]
+            })
+            .collect();
+
+        let mut nodes: Vec<usize> = (0..directions.len()).collect();
+        build(&mut nodes, &directions, 0);
+
+        Self {
+            hexasphere,
+            directions,
+            nodes,
+        }
+    }
+
+    /// Returns the hexasphere this index was built for.
+    pub fn hexasphere(&self) -> &'a Hexasphere {
+        self.hexasphere
+    }
+
+    /// Finds the tile whose center direction is closest to `direction`.
+    ///
+    /// Gives exactly the same answer as comparing the angle to every tile
+    /// center. The nearest center usually, but not always, belongs to the tile
+    /// containing the direction; see [`TileIndex::tile_containing`].
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - Direction from the sphere's center; need not be normalized
+    ///
+    /// # Returns
+    ///
+    /// The id of the nearest tile, or an arbitrary tile for a zero vector
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hexasphere has no tiles.
+    pub fn nearest(&self, direction: &Vector3) -> usize {
+        assert!(!self.nodes.is_empty(), "cannot query an empty TileIndex");
+
+        let direction = direction.normalize();
+        let query = [direction.x, direction.y, direction.z];
+        let mut best = (f64::NEG_INFINITY, self.nodes[0]);
+        self.search(&self.nodes, 0, &query, &mut best);
+        best.1
+    }
+
+    /// Finds the tile whose boundary contains the direction of a point.
+    ///
+    /// Equivalent to [`Hexasphere::tile_containing`], using the index to find
+    /// the nearest center.
+    ///
+    /// # Returns
+    ///
+    /// The id of the containing tile, or `None` for points in a gap or at the sphere center
+    pub fn tile_containing(&self, point: &Point) -> Option<usize> {
+        if self.nodes.is_empty() || point == self.hexasphere.center() {
+            return None;
+        }
+        let nearest = self.nearest(&self.hexasphere.relative(point));
+        self.hexasphere.tile_containing_near(nearest, point)
+    }
+
+    /// Finds the tile containing a geographic coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, LatLon, TileIndex};
+    /// let earth = Hexasphere::new(6371.0, 20, 1.0);
+    /// let index = TileIndex::new(&earth);
+    ///
+    /// let paris = index.tile_at_lat_lon(&LatLon::new(48.8566, 2.3522));
+    /// assert!(paris.is_some());
+    /// ```
+    pub fn tile_at_lat_lon(&self, lat_lon: &LatLon) -> Option<usize> {
+        let offset = Vector3::from(&lat_lon.to_point(self.hexasphere.radius));
+        self.tile_containing(&self.hexasphere.placed(&offset))
+    }
+
+    /// Branch-and-bound nearest search over the subtree stored in `nodes`.
+    ///
+    /// `best` holds the largest dot product found so far and its tile id.
+    fn search(&self, nodes: &[usize], depth: usize, query: &[f64; 3], best: &mut (f64, usize)) {
+        if nodes.is_empty() {
+            return;
+        }
+        let middle = nodes.len() / 2;
+        let id = nodes[middle];
+        let point = &self.directions[id];
+
+        let dot = point[0] * query[0] + point[1] * query[1] + point[2] * query[2];
+        if dot > best.0 {
+            *best = (dot, id);
+        }
+
+        let axis = depth % 3;
+        let offset = query[axis] - point[axis];
+        let (near, far) = if offset < 0.0 {
+            (&nodes[..middle], &nodes[middle + 1..])
+        } else {
+            (&nodes[middle + 1..], &nodes[..middle])
+        };
+
+        self.search(near, depth + 1, query, best);
+        // For unit vectors, |q − p|² = 2 − 2·(q·p); skip the far side if the
+        // splitting plane is further away than the best match
+        if offset * offset <= 2.0 - 2.0 * best.0 + SEARCH_SLACK {
+            self.search(far, depth + 1, query, best);
+        }
+    }
+}
+
+/// Extra squared distance searched past the bound, absorbing rounding error.
+const SEARCH_SLACK: f64 = 1e-12;
+
+/// Arranges `nodes` into an implicit kd-tree split on axis `depth % 3`.
+fn build(nodes: &mut [usize], directions: &[[f64; 3]], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+    let axis = depth % 3;
+    let middle = nodes.len() / 2;
+    nodes.select_nth_unstable_by(middle, |&a, &b| {
+        directions[a][axis].total_cmp(&directions[b][axis])
+    });
+
+    let (left, right) = nodes.split_at_mut(middle);
+    build(left, directions, depth + 1);
+    build(&mut right[1..], directions, depth + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TileIndex;
+    use crate::geometry::{Point, Vector3};
+    use crate::hexasphere::core::Hexasphere;
+
+    /// Deterministic pseudo-random unit vectors (xorshift64*)
+    fn random_directions(count: usize) -> Vec<Vector3> {
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = || {
+            state ^= state >> 12;
+            state ^= state << 25;
+            state ^= state >> 27;
+            (state.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        (0..count)
+            .map(|_| {
+                let z: f64 = 2.0 * next() - 1.0;
+                let angle = std::f64::consts::TAU * next();
+                let r = (1.0 - z * z).sqrt();
+                Vector3::new(r * angle.cos(), r * angle.sin(), z)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_index_matches_brute_force() {
+        for hexasphere in [
+            Hexasphere::new(10.0, 12, 1.0),
+            Hexasphere::new_goldberg(3.0, 4, 3, 0.7),
+        ] {
+            let index = TileIndex::new(&hexasphere);
+            let centers: Vec<Vector3> = hexasphere
+                .tiles
+                .iter()
+                .map(|tile| Vector3::from(&tile.center_point).normalize())
+                .collect();
+
+            for direction in random_directions(10_000) {
+                let brute = (0..centers.len())
+                    .max_by(|&a, &b| {
+                        centers[a]
+                            .dot(&direction)
+                            .total_cmp(&centers[b].dot(&direction))
+                    })
+                    .unwrap();
+                assert_eq!(index.nearest(&direction), brute);
+
+                let point = Point {
+                    x: direction.x * 5.0,
+                    y: direction.y * 5.0,
+                    z: direction.z * 5.0,
+                };
+                assert_eq!(
+                    index.tile_containing(&point),
+                    hexasphere.tile_containing(&point)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_index_finds_every_center() {
+        let hexasphere = Hexasphere::new(10.0, 7, 1.0);
+        let index = TileIndex::new(&hexasphere);
+
+        for tile in &hexasphere.tiles {
+            assert_eq!(index.nearest(&Vector3::from(&tile.center_point)), tile.id);
+            let lat_lon = tile.get_lat_lon(10.0);
+            assert_eq!(index.tile_at_lat_lon(&lat_lon), Some(tile.id));
+        }
+        assert_eq!(index.tile_containing(&Point::new(0.0, 0.0, 0.0)), None);
+    }
+}
@@ -0,0 +1,226 @@
+//! Per-tile transforms for drawing one shared tile mesh many times.
+//!
+//! Instanced rendering uploads a single regular hexagon (and pentagon) mesh
+//! once and places a copy on every tile through a per-instance transform.
+//! [`Hexasphere::instancing_data`] produces those transforms in the layout
+//! GPU buffers expect.
+
+use crate::hexasphere::core::Hexasphere;
+use crate::hexasphere::statistics::UniformRadiusPolicy;
+
+/// How [`Hexasphere::instancing_data`] sizes each instance.
+///
+/// A [`UniformRadiusPolicy`] converts into `InstanceRadius::Uniform`, so it
+/// can be passed directly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Hexasphere, InstanceRadius, UniformRadiusPolicy};
+/// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+/// let uniform = hexasphere.instancing_data(UniformRadiusPolicy::NoOverlap);
+/// let fitted = hexasphere.instancing_data(InstanceRadius::PerTile);
+/// assert_eq!(uniform.radii.len(), fitted.radii.len());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceRadius {
+    /// One radius for all hexagons, picked by
+    /// [`Hexasphere::uniform_hexagon_radius_for`], and one for all pentagons,
+    /// their mean radius
+    Uniform(UniformRadiusPolicy),
+    /// Each tile's own average radius ([`crate::Tile::get_average_radius`])
+    PerTile,
+}
+
+impl Default for InstanceRadius {
+    fn default() -> Self {
+        InstanceRadius::Uniform(UniformRadiusPolicy::default())
+    }
+}
+
+impl From<UniformRadiusPolicy> for InstanceRadius {
+    fn from(policy: UniformRadiusPolicy) -> Self {
+        InstanceRadius::Uniform(policy)
+    }
+}
+
+/// Per-instance data for instanced tile rendering, indexed by tile id.
+///
+/// Built by [`Hexasphere::instancing_data`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstancingData {
+    /// Column-major 4×4 model matrices, one per tile, mapping a unit-radius
+    /// polygon in the local XZ plane onto the tile; contiguous, so
+    /// `transforms_f32.as_ptr()` can be copied into a GPU buffer as is
+    pub transforms_f32: Vec<[f32; 16]>,
+    /// Whether each instance is a pentagon and should use the pentagon mesh
+    pub is_pentagon: Vec<bool>,
+    /// The radius baked into each transform
+    pub radii: Vec<f32>,
+}
+
+impl Hexasphere {
+    /// Builds per-instance transforms for drawing one regular polygon mesh per tile.
+    ///
+    /// Each transform is [`crate::tile::TileOrientation::to_transform_matrix_column_major_f32`]
+    /// at the tile center with the instance radius multiplied into its three
+    /// axis columns. A vertex `(x, y, z)` of a polygon with circumradius 1 in
+    /// the local XZ plane ends up at `center + radius × (x·right + y·up +
+    /// z·forward)`, so the polygon's vertex on the +X axis points at the
+    /// tile's first boundary vertex. The `up` axis is scaled as well, which
+    /// keeps prisms in proportion.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - A [`UniformRadiusPolicy`] for one hexagon size, or
+    ///   [`InstanceRadius::PerTile`] to fit each tile
+    ///
+    /// # Returns
+    ///
+    /// Transforms, pentagon flags and radii in tile id order. Tiles without a
+    /// boundary use the identity orientation.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Instanced rendering**: One draw call for all hexagons and one for
+    ///   the 12 pentagons, filtered by `is_pentagon`
+    /// - **Uniform look**: Identical hexagons everywhere with a uniform policy
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = boundary points per tile
+    /// - Space complexity: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, UniformRadiusPolicy};
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let instances = hexasphere.instancing_data(UniformRadiusPolicy::Mean);
+    ///
+    /// assert_eq!(instances.transforms_f32.len(), hexasphere.tiles.len());
+    /// assert_eq!(instances.is_pentagon.iter().filter(|&&flag| flag).count(), 12);
+    ///
+    /// // The last column is the tile center
+    /// let center = &hexasphere.tiles[0].center_point;
+    /// assert_eq!(instances.transforms_f32[0][12], center.x as f32);
+    /// ```
+    pub fn instancing_data(&self, radius: impl Into<InstanceRadius>) -> InstancingData {
+        let (hexagon_radius, pentagon_radius) = match radius.into() {
+            InstanceRadius::Uniform(policy) => {
+                let pentagon_radii: Vec<f64> = self
+                    .pentagons()
+                    .map(|(_, tile)| tile.get_average_radius())
+                    .collect();
+                let pentagon_mean = if pentagon_radii.is_empty() {
+                    0.0
+                } else {
+                    pentagon_radii.iter().sum::<f64>() / pentagon_radii.len() as f64
+                };
+                (
+                    Some(self.uniform_hexagon_radius_for(policy)),
+                    Some(pentagon_mean),
+                )
+            }
+            InstanceRadius::PerTile => (None, None),
+        };
+
+        let mut data = InstancingData {
+            transforms_f32: Vec::with_capacity(self.tiles.len()),
+            is_pentagon: Vec::with_capacity(self.tiles.len()),
+            radii: Vec::with_capacity(self.tiles.len()),
+        };
+        for tile in &self.tiles {
+            let uniform = if tile.is_pentagon() {
+                pentagon_radius
+            } else {
+                hexagon_radius
+            };
+            let radius = uniform.unwrap_or_else(|| tile.get_average_radius()) as f32;
+
+            let orientation = tile.get_orientation().unwrap_or_default();
+            let mut transform =
+                orientation.to_transform_matrix_column_major_f32(&tile.center_point);
+            for value in &mut transform[..12] {
+                *value *= radius;
+            }
+
+            data.transforms_f32.push(transform);
+            data.is_pentagon.push(tile.is_pentagon());
+            data.radii.push(radius);
+        }
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies a column-major 4×4 matrix to a point.
+    fn transform_point(matrix: &[f32; 16], point: [f32; 3]) -> [f32; 3] {
+        let [x, y, z] = point;
+        [0, 1, 2].map(|row| {
+            matrix[row] * x + matrix[4 + row] * y + matrix[8 + row] * z + matrix[12 + row]
+        })
+    }
+
+    #[test]
+    fn test_per_tile_transforms_reach_first_boundary_point() {
+        let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+        let instances = hexasphere.instancing_data(InstanceRadius::PerTile);
+
+        assert_eq!(instances.transforms_f32.len(), hexasphere.tiles.len());
+        assert_eq!(instances.is_pentagon.len(), hexasphere.tiles.len());
+        assert_eq!(instances.radii.len(), hexasphere.tiles.len());
+
+        for tile in &hexasphere.tiles {
+            let corner = transform_point(&instances.transforms_f32[tile.id], [1.0, 0.0, 0.0]);
+            let first = tile.boundary[0].to_array().map(|value| value as f32);
+            let distance = corner
+                .iter()
+                .zip(first)
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f32>()
+                .sqrt();
+
+            // Tiles are not regular, so their corners stray from the mean radius
+            let radius = instances.radii[tile.id];
+            assert!(distance < 0.2 * radius, "tile {}: {distance}", tile.id);
+            assert_eq!(instances.is_pentagon[tile.id], tile.is_pentagon());
+        }
+    }
+
+    #[test]
+    fn test_uniform_radii_per_shape() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let policy = UniformRadiusPolicy::NoOverlap;
+        let instances = hexasphere.instancing_data(policy);
+
+        let hexagon_radius = hexasphere.uniform_hexagon_radius_for(policy) as f32;
+        let pentagon_radius = hexasphere
+            .pentagons()
+            .next()
+            .unwrap()
+            .1
+            .get_average_radius() as f32;
+        for (id, &radius) in instances.radii.iter().enumerate() {
+            if instances.is_pentagon[id] {
+                assert!((radius - pentagon_radius).abs() < 1e-3);
+            } else {
+                assert_eq!(radius, hexagon_radius);
+            }
+
+            // Every axis column carries the radius
+            let columns = &instances.transforms_f32[id];
+            for axis in 0..3 {
+                let length = columns[axis * 4..axis * 4 + 3]
+                    .iter()
+                    .map(|value| value * value)
+                    .sum::<f32>()
+                    .sqrt();
+                assert!((length - radius).abs() < 1e-4 * radius.max(1.0));
+            }
+        }
+    }
+}
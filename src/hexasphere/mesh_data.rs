@@ -0,0 +1,564 @@
+//! Combined render mesh for the whole hexasphere.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+use crate::hexasphere::export::polygon_normal;
+use crate::tile::core::{equirectangular_uvs, Tile};
+use crate::utils::AxisConvention;
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::TAU;
+use std::ops::Range;
+
+/// How [`Hexasphere::to_mesh`] assigns texture coordinates.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{MeshOptions, UvMode};
+/// // Drape a world map over the whole sphere
+/// let options = MeshOptions {
+///     uv_mode: UvMode::LatLon,
+///     ..MeshOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UvMode {
+    /// Boundary point `i` of a `k`-gon maps to the `i`-th corner of a regular
+    /// `k`-gon inscribed in the unit square, ignoring the tile's real shape
+    #[default]
+    Regular,
+    /// The tile's real shape projected onto its tangent plane, see
+    /// [`crate::Tile::local_uvs`]
+    Local,
+    /// Equirectangular coordinates shared by the whole sphere, see
+    /// [`crate::Tile::latlon_uvs`]
+    LatLon,
+}
+
+/// Settings for [`Hexasphere::to_mesh`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{AxisConvention, MeshOptions, UvMode};
+/// // Smooth shading with a 10% gap around every tile, Z-up for Blender
+/// let options = MeshOptions {
+///     smooth_normals: true,
+///     boundary_scale: 0.9,
+///     uv_mode: UvMode::Local,
+///     axes: AxisConvention::ZUp,
+/// };
+/// assert_ne!(options, MeshOptions::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshOptions {
+    /// `true` uses the sphere normal at every vertex; `false` (the default)
+    /// uses each tile's flat polygon normal for a faceted look
+    pub smooth_normals: bool,
+    /// Scale applied to tile boundaries with [`crate::Tile::scaled_boundary`];
+    /// 1.0 (the default) keeps tiles touching, smaller values open gaps
+    pub boundary_scale: f64,
+    /// Texture coordinate layout, [`UvMode::Regular`] by default
+    pub uv_mode: UvMode,
+    /// Axis convention of positions and normals, [`AxisConvention::YUp`] by default
+    pub axes: AxisConvention,
+}
+
+impl Default for MeshOptions {
+    fn default() -> Self {
+        Self {
+            smooth_normals: false,
+            boundary_scale: 1.0,
+            uv_mode: UvMode::Regular,
+            axes: AxisConvention::YUp,
+        }
+    }
+}
+
+/// Interleaving-ready vertex and index buffers for a whole mesh.
+///
+/// All per-vertex arrays have the same length. Triangles are listed in
+/// `indices` three at a time, wound counter-clockwise when seen from outside
+/// the sphere.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Hexasphere, MeshOptions};
+/// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+/// let mesh = hexasphere.to_mesh(MeshOptions::default());
+///
+/// assert_eq!(mesh.positions.len(), mesh.normals.len());
+/// assert_eq!(mesh.indices.len() % 3, 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshData {
+    /// Vertex positions
+    pub positions: Vec<[f32; 3]>,
+    /// Unit vertex normals
+    pub normals: Vec<[f32; 3]>,
+    /// Texture coordinates, in the unit square unless noted by [`UvMode`]
+    pub uvs: Vec<[f32; 2]>,
+    /// Triangle list indexing the vertex arrays
+    pub indices: Vec<u32>,
+    /// `tile_ranges[i]` is the span of `indices` holding tile `i`'s triangles
+    pub tile_ranges: Vec<Range<u32>>,
+}
+
+impl MeshData {
+    /// Returns the number of triangles in the mesh.
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Appends the mesh of one more tile, shifting its indices past the
+    /// existing vertices and recording its range in `tile_ranges`.
+    pub(crate) fn append_tile(&mut self, tile: MeshData) {
+        let offset = self.positions.len() as u32;
+        let start = self.indices.len() as u32;
+
+        self.positions.extend(tile.positions);
+        self.normals.extend(tile.normals);
+        self.uvs.extend(tile.uvs);
+        self.indices
+            .extend(tile.indices.into_iter().map(|index| index + offset));
+        self.tile_ranges.push(start..self.indices.len() as u32);
+    }
+
+    /// Appends a vertex and returns its index.
+    pub(crate) fn push_vertex(&mut self, position: &Point, normal: &Vector3, uv: [f64; 2]) -> u32 {
+        let index = self.positions.len() as u32;
+        self.positions
+            .push([position.x as f32, position.y as f32, position.z as f32]);
+        self.normals
+            .push([normal.x as f32, normal.y as f32, normal.z as f32]);
+        self.uvs.push([uv[0] as f32, uv[1] as f32]);
+        index
+    }
+}
+
+impl Hexasphere {
+    /// Builds one combined triangle mesh for all tiles.
+    ///
+    /// Every tile is triangulated as a fan from its center, so a tile with
+    /// `k` boundary points contributes `k + 1` vertices and `k` triangles.
+    /// Vertices are not shared between tiles, which keeps tile borders sharp
+    /// and lets each tile be recolored on its own through
+    /// [`MeshData::tile_ranges`]. One draw call renders the whole sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Normal style and boundary scale, see [`MeshOptions`]
+    ///
+    /// # Returns
+    ///
+    /// A [`MeshData`] with `f32` vertex attributes and `u32` indices
+    ///
+    /// # Texture Coordinates
+    ///
+    /// Chosen by [`MeshOptions::uv_mode`]. In the per-tile modes the center
+    /// maps to (0.5, 0.5) so one hexagon (or pentagon) texture covers each
+    /// tile; UVs always follow the unscaled tile, so shrinking tiles with
+    /// `boundary_scale` shrinks the texture with them.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Rendering**: Upload once instead of one mesh per tile
+    /// - **Per-tile coloring**: Write vertex colors for a tile's index range
+    /// - **Picking**: Map a hit triangle back to its tile
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = boundary points per tile
+    /// - Space complexity: ~7 vertices and 18 indices per hexagon
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, MeshOptions};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let mesh = hexasphere.to_mesh(MeshOptions {
+    ///     smooth_normals: true,
+    ///     ..MeshOptions::default()
+    /// });
+    ///
+    /// // Find the tile a triangle belongs to
+    /// let triangle = 100;
+    /// let tile = mesh
+    ///     .tile_ranges
+    ///     .iter()
+    ///     .position(|range| range.contains(&(triangle as u32 * 3)))
+    ///     .unwrap();
+    /// assert!(tile < hexasphere.tiles.len());
+    /// ```
+    pub fn to_mesh(&self, options: MeshOptions) -> MeshData {
+        let mut mesh = MeshData::default();
+        for tile in &self.tiles {
+            mesh.append_tile(self.tile_mesh(tile, &options));
+        }
+        mesh
+    }
+
+    /// Fan-triangulates one tile for [`Hexasphere::to_mesh`], with indices
+    /// starting at 0 and no `tile_ranges`.
+    pub(crate) fn tile_mesh(&self, tile: &Tile, options: &MeshOptions) -> MeshData {
+        let mut mesh = MeshData::default();
+        let boundary = tile.scaled_boundary(options.boundary_scale);
+        let k = boundary.len();
+        if k < 3 {
+            return mesh;
+        }
+
+        let flat_normal = polygon_normal(&boundary);
+        let normal_at = |point: &Point| {
+            if options.smooth_normals {
+                self.relative(point).normalize()
+            } else {
+                flat_normal.clone()
+            }
+        };
+
+        // Center first, then one per boundary point
+        let uvs: Vec<(f64, f64)> = match options.uv_mode {
+            UvMode::Regular => std::iter::once((0.5, 0.5))
+                .chain((0..k).map(|i| {
+                    let angle = TAU * i as f64 / k as f64;
+                    (0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin())
+                }))
+                .collect(),
+            UvMode::Local => std::iter::once((0.5, 0.5))
+                .chain(tile.local_uvs())
+                .collect(),
+            UvMode::LatLon => {
+                let relative: Vec<Point> = std::iter::once(&tile.center_point)
+                    .chain(&tile.boundary)
+                    .map(|point| tile.relative(point))
+                    .collect();
+                equirectangular_uvs(relative.iter(), self.radius)
+            }
+        };
+
+        let axes = options.axes;
+        let center = mesh.push_vertex(
+            &axes.convert(&tile.center_point),
+            &axes.convert_vector(&normal_at(&tile.center_point)),
+            [uvs[0].0, uvs[0].1],
+        );
+        for (point, &(u, v)) in boundary.iter().zip(&uvs[1..]) {
+            mesh.push_vertex(
+                &axes.convert(point),
+                &axes.convert_vector(&normal_at(point)),
+                [u, v],
+            );
+        }
+        let (_, triangles) = tile.triangulate();
+        for triangle in triangles {
+            mesh.indices.extend(triangle.map(|index| center + index));
+        }
+        mesh
+    }
+
+    /// Builds a closed two-surface shell of the given thickness.
+    ///
+    /// Extruding every tile with [`Hexasphere::create_thick_tiles`] leaves two
+    /// coincident walls on every shared edge, which slicers reject and which
+    /// doubles the triangle count. This instead emits one outer surface, one
+    /// inner surface offset `thickness` toward the center, and walls only
+    /// along borders that no other tile covers. Vertices are shared between
+    /// tiles, so the result is watertight: every edge belongs to exactly two
+    /// triangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `thickness` - Radial distance between the outer and inner surface
+    ///
+    /// # Returns
+    ///
+    /// A [`MeshData`] whose `tile_ranges` cover each tile's outer fan, inner
+    /// fan and border walls. Normals are radial (negated on the inner
+    /// surface) and `uvs` are all zero.
+    ///
+    /// # Borders
+    ///
+    /// A full sphere built with `hex_size = 1.0` has no borders, so no walls
+    /// are emitted. With `hex_size < 1.0` every tile rim is a border and gets
+    /// its own wall, keeping the shell closed around each separated tile.
+    ///
+    /// # Use Cases
+    ///
+    /// - **3D printing**: A hollow geodesic sphere with solid walls
+    /// - **Physics**: A closed collision shell
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let shell = hexasphere.create_shell_mesh(0.5);
+    ///
+    /// // Outer and inner fans only, no interior walls
+    /// let sides: usize = hexasphere.tiles.iter().map(|t| t.boundary.len()).sum();
+    /// assert_eq!(shell.triangle_count(), 2 * sides);
+    /// ```
+    pub fn create_shell_mesh(&self, thickness: f64) -> MeshData {
+        let mut mesh = MeshData::default();
+        let mut lookup: HashMap<Point, u32> = HashMap::new();
+
+        // Each outer vertex is immediately followed by its inner twin
+        let mut vertex = |mesh: &mut MeshData, point: &Point| -> u32 {
+            *lookup.entry(point.clone()).or_insert_with(|| {
+                let offset = self.relative(point);
+                let direction = offset.normalize();
+                let length = offset.dot(&direction);
+                let inner = Point::from(Vector3::from(
+                    &self.placed(&(&direction * (length - thickness))),
+                ));
+                let inward = &direction * -1.0;
+
+                let outer = mesh.push_vertex(point, &direction, [0.0, 0.0]);
+                mesh.push_vertex(&inner, &inward, [0.0, 0.0]);
+                outer
+            })
+        };
+
+        let fans: Vec<(u32, Vec<u32>)> = self
+            .tiles
+            .iter()
+            .map(|tile| {
+                let center = vertex(&mut mesh, &tile.center_point);
+                let rim = tile
+                    .boundary
+                    .iter()
+                    .map(|point| vertex(&mut mesh, point))
+                    .collect();
+                (center, rim)
+            })
+            .collect();
+
+        let edges: HashSet<(u32, u32)> = fans
+            .iter()
+            .filter(|(_, rim)| rim.len() >= 3)
+            .flat_map(|(_, rim)| (0..rim.len()).map(|i| (rim[i], rim[(i + 1) % rim.len()])))
+            .collect();
+
+        for (center, rim) in &fans {
+            let start = mesh.indices.len() as u32;
+            let k = rim.len();
+
+            if k >= 3 {
+                for i in 0..k {
+                    let (a, b) = (rim[i], rim[(i + 1) % k]);
+                    mesh.indices.extend([*center, a, b]);
+                    mesh.indices.extend([center + 1, b + 1, a + 1]);
+                }
+
+                // Close the shell wherever no other tile shares this edge
+                for i in 0..k {
+                    let (a, b) = (rim[i], rim[(i + 1) % k]);
+                    if !edges.contains(&(b, a)) {
+                        mesh.indices.extend([a, a + 1, b]);
+                        mesh.indices.extend([b, a + 1, b + 1]);
+                    }
+                }
+            }
+
+            mesh.tile_ranges.push(start..mesh.indices.len() as u32);
+        }
+
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MeshOptions, UvMode};
+    use crate::geometry::Vector3;
+    use crate::hexasphere::core::Hexasphere;
+    use crate::utils::AxisConvention;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_to_mesh_counts_and_bounds() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let mesh = hexasphere.to_mesh(MeshOptions::default());
+
+        let boundary_points: usize = hexasphere.tiles.iter().map(|t| t.boundary.len()).sum();
+        assert_eq!(mesh.triangle_count(), boundary_points);
+        assert_eq!(
+            mesh.positions.len(),
+            boundary_points + hexasphere.tiles.len()
+        );
+        assert_eq!(mesh.normals.len(), mesh.positions.len());
+        assert_eq!(mesh.uvs.len(), mesh.positions.len());
+        assert!(mesh
+            .indices
+            .iter()
+            .all(|&i| (i as usize) < mesh.positions.len()));
+        assert!(mesh
+            .uvs
+            .iter()
+            .all(|uv| uv.iter().all(|&c| (0.0..=1.0).contains(&c))));
+    }
+
+    #[test]
+    fn test_to_mesh_tile_ranges_cover_indices() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let mesh = hexasphere.to_mesh(MeshOptions::default());
+
+        assert_eq!(mesh.tile_ranges.len(), hexasphere.tiles.len());
+        let mut expected_start = 0;
+        for (range, tile) in mesh.tile_ranges.iter().zip(&hexasphere.tiles) {
+            assert_eq!(range.start, expected_start);
+            assert_eq!(range.len(), tile.boundary.len() * 3);
+            expected_start = range.end;
+        }
+        assert_eq!(expected_start as usize, mesh.indices.len());
+    }
+
+    #[test]
+    fn test_to_mesh_winding_and_normals_point_outward() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+        for smooth_normals in [false, true] {
+            let mesh = hexasphere.to_mesh(MeshOptions {
+                smooth_normals,
+                boundary_scale: 0.8,
+                ..MeshOptions::default()
+            });
+
+            for triangle in mesh.indices.chunks(3) {
+                let [a, b, c] = [0, 1, 2].map(|k| {
+                    let p = mesh.positions[triangle[k] as usize];
+                    Vector3::new(p[0] as f64, p[1] as f64, p[2] as f64)
+                });
+                let face_normal = (b.clone() - a.clone()).cross(&(c - a.clone()));
+                assert!(face_normal.dot(&a) > 0.0, "triangle wound inward");
+
+                for &index in triangle {
+                    let n = mesh.normals[index as usize];
+                    let normal = Vector3::new(n[0] as f64, n[1] as f64, n[2] as f64);
+                    assert!((normal.dot(&normal).sqrt() - 1.0).abs() < 1e-5);
+                    assert!(normal.dot(&a) > 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_mesh_z_up_rotates_positions_and_normals() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let y_up = hexasphere.to_mesh(MeshOptions::default());
+        let z_up = hexasphere.to_mesh(MeshOptions {
+            axes: AxisConvention::ZUp,
+            ..MeshOptions::default()
+        });
+
+        assert_eq!(z_up.indices, y_up.indices);
+        for (a, b) in y_up.positions.iter().zip(&z_up.positions) {
+            assert_eq!(*b, [a[0], -a[2], a[1]]);
+        }
+        for (a, b) in y_up.normals.iter().zip(&z_up.normals) {
+            assert_eq!(*b, [a[0], -a[2], a[1]]);
+        }
+
+        // Still wound counter-clockwise seen from outside
+        for triangle in z_up.indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|k| {
+                let p = z_up.positions[triangle[k] as usize];
+                Vector3::new(p[0] as f64, p[1] as f64, p[2] as f64)
+            });
+            let face_normal = (b.clone() - a.clone()).cross(&(c - a.clone()));
+            assert!(face_normal.dot(&a) > 0.0, "triangle wound inward");
+        }
+    }
+
+    #[test]
+    fn test_to_mesh_boundary_scale() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let full = hexasphere.to_mesh(MeshOptions::default());
+        let gapped = hexasphere.to_mesh(MeshOptions {
+            boundary_scale: 0.5,
+            ..MeshOptions::default()
+        });
+
+        // Tile 0: center at index 0, first boundary point at index 1
+        let tile = &hexasphere.tiles[0];
+        let expected = tile.scaled_boundary(0.5)[0].clone();
+        assert_eq!(
+            gapped.positions[1],
+            [expected.x as f32, expected.y as f32, expected.z as f32]
+        );
+        assert_eq!(gapped.indices, full.indices);
+    }
+
+    #[test]
+    fn test_to_mesh_uv_modes() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let regular = hexasphere.to_mesh(MeshOptions::default());
+
+        for uv_mode in [UvMode::Local, UvMode::LatLon] {
+            let mesh = hexasphere.to_mesh(MeshOptions {
+                uv_mode,
+                ..MeshOptions::default()
+            });
+            assert_eq!(mesh.uvs.len(), regular.uvs.len());
+            assert_eq!(mesh.indices, regular.indices);
+            assert_ne!(mesh.uvs, regular.uvs);
+        }
+
+        // Tile 0: center at index 0, boundary after it
+        let local = hexasphere.to_mesh(MeshOptions {
+            uv_mode: UvMode::Local,
+            ..MeshOptions::default()
+        });
+        let tile = &hexasphere.tiles[0];
+        assert_eq!(local.uvs[0], [0.5, 0.5]);
+        for (uv, (u, v)) in local.uvs[1..].iter().zip(tile.local_uvs()) {
+            assert_eq!(*uv, [u as f32, v as f32]);
+        }
+    }
+
+    /// Asserts every directed edge appears once and is matched by its reverse
+    fn assert_watertight(indices: &[u32]) {
+        let mut directed = HashSet::new();
+        for triangle in indices.chunks(3) {
+            for i in 0..3 {
+                let edge = (triangle[i], triangle[(i + 1) % 3]);
+                assert!(directed.insert(edge), "edge {:?} used twice", edge);
+            }
+        }
+        for &(a, b) in &directed {
+            assert!(directed.contains(&(b, a)), "edge ({}, {}) is open", a, b);
+        }
+    }
+
+    #[test]
+    fn test_shell_mesh_is_watertight() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let shell = hexasphere.create_shell_mesh(0.5);
+
+        let sides: usize = hexasphere.tiles.iter().map(|t| t.boundary.len()).sum();
+        assert_eq!(shell.triangle_count(), 2 * sides);
+        assert_eq!(shell.tile_ranges.len(), hexasphere.tiles.len());
+        assert_watertight(&shell.indices);
+
+        // Inner twins sit one thickness below their outer vertex
+        for pair in shell.positions.chunks(2) {
+            let [outer, inner] =
+                [pair[0], pair[1]].map(|p| Vector3::new(p[0] as f64, p[1] as f64, p[2] as f64));
+            let depth = outer.dot(&outer).sqrt() - inner.dot(&inner).sqrt();
+            assert!((depth - 0.5).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_shell_mesh_walls_close_gaps() {
+        let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+        let shell = hexasphere.create_shell_mesh(0.5);
+
+        // Separated tiles each become a closed slab: two fans plus walls
+        let sides: usize = hexasphere.tiles.iter().map(|t| t.boundary.len()).sum();
+        assert_eq!(shell.triangle_count(), 4 * sides);
+        assert_watertight(&shell.indices);
+    }
+}
@@ -1,8 +1,58 @@
 //! Main hexasphere structure and construction algorithms.
 
+pub mod approximation_report;
+mod base_faces;
+pub mod builder;
 pub mod core;
+pub mod csv;
+pub mod edges;
 pub mod export;
+pub mod geographic;
+#[cfg(feature = "gltf-export")]
+pub mod gltf;
+mod goldberg;
+pub mod graph;
+pub mod hexasphere_js;
+pub mod index;
+pub mod instancing;
+pub mod integration;
+pub mod mesh_data;
+mod parallel;
+pub mod partition;
+pub mod ply;
+pub mod progress;
+pub mod raycast;
+mod refinement;
+pub mod relaxation;
+pub mod rotation;
+#[cfg(feature = "rand")]
+pub mod sampling;
+pub mod snapshot;
 pub mod statistics;
+mod streaming;
+pub mod terrain;
+pub mod traversal;
+pub mod validation;
+pub mod visibility;
 
+pub use approximation_report::{ApproximationReport, TileApproximationError};
+pub use builder::{BuildError, HexasphereBuilder, HexasphereError};
 pub use core::Hexasphere;
-pub use statistics::HexagonStats;
+pub use edges::{EdgeGap, GapStats, TileEdge};
+pub use export::ObjExport;
+pub use geographic::BboxMode;
+#[cfg(feature = "gltf-export")]
+pub use gltf::GltfMode;
+pub use index::TileIndex;
+pub use instancing::{InstanceRadius, InstancingData};
+pub use integration::TileIntegrals;
+pub use mesh_data::{MeshData, MeshOptions, UvMode};
+pub use progress::BuildPhase;
+pub use raycast::RayHit;
+pub use relaxation::RelaxationReport;
+pub use snapshot::DecodeError;
+pub use statistics::{
+    CoverageReport, DistortionStats, HexagonStats, TileStats, UniformRadiusPolicy,
+};
+pub use validation::TopologyIssue;
+pub use visibility::HorizonMode;
@@ -0,0 +1,190 @@
+//! Optional data parallelism for construction and per-tile queries.
+//!
+//! With the `rayon` feature these helpers spread work across threads when
+//! asked to; without it they always run serially. Both paths preserve input
+//! order, so construction results do not depend on the thread count.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Maps every item, in parallel if `parallel` is set, keeping the input order.
+pub(crate) fn map_vec<T, U, F>(items: Vec<T>, parallel: bool, f: F) -> Vec<U>
+where
+    T: Send,
+    U: Send,
+    F: Fn(T) -> U + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    if parallel {
+        return items.into_par_iter().map(f).collect();
+    }
+    let _ = parallel;
+    items.into_iter().map(f).collect()
+}
+
+/// Applies `f` to every item in place, in parallel if `parallel` is set.
+pub(crate) fn for_each_mut<T, F>(items: &mut [T], parallel: bool, f: F)
+where
+    T: Send,
+    F: Fn(&mut T) + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    if parallel {
+        items.par_iter_mut().for_each(f);
+        return;
+    }
+    let _ = parallel;
+    items.iter_mut().for_each(f);
+}
+
+#[cfg(feature = "rayon")]
+mod tiles {
+    use crate::approximation::RegularHexagonParams;
+    use crate::hexasphere::core::Hexasphere;
+    use crate::hexasphere::mesh_data::{MeshData, MeshOptions};
+    use crate::hexasphere::statistics::{hexagon_stats_from, HexagonMeasurement, HexagonStats};
+    use crate::tile::core::Tile;
+    use crate::tile::ThickTile;
+    use rayon::prelude::*;
+
+    impl Hexasphere {
+        /// Iterates over all tiles and their indices in parallel.
+        ///
+        /// The parallel counterpart of [`Hexasphere::iter`]. The iterator is
+        /// indexed, so `collect` into a `Vec` keeps tile order.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use geotiles::Hexasphere;
+        /// use rayon::prelude::*;
+        ///
+        /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        /// let areas: Vec<f64> = hexasphere
+        ///     .par_iter_tiles()
+        ///     .map(|(_, tile)| tile.get_area())
+        ///     .collect();
+        /// assert_eq!(areas[7], hexasphere.tiles[7].get_area());
+        /// ```
+        pub fn par_iter_tiles(&self) -> impl IndexedParallelIterator<Item = (usize, &Tile)> + '_ {
+            self.tiles.par_iter().enumerate()
+        }
+
+        /// Parallel version of [`Hexasphere::calculate_hexagon_stats`].
+        ///
+        /// Hexagons are measured in parallel and aggregated in tile order, so
+        /// the result is identical to the serial version.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use geotiles::Hexasphere;
+        /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        /// assert_eq!(hexasphere.calculate_hexagon_stats_par(), hexasphere.calculate_hexagon_stats());
+        /// ```
+        pub fn calculate_hexagon_stats_par(&self) -> HexagonStats {
+            let measurements: Vec<HexagonMeasurement> = self
+                .par_iter_tiles()
+                .filter(|(_, tile)| tile.is_hexagon())
+                .map(|(_, hex)| HexagonMeasurement::of(hex))
+                .collect();
+            hexagon_stats_from(&measurements, self.pentagons().count())
+        }
+
+        /// Parallel version of [`Hexasphere::get_regular_hexagon_approximations`],
+        /// returning the same hexagons in the same order.
+        pub fn get_regular_hexagon_approximations_par(&self) -> Vec<RegularHexagonParams> {
+            self.tiles
+                .par_iter()
+                .filter_map(|tile| tile.get_regular_hexagon_params())
+                .collect()
+        }
+
+        /// Parallel version of [`Hexasphere::create_thick_tiles`], returning one
+        /// thick tile per tile in tile order.
+        pub fn create_thick_tiles_par(&self, thickness: f64) -> Vec<ThickTile> {
+            self.tiles
+                .par_iter()
+                .map(|tile| ThickTile::from_surface_tile(tile, thickness))
+                .collect()
+        }
+
+        /// Parallel version of [`Hexasphere::to_mesh`].
+        ///
+        /// Tiles are triangulated in parallel and concatenated in tile order,
+        /// so the buffers are identical to the serial version.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use geotiles::{Hexasphere, MeshOptions};
+        /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        /// let mesh = hexasphere.to_mesh_par(MeshOptions::default());
+        /// assert_eq!(mesh, hexasphere.to_mesh(MeshOptions::default()));
+        /// ```
+        pub fn to_mesh_par(&self, options: MeshOptions) -> MeshData {
+            let parts: Vec<MeshData> = self
+                .tiles
+                .par_iter()
+                .map(|tile| self.tile_mesh(tile, &options))
+                .collect();
+
+            let mut mesh = MeshData::default();
+            for part in parts {
+                mesh.append_tile(part);
+            }
+            mesh
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::hexasphere::core::Hexasphere;
+        use crate::hexasphere::mesh_data::{MeshOptions, UvMode};
+        use rayon::prelude::*;
+
+        #[test]
+        fn test_parallel_matches_serial() {
+            let hexasphere = Hexasphere::new(10.0, 4, 0.9);
+
+            assert_eq!(
+                hexasphere.calculate_hexagon_stats_par(),
+                hexasphere.calculate_hexagon_stats()
+            );
+
+            let serial = hexasphere.get_regular_hexagon_approximations();
+            let parallel = hexasphere.get_regular_hexagon_approximations_par();
+            assert_eq!(serial.len(), parallel.len());
+            for (a, b) in serial.iter().zip(&parallel) {
+                assert_eq!((&a.center, a.radius), (&b.center, b.radius));
+                assert_eq!(a.orientation.right, b.orientation.right);
+            }
+
+            let serial = hexasphere.create_thick_tiles(0.5);
+            let parallel = hexasphere.create_thick_tiles_par(0.5);
+            assert_eq!(serial.len(), parallel.len());
+            for (a, b) in serial.iter().zip(&parallel) {
+                assert_eq!(a.outer_boundary, b.outer_boundary);
+                assert_eq!(a.inner_boundary, b.inner_boundary);
+            }
+
+            for uv_mode in [UvMode::Regular, UvMode::Local, UvMode::LatLon] {
+                let options = MeshOptions {
+                    smooth_normals: true,
+                    uv_mode,
+                    ..MeshOptions::default()
+                };
+                assert_eq!(hexasphere.to_mesh_par(options), hexasphere.to_mesh(options));
+            }
+
+            let indices: Vec<usize> = hexasphere
+                .par_iter_tiles()
+                .map(|(index, tile)| {
+                    assert_eq!(index, tile.id);
+                    index
+                })
+                .collect();
+            assert_eq!(indices, (0..hexasphere.tiles.len()).collect::<Vec<_>>());
+        }
+    }
+}
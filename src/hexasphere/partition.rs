@@ -0,0 +1,205 @@
+//! Splitting the sphere into contiguous regions.
+
+use crate::hexasphere::core::Hexasphere;
+use crate::tile::core::Tile;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+impl Hexasphere {
+    /// Splits all tiles into `n` connected regions of similar tile count.
+    ///
+    /// Shorthand for [`Hexasphere::partition_weighted`] with every tile
+    /// weighing 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+    /// let plates = hexasphere.partition(8, 42);
+    ///
+    /// assert_eq!(plates.len(), hexasphere.tiles.len());
+    /// assert!(plates.iter().all(|&plate| plate < 8));
+    /// assert_eq!(plates, hexasphere.partition(8, 42));
+    /// ```
+    pub fn partition(&self, n: usize, seed: u64) -> Vec<usize> {
+        self.partition_weighted(n, seed, |_, _| 1.0)
+    }
+
+    /// Splits all tiles into `n` connected regions of similar total weight.
+    ///
+    /// Regions grow from `n` distinct seed tiles chosen pseudo-randomly from
+    /// `seed`. Growth is a multi-source breadth-first search: at every step
+    /// the region with the smallest total weight so far claims the next
+    /// unclaimed tile on its frontier. Every region is therefore connected,
+    /// and regions stay roughly balanced unless one gets enclosed by others.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of regions; values above the tile count are clamped to it
+    /// * `seed` - Seed for choosing the starting tiles; the same seed always
+    ///   gives the same partition on every platform
+    /// * `weight` - Non-negative weight of each tile given its id and the tile,
+    ///   e.g. `|_, tile| tile.get_area()` to balance by area
+    ///
+    /// # Returns
+    ///
+    /// The region id of every tile, indexed like `tiles`, each below `n`.
+    /// Empty if there are no tiles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0 while the hexasphere has tiles.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Tectonic plates**: Split a planet into plates for terrain generation
+    /// - **Continents and territories**: Starting regions for players or nations
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n_tiles × k × log n) where k = neighbors per tile
+    /// - Space complexity: O(n_tiles)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+    /// let regions = hexasphere.partition_weighted(5, 7, |_, tile| tile.get_area());
+    ///
+    /// let mut areas = [0.0; 5];
+    /// for (tile, &region) in hexasphere.tiles.iter().zip(&regions) {
+    ///     areas[region] += tile.get_area();
+    /// }
+    /// assert!(areas.iter().all(|&area| area > 0.0));
+    /// ```
+    pub fn partition_weighted(
+        &self,
+        n: usize,
+        seed: u64,
+        weight: impl Fn(usize, &Tile) -> f64,
+    ) -> Vec<usize> {
+        let count = self.tiles.len();
+        if count == 0 {
+            return Vec::new();
+        }
+        assert!(n > 0, "cannot partition tiles into 0 regions");
+        let n = n.min(count);
+
+        // Partial Fisher-Yates shuffle picks n distinct seed tiles
+        let mut rng = SplitMix64(seed);
+        let mut order: Vec<usize> = (0..count).collect();
+        for i in 0..n {
+            let j = i + (rng.next() % (count - i) as u64) as usize;
+            order.swap(i, j);
+        }
+
+        let mut region = vec![usize::MAX; count];
+        let mut frontiers: Vec<VecDeque<usize>> = vec![VecDeque::new(); n];
+        let mut totals = vec![0.0_f64; n];
+        // Min-heap of (total weight bits, region); the bits of non-negative
+        // floats sort like the floats themselves
+        let mut queue = BinaryHeap::new();
+
+        for (id, &tile) in order[..n].iter().enumerate() {
+            region[tile] = id;
+            totals[id] = weight(tile, &self.tiles[tile]).max(0.0);
+            frontiers[id].extend(self.tiles[tile].neighbors.iter().copied());
+            queue.push(Reverse((totals[id].to_bits(), id)));
+        }
+
+        while let Some(Reverse((_, id))) = queue.pop() {
+            let Some(tile) = next_unclaimed(&mut frontiers[id], &region) else {
+                // Enclosed by other regions; it stops growing
+                continue;
+            };
+
+            region[tile] = id;
+            totals[id] += weight(tile, &self.tiles[tile]).max(0.0);
+            frontiers[id].extend(self.tiles[tile].neighbors.iter().copied());
+            queue.push(Reverse((totals[id].to_bits(), id)));
+        }
+
+        // Only tiles unreachable from every seed remain, e.g. after hand edits
+        for id in &mut region {
+            if *id == usize::MAX {
+                *id = 0;
+            }
+        }
+        region
+    }
+}
+
+/// Pops frontier entries until one is an unclaimed, valid tile.
+fn next_unclaimed(frontier: &mut VecDeque<usize>, region: &[usize]) -> Option<usize> {
+    while let Some(tile) = frontier.pop_front() {
+        if region.get(tile) == Some(&usize::MAX) {
+            return Some(tile);
+        }
+    }
+    None
+}
+
+/// Small, portable pseudo-random generator (SplitMix64) for reproducible seeds.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_partition_regions_are_connected_and_balanced() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+
+        for (n, seed) in [(4, 1), (6, 2), (6, 99), (10, 12345)] {
+            let regions = hexasphere.partition(n, seed);
+            assert_eq!(regions.len(), hexasphere.tiles.len());
+            assert!(regions.iter().all(|&region| region < n));
+
+            let mut sizes = vec![0; n];
+            for (region, size) in sizes.iter_mut().enumerate() {
+                let members: Vec<usize> = (0..regions.len())
+                    .filter(|&id| regions[id] == region)
+                    .collect();
+                *size = members.len();
+
+                let filled = hexasphere.flood_fill(members[0], |id| regions[id] == region);
+                assert_eq!(filled.len(), members.len(), "region {region} is split");
+            }
+
+            let (min, max) = (sizes.iter().min().unwrap(), sizes.iter().max().unwrap());
+            assert!(*max <= 3 * *min, "n {n} seed {seed}: sizes {sizes:?}");
+        }
+    }
+
+    #[test]
+    fn test_partition_is_deterministic() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+        let first = hexasphere.partition(7, 2024);
+
+        assert_eq!(first, hexasphere.partition(7, 2024));
+        assert_ne!(first, hexasphere.partition(7, 2025));
+    }
+
+    #[test]
+    fn test_partition_edge_cases() {
+        let hexasphere = Hexasphere::new(10.0, 1, 1.0);
+
+        assert!(hexasphere.partition(1, 0).iter().all(|&region| region == 0));
+
+        let mut all = hexasphere.partition(100, 3);
+        all.sort_unstable();
+        assert_eq!(all, (0..12).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,139 @@
+//! Progress reporting for long-running construction.
+
+use crate::hexasphere::builder::HexasphereBuilder;
+use crate::hexasphere::core::Hexasphere;
+
+/// A stage of hexasphere construction, in the order they run.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::BuildPhase;
+/// assert!(BuildPhase::Subdividing < BuildPhase::BuildingTiles);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BuildPhase {
+    /// Splitting the 20 icosahedron faces into smaller triangles
+    Subdividing,
+    /// Projecting vertices onto the sphere and merging duplicates
+    Projecting,
+    /// Building the triangle mesh and grouping triangles around each vertex
+    GroupingFaces,
+    /// Creating one tile per vertex, with its neighbors resolved
+    BuildingTiles,
+}
+
+impl Hexasphere {
+    /// Creates a new hexasphere, reporting construction progress.
+    ///
+    /// Builds exactly what [`Hexasphere::new`] builds, calling `progress`
+    /// along the way so a UI can show a progress bar during large builds.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the target sphere
+    /// * `num_divisions` - Number of subdivision levels, as for [`Hexasphere::new`]
+    /// * `hex_size` - Scale factor for tile boundaries, as for [`Hexasphere::new`]
+    /// * `progress` - Called with the current [`BuildPhase`] and how far
+    ///   through that phase construction is, from 0.0 to 1.0
+    ///
+    /// # Reporting
+    ///
+    /// Phases are reported in order. Each starts at 0.0 and ends at exactly
+    /// 1.0; in between, subdivision reports once per icosahedron face and
+    /// tile building about once per 1% of tiles. The callback only observes
+    /// the build and cannot change or abort it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{BuildPhase, Hexasphere};
+    /// let hexasphere = Hexasphere::new_with_progress(10.0, 8, 1.0, |phase, fraction| {
+    ///     if phase == BuildPhase::BuildingTiles {
+    ///         print!("\rBuilding tiles: {:3.0}%", fraction * 100.0);
+    ///     }
+    /// });
+    /// assert_eq!(hexasphere.tiles.len(), 642);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics for oversized spheres, like [`Hexasphere::new`].
+    pub fn new_with_progress(
+        radius: f64,
+        num_divisions: usize,
+        hex_size: f64,
+        mut progress: impl FnMut(BuildPhase, f32),
+    ) -> Self {
+        let options = HexasphereBuilder::new()
+            .radius(radius)
+            .subdivisions(num_divisions)
+            .hex_size(hex_size);
+        if let Err(error) = options.validate_tile_count() {
+            panic!("{}", error);
+        }
+        Self::construct_with_progress(&options, &mut progress).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BuildPhase;
+    use crate::hexasphere::core::Hexasphere;
+
+    fn record(num_divisions: usize) -> (Hexasphere, Vec<(BuildPhase, f32)>) {
+        let mut events = Vec::new();
+        let hexasphere = Hexasphere::new_with_progress(10.0, num_divisions, 0.9, |phase, f| {
+            events.push((phase, f))
+        });
+        (hexasphere, events)
+    }
+
+    #[test]
+    fn test_progress_phases_in_order_and_complete() {
+        let (_, events) = record(6);
+
+        let phases = [
+            BuildPhase::Subdividing,
+            BuildPhase::Projecting,
+            BuildPhase::GroupingFaces,
+            BuildPhase::BuildingTiles,
+        ];
+        for pair in events.windows(2) {
+            let ((phase_a, a), (phase_b, b)) = (pair[0], pair[1]);
+            assert!(phase_a <= phase_b, "{:?} after {:?}", phase_b, phase_a);
+            if phase_a == phase_b {
+                assert!(a <= b);
+            }
+        }
+        for phase in phases {
+            let fractions: Vec<f32> = events
+                .iter()
+                .filter(|(p, _)| *p == phase)
+                .map(|&(_, f)| f)
+                .collect();
+            assert_eq!(fractions.first(), Some(&0.0), "{:?}", phase);
+            assert_eq!(fractions.last(), Some(&1.0), "{:?}", phase);
+        }
+
+        // Fine-grained where it matters
+        let count = |phase| events.iter().filter(|(p, _)| *p == phase).count();
+        assert_eq!(count(BuildPhase::Subdividing), 21);
+        assert!(count(BuildPhase::BuildingTiles) > 50);
+    }
+
+    #[test]
+    fn test_progress_build_matches_new() {
+        for num_divisions in [0, 1, 4] {
+            let (hexasphere, _) = record(num_divisions);
+            let plain = Hexasphere::new(10.0, num_divisions, 0.9);
+
+            assert_eq!(hexasphere.tiles.len(), plain.tiles.len());
+            for (a, b) in hexasphere.tiles.iter().zip(&plain.tiles) {
+                assert_eq!(a.center_point, b.center_point);
+                assert_eq!(a.boundary, b.boundary);
+                assert_eq!(a.neighbors, b.neighbors);
+            }
+        }
+    }
+}
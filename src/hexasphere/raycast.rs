@@ -0,0 +1,175 @@
+//! Ray picking against the hexasphere.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+
+/// Where a ray hit the hexasphere, as returned by [`Hexasphere::raycast`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RayHit {
+    /// Index of the tile that was hit
+    pub tile_index: usize,
+    /// Intersection point on the sphere of radius `Hexasphere::radius`
+    pub point: Point,
+    /// Distance from the ray origin to `point`
+    pub distance: f64,
+}
+
+impl Hexasphere {
+    /// Finds the tile hit by a ray, e.g. for mouse picking on a rendered globe.
+    ///
+    /// The ray is intersected with the sphere of radius `self.radius`
+    /// centered at [`Hexasphere::center`]. Of the two intersections, the
+    /// nearest one in front of the ray origin is used, so rays starting inside
+    /// the sphere hit the far side. The hit point is then resolved to the tile
+    /// containing it, or to the tile with the nearest center when it falls
+    /// into a gap between tiles built with `hex_size < 1.0`.
+    ///
+    /// Tiles are flat polygons slightly beneath the sphere, so the hit point
+    /// lies just above the rendered tile surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Start of the ray, in the hexasphere's coordinate space
+    /// * `dir` - Direction of the ray; need not be normalized
+    ///
+    /// # Returns
+    ///
+    /// The hit, or `None` if the ray misses the sphere, points away from it,
+    /// has a zero direction, or the hexasphere has no tiles
+    ///
+    /// # Use Cases
+    ///
+    /// - **Mouse picking**: Select the tile under the cursor
+    /// - **Line of sight**: Find where a projectile meets the ground
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n) for the nearest-center scan
+    /// - Space complexity: O(1)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, Point, Vector3};
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let camera = Point::new(0.0, 0.0, 30.0);
+    ///
+    /// let hit = hexasphere.raycast(&camera, &Vector3::new(0.0, 0.0, -1.0)).unwrap();
+    /// assert!((hit.distance - 20.0).abs() < 1e-9);
+    /// assert_eq!(Some(hit.tile_index), hexasphere.tile_containing(&hit.point));
+    ///
+    /// assert!(hexasphere.raycast(&camera, &Vector3::new(0.0, 0.0, 1.0)).is_none());
+    /// ```
+    pub fn raycast(&self, origin: &Point, dir: &Vector3) -> Option<RayHit> {
+        let direction = dir.normalize();
+        if direction.x == 0.0 && direction.y == 0.0 && direction.z == 0.0 {
+            return None;
+        }
+
+        // |o + t·d|² = r² with |d| = 1 gives t² + 2bt + c = 0
+        let start = self.relative(origin);
+        let b = start.dot(&direction);
+        let c = start.dot(&start) - self.radius * self.radius;
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let (near, far) = (-b - root, -b + root);
+        let distance = if near >= 0.0 {
+            near
+        } else if far >= 0.0 {
+            far
+        } else {
+            return None;
+        };
+
+        let hit = &start + &(&direction * distance);
+        let point = self.placed(&hit);
+        let nearest = self.nearest_tile(&hit)?;
+        let tile_index = self
+            .tile_containing_near(nearest, &point)
+            .unwrap_or(nearest);
+
+        Some(RayHit {
+            tile_index,
+            point,
+            distance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Point, Vector3};
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_ray_toward_origin_hits_front_tile() {
+        let radius = 10.0;
+        let hexasphere = Hexasphere::new(radius, 5, 1.0);
+        let origin = Point::new(0.0, 0.0, 3.0 * radius);
+
+        let hit = hexasphere
+            .raycast(&origin, &Vector3::new(0.0, 0.0, -2.0))
+            .unwrap();
+        assert!((hit.distance - 2.0 * radius).abs() < 1e-9);
+        assert!((hit.point.z - radius).abs() < 1e-9);
+        assert_eq!(
+            Some(hit.tile_index),
+            hexasphere.tile_containing(&Point::new(0.0, 0.0, radius))
+        );
+
+        // From inside the sphere the far side is hit
+        let inside = hexasphere
+            .raycast(&Point::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 0.0, -1.0))
+            .unwrap();
+        assert!((inside.distance - radius).abs() < 1e-9);
+        assert!((inside.point.z + radius).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rays_that_miss() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let origin = Point::new(0.0, 0.0, 30.0);
+
+        assert!(hexasphere
+            .raycast(&origin, &Vector3::new(0.0, 0.0, 1.0))
+            .is_none());
+        assert!(hexasphere
+            .raycast(&origin, &Vector3::new(1.0, 0.0, 0.0))
+            .is_none());
+        assert!(hexasphere
+            .raycast(&origin, &Vector3::new(0.0, 0.0, 0.0))
+            .is_none());
+    }
+
+    #[test]
+    fn test_grazing_rays_are_stable() {
+        let radius = 10.0;
+        let hexasphere = Hexasphere::new(radius, 6, 0.8);
+        let direction = Vector3::new(0.0, 0.0, -1.0);
+        let edge = hexasphere.tile_containing(&Point::new(radius, 0.0, 0.0));
+
+        // Exactly tangent: a single touching point
+        let tangent = hexasphere
+            .raycast(&Point::new(radius, 0.0, 30.0), &direction)
+            .unwrap();
+        assert!((tangent.distance - 30.0).abs() < 1e-9);
+
+        // Just inside and just outside the silhouette, unrounded
+        let offset = |x: f64| Point { x, y: 0.0, z: 30.0 };
+        let inside = hexasphere
+            .raycast(&offset(radius - 1e-9), &direction)
+            .unwrap();
+        assert!(inside.point.distance_to(&tangent.point) < 1e-3);
+        assert_eq!(inside.tile_index, tangent.tile_index);
+        if let Some(edge) = edge {
+            assert_eq!(tangent.tile_index, edge);
+        }
+        assert!(hexasphere
+            .raycast(&offset(radius + 1e-9), &direction)
+            .is_none());
+    }
+}
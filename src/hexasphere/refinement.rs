@@ -0,0 +1,190 @@
+//! Refining an existing hexasphere into a finer one.
+//!
+//! The tile centers of a hexasphere and their neighbor lists form its
+//! geodesic triangulation: every tile corner is a triangle spanned by a tile
+//! and two consecutive neighbors. Refinement subdivides those triangles
+//! rather than the original icosahedron, so the coarse centers survive as
+//! vertices of the finer sphere.
+
+use crate::geometry::{Point, VertexPool};
+use crate::hexasphere::builder::{BuildError, HexasphereBuilder};
+use crate::hexasphere::core::{Hexasphere, TilePlan};
+use crate::utils::math::{subdivide_face_indexed, SubdivisionMode};
+
+/// Scale the coarse centers are pooled at, matching the icosahedron corners
+/// of the construction pipeline so pool rounding stays negligible
+const POOL_RADIUS: f64 = 1000.0;
+
+impl Hexasphere {
+    /// Builds a finer hexasphere whose tile centers include every center of this one.
+    ///
+    /// Each triangle of this sphere's geodesic triangulation is split into
+    /// `(additional_divisions + 1)²` triangles and the new vertices are
+    /// projected onto the sphere. The frequency is therefore multiplied by
+    /// `additional_divisions + 1`: refining `Hexasphere::new(r, 2, s)` by 1
+    /// gives as many tiles as `Hexasphere::new(r, 4, s)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional_divisions` - Extra divisions per triangle edge; 0 rebuilds
+    ///   the same tiles
+    ///
+    /// # Returns
+    ///
+    /// A new hexasphere with the same radius and full-size tiles (`hex_size` 1.0);
+    /// use [`Hexasphere::with_hex_size`] to shrink them. Its
+    /// [`Hexasphere::subdivisions`] are this sphere's times `additional_divisions + 1`.
+    ///
+    /// # Guarantees
+    ///
+    /// - Every tile center of `self` is, within rounding, the center of a
+    ///   tile of the refined sphere
+    /// - Pentagon centers stay pentagon centers; all new tiles are hexagons
+    ///
+    /// The new centers interpolate between the coarse centers, so apart from
+    /// the coarse ones they differ slightly from the centers
+    /// [`Hexasphere::new`] would place at the combined frequency. Tile ids
+    /// follow the usual center order and do not match the coarse ids.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let coarse = Hexasphere::new(10.0, 2, 1.0);
+    /// let fine = coarse.refined(1);
+    /// assert_eq!(fine.tiles.len(), Hexasphere::new(10.0, 4, 1.0).tiles.len());
+    /// assert_eq!(fine.pentagons().count(), 12);
+    /// ```
+    ///
+    /// # Use Cases
+    ///
+    /// - **Level of detail**: Map each coarse tile to its counterpart at the next level
+    /// - **Hierarchical data**: Seed fine tiles from the coarse tile they grew from
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × (k + 1)²) where n = tile count, k = `additional_divisions`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the refined sphere would exceed the default tile limit, like
+    /// [`Hexasphere::new`]. Requires complete neighbor lists; tiles without
+    /// neighbors leave holes in the refined sphere.
+    pub fn refined(&self, additional_divisions: usize) -> Hexasphere {
+        let frequency = additional_divisions.saturating_add(1);
+        let options = HexasphereBuilder::new()
+            .radius(self.radius)
+            .center(self.center().clone())
+            .vertex_precision(self.vertex_precision());
+
+        // A closed triangulation with V vertices has 2(V - 2) triangles, each
+        // gaining the same share of vertices
+        let requested = self
+            .tiles
+            .len()
+            .saturating_sub(2)
+            .saturating_mul(frequency.saturating_mul(frequency))
+            .saturating_add(2);
+        if requested > options.max_tiles {
+            panic!(
+                "{}",
+                BuildError::TooManyTiles {
+                    requested,
+                    limit: options.max_tiles,
+                }
+            );
+        }
+
+        let scale = POOL_RADIUS / self.radius;
+        let mut pool = VertexPool::new();
+        let centers: Vec<u32> = self
+            .tiles
+            .iter()
+            .map(|tile| {
+                let offset = self.relative(&tile.center_point);
+                pool.insert(Point {
+                    x: offset.x * scale,
+                    y: offset.y * scale,
+                    z: offset.z * scale,
+                })
+            })
+            .collect();
+
+        // Each triangle is emitted once, by its lowest tile id
+        let mut triangles = Vec::new();
+        for (id, tile) in self.tiles.iter().enumerate() {
+            let count = tile.neighbors.len();
+            for i in 0..count {
+                let (a, b) = (tile.neighbors[i], tile.neighbors[(i + 1) % count]);
+                if id < a && id < b && a < centers.len() && b < centers.len() {
+                    triangles.extend(subdivide_face_indexed(
+                        [centers[id], centers[a], centers[b]],
+                        frequency,
+                        SubdivisionMode::Linear,
+                        &mut pool,
+                    ));
+                }
+            }
+        }
+
+        let plan = TilePlan::from_pool(pool, triangles, 0, &options, &mut |_, _| {});
+        let mut refined = Self::from_plan(&plan, &options, &mut |_, _| {});
+        // 0 subdivisions build the same icosahedron as 1
+        refined.subdivisions = self.subdivisions.map(|d| d.max(1) * frequency);
+        refined
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Vector3;
+    use crate::utils::tile_count;
+
+    fn nearest_tile(hexasphere: &Hexasphere, point: &Point) -> (usize, f64) {
+        let target = Vector3::from(point);
+        hexasphere
+            .tiles
+            .iter()
+            .map(|tile| {
+                (
+                    tile.id,
+                    Vector3::from(&tile.center_point).distance_to(&target),
+                )
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_refined_keeps_coarse_centers() {
+        let coarse = Hexasphere::new(10.0, 2, 1.0);
+        for additional in 1..=2 {
+            let fine = coarse.refined(additional);
+            assert_eq!(fine.tiles.len(), tile_count(2 * (additional + 1)));
+            assert_eq!(fine.pentagons().count(), 12);
+            assert_eq!(fine.subdivisions(), Some(2 * (additional + 1)));
+            assert!(fine.validate().is_ok());
+
+            for tile in &coarse.tiles {
+                let (id, distance) = nearest_tile(&fine, &tile.center_point);
+                assert!(distance < 1e-9, "center moved by {distance}");
+                assert_eq!(fine.tiles[id].is_pentagon(), tile.is_pentagon());
+            }
+        }
+    }
+
+    #[test]
+    fn test_refined_by_zero_rebuilds_same_tiles() {
+        let coarse = Hexasphere::new(5.0, 3, 1.0);
+        let same = coarse.refined(0);
+
+        assert_eq!(same.tiles.len(), coarse.tiles.len());
+        for (a, b) in coarse.tiles.iter().zip(&same.tiles) {
+            assert!(
+                Vector3::from(&a.center_point).distance_to(&Vector3::from(&b.center_point)) < 1e-9
+            );
+            assert_eq!(a.neighbors.len(), b.neighbors.len());
+        }
+    }
+}
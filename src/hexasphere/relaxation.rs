@@ -0,0 +1,288 @@
+//! Lloyd relaxation of tile centers to equalize tile areas.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+use crate::utils::triangle_area;
+
+/// Tile area spread before and after [`Hexasphere::relax`].
+///
+/// Ratios are largest tile area divided by smallest tile area, so 1.0 would
+/// mean perfectly equal tiles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+/// let report = hexasphere.relax(5);
+///
+/// println!(
+///     "max/min area ratio {:.3} -> {:.3}",
+///     report.area_ratio_before, report.area_ratio_after
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelaxationReport {
+    /// Largest over smallest tile area before relaxing
+    pub area_ratio_before: f64,
+    /// Largest over smallest tile area after relaxing
+    pub area_ratio_after: f64,
+}
+
+impl Hexasphere {
+    /// Evens out tile areas by repeatedly moving tile centers to their cell centroids.
+    ///
+    /// Each iteration is one step of Lloyd's algorithm on the sphere:
+    ///
+    /// 1. **Move centers**: Every tile center moves to the area-weighted centroid
+    ///    of its full-size cell, projected back onto the sphere
+    /// 2. **Rebuild boundaries**: Every boundary point is recomputed as the
+    ///    centroid of the triangle formed by the tile and the two neighbors
+    ///    meeting at that corner, exactly as during construction
+    ///
+    /// Topology never changes: neighbor lists, tile ids and tile order stay
+    /// the same and pentagons remain pentagons. The `hex_size` used at
+    /// construction is measured from the existing boundaries and preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `iterations` - Number of relaxation steps; 0 leaves the tiles unchanged
+    ///
+    /// # Returns
+    ///
+    /// A [`RelaxationReport`] with the max/min tile area ratio before and after
+    ///
+    /// # Use Cases
+    ///
+    /// - **Simulation fairness**: Tiles that cover similar amounts of surface
+    /// - **Resource distribution**: Per-tile quantities that should scale evenly
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(iterations × n × k) where n = tiles, k = neighbors per tile
+    /// - Space complexity: O(n) for the new centers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let report = hexasphere.relax(5);
+    ///
+    /// assert!(report.area_ratio_after < report.area_ratio_before);
+    /// assert_eq!(hexasphere.tiles.iter().filter(|t| t.is_pentagon()).count(), 12);
+    /// ```
+    pub fn relax(&mut self, iterations: usize) -> RelaxationReport {
+        let area_ratio_before = self.tile_area_ratio();
+        let hex_size = self.measure_hex_size();
+        let precision = self.vertex_precision();
+
+        for _ in 0..iterations {
+            let centers: Vec<Point> = (0..self.tiles.len())
+                .map(|id| self.cell_centroid(id))
+                .collect();
+
+            for tile in &mut self.tiles {
+                tile.clear_cache();
+                let n = tile.neighbors.len();
+                if n != tile.boundary.len() {
+                    tile.center_point = centers[tile.id].clone();
+                    continue;
+                }
+
+                let center = &centers[tile.id];
+                tile.boundary = (0..n)
+                    .map(|i| {
+                        let previous = &centers[tile.neighbors[(i + n - 1) % n]];
+                        let next = &centers[tile.neighbors[i]];
+                        let corner = flat_centroid(center, previous, next, precision);
+                        center.segment_with_precision(&corner, hex_size, precision)
+                    })
+                    .collect();
+                tile.center_point = center.clone();
+            }
+
+            self.refresh_neighbor_ids();
+        }
+
+        RelaxationReport {
+            area_ratio_before,
+            area_ratio_after: self.tile_area_ratio(),
+        }
+    }
+
+    /// Calculates the ratio between the largest and smallest tile area.
+    ///
+    /// # Returns
+    ///
+    /// `max_area / min_area` over all tiles, or 1.0 if there are no tiles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// assert!(hexasphere.tile_area_ratio() >= 1.0);
+    /// ```
+    pub fn tile_area_ratio(&self) -> f64 {
+        if self.tiles.is_empty() {
+            return 1.0;
+        }
+
+        let areas = self.tiles.iter().map(|tile| tile.get_area());
+        let (min, max) = areas.fold((f64::INFINITY, 0.0_f64), |(min, max), area| {
+            (min.min(area), max.max(area))
+        });
+        max / min
+    }
+
+    /// Recovers the boundary scale factor the tiles were built with.
+    fn measure_hex_size(&self) -> f64 {
+        let Some(tile) = self
+            .tiles
+            .iter()
+            .find(|tile| !tile.boundary.is_empty() && tile.neighbors.len() == tile.boundary.len())
+        else {
+            return 1.0;
+        };
+
+        let n = tile.boundary.len();
+        let center = &tile.center_point;
+        let total: f64 = (0..n)
+            .map(|i| {
+                let previous = &self.tiles[tile.neighbors[(i + n - 1) % n]].center_point;
+                let next = &self.tiles[tile.neighbors[i]].center_point;
+                let full = flat_centroid(center, previous, next, self.vertex_precision());
+                center.distance_to(&tile.boundary[i]) / center.distance_to(&full)
+            })
+            .sum();
+        (total / n as f64).clamp(0.01, 1.0)
+    }
+
+    /// Area-weighted centroid of a tile's full-size cell, projected onto the sphere.
+    fn cell_centroid(&self, id: usize) -> Point {
+        let tile = &self.tiles[id];
+        let n = tile.neighbors.len();
+        if n < 3 || n != tile.boundary.len() {
+            return tile.center_point.clone();
+        }
+
+        let center = &tile.center_point;
+        let corners: Vec<Point> = (0..n)
+            .map(|i| {
+                let previous = &self.tiles[tile.neighbors[(i + n - 1) % n]].center_point;
+                let next = &self.tiles[tile.neighbors[i]].center_point;
+                flat_centroid(center, previous, next, self.vertex_precision())
+            })
+            .collect();
+
+        let mut weighted = Vector3::new(0.0, 0.0, 0.0);
+        for i in 0..n {
+            let (a, b) = (&corners[i], &corners[(i + 1) % n]);
+            let area = triangle_area(center, a, b);
+            let centroid = self.relative(&flat_centroid(center, a, b, self.vertex_precision()));
+            weighted = weighted + centroid * area;
+        }
+
+        let direction = weighted.normalize();
+        self.placed(&(direction * self.radius))
+    }
+}
+
+/// Plain average of three points, as used for tile boundary corners.
+fn flat_centroid(a: &Point, b: &Point, c: &Point, decimals: u32) -> Point {
+    Point::with_precision(
+        (a.x + b.x + c.x) / 3.0,
+        (a.y + b.y + c.y) / 3.0,
+        (a.z + b.z + c.z) / 3.0,
+        decimals,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_relax_improves_area_ratio() {
+        let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let report = hexasphere.relax(5);
+
+        assert!(
+            report.area_ratio_after < report.area_ratio_before,
+            "area ratio should shrink: {} -> {}",
+            report.area_ratio_before,
+            report.area_ratio_after
+        );
+        assert_eq!(report.area_ratio_after, hexasphere.tile_area_ratio());
+        assert_eq!(
+            hexasphere.tiles.iter().filter(|t| t.is_pentagon()).count(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_relax_preserves_topology() {
+        let original = Hexasphere::new(10.0, 3, 1.0);
+        let mut relaxed = Hexasphere::new(10.0, 3, 1.0);
+        relaxed.relax(3);
+
+        assert_eq!(relaxed.tiles.len(), original.tiles.len());
+        for (before, after) in original.tiles.iter().zip(&relaxed.tiles) {
+            assert_eq!(after.id, before.id);
+            assert_eq!(after.neighbors, before.neighbors);
+            assert_eq!(after.boundary.len(), before.boundary.len());
+
+            // Centers stay on the sphere and neighbor ids follow them
+            let distance = (after.center_point.x.powi(2)
+                + after.center_point.y.powi(2)
+                + after.center_point.z.powi(2))
+            .sqrt();
+            assert!((distance - 10.0).abs() < 0.01);
+            for (neighbor_id, &neighbor) in after.neighbor_ids.iter().zip(&after.neighbors) {
+                assert_eq!(
+                    neighbor_id,
+                    &relaxed.tiles[neighbor].center_point.to_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_relax_keeps_shared_corners_and_hex_size() {
+        let mut touching = Hexasphere::new(10.0, 3, 1.0);
+        touching.relax(2);
+
+        // With hex_size 1.0 adjacent tiles still share both corners of their edge
+        for tile in &touching.tiles {
+            for (edge, &neighbor) in tile.neighbors.iter().enumerate() {
+                let start = &tile.boundary[edge];
+                let end = &tile.boundary[(edge + 1) % tile.boundary.len()];
+                let other = &touching.tiles[neighbor].boundary;
+                assert!(other.iter().any(|p| p.distance_to(start) < 0.002));
+                assert!(other.iter().any(|p| p.distance_to(end) < 0.002));
+            }
+        }
+
+        // Gaps from a smaller hex_size survive relaxation
+        let mut gapped = Hexasphere::new(10.0, 3, 0.8);
+        let full_area: f64 = touching.tiles.iter().map(|t| t.get_area()).sum();
+        gapped.relax(2);
+        let gapped_area: f64 = gapped.tiles.iter().map(|t| t.get_area()).sum();
+        let ratio = gapped_area / full_area;
+        assert!((ratio - 0.64).abs() < 0.01, "area ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_relax_zero_iterations_is_noop() {
+        let original = Hexasphere::new(10.0, 2, 1.0);
+        let mut relaxed = Hexasphere::new(10.0, 2, 1.0);
+        let report = relaxed.relax(0);
+
+        assert_eq!(report.area_ratio_before, report.area_ratio_after);
+        for (before, after) in original.tiles.iter().zip(&relaxed.tiles) {
+            assert_eq!(before.center_point, after.center_point);
+            assert_eq!(before.boundary, after.boundary);
+        }
+    }
+}
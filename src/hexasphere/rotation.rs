@@ -0,0 +1,173 @@
+//! Rigid rotation of a whole hexasphere about its center.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+
+impl Hexasphere {
+    /// Rotates every tile about an axis through the sphere's center.
+    ///
+    /// Tile centers and boundary points are rotated using Rodrigues' rotation
+    /// formula and rounded to [`Hexasphere::vertex_precision`] decimals. Topology never
+    /// changes: tile ids, tile order and neighbor lists stay the same, while
+    /// `neighbor_ids` are refreshed to follow the moved centers. After
+    /// rotating, tiles are generally no longer sorted by center coordinates.
+    ///
+    /// Orientations, statistics and exports are all derived from the tile
+    /// points on demand, so they reflect the rotation automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Rotation axis; need not be normalized. A zero axis leaves the tiles unchanged
+    /// * `angle_rad` - Rotation angle in radians, counter-clockwise when looking down the axis
+    ///
+    /// # Use Cases
+    ///
+    /// - **Geographic alignment**: Move pentagons away from important locations
+    /// - **Randomized worlds**: Give every generated planet a different orientation
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = boundary points per tile
+    /// - Space complexity: O(1) beyond the refreshed neighbor ids
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, Vector3};
+    /// let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let before = hexasphere.tiles[0].center_point.clone();
+    ///
+    /// hexasphere.rotate(&Vector3::new(0.0, 1.0, 0.0), std::f64::consts::FRAC_PI_2);
+    ///
+    /// let after = &hexasphere.tiles[0].center_point;
+    /// assert!((after.x - before.z).abs() < 1e-2);
+    /// assert!((after.z + before.x).abs() < 1e-2);
+    /// ```
+    pub fn rotate(&mut self, axis: &Vector3, angle_rad: f64) {
+        let axis = axis.normalize();
+        if axis.x == 0.0 && axis.y == 0.0 && axis.z == 0.0 {
+            return;
+        }
+
+        let (sin, cos) = angle_rad.sin_cos();
+        let center = Vector3::from(self.center());
+        let precision = self.vertex_precision();
+        let rotate_point = |point: &Point| {
+            let v = Vector3::from(point) - center.clone();
+            let rotated = &v * cos + axis.cross(&v) * sin + &axis * (axis.dot(&v) * (1.0 - cos));
+            let moved = rotated + center.clone();
+            Point::with_precision(moved.x, moved.y, moved.z, precision)
+        };
+
+        for tile in &mut self.tiles {
+            tile.clear_cache();
+            tile.center_point = rotate_point(&tile.center_point);
+            for point in &mut tile.boundary {
+                *point = rotate_point(point);
+            }
+        }
+        self.refresh_neighbor_ids();
+    }
+
+    /// Returns a rotated copy of this hexasphere, leaving `self` unchanged.
+    ///
+    /// See [`Hexasphere::rotate`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, Vector3};
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tilted = hexasphere.rotated(&Vector3::new(1.0, 0.0, 0.0), 0.4);
+    ///
+    /// assert_eq!(tilted.tiles.len(), hexasphere.tiles.len());
+    /// assert_ne!(tilted.tiles[0].center_point, hexasphere.tiles[0].center_point);
+    /// ```
+    pub fn rotated(&self, axis: &Vector3, angle_rad: f64) -> Hexasphere {
+        let mut rotated = self.clone();
+        rotated.rotate(axis, angle_rad);
+        rotated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Vector3;
+    use crate::hexasphere::builder::HexasphereBuilder;
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_rotation_preserves_distances() {
+        let hexasphere = Hexasphere::new(10.0, 4, 0.9);
+        let rotated = hexasphere.rotated(&Vector3::new(1.0, 2.0, -0.5), 1.234);
+
+        let stride = 7;
+        for a in (0..hexasphere.tiles.len()).step_by(stride) {
+            for b in (0..hexasphere.tiles.len()).step_by(stride) {
+                let before = hexasphere.tiles[a]
+                    .center_point
+                    .distance_to(&hexasphere.tiles[b].center_point);
+                let after = rotated.tiles[a]
+                    .center_point
+                    .distance_to(&rotated.tiles[b].center_point);
+                assert!(
+                    (before - after).abs() < 5e-3,
+                    "{a}-{b}: {before} vs {after}"
+                );
+            }
+        }
+
+        for (original, moved) in hexasphere.tiles.iter().zip(&rotated.tiles) {
+            assert_eq!(original.neighbors, moved.neighbors);
+            for (i, &neighbor) in moved.neighbors.iter().enumerate() {
+                assert_eq!(
+                    moved.neighbor_ids[i],
+                    rotated.tiles[neighbor].center_point.to_string()
+                );
+            }
+        }
+        assert!(rotated
+            .tiles
+            .iter()
+            .all(|tile| rotated.tile_containing(&tile.center_point) == Some(tile.id)));
+    }
+
+    #[test]
+    fn test_full_turn_returns_to_start() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let turned = hexasphere.rotated(&Vector3::new(0.3, -1.0, 0.7), std::f64::consts::TAU);
+
+        for (original, moved) in hexasphere.tiles.iter().zip(&turned.tiles) {
+            assert!(original.center_point.distance_to(&moved.center_point) < 2e-3);
+            for (a, b) in original.boundary.iter().zip(&moved.boundary) {
+                assert!(a.distance_to(b) < 2e-3);
+            }
+        }
+
+        let unchanged = hexasphere.rotated(&Vector3::new(0.0, 0.0, 0.0), 1.0);
+        for (original, same) in hexasphere.tiles.iter().zip(&unchanged.tiles) {
+            assert_eq!(original.center_point, same.center_point);
+            assert_eq!(original.boundary, same.boundary);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_keeps_vertex_precision() {
+        let hexasphere = HexasphereBuilder::new()
+            .radius(0.001)
+            .subdivisions(3)
+            .vertex_precision(9)
+            .build()
+            .unwrap();
+        let axis = Vector3::new(0.3, -1.0, 0.7);
+        let back = hexasphere.rotated(&axis, 0.8).rotated(&axis, -0.8);
+
+        for (original, moved) in hexasphere.tiles.iter().zip(&back.tiles) {
+            assert!(original.center_point.distance_to(&moved.center_point) < 1e-8);
+            for (a, b) in original.boundary.iter().zip(&moved.boundary) {
+                assert!(a.distance_to(b) < 1e-8);
+            }
+        }
+        assert!(back.validate().is_ok());
+    }
+}
@@ -0,0 +1,211 @@
+//! Random sampling of surface points and tiles.
+//!
+//! Only available with the `rand` feature enabled.
+
+use crate::geometry::Point;
+use crate::hexasphere::core::Hexasphere;
+use rand::Rng;
+use std::f64::consts::PI;
+
+impl Hexasphere {
+    /// Samples a point uniformly distributed over the sphere surface.
+    ///
+    /// Uses the cylindrical equal-area method (Archimedes' hat-box theorem): the
+    /// height along the Y axis is drawn uniformly from `[-radius, radius]` and the
+    /// longitude uniformly from `[0, 2π)`. Every region of the sphere therefore
+    /// receives samples in proportion to its area, with no clustering at the poles.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - Random number generator to draw from
+    ///
+    /// # Returns
+    ///
+    /// A `Point` lying on the sphere of radius `self.radius` around its center
+    ///
+    /// # Use Cases
+    ///
+    /// - **Resource scattering**: Placing items evenly over a planet surface
+    /// - **Monte Carlo estimates**: Integrating quantities over the sphere
+    /// - **Spawn points**: Unbiased random locations for game entities
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// use rand::SeedableRng;
+    ///
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    ///
+    /// let point = hexasphere.random_surface_point(&mut rng);
+    /// let distance = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+    /// assert!((distance - 10.0).abs() < 0.01);
+    /// ```
+    pub fn random_surface_point(&self, rng: &mut impl Rng) -> Point {
+        let height: f64 = rng.random_range(-1.0..=1.0);
+        let longitude: f64 = rng.random_range(0.0..2.0 * PI);
+        let ring_radius = (1.0 - height * height).max(0.0).sqrt();
+
+        let center = self.center();
+        Point::new(
+            center.x + self.radius * ring_radius * longitude.sin(),
+            center.y + self.radius * height,
+            center.z + self.radius * ring_radius * longitude.cos(),
+        )
+    }
+
+    /// Picks a random tile with probability proportional to its area.
+    ///
+    /// Choosing a tile index uniformly would over-represent small tiles: the 12
+    /// pentagons and the hexagons around them cover less surface than the average
+    /// tile. This method weights each tile by `Tile::get_area()` so that the
+    /// selection matches where a uniformly random surface point would land.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - Random number generator to draw from
+    ///
+    /// # Returns
+    ///
+    /// The index of the selected tile in `self.tiles`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hexasphere has no tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// use rand::SeedableRng;
+    ///
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    ///
+    /// let tile_index = hexasphere.random_tile(&mut rng);
+    /// assert!(tile_index < hexasphere.tiles.len());
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n) per call where n = number of tiles
+    /// - Space complexity: O(1) additional memory
+    pub fn random_tile(&self, rng: &mut impl Rng) -> usize {
+        assert!(
+            !self.tiles.is_empty(),
+            "cannot sample a tile from an empty hexasphere"
+        );
+
+        let total_area: f64 = self.tiles.iter().map(|tile| tile.get_area()).sum();
+        let mut target = rng.random_range(0.0..total_area);
+
+        for (index, tile) in self.tiles.iter().enumerate() {
+            let area = tile.get_area();
+            if target < area {
+                return index;
+            }
+            target -= area;
+        }
+
+        // Floating-point drift can leave a tiny remainder after the last tile
+        self.tiles.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random_surface_point_on_sphere() {
+        let hexasphere = Hexasphere::new(5.0, 2, 1.0);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..1000 {
+            let point = hexasphere.random_surface_point(&mut rng);
+            let distance = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+            assert!(
+                (distance - 5.0).abs() < 0.01,
+                "Sampled point should lie on the sphere: {}",
+                distance
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_surface_point_is_unbiased() {
+        let hexasphere = Hexasphere::new(1.0, 2, 1.0);
+        let mut rng = StdRng::seed_from_u64(2);
+        let samples = 20_000;
+
+        let mut sum_y = 0.0;
+        let mut northern = 0;
+        for _ in 0..samples {
+            let point = hexasphere.random_surface_point(&mut rng);
+            sum_y += point.y;
+            if point.y > 0.0 {
+                northern += 1;
+            }
+        }
+
+        // Mean height should be near zero and hemispheres equally hit
+        assert!((sum_y / samples as f64).abs() < 0.02);
+        let northern_fraction = northern as f64 / samples as f64;
+        assert!(
+            (northern_fraction - 0.5).abs() < 0.02,
+            "Northern fraction should be ~0.5: {}",
+            northern_fraction
+        );
+    }
+
+    #[test]
+    fn test_random_tile_is_area_weighted() {
+        let hexasphere = Hexasphere::new(1.0, 4, 1.0);
+        let mut rng = StdRng::seed_from_u64(3);
+        let samples = 50_000;
+
+        let mut pentagon_hits = 0;
+        for _ in 0..samples {
+            let index = hexasphere.random_tile(&mut rng);
+            assert!(index < hexasphere.tiles.len());
+            if hexasphere.tiles[index].is_pentagon() {
+                pentagon_hits += 1;
+            }
+        }
+
+        let observed_rate = pentagon_hits as f64 / samples as f64;
+        let uniform_rate = 12.0 / hexasphere.tiles.len() as f64;
+
+        let total_area: f64 = hexasphere.tiles.iter().map(|t| t.get_area()).sum();
+        let pentagon_area: f64 = hexasphere
+            .tiles
+            .iter()
+            .filter(|t| t.is_pentagon())
+            .map(|t| t.get_area())
+            .sum();
+        let expected_rate = pentagon_area / total_area;
+
+        // Chi-squared style check: the observed count should be within a few
+        // standard deviations of the area-weighted expectation
+        let expected_hits = expected_rate * samples as f64;
+        let std_dev = (samples as f64 * expected_rate * (1.0 - expected_rate)).sqrt();
+        assert!(
+            (pentagon_hits as f64 - expected_hits).abs() < 4.0 * std_dev,
+            "Pentagon hits {} should be near area-weighted expectation {:.1}",
+            pentagon_hits,
+            expected_hits
+        );
+
+        // Pentagons are smaller than average, so they must be hit less often
+        // than a uniform per-tile choice would suggest
+        assert!(
+            observed_rate < uniform_rate * 0.95,
+            "Pentagon rate {} should be noticeably below uniform rate {}",
+            observed_rate,
+            uniform_rate
+        );
+    }
+}
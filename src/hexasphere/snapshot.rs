@@ -0,0 +1,462 @@
+//! Compact binary snapshots of generated hexaspheres.
+//!
+//! The layout is little-endian throughout:
+//!
+//! | Field        | Type       | Notes                               |
+//! |--------------|------------|-------------------------------------|
+//! | magic        | `[u8; 4]`  | `b"GTHX"`                           |
+//! | version      | `u8`       | Currently `4`                       |
+//! | radius       | `f64`      |                                     |
+//! | tile count   | `u32`      |                                     |
+//! | subdivisions | `u32`      | `u32::MAX` if unknown; not in version 1 |
+//! | center       | `3 × f64`  | sphere center; since version 3      |
+//! | precision    | `u32`      | vertex decimals; since version 4    |
+//! | per tile     |            | repeated `tile count` times         |
+//! | - center     | `3 × f64`  | x, y, z                             |
+//! | - boundary   | `u32` + `n × 3 × f64` | point count, then points |
+//! | - neighbors  | `u32` + `m × u32`     | count, then tile indices |
+
+use crate::geometry::Point;
+use crate::hexasphere::builder::DEFAULT_VERTEX_PRECISION;
+use crate::hexasphere::core::Hexasphere;
+use crate::tile::core::Tile;
+use std::fmt;
+
+const MAGIC: &[u8; 4] = b"GTHX";
+const VERSION: u8 = 4;
+
+/// Stands in for [`Hexasphere::subdivisions`] being `None`
+const UNKNOWN_SUBDIVISIONS: u32 = u32::MAX;
+
+/// Errors that can occur while decoding a binary hexasphere snapshot.
+///
+/// Decoding never panics on malformed input; every inconsistency in the byte
+/// stream is reported through one of these variants.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{DecodeError, Hexasphere};
+/// let result = Hexasphere::from_bytes(b"not a snapshot");
+/// assert_eq!(result.unwrap_err(), DecodeError::InvalidMagic);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data does not start with the snapshot magic bytes
+    InvalidMagic,
+    /// The snapshot was written by an unsupported format version
+    UnsupportedVersion(u8),
+    /// The data ended before the snapshot was complete
+    UnexpectedEnd,
+    /// A tile references a neighbor index outside the tile array
+    InvalidNeighbor {
+        /// Index of the tile holding the bad reference
+        tile: usize,
+        /// The out-of-range neighbor index
+        neighbor: usize,
+    },
+    /// Extra bytes remain after the last tile
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidMagic => write!(f, "data is not a hexasphere snapshot"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            DecodeError::UnexpectedEnd => write!(f, "snapshot data ended unexpectedly"),
+            DecodeError::InvalidNeighbor { tile, neighbor } => write!(
+                f,
+                "tile {} references neighbor {} which does not exist",
+                tile, neighbor
+            ),
+            DecodeError::TrailingBytes(count) => {
+                write!(f, "{} unexpected bytes after the last tile", count)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl Hexasphere {
+    /// Serializes the hexasphere into a compact binary snapshot.
+    ///
+    /// Stores the radius, [`Hexasphere::subdivisions`], [`Hexasphere::center`]
+    /// and, for every tile, its center, boundary points and
+    /// resolved neighbor indices. Neighbor indices are stored directly, so a
+    /// loaded snapshot is ready for pathfinding without re-resolving neighbors.
+    ///
+    /// # Returns
+    ///
+    /// The snapshot bytes (see the module documentation for the layout)
+    ///
+    /// # Size
+    ///
+    /// Roughly 200 bytes per hexagon, compared to several kilobytes per tile for
+    /// a text format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let bytes = hexasphere.to_bytes();
+    ///
+    /// let restored = Hexasphere::from_bytes(&bytes).unwrap();
+    /// assert_eq!(restored.tiles.len(), hexasphere.tiles.len());
+    /// assert_eq!(restored.tiles[0].neighbors, hexasphere.tiles[0].neighbors);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.radius.to_le_bytes());
+        bytes.extend_from_slice(&(self.tiles.len() as u32).to_le_bytes());
+        let subdivisions = self
+            .subdivisions()
+            .and_then(|d| u32::try_from(d).ok())
+            .unwrap_or(UNKNOWN_SUBDIVISIONS);
+        bytes.extend_from_slice(&subdivisions.to_le_bytes());
+        write_point(&mut bytes, self.center());
+        bytes.extend_from_slice(&self.vertex_precision().to_le_bytes());
+
+        for tile in &self.tiles {
+            write_point(&mut bytes, &tile.center_point);
+
+            bytes.extend_from_slice(&(tile.boundary.len() as u32).to_le_bytes());
+            for point in &tile.boundary {
+                write_point(&mut bytes, point);
+            }
+
+            bytes.extend_from_slice(&(tile.neighbors.len() as u32).to_le_bytes());
+            for &neighbor in &tile.neighbors {
+                bytes.extend_from_slice(&(neighbor as u32).to_le_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    /// Restores a hexasphere from a binary snapshot created by [`Hexasphere::to_bytes`].
+    ///
+    /// All lengths and neighbor indices are validated, so truncated or
+    /// corrupted data produces an error instead of a panic. `neighbor_ids` are
+    /// rebuilt from the neighbors' center points. Version 1 snapshots, which
+    /// predate the subdivision count, load with unknown subdivisions, and
+    /// snapshots before version 3 load centered on the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Snapshot data
+    ///
+    /// # Returns
+    ///
+    /// The restored `Hexasphere`, or a `DecodeError` describing the problem
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # fn load_example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// # std::fs::write("sphere.bin", hexasphere.to_bytes())?;
+    /// let bytes = std::fs::read("sphere.bin")?;
+    /// let hexasphere = Hexasphere::from_bytes(&bytes)?;
+    /// # std::fs::remove_file("sphere.bin")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Hexasphere, DecodeError> {
+        let mut reader = Reader { bytes };
+
+        if reader.take(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+            return Err(DecodeError::InvalidMagic);
+        }
+        let version = reader.take(1)?[0];
+        if !(1..=VERSION).contains(&version) {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+
+        let radius = reader.read_f64()?;
+        // Every tile needs at least a center and two counts
+        let tile_count = reader.read_count(3 * 8 + 2 * 4)?;
+        let subdivisions = if version >= 2 {
+            Some(reader.read_u32()?)
+                .filter(|&d| d != UNKNOWN_SUBDIVISIONS)
+                .map(|d| d as usize)
+        } else {
+            None
+        };
+        let sphere_center = if version >= 3 {
+            reader.read_point()?
+        } else {
+            Point::ORIGIN
+        };
+        let precision = if version >= 4 {
+            reader.read_u32()?
+        } else {
+            DEFAULT_VERTEX_PRECISION
+        };
+
+        let mut tiles = Vec::with_capacity(tile_count);
+        for id in 0..tile_count {
+            let center_point = reader.read_point()?;
+
+            let boundary_count = reader.read_count(3 * 8)?;
+            let boundary = (0..boundary_count)
+                .map(|_| reader.read_point())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let neighbor_count = reader.read_count(4)?;
+            let neighbors = (0..neighbor_count)
+                .map(|_| reader.read_u32().map(|n| n as usize))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            tiles.push(Tile {
+                id,
+                center_point,
+                boundary,
+                neighbor_ids: Vec::new(),
+                neighbors,
+                base_face: 0,
+                sphere_center: sphere_center.clone(),
+                sphere_radius: radius,
+                cache: None,
+            });
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(DecodeError::TrailingBytes(reader.bytes.len()));
+        }
+
+        for index in 0..tiles.len() {
+            let mut neighbor_ids = Vec::with_capacity(tiles[index].neighbors.len());
+            for &neighbor in &tiles[index].neighbors {
+                let neighbor_tile = tiles.get(neighbor).ok_or(DecodeError::InvalidNeighbor {
+                    tile: index,
+                    neighbor,
+                })?;
+                neighbor_ids.push(neighbor_tile.center_point.to_string());
+            }
+            tiles[index].neighbor_ids = neighbor_ids;
+        }
+
+        Ok(Hexasphere::from_tiles(
+            radius,
+            tiles,
+            subdivisions,
+            precision,
+        ))
+    }
+}
+
+fn write_point(bytes: &mut Vec<u8>, point: &Point) {
+    bytes.extend_from_slice(&point.x.to_le_bytes());
+    bytes.extend_from_slice(&point.y.to_le_bytes());
+    bytes.extend_from_slice(&point.z.to_le_bytes());
+}
+
+/// Cursor over the remaining snapshot bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < count {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let (head, tail) = self.bytes.split_at(count);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        let mut buffer = [0u8; 4];
+        buffer.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(buffer))
+    }
+
+    fn read_f64(&mut self) -> Result<f64, DecodeError> {
+        let mut buffer = [0u8; 8];
+        buffer.copy_from_slice(self.take(8)?);
+        Ok(f64::from_le_bytes(buffer))
+    }
+
+    fn read_point(&mut self) -> Result<Point, DecodeError> {
+        Ok(Point {
+            x: self.read_f64()?,
+            y: self.read_f64()?,
+            z: self.read_f64()?,
+        })
+    }
+
+    /// Reads an element count, rejecting counts the remaining data cannot hold.
+    fn read_count(&mut self, min_element_size: usize) -> Result<usize, DecodeError> {
+        let count = self.read_u32()? as usize;
+        if count.saturating_mul(min_element_size) > self.bytes.len() {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecodeError;
+    use crate::geometry::Point;
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_round_trip_is_exact() {
+        let hexasphere = Hexasphere::new(10.0, 3, 0.9);
+        let bytes = hexasphere.to_bytes();
+        let restored = Hexasphere::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.to_bytes(), bytes);
+        assert_eq!(restored.radius, hexasphere.radius);
+        assert_eq!(
+            restored.calculate_hexagon_stats(),
+            hexasphere.calculate_hexagon_stats()
+        );
+
+        for (original, loaded) in hexasphere.tiles.iter().zip(&restored.tiles) {
+            assert_eq!(loaded.id, original.id);
+            assert_eq!(loaded.center_point, original.center_point);
+            assert_eq!(loaded.boundary, original.boundary);
+            assert_eq!(loaded.neighbors, original.neighbors);
+            assert_eq!(loaded.neighbor_ids, original.neighbor_ids);
+        }
+    }
+
+    #[test]
+    fn test_subdivisions_round_trip() {
+        let bytes = Hexasphere::new(10.0, 3, 1.0).to_bytes();
+        assert_eq!(
+            Hexasphere::from_bytes(&bytes).unwrap().subdivisions(),
+            Some(3)
+        );
+
+        // Version 1 had no subdivision, center or precision fields after the tile count
+        let mut version_1 = bytes.clone();
+        version_1[4] = 1;
+        version_1.drain(17..49);
+        let restored = Hexasphere::from_bytes(&version_1).unwrap();
+        assert_eq!(restored.subdivisions(), None);
+        assert_eq!(restored.tiles.len(), 92);
+    }
+
+    #[test]
+    fn test_center_round_trip() {
+        let center = Point::new(100.0, -20.5, 3.0);
+        let moved = Hexasphere::builder()
+            .radius(10.0)
+            .subdivisions(2)
+            .center(center.clone())
+            .build()
+            .unwrap();
+        let bytes = moved.to_bytes();
+
+        let restored = Hexasphere::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.center(), &center);
+        assert!(restored
+            .tiles
+            .iter()
+            .all(|tile| tile.sphere_center == center));
+        assert_eq!(
+            restored.tiles[4].get_lat_lon(10.0),
+            moved.tiles[4].get_lat_lon(10.0)
+        );
+
+        // Version 2 had no center field and always meant the origin
+        let mut version_2 = Hexasphere::new(10.0, 2, 1.0).to_bytes();
+        version_2[4] = 2;
+        version_2.drain(21..49);
+        assert_eq!(
+            Hexasphere::from_bytes(&version_2).unwrap().center(),
+            &Point::ORIGIN
+        );
+    }
+
+    #[test]
+    fn test_precision_round_trip() {
+        let tiny = Hexasphere::builder()
+            .radius(0.001)
+            .subdivisions(3)
+            .vertex_precision(9)
+            .build()
+            .unwrap();
+        let bytes = tiny.to_bytes();
+        assert_eq!(
+            Hexasphere::from_bytes(&bytes).unwrap().vertex_precision(),
+            9
+        );
+
+        // Version 3 had no precision field and always meant 3 decimals
+        let mut version_3 = bytes;
+        version_3[4] = 3;
+        version_3.drain(45..49);
+        let restored = Hexasphere::from_bytes(&version_3).unwrap();
+        assert_eq!(restored.vertex_precision(), 3);
+        assert_eq!(restored.tiles.len(), 92);
+    }
+
+    #[test]
+    fn test_truncated_data_is_rejected() {
+        let bytes = Hexasphere::new(1.0, 1, 1.0).to_bytes();
+
+        for length in 0..bytes.len() {
+            assert!(
+                Hexasphere::from_bytes(&bytes[..length]).is_err(),
+                "truncation at {} bytes should fail",
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn test_corrupted_data_is_rejected() {
+        let bytes = Hexasphere::new(1.0, 1, 1.0).to_bytes();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert_eq!(
+            Hexasphere::from_bytes(&bad_magic).unwrap_err(),
+            DecodeError::InvalidMagic
+        );
+
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 99;
+        assert_eq!(
+            Hexasphere::from_bytes(&bad_version).unwrap_err(),
+            DecodeError::UnsupportedVersion(99)
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.extend_from_slice(&[0, 0, 0]);
+        assert_eq!(
+            Hexasphere::from_bytes(&trailing).unwrap_err(),
+            DecodeError::TrailingBytes(3)
+        );
+
+        // Point the last neighbor index of the last tile far out of range
+        let mut bad_neighbor = bytes.clone();
+        let end = bad_neighbor.len();
+        bad_neighbor[end - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Hexasphere::from_bytes(&bad_neighbor).unwrap_err(),
+            DecodeError::InvalidNeighbor { .. }
+        ));
+
+        // A huge tile count must not trigger a huge allocation
+        let mut huge_count = bytes;
+        huge_count[13..17].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            Hexasphere::from_bytes(&huge_count).unwrap_err(),
+            DecodeError::UnexpectedEnd
+        );
+    }
+}
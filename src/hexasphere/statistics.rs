@@ -1,7 +1,9 @@
 //! Statistical analysis of hexagon properties.
 
+use crate::geometry::Vector3;
 use crate::hexasphere::core::Hexasphere;
 use crate::tile::core::Tile;
+use std::f64::consts::PI;
 
 /// Statistical analysis of hexagon properties across the entire hexasphere.
 ///
@@ -30,7 +32,7 @@ use crate::tile::core::Tile;
 ///     println!("Regular hexagon approximation should work well!");
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct HexagonStats {
     /// Total number of hexagonal tiles (should be much larger than pentagon count)
     pub total_hexagons: usize,
@@ -48,6 +50,140 @@ pub struct HexagonStats {
     pub max_hexagon_radius: f64,
     /// Standard deviation of hexagon radii (measure of size consistency)
     pub radius_std_deviation: f64,
+    /// Median hexagon radius
+    pub median_hexagon_radius: f64,
+    /// 5th percentile of hexagon radii
+    pub p05_hexagon_radius: f64,
+    /// 95th percentile of hexagon radii
+    pub p95_hexagon_radius: f64,
+    /// Lowest hexagon [`Tile::compactness`] (the most squashed hexagon)
+    pub min_hexagon_compactness: f64,
+    /// Mean hexagon [`Tile::compactness`]; a regular hexagon scores `π/(2√3)` ≈ 0.9069
+    pub mean_hexagon_compactness: f64,
+}
+
+/// Angular distortion of tiles compared to regular polygons.
+///
+/// Size statistics in [`HexagonStats`] miss the most visible distortion of a
+/// geodesic tiling: tiles near the pentagons are squashed, so their interior
+/// angles drift away from the ideal 120° of a regular hexagon (108° for a
+/// regular pentagon). This report summarizes those deviations over every
+/// interior angle of every tile.
+///
+/// All angles are in radians.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+/// let distortion = hexasphere.calculate_distortion_stats();
+///
+/// println!(
+///     "Worst angle is {:.1}° off (tile {})",
+///     distortion.max_angle_deviation.to_degrees(),
+///     distortion.worst_tile
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistortionStats {
+    /// Smallest absolute deviation of any interior angle from its ideal value
+    pub min_angle_deviation: f64,
+    /// Largest absolute deviation of any interior angle from its ideal value
+    pub max_angle_deviation: f64,
+    /// Mean absolute deviation over all interior angles
+    pub mean_angle_deviation: f64,
+    /// Id of the tile containing the angle with the largest deviation
+    pub worst_tile: usize,
+}
+
+/// Size and shape measurements of a single tile.
+///
+/// Unlike [`HexagonStats`], these are reported for every tile, pentagons
+/// included, so outliers can be located by index.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+/// let stats = hexasphere.tile_stats();
+///
+/// let worst = &stats[hexasphere.worst_tiles_by_regularity(1)[0]];
+/// println!(
+///     "tile {} edges range from {:.3} to {:.3}",
+///     worst.index, worst.edge_length_min, worst.edge_length_max
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileStats {
+    /// Index of the tile in `Hexasphere::tiles`
+    pub index: usize,
+    /// Average distance from the center to the boundary points
+    pub radius: f64,
+    /// Length of the shortest boundary edge
+    pub edge_length_min: f64,
+    /// Length of the longest boundary edge
+    pub edge_length_max: f64,
+    /// Surface area of the tile polygon
+    pub area: f64,
+    /// Standard deviation of the edge lengths divided by their mean; 0.0 for
+    /// a polygon with equal sides
+    pub regularity: f64,
+}
+
+/// How completely the tiles cover the sphere.
+///
+/// Tile polygons are flat, so even touching tiles (`hex_size` 1.0) sit
+/// slightly beneath the sphere and their summed area falls short of
+/// `4πr²`; shrinking `hex_size` opens real gaps on top of that.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// let report = Hexasphere::new(10.0, 8, 1.0).coverage_report();
+/// println!("{:.2}% of the surface is missing", report.gap_fraction * 100.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    /// Sum of the flat polygon areas ([`Tile::get_area`])
+    pub planar_area_sum: f64,
+    /// Sum of the areas projected onto the sphere ([`Tile::get_spherical_area`])
+    pub spherical_area_sum: f64,
+    /// Surface area of the sphere, `4πr²`
+    pub sphere_area: f64,
+    /// Fraction of the sphere not covered by flat tile area,
+    /// `1 − planar_area_sum / sphere_area`
+    pub gap_fraction: f64,
+}
+
+/// How [`Hexasphere::uniform_hexagon_radius_for`] picks one radius for all hexagons.
+///
+/// Tile sizes vary across the sphere, so a single radius cannot avoid both
+/// gaps and overlaps. Each policy makes a different trade.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Hexasphere, UniformRadiusPolicy};
+/// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+/// let tight = hexasphere.uniform_hexagon_radius_for(UniformRadiusPolicy::NoOverlap);
+/// let loose = hexasphere.uniform_hexagon_radius_for(UniformRadiusPolicy::NoGaps);
+/// assert!(tight < loose);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UniformRadiusPolicy {
+    /// Mean hexagon radius, as [`Hexasphere::get_uniform_hexagon_radius`];
+    /// leaves both gaps and overlaps
+    #[default]
+    Mean,
+    /// Largest radius at which no two adjacent hexagons can intersect,
+    /// whatever their rotation; leaves gaps everywhere
+    NoOverlap,
+    /// Smallest radius at which every hexagon covers all of its edge
+    /// midpoints, whatever its rotation; overlaps everywhere
+    NoGaps,
 }
 
 impl Hexasphere {
@@ -64,7 +200,9 @@ impl Hexasphere {
     /// # Analysis Performed
     ///
     /// - **Size measurements**: Radius, edge length, and area for each hexagon
-    /// - **Statistical analysis**: Mean, min, max, and standard deviation
+    /// - **Shape measurements**: Minimum and mean compactness
+    /// - **Statistical analysis**: Mean, min, max, standard deviation, median
+    ///   and 5th/95th percentiles (linearly interpolated)
     /// - **Pentagon count**: Always exactly 12 for validation
     /// - **Quality metrics**: Variation coefficients for approximation assessment
     ///
@@ -107,67 +245,493 @@ impl Hexasphere {
     /// # Performance
     ///
     /// - Time complexity: O(n×m) where n = hexagon count, m = average boundary points
-    /// - Space complexity: O(1) additional memory (streaming calculation)
+    /// - Space complexity: O(n) for the per-hexagon measurements
     /// - Typical execution time: < 1ms for subdivision levels 0-6
     pub fn calculate_hexagon_stats(&self) -> HexagonStats {
-        let hexagons: Vec<&Tile> = self.tiles.iter().filter(|tile| tile.is_hexagon()).collect();
-        let pentagons: Vec<&Tile> = self
-            .tiles
-            .iter()
-            .filter(|tile| tile.is_pentagon())
+        let measurements: Vec<HexagonMeasurement> = self
+            .hexagons()
+            .map(|(_, hex)| HexagonMeasurement::of(hex))
             .collect();
+        hexagon_stats_from(&measurements, self.pentagons().count())
+    }
+
+    /// Compares the total tile area with the sphere's surface area.
+    ///
+    /// # Returns
+    ///
+    /// A [`CoverageReport`] with planar and spherical area sums
+    ///
+    /// # Use Cases
+    ///
+    /// - **Simulation**: Decide whether fluxes between tiles need area weighting
+    /// - **Parameter choice**: See how much surface a smaller `hex_size` gives up
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n×m) where n = tile count, m = average boundary points
+    /// - Space complexity: O(1)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let coarse = Hexasphere::new(10.0, 3, 1.0).coverage_report();
+    /// let fine = Hexasphere::new(10.0, 12, 1.0).coverage_report();
+    ///
+    /// assert!(fine.gap_fraction < coarse.gap_fraction);
+    /// assert!((coarse.spherical_area_sum / coarse.sphere_area - 1.0).abs() < 1e-3);
+    /// ```
+    pub fn coverage_report(&self) -> CoverageReport {
+        let (planar_area_sum, spherical_area_sum) =
+            self.tiles
+                .iter()
+                .fold((0.0, 0.0), |(planar, spherical), tile| {
+                    (
+                        planar + tile.get_area(),
+                        spherical + tile.get_spherical_area(self.radius),
+                    )
+                });
+        let sphere_area = 4.0 * PI * self.radius * self.radius;
+
+        CoverageReport {
+            planar_area_sum,
+            spherical_area_sum,
+            sphere_area,
+            gap_fraction: 1.0 - planar_area_sum / sphere_area,
+        }
+    }
+
+    /// Counts hexagons by radius in equal-width buckets.
+    ///
+    /// A single mean hides that hexagon sizes are bimodal: hexagons near the
+    /// pentagons are noticeably smaller than those in between. The histogram
+    /// spans the smallest to the largest hexagon radius.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - Number of buckets
+    ///
+    /// # Returns
+    ///
+    /// `(bucket_center, count)` for every bucket in ascending order, or an
+    /// empty vector if `bins` is 0 or there are no hexagons
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+    ///
+    /// for (center, count) in hexasphere.radius_histogram(10) {
+    ///     println!("{:.4}: {}", center, "#".repeat(count / 10));
+    /// }
+    /// ```
+    pub fn radius_histogram(&self, bins: usize) -> Vec<(f64, usize)> {
+        let radii = self.hexagon_radii();
+        if bins == 0 || radii.is_empty() {
+            return Vec::new();
+        }
 
-        if hexagons.is_empty() {
-            return HexagonStats {
-                total_hexagons: 0,
-                total_pentagons: pentagons.len(),
-                average_hexagon_radius: 0.0,
-                average_hexagon_edge_length: 0.0,
-                average_hexagon_area: 0.0,
-                min_hexagon_radius: 0.0,
-                max_hexagon_radius: 0.0,
-                radius_std_deviation: 0.0,
+        let min = radii.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = radii.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let width = (max - min) / bins as f64;
+
+        let mut counts = vec![0; bins];
+        for radius in radii {
+            let bin = if width > 0.0 {
+                (((radius - min) / width) as usize).min(bins - 1)
+            } else {
+                0
             };
+            counts[bin] += 1;
         }
 
-        let radii: Vec<f64> = hexagons
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(bin, count)| ((min + (bin as f64 + 0.5) * width).min(max), count))
+            .collect()
+    }
+
+    /// Average radius of every hexagonal tile, in tile order.
+    fn hexagon_radii(&self) -> Vec<f64> {
+        self.tiles
             .iter()
+            .filter(|tile| tile.is_hexagon())
             .map(|hex| hex.get_average_radius())
-            .collect();
-        let edge_lengths: Vec<f64> = hexagons
+            .collect()
+    }
+
+    /// Measures every tile individually.
+    ///
+    /// # Returns
+    ///
+    /// One [`TileStats`] per tile, indexed like `tiles`. Tiles with fewer than
+    /// two boundary points report zero for every measurement.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Distortion debugging**: Find which tiles are the outliers
+    /// - **Visualization**: Color tiles by size or regularity
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n×m) where n = tile count, m = average boundary points
+    /// - Space complexity: O(n) for the result
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let stats = hexasphere.tile_stats();
+    ///
+    /// assert_eq!(stats.len(), hexasphere.tiles.len());
+    /// assert!(stats.iter().all(|s| s.edge_length_min <= s.edge_length_max));
+    /// ```
+    pub fn tile_stats(&self) -> Vec<TileStats> {
+        self.tiles
             .iter()
-            .map(|hex| hex.get_average_edge_length())
-            .collect();
-        let areas: Vec<f64> = hexagons.iter().map(|hex| hex.get_area()).collect();
-
-        let avg_radius = radii.iter().sum::<f64>() / radii.len() as f64;
-        let avg_edge_length = edge_lengths.iter().sum::<f64>() / edge_lengths.len() as f64;
-        let avg_area = areas.iter().sum::<f64>() / areas.len() as f64;
-
-        let min_radius = radii.iter().copied().fold(f64::INFINITY, f64::min);
-        let max_radius = radii.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-
-        // Calculate standard deviation
-        let variance =
-            radii.iter().map(|r| (r - avg_radius).powi(2)).sum::<f64>() / radii.len() as f64;
-        let std_deviation = variance.sqrt();
-
-        HexagonStats {
-            total_hexagons: hexagons.len(),
-            total_pentagons: pentagons.len(),
-            average_hexagon_radius: avg_radius,
-            average_hexagon_edge_length: avg_edge_length,
-            average_hexagon_area: avg_area,
-            min_hexagon_radius: min_radius,
-            max_hexagon_radius: max_radius,
-            radius_std_deviation: std_deviation,
+            .enumerate()
+            .map(|(index, tile)| {
+                let n = tile.boundary.len();
+                if n < 2 {
+                    return TileStats {
+                        index,
+                        radius: 0.0,
+                        edge_length_min: 0.0,
+                        edge_length_max: 0.0,
+                        area: 0.0,
+                        regularity: 0.0,
+                    };
+                }
+
+                let edges: Vec<f64> = (0..n)
+                    .map(|i| tile.boundary[i].distance_to(&tile.boundary[(i + 1) % n]))
+                    .collect();
+                let mean = edges.iter().sum::<f64>() / n as f64;
+                let variance = edges.iter().map(|e| (e - mean).powi(2)).sum::<f64>() / n as f64;
+
+                TileStats {
+                    index,
+                    radius: tile.get_average_radius(),
+                    edge_length_min: edges.iter().copied().fold(f64::INFINITY, f64::min),
+                    edge_length_max: edges.iter().copied().fold(0.0, f64::max),
+                    area: tile.get_area(),
+                    regularity: if mean > 0.0 {
+                        variance.sqrt() / mean
+                    } else {
+                        0.0
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the `n` tiles with the most uneven edge lengths.
+    ///
+    /// # Returns
+    ///
+    /// Tile indices sorted by [`TileStats::regularity`], worst first. Ties keep
+    /// ascending index order. At most `n` indices are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let worst = hexasphere.worst_tiles_by_regularity(5);
+    /// assert_eq!(worst.len(), 5);
+    /// ```
+    pub fn worst_tiles_by_regularity(&self, n: usize) -> Vec<usize> {
+        top_tiles(self.tile_stats(), n, |stats| stats.regularity)
+    }
+
+    /// Returns the `n` tiles with the largest surface area.
+    ///
+    /// # Returns
+    ///
+    /// Tile indices sorted by [`TileStats::area`], largest first. Ties keep
+    /// ascending index order. At most `n` indices are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let largest = hexasphere.largest_tiles(3);
+    ///
+    /// let areas: Vec<f64> = largest.iter().map(|&i| hexasphere.tiles[i].get_area()).collect();
+    /// assert!(areas.windows(2).all(|w| w[0] >= w[1]));
+    /// ```
+    pub fn largest_tiles(&self, n: usize) -> Vec<usize> {
+        top_tiles(self.tile_stats(), n, |stats| stats.area)
+    }
+
+    /// Calculate how far tile interior angles deviate from regular polygons.
+    ///
+    /// Every interior angle (see [`Tile::interior_angles`]) is compared with the
+    /// interior angle of a regular polygon with the same number of sides: 120°
+    /// for hexagons and 108° for pentagons.
+    ///
+    /// # Returns
+    ///
+    /// A `DistortionStats` summarizing the absolute deviations. All fields are
+    /// zero if the hexasphere has no tiles.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Subdivision choice**: Pick the level where shapes are regular enough
+    /// - **Quality comparison**: Compare construction options by shape regularity
+    /// - **Debugging**: Locate the most distorted tile via `worst_tile`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(100.0, 4, 1.0);
+    /// let stats = hexasphere.calculate_distortion_stats();
+    ///
+    /// // Typical angles are within a couple of degrees of a regular polygon
+    /// assert!(stats.mean_angle_deviation.to_degrees() < 3.0);
+    /// ```
+    ///
+    /// # Behavior Across Subdivisions
+    ///
+    /// Higher subdivision does not make tiles more regular in shape. The
+    /// hexagons around each pentagon stay squashed at every level, so the
+    /// worst deviation grows slowly towards a limit of roughly 7° while the
+    /// mean settles around 1.7°. Only tile *size* becomes more uniform
+    /// relative to the sphere.
+    ///
+    /// Boundary coordinates are rounded to 3 decimals, so on small spheres with
+    /// many subdivisions the rounding itself adds measurable angle noise.
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n×m) where n = tile count, m = average boundary points
+    /// - Space complexity: O(m) additional memory per tile
+    pub fn calculate_distortion_stats(&self) -> DistortionStats {
+        let mut min_deviation = f64::INFINITY;
+        let mut max_deviation = 0.0;
+        let mut total_deviation = 0.0;
+        let mut angle_count = 0;
+        let mut worst_tile = 0;
+
+        for tile in &self.tiles {
+            let sides = tile.boundary.len() as f64;
+            let ideal = PI * (sides - 2.0) / sides;
+
+            for angle in tile.interior_angles() {
+                let deviation = (angle - ideal).abs();
+                min_deviation = f64::min(min_deviation, deviation);
+                if deviation > max_deviation {
+                    max_deviation = deviation;
+                    worst_tile = tile.id;
+                }
+                total_deviation += deviation;
+                angle_count += 1;
+            }
+        }
+
+        if angle_count == 0 {
+            return DistortionStats {
+                min_angle_deviation: 0.0,
+                max_angle_deviation: 0.0,
+                mean_angle_deviation: 0.0,
+                worst_tile: 0,
+            };
+        }
+
+        DistortionStats {
+            min_angle_deviation: min_deviation,
+            max_angle_deviation: max_deviation,
+            mean_angle_deviation: total_deviation / angle_count as f64,
+            worst_tile,
         }
     }
+
+    /// Picks one radius for all regular hexagons according to `policy`.
+    ///
+    /// Both strict policies only look at distances, so they hold for any
+    /// hexagon rotation, including the one from [`Tile::get_orientation`].
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Which trade between gaps and overlaps to make
+    ///
+    /// # Returns
+    ///
+    /// The radius (center to corner), or 0.0 if there are no hexagons:
+    ///
+    /// - [`UniformRadiusPolicy::Mean`]: The mean hexagon radius
+    /// - [`UniformRadiusPolicy::NoOverlap`]: Half the shortest distance
+    ///   between the centers of two adjacent hexagons, so their circumscribed
+    ///   circles touch at most
+    /// - [`UniformRadiusPolicy::NoGaps`]: The longest distance from a hexagon
+    ///   center to one of its edge midpoints, divided by √3/2 so that the
+    ///   inscribed circle reaches it
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n×m) where n = tile count, m = average boundary points
+    /// - Space complexity: O(1) for the strict policies, O(n) for the mean
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, UniformRadiusPolicy};
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let radius = hexasphere.uniform_hexagon_radius_for(UniformRadiusPolicy::NoOverlap);
+    ///
+    /// for tile in hexasphere.tiles.iter().filter(|tile| tile.is_hexagon()) {
+    ///     let mut hexagon = tile.get_regular_hexagon_params().unwrap();
+    ///     hexagon.radius = radius;
+    ///     // spawn_hexagon(hexagon.generate_mesh());
+    /// }
+    /// ```
+    pub fn uniform_hexagon_radius_for(&self, policy: UniformRadiusPolicy) -> f64 {
+        let hexagons = self.tiles.iter().filter(|tile| tile.is_hexagon());
+
+        match policy {
+            UniformRadiusPolicy::Mean => self.get_uniform_hexagon_radius(),
+            UniformRadiusPolicy::NoOverlap => {
+                let closest = hexagons
+                    .flat_map(|tile| {
+                        tile.neighbors
+                            .iter()
+                            .filter_map(|&id| self.tiles.get(id))
+                            .filter(|neighbor| neighbor.is_hexagon())
+                            .map(|neighbor| tile.center_point.distance_to(&neighbor.center_point))
+                    })
+                    .fold(f64::INFINITY, f64::min);
+                if closest.is_finite() {
+                    closest / 2.0
+                } else {
+                    0.0
+                }
+            }
+            UniformRadiusPolicy::NoGaps => {
+                let farthest = hexagons
+                    .flat_map(|tile| {
+                        let sides = tile.boundary.len();
+                        let center = Vector3::from(&tile.center_point);
+                        (0..sides).map(move |i| {
+                            let start = Vector3::from(&tile.boundary[i]);
+                            let end = Vector3::from(&tile.boundary[(i + 1) % sides]);
+                            center.distance_to(&((start + end) * 0.5))
+                        })
+                    })
+                    .fold(0.0, f64::max);
+                farthest * 2.0 / 3f64.sqrt()
+            }
+        }
+    }
+}
+
+/// Radius, edge length, area and compactness of one hexagon, the inputs of [`HexagonStats`].
+pub(crate) struct HexagonMeasurement {
+    radius: f64,
+    edge_length: f64,
+    area: f64,
+    compactness: f64,
+}
+
+impl HexagonMeasurement {
+    pub(crate) fn of(hex: &Tile) -> Self {
+        Self {
+            radius: hex.get_average_radius(),
+            edge_length: hex.get_average_edge_length(),
+            area: hex.get_area(),
+            compactness: hex.compactness(),
+        }
+    }
+}
+
+/// Aggregates per-hexagon measurements, given in tile order.
+pub(crate) fn hexagon_stats_from(
+    measurements: &[HexagonMeasurement],
+    total_pentagons: usize,
+) -> HexagonStats {
+    if measurements.is_empty() {
+        return HexagonStats {
+            total_hexagons: 0,
+            total_pentagons,
+            average_hexagon_radius: 0.0,
+            average_hexagon_edge_length: 0.0,
+            average_hexagon_area: 0.0,
+            min_hexagon_radius: 0.0,
+            max_hexagon_radius: 0.0,
+            radius_std_deviation: 0.0,
+            median_hexagon_radius: 0.0,
+            p05_hexagon_radius: 0.0,
+            p95_hexagon_radius: 0.0,
+            min_hexagon_compactness: 0.0,
+            mean_hexagon_compactness: 0.0,
+        };
+    }
+
+    let count = measurements.len() as f64;
+    let radii: Vec<f64> = measurements.iter().map(|m| m.radius).collect();
+
+    let avg_radius = radii.iter().sum::<f64>() / count;
+    let avg_edge_length = measurements.iter().map(|m| m.edge_length).sum::<f64>() / count;
+    let avg_area = measurements.iter().map(|m| m.area).sum::<f64>() / count;
+    let avg_compactness = measurements.iter().map(|m| m.compactness).sum::<f64>() / count;
+    let min_compactness = measurements
+        .iter()
+        .map(|m| m.compactness)
+        .fold(f64::INFINITY, f64::min);
+
+    let min_radius = radii.iter().copied().fold(f64::INFINITY, f64::min);
+    let max_radius = radii.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    // Calculate standard deviation
+    let variance = radii.iter().map(|r| (r - avg_radius).powi(2)).sum::<f64>() / count;
+    let std_deviation = variance.sqrt();
+
+    let mut sorted = radii;
+    sorted.sort_by(f64::total_cmp);
+
+    HexagonStats {
+        total_hexagons: measurements.len(),
+        total_pentagons,
+        average_hexagon_radius: avg_radius,
+        average_hexagon_edge_length: avg_edge_length,
+        average_hexagon_area: avg_area,
+        min_hexagon_radius: min_radius,
+        max_hexagon_radius: max_radius,
+        radius_std_deviation: std_deviation,
+        median_hexagon_radius: percentile(&sorted, 0.5),
+        p05_hexagon_radius: percentile(&sorted, 0.05),
+        p95_hexagon_radius: percentile(&sorted, 0.95),
+        min_hexagon_compactness: min_compactness,
+        mean_hexagon_compactness: avg_compactness,
+    }
+}
+
+/// Linearly interpolated percentile of ascending, non-empty `sorted` values.
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let position = fraction * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// Indices of the `n` tiles with the largest `key`, in descending order.
+///
+/// The sort is stable and `stats` is in index order, so ties keep ascending indices.
+fn top_tiles(mut stats: Vec<TileStats>, n: usize, key: impl Fn(&TileStats) -> f64) -> Vec<usize> {
+    stats.sort_by(|a, b| key(b).total_cmp(&key(a)));
+    stats.into_iter().take(n).map(|stats| stats.index).collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use super::UniformRadiusPolicy;
+    use crate::geometry::Point;
     use crate::hexasphere::core::Hexasphere;
+    use std::f64::consts::PI;
 
     #[test]
     fn test_hexagon_stats_basic() {
@@ -382,4 +946,258 @@ mod tests {
             stats.average_hexagon_radius
         );
     }
+
+    #[test]
+    fn test_interior_angle_sums() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+
+        for tile in &hexasphere.tiles {
+            let angles = tile.interior_angles();
+            assert_eq!(angles.len(), tile.boundary.len());
+
+            // Tiles are nearly flat, so angles sum close to the planar (n - 2)·π
+            let expected = PI * (tile.boundary.len() as f64 - 2.0);
+            let sum: f64 = angles.iter().sum();
+            assert!(
+                (sum - expected).abs() < 0.05,
+                "tile {} angle sum {} vs expected {}",
+                tile.id,
+                sum,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_distortion_stats_relationships() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let stats = hexasphere.calculate_distortion_stats();
+
+        assert!(stats.min_angle_deviation >= 0.0);
+        assert!(stats.min_angle_deviation <= stats.mean_angle_deviation);
+        assert!(stats.mean_angle_deviation <= stats.max_angle_deviation);
+
+        // The worst tile really contains the largest deviation
+        let worst = &hexasphere.tiles[stats.worst_tile];
+        let ideal = PI * (worst.boundary.len() as f64 - 2.0) / worst.boundary.len() as f64;
+        let worst_deviation = worst
+            .interior_angles()
+            .iter()
+            .map(|angle| (angle - ideal).abs())
+            .fold(0.0, f64::max);
+        assert_eq!(worst_deviation, stats.max_angle_deviation);
+    }
+
+    #[test]
+    fn test_distortion_across_subdivisions() {
+        let level2 = Hexasphere::new(100.0, 2, 1.0).calculate_distortion_stats();
+        let level4 = Hexasphere::new(100.0, 4, 1.0).calculate_distortion_stats();
+        let level6 = Hexasphere::new(100.0, 6, 1.0).calculate_distortion_stats();
+
+        // Hexagons next to pentagons stay distorted, so the worst case does not
+        // shrink with subdivision but stays bounded
+        assert!(level4.max_angle_deviation > level2.max_angle_deviation);
+        for stats in [&level2, &level4, &level6] {
+            assert!(stats.max_angle_deviation.to_degrees() < 8.0);
+        }
+
+        // The average settles once there are enough hexagons
+        let ratio = level6.mean_angle_deviation / level4.mean_angle_deviation;
+        assert!((ratio - 1.0).abs() < 0.1, "mean deviation ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_distortion_stats_icosahedron() {
+        // Level 0 only has pentagons, which are regular by symmetry
+        let stats = Hexasphere::new(1.0, 0, 1.0).calculate_distortion_stats();
+        assert!(stats.max_angle_deviation < 0.01);
+    }
+
+    #[test]
+    fn test_worst_regularity_tiles_surround_pentagons() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let worst = hexasphere.worst_tiles_by_regularity(12);
+        assert_eq!(worst.len(), 12);
+
+        for &index in &worst {
+            let tile = &hexasphere.tiles[index];
+            let near_pentagon = tile.is_pentagon()
+                || tile
+                    .neighbors
+                    .iter()
+                    .any(|&neighbor| hexasphere.tiles[neighbor].is_pentagon());
+            assert!(near_pentagon, "tile {index} is far from every pentagon");
+        }
+    }
+
+    #[test]
+    fn test_tile_rankings_are_sorted_and_sized() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let stats = hexasphere.tile_stats();
+        assert_eq!(stats.len(), hexasphere.tiles.len());
+        assert!(stats.iter().enumerate().all(|(i, s)| s.index == i));
+        assert_eq!(stats.iter().filter(|s| s.regularity.is_nan()).count(), 0);
+
+        let largest = hexasphere.largest_tiles(20);
+        assert_eq!(largest.len(), 20);
+        for pair in largest.windows(2) {
+            let (a, b) = (&stats[pair[0]], &stats[pair[1]]);
+            assert!(a.area > b.area || (a.area == b.area && a.index < b.index));
+        }
+
+        let all = hexasphere.worst_tiles_by_regularity(usize::MAX);
+        assert_eq!(all.len(), hexasphere.tiles.len());
+        assert_eq!(all, hexasphere.worst_tiles_by_regularity(usize::MAX));
+        assert!(hexasphere.largest_tiles(0).is_empty());
+    }
+
+    #[test]
+    fn test_radius_percentiles_are_monotone() {
+        for divisions in [2, 5, 9] {
+            let stats = Hexasphere::new(10.0, divisions, 1.0).calculate_hexagon_stats();
+            assert!(stats.min_hexagon_radius <= stats.p05_hexagon_radius);
+            assert!(stats.p05_hexagon_radius <= stats.median_hexagon_radius);
+            assert!(stats.median_hexagon_radius <= stats.p95_hexagon_radius);
+            assert!(stats.p95_hexagon_radius <= stats.max_hexagon_radius);
+        }
+    }
+
+    #[test]
+    fn test_hexagon_compactness_stats() {
+        let regular = PI / (2.0 * 3f64.sqrt());
+        let stats = Hexasphere::new(10.0, 6, 1.0).calculate_hexagon_stats();
+
+        assert!(stats.min_hexagon_compactness > 0.0);
+        assert!(stats.min_hexagon_compactness <= stats.mean_hexagon_compactness);
+        assert!(stats.mean_hexagon_compactness <= regular);
+        assert!(regular - stats.mean_hexagon_compactness < 0.01);
+
+        let empty = Hexasphere::new(10.0, 1, 1.0).calculate_hexagon_stats();
+        assert_eq!(empty.min_hexagon_compactness, 0.0);
+        assert_eq!(empty.mean_hexagon_compactness, 0.0);
+    }
+
+    #[test]
+    fn test_radius_histogram() {
+        let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+        let stats = hexasphere.calculate_hexagon_stats();
+
+        for bins in [1, 7, 50] {
+            let histogram = hexasphere.radius_histogram(bins);
+            assert_eq!(histogram.len(), bins);
+            let total: usize = histogram.iter().map(|(_, count)| count).sum();
+            assert_eq!(total, stats.total_hexagons);
+            for (center, _) in &histogram {
+                assert!(*center >= stats.min_hexagon_radius);
+                assert!(*center <= stats.max_hexagon_radius);
+            }
+            assert!(histogram.windows(2).all(|w| w[0].0 < w[1].0));
+        }
+
+        assert!(hexasphere.radius_histogram(0).is_empty());
+        assert!(Hexasphere::new(10.0, 1, 1.0).radius_histogram(5).is_empty());
+    }
+
+    #[test]
+    fn test_coverage_gap_shrinks_with_subdivision() {
+        let gaps: Vec<f64> = [1, 2, 3, 5, 8, 12]
+            .iter()
+            .map(|&divisions| {
+                let report = Hexasphere::new(10.0, divisions, 1.0).coverage_report();
+                assert!((report.spherical_area_sum / report.sphere_area - 1.0).abs() < 1e-3);
+                report.gap_fraction
+            })
+            .collect();
+
+        assert!(gaps[0] > 0.0);
+        assert!(gaps.windows(2).all(|w| w[1] < w[0]), "{gaps:?}");
+    }
+
+    #[test]
+    fn test_coverage_gap_grows_as_tiles_shrink() {
+        let gaps: Vec<f64> = [1.0, 0.9, 0.7, 0.5]
+            .iter()
+            .map(|&hex_size| {
+                Hexasphere::new(10.0, 4, hex_size)
+                    .coverage_report()
+                    .gap_fraction
+            })
+            .collect();
+
+        assert!(gaps.windows(2).all(|w| w[1] > w[0]), "{gaps:?}");
+    }
+
+    #[test]
+    fn test_no_overlap_radius_separates_adjacent_hexagons() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let radius = hexasphere.uniform_hexagon_radius_for(UniformRadiusPolicy::NoOverlap);
+        assert!(radius > 0.0);
+
+        let hexagon = |id: usize| {
+            let mut params = hexasphere.tiles[id].get_regular_hexagon_params()?;
+            params.radius = radius;
+            Some((params.center.clone(), params.generate_vertices()))
+        };
+        // Bounding circle of a generated vertex set around its center
+        let circle = |center: &Point, vertices: &[Point]| {
+            vertices
+                .iter()
+                .map(|vertex| center.distance_to(vertex))
+                .fold(0.0, f64::max)
+        };
+
+        let mut pairs = 0;
+        for tile in hexasphere.tiles.iter().filter(|tile| tile.is_hexagon()) {
+            for &neighbor in &tile.neighbors {
+                let (Some((a, a_vertices)), Some((b, b_vertices))) =
+                    (hexagon(tile.id), hexagon(neighbor))
+                else {
+                    continue;
+                };
+                // generate_vertices rounds to three decimals
+                let reach = circle(&a, &a_vertices) + circle(&b, &b_vertices);
+                assert!(a.distance_to(&b) >= reach - 2e-3);
+                pairs += 1;
+            }
+        }
+        assert!(pairs > 0);
+    }
+
+    #[test]
+    fn test_no_gaps_radius_covers_edge_midpoints() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let radius = hexasphere.uniform_hexagon_radius_for(UniformRadiusPolicy::NoGaps);
+        let apothem = radius * 3f64.sqrt() / 2.0;
+
+        for tile in hexasphere.tiles.iter().filter(|tile| tile.is_hexagon()) {
+            let sides = tile.boundary.len();
+            for i in 0..sides {
+                let midpoint = tile.boundary[i].segment(&tile.boundary[(i + 1) % sides], 0.5);
+                assert!(tile.center_point.distance_to(&midpoint) <= apothem + 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_radius_policies_are_ordered() {
+        for divisions in [2, 4, 8] {
+            let hexasphere = Hexasphere::new(10.0, divisions, 1.0);
+            let radius = |policy| hexasphere.uniform_hexagon_radius_for(policy);
+
+            let mean = radius(UniformRadiusPolicy::Mean);
+            assert_eq!(mean, hexasphere.get_uniform_hexagon_radius());
+            assert!(radius(UniformRadiusPolicy::NoOverlap) < mean);
+            assert!(mean < radius(UniformRadiusPolicy::NoGaps));
+        }
+
+        // The icosahedron has no hexagons at all
+        let icosahedron = Hexasphere::new(10.0, 1, 1.0);
+        for policy in [
+            UniformRadiusPolicy::Mean,
+            UniformRadiusPolicy::NoOverlap,
+            UniformRadiusPolicy::NoGaps,
+        ] {
+            assert_eq!(icosahedron.uniform_hexagon_radius_for(policy), 0.0);
+        }
+    }
 }
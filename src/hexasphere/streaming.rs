@@ -0,0 +1,99 @@
+//! Tile construction without storing the finished sphere.
+//!
+//! [`Hexasphere::new`] keeps every tile, with its boundary, faces and
+//! neighbor ids, until construction ends. Streaming builds the same tiles
+//! one at a time and hands each to a callback, so only the indexed geodesic
+//! mesh stays in memory.
+
+use crate::hexasphere::builder::HexasphereBuilder;
+use crate::hexasphere::core::{Hexasphere, TilePlan};
+use crate::tile::core::Tile;
+
+impl Hexasphere {
+    /// Builds a hexasphere tile by tile, passing each tile to `f` instead of storing it.
+    ///
+    /// Runs the same pipeline as [`Hexasphere::new`] and produces identical
+    /// tiles, ids and neighbor lists, in id order. Each tile is dropped once
+    /// `f` returns unless `f` keeps it.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the target sphere
+    /// * `num_divisions` - Number of subdivision levels, as for [`Hexasphere::new`]
+    /// * `hex_size` - Scale factor for tile boundaries, as for [`Hexasphere::new`]
+    /// * `f` - Called once per tile with its id and the finished tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let mut count = 0;
+    /// let mut area = 0.0;
+    /// Hexasphere::for_each_tile(10.0, 4, 1.0, |_, tile| {
+    ///     count += 1;
+    ///     area += tile.get_area();
+    /// });
+    /// assert_eq!(count, 162);
+    /// ```
+    ///
+    /// # Use Cases
+    ///
+    /// - **One-pass export**: Write very large spheres straight to a file
+    /// - **Aggregation**: Compute statistics without keeping the tiles
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n), like [`Hexasphere::new`]
+    /// - Space complexity: O(n) for the indexed vertices and triangles and a
+    ///   vertex-to-id lookup, but only one tile is alive at a time
+    ///
+    /// # Panics
+    ///
+    /// Panics for oversized spheres, like [`Hexasphere::new`].
+    pub fn for_each_tile(
+        radius: f64,
+        num_divisions: usize,
+        hex_size: f64,
+        mut f: impl FnMut(usize, Tile),
+    ) {
+        let options = HexasphereBuilder::new()
+            .radius(radius)
+            .goldberg(num_divisions, 0)
+            .hex_size(hex_size);
+        if let Err(error) = options.validate_tile_count() {
+            panic!("{}", error);
+        }
+
+        let plan = TilePlan::new(&options, &mut |_, _| {});
+        for (id, (vertex, triangles)) in plan.tile_vertices.iter().enumerate() {
+            f(id, plan.build_tile(*vertex, triangles));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_each_tile_matches_materialized_sphere() {
+        let hexasphere = Hexasphere::new(10.0, 4, 0.9);
+
+        let mut count = 0;
+        let mut area = 0.0;
+        Hexasphere::for_each_tile(10.0, 4, 0.9, |id, tile| {
+            let expected = &hexasphere.tiles[id];
+            assert_eq!(tile.id, id);
+            assert_eq!(tile.center_point, expected.center_point);
+            assert_eq!(tile.boundary, expected.boundary);
+            assert_eq!(tile.neighbors, expected.neighbors);
+            assert_eq!(tile.base_face, expected.base_face);
+            count += 1;
+            area += tile.get_area();
+        });
+
+        let expected_area: f64 = hexasphere.tiles.iter().map(Tile::get_area).sum();
+        assert_eq!(count, hexasphere.tiles.len());
+        assert!((area - expected_area).abs() < 1e-9);
+    }
+}
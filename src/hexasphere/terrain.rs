@@ -0,0 +1,214 @@
+//! Per-tile elevation displacement for terrain meshes.
+
+use crate::geometry::{Point, Vector3};
+use crate::hexasphere::core::Hexasphere;
+use crate::hexasphere::mesh_data::{MeshData, MeshOptions};
+use crate::tile::core::Tile;
+use std::collections::HashMap;
+
+impl Hexasphere {
+    /// Returns a copy with every tile raised or lowered by its own height.
+    ///
+    /// Each point is pushed along its own direction from the sphere's
+    /// center:
+    ///
+    /// - **Centers** move by their tile's height
+    /// - **Boundary points** move by the average height of all tiles sharing
+    ///   that exact point, so touching tiles (`hex_size` 1.0) stay crack-free
+    ///   and shared corners remain exactly equal
+    ///
+    /// Moved points are rounded to [`Hexasphere::vertex_precision`] decimals.
+    ///
+    /// Tile ids, order and neighbors are unchanged. `radius` keeps the base
+    /// sphere's radius, while each tile's `sphere_radius` grows by its height
+    /// so [`Tile::lat_lon`] still locates the raised center.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Displacement of each tile, given its id and the tile;
+    ///   negative values push inward
+    ///
+    /// # Returns
+    ///
+    /// The displaced hexasphere
+    ///
+    /// # Use Cases
+    ///
+    /// - **Planet terrain**: Mountains and ocean floors from a noise function
+    /// - **Data visualization**: Per-tile values shown as extruded height
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = boundary points per tile
+    /// - Space complexity: O(n × k) for the shared corner heights
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let terrain = hexasphere.displace(|id, _| if id % 2 == 0 { 0.5 } else { 0.0 });
+    ///
+    /// let raised = &terrain.tiles[0].center_point;
+    /// let length = (raised.x.powi(2) + raised.y.powi(2) + raised.z.powi(2)).sqrt();
+    /// assert!((length - 10.5).abs() < 1e-2);
+    /// ```
+    pub fn displace(&self, height: impl Fn(usize, &Tile) -> f64) -> Hexasphere {
+        let heights: Vec<f64> = self
+            .tiles
+            .iter()
+            .map(|tile| height(tile.id, tile))
+            .collect();
+
+        let mut corner_heights: HashMap<&Point, (f64, usize)> = HashMap::new();
+        for tile in &self.tiles {
+            for point in &tile.boundary {
+                let entry = corner_heights.entry(point).or_insert((0.0, 0));
+                entry.0 += heights[tile.id];
+                entry.1 += 1;
+            }
+        }
+
+        let center = self.center().clone();
+        let precision = self.vertex_precision();
+        let mut displaced = self.clone();
+        for tile in &mut displaced.tiles {
+            tile.clear_cache();
+            for point in &mut tile.boundary {
+                let (sum, count) = corner_heights[&*point];
+                *point = push_outward(&center, point, sum / count as f64, precision);
+            }
+            tile.center_point =
+                push_outward(&center, &tile.center_point, heights[tile.id], precision);
+            tile.sphere_radius += heights[tile.id];
+        }
+        displaced.refresh_neighbor_ids();
+        displaced
+    }
+
+    /// Builds a render mesh of the displaced surface.
+    ///
+    /// Shorthand for [`Hexasphere::displace`] followed by
+    /// [`Hexasphere::to_mesh`] with the default [`MeshOptions`], whose flat
+    /// per-tile normals follow the displaced polygons.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let mesh = hexasphere.displaced_mesh(|_, tile| tile.center_point.y.abs() * 0.05);
+    ///
+    /// assert_eq!(mesh.tile_ranges.len(), hexasphere.tiles.len());
+    /// ```
+    pub fn displaced_mesh(&self, height: impl Fn(usize, &Tile) -> f64) -> MeshData {
+        self.displace(height).to_mesh(MeshOptions::default())
+    }
+}
+
+/// Moves a point along its direction from the sphere `center` by `height`,
+/// rounding the result to `decimals`.
+///
+/// A zero height returns the point untouched rather than re-rounding it.
+fn push_outward(center: &Point, point: &Point, height: f64, decimals: u32) -> Point {
+    if height == 0.0 {
+        return point.clone();
+    }
+    let position = Vector3::from(point);
+    let offset = (&position - &Vector3::from(center)).normalize() * height;
+    let moved = position + offset;
+    Point::with_precision(moved.x, moved.y, moved.z, decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Point;
+    use crate::hexasphere::builder::HexasphereBuilder;
+    use crate::hexasphere::core::Hexasphere;
+
+    fn length(point: &Point) -> f64 {
+        (point.x * point.x + point.y * point.y + point.z * point.z).sqrt()
+    }
+
+    #[test]
+    fn test_zero_height_is_identity() {
+        let hexasphere = Hexasphere::new(10.0, 3, 0.9);
+        let displaced = hexasphere.displace(|_, _| 0.0);
+
+        for (a, b) in hexasphere.tiles.iter().zip(&displaced.tiles) {
+            assert_eq!(a.center_point, b.center_point);
+            assert_eq!(a.boundary, b.boundary);
+            assert_eq!(a.neighbor_ids, b.neighbor_ids);
+        }
+    }
+
+    #[test]
+    fn test_constant_height_raises_every_point() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let displaced = hexasphere.displace(|_, _| 2.5);
+
+        for (a, b) in hexasphere.tiles.iter().zip(&displaced.tiles) {
+            assert!((length(&b.center_point) - length(&a.center_point) - 2.5).abs() < 2e-3);
+            for (p, q) in a.boundary.iter().zip(&b.boundary) {
+                assert!((length(q) - length(p) - 2.5).abs() < 2e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shared_corners_stay_shared() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let displaced = hexasphere.displace(|id, _| (id % 5) as f64 * 0.3);
+
+        for (tile, raised) in hexasphere.tiles.iter().zip(&displaced.tiles) {
+            for &neighbor in &tile.neighbors {
+                let original = &hexasphere.tiles[neighbor];
+                let moved = &displaced.tiles[neighbor];
+                for (i, point) in tile.boundary.iter().enumerate() {
+                    if let Some(j) = original.boundary.iter().position(|p| p == point) {
+                        assert_eq!(raised.boundary[i], moved.boundary[j]);
+                    }
+                }
+            }
+        }
+
+        let mesh = hexasphere.displaced_mesh(|id, _| (id % 5) as f64 * 0.3);
+        assert_eq!(mesh.tile_ranges.len(), hexasphere.tiles.len());
+    }
+
+    #[test]
+    fn test_stored_radius_follows_height() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let displaced = hexasphere.displace(|id, _| (id % 4) as f64 * 0.5 - 0.5);
+
+        for (tile, raised) in hexasphere.tiles.iter().zip(&displaced.tiles) {
+            let height = (tile.id % 4) as f64 * 0.5 - 0.5;
+            assert_eq!(raised.sphere_radius, 10.0 + height);
+            // The raised center is still reported where it started, up to
+            // the 3-decimal rounding of the moved points
+            let (before, after) = (tile.lat_lon(), raised.lat_lon());
+            assert!((before.lat - after.lat).abs() < 5e-2);
+            assert!((before.lon - after.lon).abs() < 5e-2);
+        }
+        assert_eq!(displaced.radius, 10.0);
+    }
+
+    #[test]
+    fn test_high_precision_sphere_stays_valid() {
+        let hexasphere = HexasphereBuilder::new()
+            .radius(0.001)
+            .subdivisions(3)
+            .vertex_precision(9)
+            .build()
+            .unwrap();
+        let displaced = hexasphere.displace(|id, _| (id % 3) as f64 * 1e-5);
+
+        for (tile, raised) in hexasphere.tiles.iter().zip(&displaced.tiles) {
+            let height = (tile.id % 3) as f64 * 1e-5;
+            assert!(
+                (length(&raised.center_point) - length(&tile.center_point) - height).abs() < 1e-8
+            );
+        }
+        assert!(displaced.validate().is_ok());
+    }
+}
@@ -0,0 +1,699 @@
+//! Graph traversal over tile adjacency.
+//!
+//! Tiles form a graph through their `neighbors` lists. The functions here walk
+//! that graph iteratively with an explicit queue, so arbitrarily large regions
+//! never risk overflowing the stack.
+
+use crate::geometry::Vector3;
+use crate::hexasphere::core::Hexasphere;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::f64::consts::TAU;
+
+impl Hexasphere {
+    /// Collects the connected region of tiles reachable from `seed` while `predicate` holds.
+    ///
+    /// Performs a breadth-first search through tile neighbors, only entering
+    /// tiles for which `predicate(tile_id)` returns `true`. Every tile is
+    /// visited at most once.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Id of the tile to start from
+    /// * `predicate` - Decides whether a tile belongs to the region
+    ///
+    /// # Returns
+    ///
+    /// Ids of the region's tiles in breadth-first order, starting with `seed`.
+    /// Empty if `seed` is out of range or does not satisfy the predicate.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Continent generation**: Grow land masses from seed tiles
+    /// - **Territory**: Find all tiles connected to a city through owned land
+    /// - **Paint bucket**: Recolor a connected area in an editor
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(r × k) where r = region size, k = neighbors per tile
+    /// - Space complexity: O(n) for the visited set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    ///
+    /// // All tiles in the northern hemisphere connected to the north-most tile
+    /// let north = hexasphere
+    ///     .tiles
+    ///     .iter()
+    ///     .max_by(|a, b| a.center_point.z.total_cmp(&b.center_point.z))
+    ///     .unwrap()
+    ///     .id;
+    /// let region = hexasphere.flood_fill(north, |id| hexasphere.tiles[id].center_point.z > 0.0);
+    /// assert_eq!(region[0], north);
+    /// ```
+    pub fn flood_fill(&self, seed: usize, predicate: impl Fn(usize) -> bool) -> Vec<usize> {
+        let mut visited = vec![false; self.tiles.len()];
+        if seed >= self.tiles.len() || !predicate(seed) {
+            return Vec::new();
+        }
+        self.collect_region(seed, &predicate, &mut visited)
+    }
+
+    /// Splits all tiles satisfying `predicate` into connected regions.
+    ///
+    /// Two tiles are in the same component if a path of neighboring tiles,
+    /// all satisfying the predicate, connects them.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Decides whether a tile takes part in any component
+    ///
+    /// # Returns
+    ///
+    /// One vector of tile ids per component. Components are ordered by their
+    /// lowest tile id, and each lists its tiles in breadth-first order from
+    /// that lowest id.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Island detection**: Count separate land masses
+    /// - **Validation**: Check that generated regions are contiguous
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = neighbors per tile
+    /// - Space complexity: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    ///
+    /// // The 12 pentagons are never adjacent, so each is its own component
+    /// let components = hexasphere.connected_components(|id| hexasphere.tiles[id].is_pentagon());
+    /// assert_eq!(components.len(), 12);
+    /// ```
+    pub fn connected_components(&self, predicate: impl Fn(usize) -> bool) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.tiles.len()];
+        let mut components = Vec::new();
+
+        for id in 0..self.tiles.len() {
+            if !visited[id] && predicate(id) {
+                components.push(self.collect_region(id, &predicate, &mut visited));
+            }
+        }
+
+        components
+    }
+
+    /// Computes the hop distance from every tile to the nearest seed tile.
+    ///
+    /// Runs a multi-source breadth-first search over the neighbor graph, so each
+    /// value is the minimum number of tile-to-tile steps needed to reach any seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seeds` - Ids of the source tiles; out-of-range ids are ignored
+    ///
+    /// # Returns
+    ///
+    /// One distance per tile, indexed by tile id. Seeds have distance 0 and
+    /// tiles that cannot be reached (e.g. when `seeds` is empty) have `u32::MAX`.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Falloff**: Influence or pollution that weakens with distance
+    /// - **Distance to coast**: Seed with all coastal tiles
+    /// - **Movement ranges**: Tiles reachable within a number of turns
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = neighbors per tile
+    /// - Space complexity: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let field = hexasphere.distance_field(&[0]);
+    ///
+    /// assert_eq!(field[0], 0);
+    /// for &neighbor in &hexasphere.tiles[0].neighbors {
+    ///     assert_eq!(field[neighbor], 1);
+    /// }
+    /// ```
+    pub fn distance_field(&self, seeds: &[usize]) -> Vec<u32> {
+        let mut distances = vec![u32::MAX; self.tiles.len()];
+        let mut queue = VecDeque::new();
+
+        for &seed in seeds {
+            if seed < distances.len() && distances[seed] != 0 {
+                distances[seed] = 0;
+                queue.push_back(seed);
+            }
+        }
+
+        while let Some(id) = queue.pop_front() {
+            let next = distances[id] + 1;
+            for &neighbor in &self.tiles[id].neighbors {
+                if distances[neighbor] == u32::MAX {
+                    distances[neighbor] = next;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Computes the geodesic distance from every tile to the nearest seed tile.
+    ///
+    /// Like [`Hexasphere::distance_field`], but each step between neighbors
+    /// costs the great-circle distance between their centers on a sphere of
+    /// this hexasphere's radius, and shortest paths are found with Dijkstra's
+    /// algorithm. This removes the bias of hop counts around pentagons and
+    /// gives distances in world units.
+    ///
+    /// # Arguments
+    ///
+    /// * `seeds` - Ids of the source tiles; out-of-range ids are ignored
+    ///
+    /// # Returns
+    ///
+    /// One distance per tile, indexed by tile id. Seeds have distance 0.0 and
+    /// unreachable tiles have `f64::INFINITY`.
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k × log n) where n = tiles, k = neighbors per tile
+    /// - Space complexity: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let field = hexasphere.geodesic_distance_field(&[0]);
+    ///
+    /// // Paths along tile centers are never shorter than half the circumference
+    /// let antipode = field.iter().cloned().fold(0.0, f64::max);
+    /// assert!(antipode >= std::f64::consts::PI * 10.0 - 1e-6);
+    /// ```
+    pub fn geodesic_distance_field(&self, seeds: &[usize]) -> Vec<f64> {
+        let directions: Vec<Vector3> = self
+            .tiles
+            .iter()
+            .map(|tile| self.relative(&tile.center_point).normalize())
+            .collect();
+
+        let mut distances = vec![f64::INFINITY; self.tiles.len()];
+        let mut heap = BinaryHeap::new();
+
+        for &seed in seeds {
+            if seed < distances.len() {
+                distances[seed] = 0.0;
+                heap.push(QueueEntry {
+                    distance: 0.0,
+                    id: seed,
+                });
+            }
+        }
+
+        while let Some(QueueEntry { distance, id }) = heap.pop() {
+            if distance > distances[id] {
+                continue;
+            }
+            for &neighbor in &self.tiles[id].neighbors {
+                let (a, b) = (&directions[id], &directions[neighbor]);
+                let angle = angle_between(a, b);
+                let candidate = distance + angle * self.radius;
+                if candidate < distances[neighbor] {
+                    distances[neighbor] = candidate;
+                    heap.push(QueueEntry {
+                        distance: candidate,
+                        id: neighbor,
+                    });
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Finds every tile whose center lies within an angle of another tile's center.
+    ///
+    /// The angle is measured at the sphere's center between the two center
+    /// directions, so it corresponds to a great-circle distance on the surface.
+    /// Rather than scanning every tile, the search expands through neighbors
+    /// and stops at tiles that cannot reach the query cap. A tile is explored
+    /// while its center is within `angle` plus its own reach (the largest
+    /// angle to one of its neighbors' centers), which keeps tiles near the rim
+    /// connected even when the tiles in between have centers just outside.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Id of the tile at the middle of the query
+    /// * `angle` - Largest angle in radians between center directions; `π` or
+    ///   more covers the whole sphere
+    ///
+    /// # Returns
+    ///
+    /// Tile ids ordered by increasing angle from `center`, ties by id, so
+    /// `center` itself comes first. Empty if `center` is out of range or
+    /// `angle` is negative or NaN.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Area of effect**: Everything within a blast radius
+    /// - **Selection**: Tiles within a circle drawn by the user
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(r × k + r log r) where r = tiles near the cap, k = neighbors per tile
+    /// - Space complexity: O(n) for the visited set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+    ///
+    /// let nearby = hexasphere.tiles_within_angular_radius(0, 0.3);
+    /// assert_eq!(nearby[0], 0);
+    /// assert!(nearby.len() > 7);
+    ///
+    /// let everything = hexasphere.tiles_within_angular_radius(0, std::f64::consts::PI);
+    /// assert_eq!(everything.len(), hexasphere.tiles.len());
+    /// ```
+    pub fn tiles_within_angular_radius(&self, center: usize, angle: f64) -> Vec<usize> {
+        if center >= self.tiles.len() || angle.is_nan() || angle < 0.0 {
+            return Vec::new();
+        }
+
+        let direction = |id: usize| self.relative(&self.tiles[id].center_point).normalize();
+        let origin = direction(center);
+        let reach = |id: usize, own: &Vector3| {
+            self.tiles[id]
+                .neighbors
+                .iter()
+                .map(|&neighbor| angle_between(own, &direction(neighbor)))
+                .fold(0.0, f64::max)
+        };
+
+        let mut visited = vec![false; self.tiles.len()];
+        let mut queue = VecDeque::from([center]);
+        visited[center] = true;
+        let mut found = Vec::new();
+
+        while let Some(id) = queue.pop_front() {
+            let own = direction(id);
+            let distance = angle_between(&origin, &own);
+            if distance <= angle {
+                found.push((distance, id));
+            }
+            if distance - reach(id, &own) > angle {
+                continue;
+            }
+
+            for &neighbor in &self.tiles[id].neighbors {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        found.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Finds every tile whose center is within a surface distance of another tile's center.
+    ///
+    /// Converts the distance to an angle through the sphere radius and calls
+    /// [`Hexasphere::tiles_within_angular_radius`].
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Id of the tile at the middle of the query
+    /// * `distance` - Great-circle distance along the surface, in the same units as `radius`
+    ///
+    /// # Returns
+    ///
+    /// Tile ids ordered by increasing distance from `center`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// // An Earth-sized sphere: everything within 500 km of tile 0
+    /// let earth = Hexasphere::new(6371.0, 16, 1.0);
+    /// let region = earth.tiles_within_distance(0, 500.0);
+    /// assert!(region.contains(&0));
+    /// ```
+    pub fn tiles_within_distance(&self, center: usize, distance: f64) -> Vec<usize> {
+        self.tiles_within_angular_radius(center, distance / self.radius)
+    }
+
+    /// Orders all tiles in a spiral outward from a start tile.
+    ///
+    /// Tiles are grouped into rings by their neighbor-step distance from
+    /// `start` (see [`Hexasphere::distance_field`]). Within a ring they are
+    /// sorted by their bearing around `start`: counter-clockwise seen from
+    /// outside the sphere, beginning at the direction of the first neighbor
+    /// of `start`. Ties are broken by id, so the order is deterministic.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Id of the tile to spiral out from
+    ///
+    /// # Returns
+    ///
+    /// Every tile id exactly once, starting with `start`. The first ring
+    /// equals `tiles[start].neighbors`. Tiles unreachable from `start` come
+    /// last, ordered by id. Empty if `start` is out of range.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Progressive loading**: Stream terrain outward from the camera
+    /// - **Reveal effects**: Uncover a planet ring by ring
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n log n) where n = tile count
+    /// - Space complexity: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let order = hexasphere.spiral_order(7);
+    ///
+    /// assert_eq!(order.len(), hexasphere.tiles.len());
+    /// assert_eq!(order[0], 7);
+    /// assert_eq!(order[1..7], hexasphere.tiles[7].neighbors[..]);
+    /// ```
+    pub fn spiral_order(&self, start: usize) -> Vec<usize> {
+        if start >= self.tiles.len() {
+            return Vec::new();
+        }
+
+        let depths = self.distance_field(&[start]);
+        let up = self.relative(&self.tiles[start].center_point).normalize();
+        let tangent = |id: usize| {
+            self.relative(&self.tiles[id].center_point)
+                .normalize()
+                .reject_from(&up)
+        };
+        let reference = match self.tiles[start].neighbors.first() {
+            Some(&neighbor) => tangent(neighbor),
+            None => Vector3::new(0.0, 0.0, 0.0),
+        };
+
+        // Bearing in [0, 2π), counter-clockwise around the outward direction
+        let bearing = |id: usize| {
+            let direction = tangent(id);
+            let angle = reference
+                .cross(&direction)
+                .dot(&up)
+                .atan2(reference.dot(&direction));
+            if angle < 0.0 {
+                angle + TAU
+            } else {
+                angle
+            }
+        };
+
+        let mut keyed: Vec<(u32, f64, usize)> = (0..self.tiles.len())
+            .map(|id| (depths[id], bearing(id), id))
+            .collect();
+        keyed.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)).then(a.2.cmp(&b.2)));
+        keyed.into_iter().map(|(_, _, id)| id).collect()
+    }
+
+    /// Breadth-first search from a seed already known to satisfy the predicate.
+    fn collect_region(
+        &self,
+        seed: usize,
+        predicate: &impl Fn(usize) -> bool,
+        visited: &mut [bool],
+    ) -> Vec<usize> {
+        let mut region = Vec::new();
+        let mut queue = VecDeque::from([seed]);
+        visited[seed] = true;
+
+        while let Some(id) = queue.pop_front() {
+            region.push(id);
+            for &neighbor in &self.tiles[id].neighbors {
+                if !visited[neighbor] && predicate(neighbor) {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        region
+    }
+}
+
+/// Angle in radians between two unit vectors, exact for identical vectors.
+pub(crate) fn angle_between(a: &Vector3, b: &Vector3) -> f64 {
+    let cross = a.cross(b);
+    cross.dot(&cross).sqrt().atan2(a.dot(b))
+}
+
+/// Dijkstra queue entry ordered so that `BinaryHeap` pops the smallest distance first.
+#[derive(Debug, PartialEq)]
+struct QueueEntry {
+    distance: f64,
+    id: usize,
+}
+
+impl Eq for QueueEntry {}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Vector3;
+    use crate::hexasphere::core::Hexasphere;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_flood_fill_always_true_returns_all_tiles() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let region = hexasphere.flood_fill(17, |_| true);
+
+        assert_eq!(region.len(), hexasphere.tiles.len());
+        assert_eq!(region[0], 17);
+        let unique: HashSet<_> = region.iter().collect();
+        assert_eq!(unique.len(), region.len(), "tiles must be visited once");
+    }
+
+    #[test]
+    fn test_flood_fill_rejected_or_invalid_seed() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+        assert!(hexasphere.flood_fill(0, |_| false).is_empty());
+        assert!(hexasphere
+            .flood_fill(hexasphere.tiles.len(), |_| true)
+            .is_empty());
+        assert_eq!(hexasphere.flood_fill(3, |id| id == 3), vec![3]);
+    }
+
+    #[test]
+    fn test_flood_fill_stays_inside_predicate() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let northern = |id: usize| hexasphere.tiles[id].center_point.z > 0.0;
+        let seed = (0..hexasphere.tiles.len())
+            .find(|&id| northern(id))
+            .unwrap();
+
+        let region = hexasphere.flood_fill(seed, northern);
+        assert!(region.len() > 1);
+        assert!(region.iter().all(|&id| northern(id)));
+        // Every tile in the region has a neighbor in the region (it is connected)
+        let members: HashSet<_> = region.iter().copied().collect();
+        for &id in &region[1..] {
+            assert!(hexasphere.tiles[id]
+                .neighbors
+                .iter()
+                .any(|n| members.contains(n)));
+        }
+    }
+
+    #[test]
+    fn test_northern_hemisphere_is_one_component() {
+        let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+        let northern = |id: usize| hexasphere.tiles[id].center_point.z > 0.0;
+
+        let components = hexasphere.connected_components(northern);
+        assert_eq!(components.len(), 1);
+
+        let expected = (0..hexasphere.tiles.len())
+            .filter(|&id| northern(id))
+            .count();
+        assert_eq!(components[0].len(), expected);
+    }
+
+    #[test]
+    fn test_components_partition_matching_tiles() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+        // Two polar caps separated by a band around the equator
+        let caps = |id: usize| hexasphere.tiles[id].center_point.z.abs() > 5.0;
+
+        let components = hexasphere.connected_components(caps);
+        assert_eq!(components.len(), 2);
+
+        let total: usize = components.iter().map(Vec::len).sum();
+        let expected = (0..hexasphere.tiles.len()).filter(|&id| caps(id)).count();
+        assert_eq!(total, expected);
+        assert!(components[0][0] < components[1][0]);
+    }
+
+    #[test]
+    fn test_distance_field_seeds_and_neighbor_steps() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let seeds = [0, 50, 120];
+        let field = hexasphere.distance_field(&seeds);
+
+        assert_eq!(field.len(), hexasphere.tiles.len());
+        for &seed in &seeds {
+            assert_eq!(field[seed], 0);
+        }
+        assert!(field.iter().all(|&d| d != u32::MAX));
+
+        for tile in &hexasphere.tiles {
+            for &neighbor in &tile.neighbors {
+                assert!(field[tile.id].abs_diff(field[neighbor]) <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distance_field_without_seeds() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+        assert!(hexasphere
+            .distance_field(&[])
+            .iter()
+            .all(|&d| d == u32::MAX));
+        let field = hexasphere.geodesic_distance_field(&[hexasphere.tiles.len()]);
+        assert!(field.iter().all(|d| d.is_infinite()));
+    }
+
+    #[test]
+    fn test_geodesic_distance_field() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let field = hexasphere.geodesic_distance_field(&[3]);
+        let origin = Vector3::from(&hexasphere.tiles[3].center_point).normalize();
+
+        assert_eq!(field[3], 0.0);
+        for tile in &hexasphere.tiles {
+            let direction = Vector3::from(&tile.center_point).normalize();
+
+            // A path through tile centers is never shorter than the direct arc
+            let direct = origin.dot(&direction).clamp(-1.0, 1.0).acos() * hexasphere.radius;
+            assert!(field[tile.id] >= direct - 1e-3);
+
+            // Neighbors differ by at most the length of the step between them
+            for &neighbor in &tile.neighbors {
+                let step = tile
+                    .center_point
+                    .distance_to(&hexasphere.tiles[neighbor].center_point);
+                assert!((field[tile.id] - field[neighbor]).abs() <= step * 1.01);
+            }
+        }
+    }
+
+    #[test]
+    fn test_angular_radius_zero_and_everything() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+
+        assert_eq!(hexasphere.tiles_within_angular_radius(17, 0.0), vec![17]);
+        let all = hexasphere.tiles_within_angular_radius(17, std::f64::consts::PI);
+        assert_eq!(all.len(), hexasphere.tiles.len());
+        assert_eq!(all[0], 17);
+
+        assert!(hexasphere.tiles_within_angular_radius(17, -1.0).is_empty());
+        assert!(hexasphere.tiles_within_angular_radius(999, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_angular_radius_matches_scan_and_is_symmetric() {
+        let hexasphere = Hexasphere::new(10.0, 6, 0.8);
+        let direction = |id: usize| Vector3::from(&hexasphere.tiles[id].center_point).normalize();
+
+        for angle in [0.1, 0.35, 1.2, 2.9] {
+            let within: Vec<HashSet<usize>> = (0..hexasphere.tiles.len())
+                .map(|id| {
+                    hexasphere
+                        .tiles_within_angular_radius(id, angle)
+                        .into_iter()
+                        .collect()
+                })
+                .collect();
+
+            for (a, region) in within.iter().enumerate() {
+                // Same as a brute-force scan
+                let expected: HashSet<usize> = (0..hexasphere.tiles.len())
+                    .filter(|&b| super::angle_between(&direction(a), &direction(b)) <= angle)
+                    .collect();
+                assert_eq!(region, &expected, "tile {} angle {}", a, angle);
+
+                for &b in region {
+                    assert!(within[b].contains(&a), "{} -> {} not symmetric", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tiles_within_distance_uses_radius() {
+        let hexasphere = Hexasphere::new(100.0, 5, 1.0);
+        assert_eq!(
+            hexasphere.tiles_within_distance(3, 40.0),
+            hexasphere.tiles_within_angular_radius(3, 0.4)
+        );
+    }
+
+    #[test]
+    fn test_spiral_order_is_breadth_first_permutation() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        for start in [0, 37, hexasphere.pentagon_indices()[3]] {
+            let order = hexasphere.spiral_order(start);
+            let depths = hexasphere.distance_field(&[start]);
+
+            assert_eq!(order[0], start);
+            let mut sorted = order.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..hexasphere.tiles.len()).collect::<Vec<_>>());
+            assert!(order
+                .windows(2)
+                .all(|pair| depths[pair[0]] <= depths[pair[1]]));
+
+            let ring = hexasphere.tiles[start].neighbors.len();
+            assert_eq!(order[1..=ring], hexasphere.tiles[start].neighbors[..]);
+        }
+
+        assert!(hexasphere.spiral_order(hexasphere.tiles.len()).is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+//! Structural consistency checks for a built hexasphere.
+
+use crate::hexasphere::core::Hexasphere;
+use crate::hexasphere::export::polygon_normal;
+use std::collections::HashSet;
+use std::fmt;
+
+/// A structural problem found by [`Hexasphere::validate`].
+///
+/// Tile-specific issues carry the index of the offending tile so it can be
+/// inspected directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopologyIssue {
+    /// A tile lists a neighbor index outside the tile array
+    NeighborOutOfRange {
+        /// Index of the tile holding the bad reference
+        tile: usize,
+        /// The out-of-range neighbor index
+        neighbor: usize,
+    },
+    /// A tile lists a neighbor that does not list it back
+    AsymmetricNeighbor {
+        /// Index of the tile listing the neighbor
+        tile: usize,
+        /// Index of the neighbor missing the reverse link
+        neighbor: usize,
+    },
+    /// A tile's boundary has neither 5 nor 6 points
+    InvalidVertexCount {
+        /// Index of the tile
+        tile: usize,
+        /// Number of boundary points found
+        vertices: usize,
+    },
+    /// A tile's neighbor count does not match its shape (5 for pentagons, 6 for hexagons)
+    WrongNeighborCount {
+        /// Index of the tile
+        tile: usize,
+        /// Number of neighbors the tile should have
+        expected: usize,
+        /// Number of neighbors found
+        actual: usize,
+    },
+    /// A tile's boundary winds clockwise when seen from outside the sphere
+    InwardWinding {
+        /// Index of the tile
+        tile: usize,
+    },
+    /// The sphere does not have exactly 12 pentagons
+    PentagonCount(usize),
+    /// Tile, edge and corner counts violate Euler's formula `V − E + F = 2`
+    EulerCharacteristic {
+        /// Number of distinct tile corners, each shared by three tiles
+        vertices: usize,
+        /// Number of distinct neighbor pairs
+        edges: usize,
+        /// Number of tiles
+        faces: usize,
+    },
+}
+
+impl fmt::Display for TopologyIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TopologyIssue::NeighborOutOfRange { tile, neighbor } => write!(
+                f,
+                "tile {} references neighbor {} which does not exist",
+                tile, neighbor
+            ),
+            TopologyIssue::AsymmetricNeighbor { tile, neighbor } => write!(
+                f,
+                "tile {} lists neighbor {} but not the other way around",
+                tile, neighbor
+            ),
+            TopologyIssue::InvalidVertexCount { tile, vertices } => {
+                write!(f, "tile {} has {} boundary points", tile, vertices)
+            }
+            TopologyIssue::WrongNeighborCount {
+                tile,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "tile {} has {} neighbors but should have {}",
+                tile, actual, expected
+            ),
+            TopologyIssue::InwardWinding { tile } => {
+                write!(f, "tile {} boundary winds clockwise from outside", tile)
+            }
+            TopologyIssue::PentagonCount(count) => {
+                write!(f, "found {} pentagons instead of 12", count)
+            }
+            TopologyIssue::EulerCharacteristic {
+                vertices,
+                edges,
+                faces,
+            } => write!(
+                f,
+                "V - E + F = {} - {} + {} is not 2",
+                vertices, edges, faces
+            ),
+        }
+    }
+}
+
+impl Hexasphere {
+    /// Checks that the tiles form a consistent closed Goldberg polyhedron.
+    ///
+    /// Verifies that:
+    ///
+    /// 1. Every neighbor index is in range and adjacency is symmetric
+    /// 2. Every tile has 5 or 6 boundary points and as many neighbors
+    /// 3. Every boundary winds counter-clockwise seen from outside
+    /// 4. There are exactly 12 pentagons
+    /// 5. Euler's formula `V − E + F = 2` holds, counting tiles as faces,
+    ///    neighbor pairs as edges and neighbor triples meeting at a corner
+    ///    as vertices
+    ///
+    /// Every freshly built hexasphere passes; this guards hand-edited or
+    /// deserialized data and changes to the construction code.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or every [`TopologyIssue`] found. The Euler check is skipped
+    /// while neighbor indices are out of range.
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = neighbors per tile
+    /// - Space complexity: O(n × k) for the edge and corner sets
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, TopologyIssue};
+    /// let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// assert!(hexasphere.validate().is_ok());
+    ///
+    /// // Break one direction of an adjacency
+    /// let neighbor = hexasphere.tiles[0].neighbors[0];
+    /// hexasphere.tiles[neighbor].neighbors.retain(|&n| n != 0);
+    ///
+    /// let issues = hexasphere.validate().unwrap_err();
+    /// assert!(issues.contains(&TopologyIssue::AsymmetricNeighbor { tile: 0, neighbor }));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<TopologyIssue>> {
+        let mut issues = Vec::new();
+        let count = self.tiles.len();
+        let mut indices_valid = true;
+
+        for (id, tile) in self.tiles.iter().enumerate() {
+            for &neighbor in &tile.neighbors {
+                if neighbor >= count {
+                    indices_valid = false;
+                    issues.push(TopologyIssue::NeighborOutOfRange { tile: id, neighbor });
+                } else if !self.tiles[neighbor].neighbors.contains(&id) {
+                    issues.push(TopologyIssue::AsymmetricNeighbor { tile: id, neighbor });
+                }
+            }
+
+            let vertices = tile.boundary.len();
+            if vertices != 5 && vertices != 6 {
+                issues.push(TopologyIssue::InvalidVertexCount { tile: id, vertices });
+            } else {
+                if tile.neighbors.len() != vertices {
+                    issues.push(TopologyIssue::WrongNeighborCount {
+                        tile: id,
+                        expected: vertices,
+                        actual: tile.neighbors.len(),
+                    });
+                }
+                let outward = self.relative(&tile.center_point);
+                if polygon_normal(&tile.boundary).dot(&outward) <= 0.0 {
+                    issues.push(TopologyIssue::InwardWinding { tile: id });
+                }
+            }
+        }
+
+        let pentagons = self.tiles.iter().filter(|tile| tile.is_pentagon()).count();
+        if pentagons != 12 {
+            issues.push(TopologyIssue::PentagonCount(pentagons));
+        }
+
+        if indices_valid {
+            let mut edges = HashSet::new();
+            let mut corners = HashSet::new();
+            for (id, tile) in self.tiles.iter().enumerate() {
+                let n = tile.neighbors.len();
+                for i in 0..n {
+                    let (previous, next) = (tile.neighbors[(i + n - 1) % n], tile.neighbors[i]);
+                    edges.insert((id.min(next), id.max(next)));
+                    let mut corner = [id, previous, next];
+                    corner.sort_unstable();
+                    corners.insert(corner);
+                }
+            }
+
+            let (vertices, edges) = (corners.len(), edges.len());
+            if vertices + count != edges + 2 {
+                issues.push(TopologyIssue::EulerCharacteristic {
+                    vertices,
+                    edges,
+                    faces: count,
+                });
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TopologyIssue;
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_built_spheres_are_valid() {
+        for divisions in 0..=3 {
+            for hex_size in [1.0, 0.8] {
+                let hexasphere = Hexasphere::new(10.0, divisions, hex_size);
+                assert_eq!(hexasphere.validate(), Ok(()), "divisions {divisions}");
+            }
+        }
+        assert_eq!(Hexasphere::new_goldberg(5.0, 2, 1, 1.0).validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_reports_broken_tiles() {
+        let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        hexasphere.tiles[4].boundary.reverse();
+        hexasphere.tiles[7].neighbors[0] = 10_000;
+
+        let issues = hexasphere.validate().unwrap_err();
+        assert!(issues.contains(&TopologyIssue::InwardWinding { tile: 4 }));
+        assert!(issues.contains(&TopologyIssue::NeighborOutOfRange {
+            tile: 7,
+            neighbor: 10_000
+        }));
+        assert!(!issues
+            .iter()
+            .any(|issue| matches!(issue, TopologyIssue::EulerCharacteristic { .. })));
+    }
+
+    #[test]
+    fn test_reports_counts() {
+        let mut hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let hexagon = hexasphere
+            .tiles
+            .iter()
+            .position(|t| t.is_hexagon())
+            .unwrap();
+        hexasphere.tiles[hexagon].boundary.pop();
+        hexasphere.tiles.pop();
+
+        let issues = hexasphere.validate().unwrap_err();
+        assert!(issues.contains(&TopologyIssue::WrongNeighborCount {
+            tile: hexagon,
+            expected: 5,
+            actual: 6
+        }));
+        assert!(issues.contains(&TopologyIssue::PentagonCount(13)));
+        assert_eq!(
+            TopologyIssue::PentagonCount(13).to_string(),
+            "found 13 pentagons instead of 12"
+        );
+    }
+}
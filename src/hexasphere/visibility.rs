@@ -0,0 +1,188 @@
+//! Which tiles can be seen from a point outside the sphere.
+
+use crate::geometry::Point;
+use crate::hexasphere::core::Hexasphere;
+
+/// Which tiles [`Hexasphere::tiles_visible_from_with`] counts as visible.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{HorizonMode, Hexasphere, Point};
+/// let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+/// let eye = Point::new(0.0, 0.0, 15.0);
+/// let centers = hexasphere.tiles_visible_from_with(&eye, HorizonMode::Center).unwrap();
+/// let partial = hexasphere.tiles_visible_from_with(&eye, HorizonMode::Partial).unwrap();
+/// assert!(centers.len() < partial.len());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HorizonMode {
+    /// Tiles whose center is on the near side of the horizon
+    #[default]
+    Center,
+    /// Tiles with their center or any boundary vertex on the near side, so
+    /// tiles cut by the horizon are included as well
+    Partial,
+}
+
+impl Hexasphere {
+    /// Finds the tiles whose centers can be seen from `eye`.
+    ///
+    /// Shorthand for [`Hexasphere::tiles_visible_from_with`] with [`HorizonMode::Center`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, Point};
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    ///
+    /// let visible = hexasphere.tiles_visible_from(&Point::new(0.0, 0.0, 40.0)).unwrap();
+    /// assert!(visible.len() < hexasphere.tiles.len() / 2);
+    ///
+    /// // No horizon from inside the sphere
+    /// assert!(hexasphere.tiles_visible_from(&Point::new(0.0, 0.0, 5.0)).is_none());
+    /// ```
+    pub fn tiles_visible_from(&self, eye: &Point) -> Option<Vec<usize>> {
+        self.tiles_visible_from_with(eye, HorizonMode::Center)
+    }
+
+    /// Finds the tiles on the cap of the sphere that can be seen from `eye`.
+    ///
+    /// Seen from a distance `d` from the sphere's center, the horizon lies at
+    /// the angle θ from the eye direction with `cos θ = r / d`. A point on the
+    /// sphere is visible when its direction is within θ of the eye direction.
+    /// The cap approaches a hemisphere as the eye moves away and shrinks to
+    /// a point as it descends to the surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - Position of the camera or satellite, in the hexasphere's
+    ///   coordinate space
+    /// * `mode` - Whether a tile's center or any part of it must be visible
+    ///
+    /// # Returns
+    ///
+    /// Visible tile ids in ascending order, or `None` if `eye` is inside the
+    /// sphere or not finite. The tile directly beneath the eye is always
+    /// included, even when the eye is so low that the tile's center lies
+    /// beyond the horizon.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Culling**: Skip tiles on the far side of the planet before rendering
+    /// - **Coverage**: Find the ground a satellite can see or reach by radio
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n) for [`HorizonMode::Center`], O(n × k) for
+    ///   [`HorizonMode::Partial`] where k = boundary points per tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{HorizonMode, Hexasphere, Point};
+    /// let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+    ///
+    /// // Low enough that the horizon cuts through the tile below
+    /// let eye = Point::new(0.0, 10.001, 0.0);
+    /// let below = hexasphere.tile_containing(&Point::new(0.0, 10.0, 0.0)).unwrap();
+    /// let visible = hexasphere.tiles_visible_from_with(&eye, HorizonMode::Partial).unwrap();
+    /// assert_eq!(visible, vec![below]);
+    /// ```
+    pub fn tiles_visible_from_with(&self, eye: &Point, mode: HorizonMode) -> Option<Vec<usize>> {
+        let eye = self.relative(eye);
+        let distance = eye.length();
+        if !distance.is_finite() || distance < self.radius {
+            return None;
+        }
+
+        let direction = eye.normalize();
+        let horizon = self.radius / distance;
+        let visible = |point: &Point| self.relative(point).normalize().dot(&direction) >= horizon;
+        let below = self.nearest_tile(&direction);
+
+        Some(
+            self.tiles
+                .iter()
+                .filter(|tile| {
+                    Some(tile.id) == below
+                        || visible(&tile.center_point)
+                        || (mode == HorizonMode::Partial && tile.boundary.iter().any(visible))
+                })
+                .map(|tile| tile.id)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HorizonMode;
+    use crate::geometry::{Point, Vector3};
+    use crate::hexasphere::core::Hexasphere;
+
+    #[test]
+    fn test_far_eye_sees_half_the_tiles() {
+        let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+        let eye = Point::new(3e6, -4e6, 1e6);
+
+        let centers = hexasphere.tiles_visible_from(&eye).unwrap();
+        let fraction = centers.len() as f64 / hexasphere.tiles.len() as f64;
+        assert!((0.45..=0.55).contains(&fraction), "{fraction}");
+
+        let partial = hexasphere
+            .tiles_visible_from_with(&eye, HorizonMode::Partial)
+            .unwrap();
+        assert!(centers.iter().all(|id| partial.contains(id)));
+        assert!(partial.len() > centers.len());
+    }
+
+    #[test]
+    fn test_low_eye_sees_local_neighborhood() {
+        let radius = 10.0;
+        let hexasphere = Hexasphere::new(radius, 8, 1.0);
+        let ground = Point::new(0.0, 6.0, 8.0);
+        let below = hexasphere.tile_containing(&ground).unwrap();
+        let eye = |height: f64| Point::new(0.0, 0.6 * height, 0.8 * height);
+
+        let centers = hexasphere.tiles_visible_from(&eye(radius + 0.01)).unwrap();
+        assert_eq!(centers, vec![below]);
+
+        // The horizon cuts through the tile below, so only it and its neighbors show
+        let local = hexasphere
+            .tiles_visible_from_with(&eye(radius + 0.02), HorizonMode::Partial)
+            .unwrap();
+        assert!(local.len() > 1);
+        assert!(local
+            .iter()
+            .all(|&id| id == below || hexasphere.tiles[below].neighbors.contains(&id)));
+
+        // Standing on the surface is not inside
+        assert!(hexasphere.tiles_visible_from(&eye(radius)).is_some());
+        assert!(hexasphere.tiles_visible_from(&eye(radius - 0.01)).is_none());
+        assert!(hexasphere
+            .tiles_visible_from(&Point::new(f64::NAN, 0.0, 20.0))
+            .is_none());
+    }
+
+    #[test]
+    fn test_visibility_is_rotation_invariant() {
+        let hexasphere = Hexasphere::new(10.0, 6, 1.0);
+        let axis = Vector3::new(1.0, -2.0, 0.5);
+        let angle = 0.9;
+        let rotated = hexasphere.rotated(&axis, angle);
+
+        // The same rotation as `Hexasphere::rotate`, applied to the eye
+        let eye = Point::new(12.0, 5.0, -9.0);
+        let (k, v) = (axis.normalize(), Vector3::from(&eye));
+        let (sin, cos) = f64::sin_cos(angle);
+        let turned = &v * cos + k.cross(&v) * sin + &k * (k.dot(&v) * (1.0 - cos));
+        let rotated_eye = Point::from(turned);
+
+        for mode in [HorizonMode::Center, HorizonMode::Partial] {
+            let before = hexasphere.tiles_visible_from_with(&eye, mode).unwrap();
+            let after = rotated.tiles_visible_from_with(&rotated_eye, mode).unwrap();
+            assert_eq!(before, after, "{mode:?}");
+        }
+    }
+}
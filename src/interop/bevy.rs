@@ -0,0 +1,429 @@
+//! Conversion of hexaspheres and thick tiles into Bevy meshes.
+//!
+//! Only available with the `bevy` feature enabled.
+
+use crate::geometry::Point;
+use crate::hexasphere::core::Hexasphere;
+use crate::tile::ThickTile;
+use bevy::render::mesh::{Indices, Mesh, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// Options controlling how geometry is placed when converted to a Bevy mesh.
+///
+/// Geometry is generated in the same units as the hexasphere radius and
+/// placed around the sphere's center. These options let you adjust that placement
+/// during the f64 → f32 conversion, which avoids a second pass over the
+/// vertex buffer on the Bevy side.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Hexasphere;
+/// # use geotiles::interop::bevy::BevyMeshOptions;
+/// let hexasphere = Hexasphere::new(100.0, 3, 1.0);
+///
+/// // Shrink a radius-100 sphere down to a unit-sized mesh
+/// let options = BevyMeshOptions { scale: 0.01, ..Default::default() };
+/// let mesh = hexasphere.to_bevy_mesh_with(&options);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BevyMeshOptions {
+    /// Move the center of the bounding box to the origin before scaling.
+    ///
+    /// Useful for single thick tiles, which otherwise sit on the sphere
+    /// surface far from their local origin.
+    pub recenter: bool,
+    /// Uniform scale factor applied to every position
+    pub scale: f32,
+}
+
+impl Default for BevyMeshOptions {
+    fn default() -> Self {
+        Self {
+            recenter: false,
+            scale: 1.0,
+        }
+    }
+}
+
+impl Hexasphere {
+    /// Converts the hexasphere surface into a Bevy mesh.
+    ///
+    /// Each tile is triangulated as a fan from its center point, so a hexagon
+    /// contributes 7 vertices and 6 triangles and a pentagon 6 vertices and 5
+    /// triangles. Tiles do not share vertices, which keeps the mesh ready for
+    /// per-tile coloring and gives every tile its own flat shading.
+    ///
+    /// The mesh carries positions, computed normals, spherical UVs and `u32`
+    /// triangle indices.
+    ///
+    /// # Returns
+    ///
+    /// A `Mesh` with `PrimitiveTopology::TriangleList`, ready for `Assets<Mesh>::add`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// use bevy::render::mesh::Mesh;
+    ///
+    /// let hexasphere = Hexasphere::new(1.0, 2, 1.0);
+    /// let mesh = hexasphere.to_bevy_mesh();
+    /// assert!(mesh.attribute(Mesh::ATTRIBUTE_POSITION).is_some());
+    /// assert!(mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some());
+    /// ```
+    pub fn to_bevy_mesh(&self) -> Mesh {
+        self.to_bevy_mesh_with(&BevyMeshOptions::default())
+    }
+
+    /// Converts the hexasphere surface into a Bevy mesh with custom placement.
+    ///
+    /// Identical to [`Hexasphere::to_bevy_mesh`] except that positions are
+    /// recentered and/or scaled according to `options`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Placement options applied during conversion
+    ///
+    /// # Returns
+    ///
+    /// A `Mesh` with `PrimitiveTopology::TriangleList`
+    pub fn to_bevy_mesh_with(&self, options: &BevyMeshOptions) -> Mesh {
+        let mut builder = MeshBuilder::around(self.center());
+
+        for tile in &self.tiles {
+            let mut polygon = Vec::with_capacity(tile.boundary.len() + 1);
+            polygon.push(&tile.center_point);
+            polygon.extend(tile.boundary.iter());
+            builder.add_fan(&polygon);
+        }
+
+        builder.build(options)
+    }
+}
+
+impl ThickTile {
+    /// Converts this thick tile into a Bevy mesh.
+    ///
+    /// The outer face, inner face and each side wall get their own vertices so
+    /// the computed normals stay flat across every face instead of being
+    /// smoothed around the rim.
+    ///
+    /// # Returns
+    ///
+    /// A `Mesh` with `PrimitiveTopology::TriangleList`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, ThickTile};
+    /// # use geotiles::interop::bevy::BevyMeshOptions;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let thick_tile = ThickTile::from_surface_tile(&hexasphere.tiles[0], 0.5);
+    ///
+    /// // Place the tile at the origin so it can be positioned with a Transform
+    /// let options = BevyMeshOptions { recenter: true, ..Default::default() };
+    /// let mesh = thick_tile.to_bevy_mesh_with(&options);
+    /// ```
+    pub fn to_bevy_mesh(&self) -> Mesh {
+        self.to_bevy_mesh_with(&BevyMeshOptions::default())
+    }
+
+    /// Converts this thick tile into a Bevy mesh with custom placement.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Placement options applied during conversion
+    ///
+    /// # Returns
+    ///
+    /// A `Mesh` with `PrimitiveTopology::TriangleList`
+    pub fn to_bevy_mesh_with(&self, options: &BevyMeshOptions) -> Mesh {
+        let mesh_data = self.generate_all_vertices();
+        let sides = self.outer_boundary.len();
+        let mut builder = MeshBuilder::around(&self.sphere_center);
+
+        // generate_all_vertices emits the outer fan, then the inner fan, then
+        // two triangles per side wall; split on those boundaries
+        let (outer, rest) = mesh_data.indices.split_at(3 * sides);
+        let (inner, walls) = rest.split_at(3 * sides);
+        builder.add_group(&mesh_data.vertices, outer);
+        builder.add_group(&mesh_data.vertices, inner);
+        for wall in walls.chunks(6) {
+            builder.add_group(&mesh_data.vertices, wall);
+        }
+
+        builder.build(options)
+    }
+}
+
+/// Accumulates unshared polygon groups before conversion to a Bevy mesh.
+struct MeshBuilder {
+    positions: Vec<[f64; 3]>,
+    indices: Vec<u32>,
+    /// Center of the sphere the geometry lies on, which UVs are measured from
+    sphere_center: [f64; 3],
+}
+
+impl MeshBuilder {
+    /// An empty builder for geometry on the sphere centered at `center`.
+    fn around(center: &Point) -> Self {
+        Self {
+            positions: Vec::new(),
+            indices: Vec::new(),
+            sphere_center: [center.x, center.y, center.z],
+        }
+    }
+
+    /// Adds a polygon as a triangle fan; `polygon[0]` is the fan center.
+    fn add_fan(&mut self, polygon: &[&Point]) {
+        let start = self.positions.len() as u32;
+        self.positions
+            .extend(polygon.iter().map(|point| [point.x, point.y, point.z]));
+
+        let rim = polygon.len() as u32 - 1;
+        for i in 0..rim {
+            let next_i = (i + 1) % rim;
+            self.indices
+                .extend_from_slice(&[start, start + 1 + i, start + 1 + next_i]);
+        }
+    }
+
+    /// Adds a group of triangles with fresh copies of the vertices they use.
+    fn add_group(&mut self, vertices: &[Point], indices: &[usize]) {
+        let mut remap: HashMap<usize, u32> = HashMap::new();
+
+        for &index in indices {
+            let new_index = *remap.entry(index).or_insert_with(|| {
+                let point = &vertices[index];
+                self.positions.push([point.x, point.y, point.z]);
+                self.positions.len() as u32 - 1
+            });
+            self.indices.push(new_index);
+        }
+    }
+
+    /// Computes per-vertex normals as the area-weighted sum of face normals.
+    fn normals(&self) -> Vec<[f32; 3]> {
+        let mut sums = vec![[0.0f64; 3]; self.positions.len()];
+
+        for triangle in self.indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|k| self.positions[triangle[k] as usize]);
+            let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+            let ac = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+            let face_normal = [
+                ab[1] * ac[2] - ab[2] * ac[1],
+                ab[2] * ac[0] - ab[0] * ac[2],
+                ab[0] * ac[1] - ab[1] * ac[0],
+            ];
+
+            for &index in triangle {
+                let sum = &mut sums[index as usize];
+                for axis in 0..3 {
+                    sum[axis] += face_normal[axis];
+                }
+            }
+        }
+
+        sums.iter()
+            .map(|n| {
+                let length = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+                if length == 0.0 {
+                    [0.0, 0.0, 0.0]
+                } else {
+                    [
+                        (n[0] / length) as f32,
+                        (n[1] / length) as f32,
+                        (n[2] / length) as f32,
+                    ]
+                }
+            })
+            .collect()
+    }
+
+    /// Equirectangular UVs from each vertex direction as seen from the sphere
+    /// center (Y-up, longitude from +Z).
+    fn uvs(&self) -> Vec<[f32; 2]> {
+        self.positions
+            .iter()
+            .map(|p| {
+                let p = [0, 1, 2].map(|axis| p[axis] - self.sphere_center[axis]);
+                let length = (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt();
+                if length == 0.0 {
+                    return [0.5, 0.5];
+                }
+                let latitude = (p[1] / length).clamp(-1.0, 1.0).asin();
+                let longitude = p[0].atan2(p[2]);
+                [
+                    (0.5 + longitude / (2.0 * PI)) as f32,
+                    (0.5 - latitude / PI) as f32,
+                ]
+            })
+            .collect()
+    }
+
+    /// Casts positions to f32, applies `options` and assembles the Bevy mesh.
+    fn build(self, options: &BevyMeshOptions) -> Mesh {
+        let offset = if options.recenter && !self.positions.is_empty() {
+            let mut min = [f64::INFINITY; 3];
+            let mut max = [f64::NEG_INFINITY; 3];
+            for p in &self.positions {
+                for axis in 0..3 {
+                    min[axis] = min[axis].min(p[axis]);
+                    max[axis] = max[axis].max(p[axis]);
+                }
+            }
+            [0, 1, 2].map(|axis| (min[axis] + max[axis]) / 2.0)
+        } else {
+            [0.0; 3]
+        };
+        let scale = options.scale as f64;
+
+        let positions: Vec<[f32; 3]> = self
+            .positions
+            .iter()
+            .map(|p| [0, 1, 2].map(|axis| ((p[axis] - offset[axis]) * scale) as f32))
+            .collect();
+        let normals = self.normals();
+        let uvs = self.uvs();
+
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(self.indices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BevyMeshOptions;
+    use crate::geometry::Point;
+    use crate::hexasphere::builder::HexasphereBuilder;
+    use crate::hexasphere::core::Hexasphere;
+    use crate::tile::ThickTile;
+    use bevy::render::mesh::{Indices, Mesh, VertexAttributeValues};
+
+    fn positions(mesh: &Mesh) -> &[[f32; 3]] {
+        match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float32x3(values)) => values,
+            _ => panic!("mesh should have f32x3 positions"),
+        }
+    }
+
+    fn normals(mesh: &Mesh) -> &[[f32; 3]] {
+        match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+            Some(VertexAttributeValues::Float32x3(values)) => values,
+            _ => panic!("mesh should have f32x3 normals"),
+        }
+    }
+
+    #[test]
+    fn test_hexasphere_mesh_counts() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let mesh = hexasphere.to_bevy_mesh();
+
+        let boundary_points: usize = hexasphere.tiles.iter().map(|t| t.boundary.len()).sum();
+        assert_eq!(
+            positions(&mesh).len(),
+            boundary_points + hexasphere.tiles.len()
+        );
+        assert_eq!(normals(&mesh).len(), positions(&mesh).len());
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_some());
+
+        match mesh.indices() {
+            Some(Indices::U32(indices)) => {
+                assert_eq!(indices.len(), boundary_points * 3);
+                assert!(indices
+                    .iter()
+                    .all(|&i| (i as usize) < positions(&mesh).len()));
+            }
+            _ => panic!("mesh should have u32 indices"),
+        }
+    }
+
+    fn uvs(mesh: &Mesh) -> &[[f32; 2]] {
+        match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float32x2(values)) => values,
+            _ => panic!("mesh should have f32x2 uvs"),
+        }
+    }
+
+    #[test]
+    fn test_offset_sphere_uvs_match_origin_sphere() {
+        let origin = Hexasphere::new(10.0, 3, 1.0);
+        let moved = HexasphereBuilder::new()
+            .radius(10.0)
+            .subdivisions(3)
+            .center(Point::new(40.0, -25.0, 15.0))
+            .build()
+            .unwrap();
+
+        let (mesh, base_mesh) = (moved.to_bevy_mesh(), origin.to_bevy_mesh());
+        assert_eq!(uvs(&mesh).len(), uvs(&base_mesh).len());
+        for (uv, base_uv) in uvs(&mesh).iter().zip(uvs(&base_mesh)) {
+            assert!((uv[0] - base_uv[0]).abs() < 1e-5 && (uv[1] - base_uv[1]).abs() < 1e-5);
+        }
+
+        let thick_tile = ThickTile::from_surface_tile(&moved.tiles[5], 0.5);
+        let base_thick_tile = ThickTile::from_surface_tile(&origin.tiles[5], 0.5);
+        let (mesh, base_mesh) = (thick_tile.to_bevy_mesh(), base_thick_tile.to_bevy_mesh());
+        for (uv, base_uv) in uvs(&mesh).iter().zip(uvs(&base_mesh)) {
+            assert!((uv[0] - base_uv[0]).abs() < 1e-5 && (uv[1] - base_uv[1]).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_hexasphere_mesh_normals_point_outward() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let mesh = hexasphere.to_bevy_mesh();
+
+        for (position, normal) in positions(&mesh).iter().zip(normals(&mesh)) {
+            let length = (normal[0].powi(2) + normal[1].powi(2) + normal[2].powi(2)).sqrt();
+            assert!((length - 1.0).abs() < 1e-4, "Normal should be unit length");
+
+            let dot = position[0] * normal[0] + position[1] * normal[1] + position[2] * normal[2];
+            assert!(dot > 0.0, "Normal should point away from the sphere center");
+        }
+    }
+
+    #[test]
+    fn test_thick_tile_mesh_recenter_and_scale() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let thick_tile = ThickTile::from_surface_tile(&hexasphere.tiles[0], 0.5);
+        let sides = thick_tile.outer_boundary.len();
+
+        let options = BevyMeshOptions {
+            recenter: true,
+            scale: 2.0,
+        };
+        let mesh = thick_tile.to_bevy_mesh_with(&options);
+
+        // Outer and inner fans plus four vertices per side wall
+        assert_eq!(positions(&mesh).len(), 2 * (sides + 1) + 4 * sides);
+
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for p in positions(&mesh) {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(p[axis]);
+                max[axis] = max[axis].max(p[axis]);
+            }
+        }
+        for axis in 0..3 {
+            assert!(((min[axis] + max[axis]) / 2.0).abs() < 1e-4);
+        }
+
+        let unscaled = thick_tile.to_bevy_mesh();
+        let extent = |mesh: &Mesh| {
+            let xs = positions(mesh).iter().map(|p| p[0]);
+            xs.clone().fold(f32::NEG_INFINITY, f32::max) - xs.fold(f32::INFINITY, f32::min)
+        };
+        assert!((extent(&mesh) - 2.0 * extent(&unscaled)).abs() < 1e-3);
+    }
+}
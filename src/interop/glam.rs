@@ -0,0 +1,271 @@
+//! Conversions between geotiles types and [`glam`] double-precision types.
+//!
+//! Only available with the `glam` feature enabled.
+//!
+//! # Matrix Layout
+//!
+//! `TileOrientation::to_rotation_matrix` and `to_transform_matrix` return
+//! plain arrays in **row-major** order, while glam matrices are
+//! **column-major**. Passing those arrays to `DMat4::from_cols_array` silently
+//! transposes the rotation and moves the translation into the bottom row.
+//! The methods in this module build glam matrices directly from the basis
+//! vectors, so no manual reordering is needed:
+//!
+//! - **Column 0**: `right`
+//! - **Column 1**: `up`
+//! - **Column 2**: `forward`
+//! - **Column 3**: translation (4×4 only)
+//!
+//! Local X therefore maps to `right`, local Y to the outward surface normal
+//! and local Z to `forward`, matching the Y-up convention of glam based
+//! engines.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use geotiles::Hexasphere;
+//! use glam::DVec3;
+//!
+//! let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+//! let tile = &hexasphere.tiles[0];
+//! let orientation = tile.get_orientation().unwrap();
+//!
+//! let transform = orientation.to_mat4(&tile.center_point);
+//! let center = DVec3::from(&tile.center_point);
+//!
+//! // The local origin lands on the tile center
+//! assert!(transform.transform_point3(DVec3::ZERO).abs_diff_eq(center, 1e-12));
+//! ```
+
+use crate::approximation::RegularHexagonParams;
+use crate::geometry::{Point, Vector3};
+use crate::tile::TileOrientation;
+use glam::{DMat3, DMat4, DVec3};
+
+impl From<&Point> for DVec3 {
+    fn from(point: &Point) -> Self {
+        DVec3::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<Point> for DVec3 {
+    fn from(point: Point) -> Self {
+        DVec3::from(&point)
+    }
+}
+
+impl From<&Vector3> for DVec3 {
+    fn from(vector: &Vector3) -> Self {
+        DVec3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for DVec3 {
+    fn from(vector: Vector3) -> Self {
+        DVec3::from(&vector)
+    }
+}
+
+impl From<DVec3> for Vector3 {
+    fn from(vector: DVec3) -> Self {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl TileOrientation {
+    /// Converts the orientation to a glam rotation matrix.
+    ///
+    /// The columns are `right`, `up` and `forward`, so the matrix maps local
+    /// tile coordinates to world directions. This is the column-major
+    /// equivalent of [`TileOrientation::to_rotation_matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::tile::TileOrientation;
+    /// use glam::DVec3;
+    ///
+    /// let orientation = TileOrientation::default();
+    /// let rotation = orientation.to_mat3();
+    /// assert_eq!(rotation.y_axis, DVec3::from(&orientation.up));
+    /// ```
+    pub fn to_mat3(&self) -> DMat3 {
+        DMat3::from_cols(
+            DVec3::from(&self.right),
+            DVec3::from(&self.up),
+            DVec3::from(&self.forward),
+        )
+    }
+
+    /// Converts the orientation to a glam affine transform placed at `translation`.
+    ///
+    /// This is the column-major equivalent of
+    /// [`TileOrientation::to_transform_matrix`].
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The world position of the local origin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// # let tile = &hexasphere.tiles[0];
+    /// let orientation = tile.get_orientation().unwrap();
+    /// let transform = orientation.to_mat4(&tile.center_point);
+    /// assert_eq!(transform.w_axis.truncate(), glam::DVec3::from(&tile.center_point));
+    /// ```
+    pub fn to_mat4(&self, translation: &Point) -> DMat4 {
+        DMat4::from_cols(
+            DVec3::from(&self.right).extend(0.0),
+            DVec3::from(&self.up).extend(0.0),
+            DVec3::from(&self.forward).extend(0.0),
+            DVec3::from(translation).extend(1.0),
+        )
+    }
+}
+
+impl RegularHexagonParams {
+    /// Returns the transform that places a unit hexagon onto this tile.
+    ///
+    /// The local hexagon lies in the XZ-plane with Y as its normal, has a
+    /// circumradius of 1 and vertex `i` at `(cos(i·60°), 0, sin(i·60°))`.
+    /// The transform scales it by `radius`, rotates it by the orientation and
+    /// moves it to `center`, so one shared hexagon mesh can be instanced for
+    /// every tile.
+    ///
+    /// # Returns
+    ///
+    /// A column-major `DMat4` that maps the unit hexagon onto the vertices
+    /// produced by [`RegularHexagonParams::generate_vertices`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// use glam::DVec3;
+    ///
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+    /// let params = tile.get_regular_hexagon_params().unwrap();
+    ///
+    /// let first = params.transform().transform_point3(DVec3::X);
+    /// let expected = DVec3::from(&params.generate_vertices()[0]);
+    /// assert!(first.abs_diff_eq(expected, 0.001));
+    /// ```
+    pub fn transform(&self) -> DMat4 {
+        self.orientation.to_mat4(&self.center) * DMat4::from_scale(DVec3::splat(self.radius))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approximation::RegularHexagonParams;
+    use crate::geometry::{Point, Vector3};
+    use crate::hexasphere::core::Hexasphere;
+    use crate::tile::orientation::sample_orientation;
+    use crate::tile::TileOrientation;
+    use glam::{DMat3, DMat4, DVec3};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_vector_conversions() {
+        let point = Point::new(1.5, -2.0, 3.25);
+        assert_eq!(DVec3::from(&point), DVec3::new(1.5, -2.0, 3.25));
+        assert_eq!(DVec3::from(point), DVec3::new(1.5, -2.0, 3.25));
+
+        let vector = Vector3::new(0.1, 0.2, 0.3);
+        let converted = DVec3::from(&vector);
+        assert_eq!(converted, DVec3::new(0.1, 0.2, 0.3));
+        assert_eq!(Vector3::from(converted), vector);
+    }
+
+    #[test]
+    fn test_to_mat3_matches_hand_built_columns() {
+        let o = sample_orientation();
+        let (r, u, f) = (&o.right, &o.up, &o.forward);
+
+        #[rustfmt::skip]
+        let expected = DMat3::from_cols_array(&[
+            r.x, r.y, r.z,
+            u.x, u.y, u.z,
+            f.x, f.y, f.z,
+        ]);
+        assert_eq!(o.to_mat3(), expected);
+
+        // Same matrix as the row-major array, once transposed
+        let row_major = DMat3::from_cols_array(&o.to_rotation_matrix()).transpose();
+        assert_eq!(o.to_mat3(), row_major);
+    }
+
+    #[test]
+    fn test_to_mat4_matches_hand_built_columns() {
+        let o = sample_orientation();
+        let (r, u, f) = (&o.right, &o.up, &o.forward);
+        let t = Point::new(4.0, -5.0, 6.0);
+
+        #[rustfmt::skip]
+        let expected = DMat4::from_cols_array(&[
+            r.x, r.y, r.z, 0.0,
+            u.x, u.y, u.z, 0.0,
+            f.x, f.y, f.z, 0.0,
+            t.x, t.y, t.z, 1.0,
+        ]);
+        let transform = o.to_mat4(&t);
+        assert_eq!(transform, expected);
+
+        let row_major = DMat4::from_cols_array(&o.to_transform_matrix(&t)).transpose();
+        assert_eq!(transform, row_major);
+
+        // Local axes map to the basis vectors, the origin to the translation
+        let origin = DVec3::new(4.0, -5.0, 6.0);
+        assert!(transform
+            .transform_point3(DVec3::ZERO)
+            .abs_diff_eq(origin, 1e-12));
+        assert!(transform
+            .transform_point3(DVec3::Y)
+            .abs_diff_eq(origin + DVec3::from(u), 1e-12));
+        assert!(transform.determinant() > 0.0, "orientation must not mirror");
+    }
+
+    #[test]
+    fn test_hexagon_transform_reproduces_vertices() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+
+        for params in hexasphere.get_regular_hexagon_approximations() {
+            let transform = params.transform();
+            for (i, vertex) in params.generate_vertices().iter().enumerate() {
+                let angle = i as f64 * PI / 3.0;
+                let local = DVec3::new(angle.cos(), 0.0, angle.sin());
+                let world = transform.transform_point3(local);
+                assert!(
+                    world.abs_diff_eq(DVec3::from(vertex), 0.001),
+                    "vertex {} mismatch: {:?} vs {:?}",
+                    i,
+                    world,
+                    vertex
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hexagon_transform_scales_by_radius() {
+        let params = RegularHexagonParams {
+            center: Point::new(1.0, 2.0, 3.0),
+            radius: 2.5,
+            orientation: TileOrientation::default(),
+        };
+
+        // The default orientation has right = +X, up = +Z and forward = +Y
+        #[rustfmt::skip]
+        let expected = DMat4::from_cols_array(&[
+            2.5, 0.0, 0.0, 0.0,
+            0.0, 0.0, 2.5, 0.0,
+            0.0, 2.5, 0.0, 0.0,
+            1.0, 2.0, 3.0, 1.0,
+        ]);
+        assert_eq!(params.transform(), expected);
+    }
+}
@@ -0,0 +1,206 @@
+//! Conversions between geotiles types and [`mint`] interoperability types.
+//!
+//! Only available with the `mint` feature enabled. Math libraries such as
+//! nalgebra, cgmath and glam accept `mint` types, so these conversions reach
+//! all of them without a dedicated integration each.
+//!
+//! # Matrix Layout
+//!
+//! A [`TileOrientation`] converts to either of mint's 3×3 matrix types. Both
+//! describe the same matrix as [`TileOrientation::to_rotation_matrix`], whose
+//! columns are `right`, `up` and `forward`; they differ only in which fields
+//! hold what:
+//!
+//! - [`ColumnMatrix3`]: `x`, `y` and `z` are the columns `right`, `up` and `forward`
+//! - [`RowMatrix3`]: `x`, `y` and `z` are the rows, i.e. the x, y and z
+//!   components of all three vectors
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use geotiles::{Point, Vector3};
+//! let point = Point::new(1.0, 2.0, 3.0);
+//! let converted: mint::Point3<f64> = point.clone().into();
+//! assert_eq!(Point::from(converted), point);
+//!
+//! let vector: mint::Vector3<f64> = Vector3::new(0.0, 1.0, 0.0).into();
+//! assert_eq!(vector.y, 1.0);
+//! ```
+
+use crate::geometry::{Point, Vector3};
+use crate::tile::TileOrientation;
+use mint::{ColumnMatrix3, RowMatrix3};
+
+impl From<&Point> for mint::Point3<f64> {
+    fn from(point: &Point) -> Self {
+        mint::Point3 {
+            x: point.x,
+            y: point.y,
+            z: point.z,
+        }
+    }
+}
+
+impl From<Point> for mint::Point3<f64> {
+    fn from(point: Point) -> Self {
+        mint::Point3::from(&point)
+    }
+}
+
+impl From<mint::Point3<f64>> for Point {
+    /// Keeps the coordinates as they are, without the rounding of [`Point::new`].
+    fn from(point: mint::Point3<f64>) -> Self {
+        Point {
+            x: point.x,
+            y: point.y,
+            z: point.z,
+        }
+    }
+}
+
+impl From<&Vector3> for mint::Vector3<f64> {
+    fn from(vector: &Vector3) -> Self {
+        mint::Vector3 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+impl From<Vector3> for mint::Vector3<f64> {
+    fn from(vector: Vector3) -> Self {
+        mint::Vector3::from(&vector)
+    }
+}
+
+impl From<mint::Vector3<f64>> for Vector3 {
+    fn from(vector: mint::Vector3<f64>) -> Self {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<&TileOrientation> for ColumnMatrix3<f64> {
+    fn from(orientation: &TileOrientation) -> Self {
+        ColumnMatrix3 {
+            x: (&orientation.right).into(),
+            y: (&orientation.up).into(),
+            z: (&orientation.forward).into(),
+        }
+    }
+}
+
+impl From<TileOrientation> for ColumnMatrix3<f64> {
+    fn from(orientation: TileOrientation) -> Self {
+        ColumnMatrix3::from(&orientation)
+    }
+}
+
+impl From<&TileOrientation> for RowMatrix3<f64> {
+    fn from(orientation: &TileOrientation) -> Self {
+        let (r, u, f) = (&orientation.right, &orientation.up, &orientation.forward);
+        RowMatrix3 {
+            x: mint::Vector3 {
+                x: r.x,
+                y: u.x,
+                z: f.x,
+            },
+            y: mint::Vector3 {
+                x: r.y,
+                y: u.y,
+                z: f.y,
+            },
+            z: mint::Vector3 {
+                x: r.z,
+                y: u.z,
+                z: f.z,
+            },
+        }
+    }
+}
+
+impl From<TileOrientation> for RowMatrix3<f64> {
+    fn from(orientation: TileOrientation) -> Self {
+        RowMatrix3::from(&orientation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Point, Vector3};
+    use crate::tile::orientation::sample_orientation;
+    use mint::{ColumnMatrix3, RowMatrix3};
+
+    fn to_array(vector: mint::Vector3<f64>) -> [f64; 3] {
+        [vector.x, vector.y, vector.z]
+    }
+
+    #[test]
+    fn test_point_and_vector_round_trips() {
+        let point = Point {
+            x: 0.123456789,
+            y: -2.0,
+            z: 1e-9,
+        };
+        let converted = mint::Point3::from(&point);
+        assert_eq!([converted.x, converted.y, converted.z], point.to_array());
+        assert_eq!(Point::from(converted), point);
+
+        let vector = Vector3::new(0.1, 0.2, 0.3);
+        assert_eq!(Vector3::from(mint::Vector3::from(&vector)), vector);
+    }
+
+    #[test]
+    fn test_matrix_layouts_match_rotation_matrix() {
+        let orientation = sample_orientation();
+        let expected = orientation.to_rotation_matrix();
+
+        let rows = RowMatrix3::from(&orientation);
+        let flat: Vec<f64> = [rows.x, rows.y, rows.z]
+            .into_iter()
+            .flat_map(to_array)
+            .collect();
+        assert_eq!(flat, expected);
+
+        let columns = ColumnMatrix3::from(&orientation);
+        let flat: Vec<f64> = [columns.x, columns.y, columns.z]
+            .into_iter()
+            .flat_map(to_array)
+            .collect();
+        assert_eq!(flat, orientation.to_rotation_matrix_column_major());
+    }
+
+    #[test]
+    fn test_matrices_rotate_like_rotation_matrix() {
+        let orientation = sample_orientation();
+        let m = orientation.to_rotation_matrix();
+        let local = [0.5, -1.0, 2.0];
+        let expected: Vec<f64> = (0..3)
+            .map(|row| (0..3).map(|col| m[row * 3 + col] * local[col]).sum())
+            .collect();
+
+        // Row matrix: each output component is a row dotted with the input
+        let rows = RowMatrix3::from(&orientation);
+        let by_rows: Vec<f64> = [rows.x, rows.y, rows.z]
+            .into_iter()
+            .map(|row| to_array(row).iter().zip(local).map(|(a, b)| a * b).sum())
+            .collect();
+
+        // Column matrix: the output is the columns weighted by the input
+        let columns = ColumnMatrix3::from(orientation);
+        let by_columns: Vec<f64> = (0..3)
+            .map(|i| {
+                [columns.x, columns.y, columns.z]
+                    .iter()
+                    .zip(local)
+                    .map(|(column, weight)| to_array(*column)[i] * weight)
+                    .sum()
+            })
+            .collect();
+
+        for i in 0..3 {
+            assert!((by_rows[i] - expected[i]).abs() < 1e-12);
+            assert!((by_columns[i] - expected[i]).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Integrations with third-party engines, math and graph libraries.
+//!
+//! Each integration lives in its own submodule and is only compiled when the
+//! matching cargo feature is enabled, so the core library stays dependency-free.
+
+#[cfg(feature = "bevy")]
+pub mod bevy;
+
+#[cfg(feature = "glam")]
+pub mod glam;
+
+#[cfg(feature = "mint")]
+pub mod mint;
+
+#[cfg(feature = "petgraph")]
+pub mod petgraph;
@@ -0,0 +1,118 @@
+//! Conversion of the tile adjacency to a [`petgraph`] graph.
+//!
+//! Only available with the `petgraph` feature enabled.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use geotiles::Hexasphere;
+//! use petgraph::algo::min_spanning_tree;
+//! use petgraph::data::FromElements;
+//! use petgraph::graph::UnGraph;
+//!
+//! let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+//! let graph = hexasphere.to_graph();
+//!
+//! // A spanning tree connects all tiles with one edge fewer than tiles
+//! let tree = UnGraph::<usize, f64>::from_elements(min_spanning_tree(&graph));
+//! assert_eq!(tree.edge_count(), hexasphere.tiles.len() - 1);
+//! ```
+
+use crate::hexasphere::core::Hexasphere;
+use crate::hexasphere::traversal::angle_between;
+use petgraph::graph::{NodeIndex, UnGraph};
+
+impl Hexasphere {
+    /// Builds the tile adjacency graph as a petgraph [`UnGraph`].
+    ///
+    /// Node `i` has weight `i` and stands for tile `i`, so `NodeIndex::new(id)`
+    /// addresses a tile directly. Every pair of neighbors is joined by one
+    /// edge weighted by the great-circle distance between their centers.
+    ///
+    /// # Returns
+    ///
+    /// An undirected graph with one node per tile and one edge per shared border
+    ///
+    /// # Use Cases
+    ///
+    /// - **Graph algorithms**: Spanning trees, community detection, matching
+    /// - **Path finding**: Run petgraph's A* or Dijkstra over real distances
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × k) where n = tiles, k = neighbors per tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// use petgraph::graph::NodeIndex;
+    ///
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let graph = hexasphere.to_graph();
+    ///
+    /// assert_eq!(graph[NodeIndex::new(7)], 7);
+    /// assert_eq!(graph.neighbors(NodeIndex::new(7)).count(), hexasphere.tiles[7].neighbors.len());
+    /// ```
+    pub fn to_graph(&self) -> UnGraph<usize, f64> {
+        let edge_count = self
+            .tiles
+            .iter()
+            .map(|tile| tile.neighbors.len())
+            .sum::<usize>()
+            / 2;
+        let mut graph = UnGraph::with_capacity(self.tiles.len(), edge_count);
+
+        for tile in &self.tiles {
+            graph.add_node(tile.id);
+        }
+        for tile in &self.tiles {
+            for &neighbor in tile.neighbors.iter().filter(|&&n| n > tile.id) {
+                graph.add_edge(
+                    NodeIndex::new(tile.id),
+                    NodeIndex::new(neighbor),
+                    center_distance(self, tile.id, neighbor),
+                );
+            }
+        }
+
+        graph
+    }
+}
+
+/// Great-circle distance between the centers of two tiles.
+fn center_distance(hexasphere: &Hexasphere, a: usize, b: usize) -> f64 {
+    let a = hexasphere
+        .relative(&hexasphere.tiles[a].center_point)
+        .normalize();
+    let b = hexasphere
+        .relative(&hexasphere.tiles[b].center_point)
+        .normalize();
+    angle_between(&a, &b) * hexasphere.radius
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hexasphere::core::Hexasphere;
+    use petgraph::algo::connected_components;
+
+    #[test]
+    fn test_to_graph_counts_and_connectivity() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let graph = hexasphere.to_graph();
+
+        assert_eq!(graph.node_count(), hexasphere.tiles.len());
+        assert_eq!(graph.edge_count(), hexasphere.edges().len());
+        assert_eq!(connected_components(&graph), 1);
+
+        for edge in graph.edge_indices() {
+            let (a, b) = graph.edge_endpoints(edge).unwrap();
+            let weight = graph[edge];
+            assert!(hexasphere.tiles[graph[a]].neighbors.contains(&graph[b]));
+            let chord = hexasphere.tiles[graph[a]]
+                .center_point
+                .distance_to(&hexasphere.tiles[graph[b]].center_point);
+            assert!(weight > chord && weight < chord * 1.01);
+        }
+    }
+}
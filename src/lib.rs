@@ -52,14 +52,25 @@
 //! ```
 
 pub mod approximation;
+pub mod error;
 pub mod geometry;
 pub mod hexasphere;
+pub mod interop;
 pub mod tile;
 pub mod utils;
 
 // Re-export main types for convenience
-pub use approximation::RegularHexagonParams;
-pub use geometry::{Face, Point, Vector3};
-pub use hexasphere::{HexagonStats, Hexasphere};
-pub use tile::{ThickTile, Tile};
-pub use utils::LatLon;
+pub use approximation::{RegularHexagonParams, RegularPentagonParams};
+pub use error::{Error, Result};
+pub use geometry::{Face, GeodesicMesh, Point, Vector3, VertexPool};
+#[cfg(feature = "gltf-export")]
+pub use hexasphere::GltfMode;
+pub use hexasphere::{
+    ApproximationReport, BboxMode, BuildError, BuildPhase, CoverageReport, DecodeError,
+    DistortionStats, EdgeGap, GapStats, HexagonStats, Hexasphere, HexasphereBuilder,
+    HexasphereError, HorizonMode, InstanceRadius, InstancingData, MeshData, MeshOptions, ObjExport,
+    RayHit, RelaxationReport, TileApproximationError, TileEdge, TileIndex, TileIntegrals,
+    TileStats, TopologyIssue, UniformRadiusPolicy, UvMode,
+};
+pub use tile::{ExtrusionMode, ThickTile, Tile};
+pub use utils::{AxisConvention, LatLon, LatLonBounds, SubdivisionMode};
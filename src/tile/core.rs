@@ -1,11 +1,14 @@
 //! Core tile implementation.
 
-use super::orientation::TileOrientation;
-use crate::approximation::RegularHexagonParams;
+use super::orientation::{north_tangent, TileOrientation};
+use crate::approximation::{RegularHexagonParams, RegularPentagonParams};
 use crate::geometry::Vector3;
 use crate::geometry::{Face, Point};
-use crate::utils::{calculate_surface_normal, pointing_away_from_origin, triangle_area, LatLon};
-use std::collections::HashMap;
+use crate::utils::{
+    great_circle_distance, pointing_away_from_origin, slerp, spherical_polygon_area, triangle_area,
+    LatLon, LatLonBounds,
+};
+use std::borrow::Cow;
 
 /// A polygonal tile on the geodesic sphere surface.
 ///
@@ -54,14 +57,49 @@ use std::collections::HashMap;
 /// ```
 #[derive(Debug, Clone)]
 pub struct Tile {
+    /// Stable identifier of this tile, equal to its index in `Hexasphere::tiles`
+    pub id: usize,
     /// The center point of this tile (vertex from the geodesic polyhedron)
     pub center_point: Point,
     /// Ordered vertices forming the polygon boundary
     pub boundary: Vec<Point>,
-    /// String identifiers of neighboring tiles (resolved to indices after construction)
+    /// String identifiers of neighboring tiles (resolved to indices after construction),
+    /// in the same counter-clockwise order as `neighbors`
     pub neighbor_ids: Vec<String>,
-    /// Indices of neighboring tiles in the main tiles array
+    /// Indices of neighboring tiles in the main tiles array.
+    ///
+    /// Ordered counter-clockwise to match the boundary winding: `neighbors[i]` is
+    /// the tile across the edge `boundary[i] → boundary[(i + 1) % n]`.
     pub neighbors: Vec<usize>,
+    /// The base icosahedron face the center lies on, between 0 and 19.
+    ///
+    /// Assigned by the owning `Hexasphere`; see [`crate::Hexasphere::tiles_by_base_face`]
+    /// for the numbering and tie-breaks. 0 for tiles built on their own.
+    pub base_face: u8,
+    /// Center of the sphere the tile lies on.
+    ///
+    /// Normals, latitudes and projections onto the sphere are measured from
+    /// here. The origin unless the owning `Hexasphere` was built with
+    /// [`crate::HexasphereBuilder::center`].
+    pub sphere_center: Point,
+    /// Radius of the sphere the tile lies on, used by [`Tile::lat_lon`] and
+    /// [`Tile::boundary_lat_lon`].
+    ///
+    /// Set by the owning `Hexasphere`; tiles built on their own use the
+    /// distance of `center_point` from the origin.
+    pub sphere_radius: f64,
+    /// Derived values stored by [`Tile::precompute_cache`]; boxed so tiles
+    /// without a cache stay small
+    pub(crate) cache: Option<Box<TileCache>>,
+}
+
+/// Derived values of a tile, computed once by [`Tile::precompute_cache`].
+#[derive(Debug, Clone)]
+pub(crate) struct TileCache {
+    orientation: Option<TileOrientation>,
+    average_radius: f64,
+    average_edge_length: f64,
+    area: f64,
 }
 
 impl Tile {
@@ -73,31 +111,36 @@ impl Tile {
     /// 1. Finding all triangular faces that touch the center point
     /// 2. Using face centroids as tile boundary points
     /// 3. Scaling boundary points toward the center based on `hex_size`
-    /// 4. Identifying neighboring tiles from the faces
+    /// 4. Identifying neighboring tiles from the vertices shared by consecutive faces
     /// 5. Fixing the boundary orientation for consistent winding
     ///
     /// # Arguments
     ///
     /// * `center_point` - The vertex that becomes the tile center
-    /// * `faces` - Mutable slice of faces that surround this vertex
+    /// * `faces` - Faces that surround this vertex, sorted around it
     /// * `hex_size` - Scale factor for tile size (0.01 to 1.0)
     ///   - 1.0: Tiles touch at their boundaries  
     ///   - 0.5: Tiles are half-size with gaps between them
     ///   - 0.01: Very small tiles with large gaps
     ///
+    /// The tile's `id` starts at 0; `Hexasphere` assigns each tile its index
+    /// in the tile array during construction.
+    ///
     /// # Mathematical Details
     ///
     /// For each face touching the center point:
     /// - Calculate face centroid C
     /// - Create boundary point B = C + (center - C) × (1 - hex_size)
     /// - This scales the centroid toward the center by the hex_size factor
+    /// - With hex_size 1.0, B is C unchanged, so tiles built from the same
+    ///   face share bit-identical corner points
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use geotiles::{Face, Point, Tile};
     /// # let center = Point::new(0.1, 0.2, 0.3);
-    /// # let mut faces = vec![
+    /// # let faces = vec![
     /// #     Face::new(0, center.clone(), Point::new(0.2, 0.3, 0.4), Point::new(0.3, 0.4, 0.5)),
     /// #     Face::new(1, center.clone(), Point::new(0.3, 0.4, 0.5), Point::new(0.4, 0.5, 0.6)),
     /// #     Face::new(2, center.clone(), Point::new(0.4, 0.5, 0.6), Point::new(0.5, 0.6, 0.7)),
@@ -105,36 +148,79 @@ impl Tile {
     /// #     Face::new(4, center.clone(), Point::new(0.6, 0.7, 0.8), Point::new(0.7, 0.8, 0.9)),
     /// #     Face::new(5, center.clone(), Point::new(0.7, 0.8, 0.9), Point::new(0.2, 0.3, 0.4)),
     /// # ];
-    /// let tile = Tile::new(center, &mut faces, 0.9);
+    /// let tile = Tile::new(center, &faces, 0.9);
     /// // Creates a tile that's 90% of full size
     /// ```
-    pub fn new(center_point: Point, faces: &mut [Face], hex_size: f64) -> Self {
+    pub fn new(center_point: Point, faces: &[Face], hex_size: f64) -> Self {
+        Self::with_precision(center_point, faces, hex_size, 3)
+    }
+
+    /// Like [`Tile::new`], rounding boundary points to `decimals` places.
+    pub(crate) fn with_precision(
+        center_point: Point,
+        faces: &[Face],
+        hex_size: f64,
+        decimals: u32,
+    ) -> Self {
+        Self::with_neighbors(center_point, faces, hex_size, decimals, Vec::new())
+    }
+
+    /// Like [`Tile::with_precision`], with `neighbors` already known.
+    ///
+    /// `neighbors[i]` is the tile across the edge between `faces[i]` and
+    /// `faces[i + 1]`; it is reordered along with the boundary if the
+    /// winding has to be fixed.
+    pub(crate) fn with_neighbors(
+        center_point: Point,
+        faces: &[Face],
+        hex_size: f64,
+        decimals: u32,
+        neighbors: Vec<usize>,
+    ) -> Self {
         let hex_size = hex_size.clamp(0.01, 1.0);
 
         let mut boundary = Vec::new();
-        let mut neighbor_hash = HashMap::new();
-
-        // Build boundary and collect neighbors
-        for face in faces.iter_mut() {
-            // Add boundary point
-            let centroid = face.get_centroid().clone();
-            boundary.push(center_point.segment(&centroid, hex_size));
 
-            // Collect neighbors
-            let other_points = face.get_other_points(&center_point);
-            for other_point in other_points {
-                neighbor_hash.insert(other_point.to_string(), true);
+        // Build boundary from face centroids. Full-size tiles use the centroid
+        // itself, a function of the shared face alone, so the tiles meeting
+        // at a corner store bit-identical points.
+        for face in faces.iter() {
+            let centroid = face.centroid_with_precision(decimals);
+            if hex_size >= 1.0 {
+                boundary.push(centroid);
+            } else {
+                boundary.push(center_point.segment_with_precision(&centroid, hex_size, decimals));
             }
         }
 
-        let neighbor_ids: Vec<String> = neighbor_hash.into_keys().collect();
+        // The edge boundary[i] -> boundary[i + 1] separates faces i and i + 1,
+        // so the tile across it is centered on the vertex those faces share
+        let mut neighbor_ids = Vec::with_capacity(faces.len());
+        for i in 0..faces.len() {
+            let next_face = &faces[(i + 1) % faces.len()];
+            let shared = faces[i]
+                .get_other_points(&center_point)
+                .into_iter()
+                .find(|point| next_face.points.contains(point));
+            if let Some(point) = shared {
+                let id = point.to_string();
+                if !neighbor_ids.contains(&id) {
+                    neighbor_ids.push(id);
+                }
+            }
+        }
 
         // Fix boundary orientation
         let mut tile = Self {
+            id: 0,
             center_point: center_point.clone(),
             boundary,
             neighbor_ids,
-            neighbors: Vec::new(),
+            neighbors,
+            base_face: 0,
+            sphere_center: Point::ORIGIN,
+            sphere_radius: Vector3::from(&center_point).length(),
+            cache: None,
         };
 
         tile.fix_boundary_orientation();
@@ -157,16 +243,40 @@ impl Tile {
     ///
     /// # Algorithm
     ///
-    /// 1. Calculate surface normal using first three boundary points
+    /// 1. Calculate surface normal using first three boundary points, without
+    ///    rounding so that small tiles keep a non-zero normal
     /// 2. Check if normal points away from sphere center (outward)
-    /// 3. If normal points inward, reverse the boundary vertex order
+    /// 3. If normal points inward, reverse the boundary vertex order and
+    ///    reorder the neighbor IDs and neighbors so they stay aligned with
+    ///    the edges. Known neighbors must have one entry per boundary edge.
     fn fix_boundary_orientation(&mut self) {
         if self.boundary.len() >= 3 {
-            let normal =
-                calculate_surface_normal(&self.boundary[1], &self.boundary[2], &self.boundary[0]);
-
-            if !pointing_away_from_origin(&self.center_point, &normal) {
+            let [a, b, c] =
+                [&self.boundary[1], &self.boundary[2], &self.boundary[0]].map(Vector3::from);
+            let normal = (&b - &a).cross(&(&c - &a));
+            let normal = Point {
+                x: normal.x,
+                y: normal.y,
+                z: normal.z,
+            };
+
+            if !pointing_away_from_origin(&self.relative(&self.center_point), &normal) {
                 self.boundary.reverse();
+
+                // Reversing turns edge i into edge n - 2 - i; keep neighbors aligned
+                if self.neighbor_ids.len() == self.boundary.len() {
+                    self.neighbor_ids.reverse();
+                    self.neighbor_ids.rotate_left(1);
+                }
+                if !self.neighbors.is_empty() {
+                    debug_assert_eq!(
+                        self.neighbors.len(),
+                        self.boundary.len(),
+                        "neighbors must line up with the boundary edges"
+                    );
+                    self.neighbors.reverse();
+                    self.neighbors.rotate_left(1);
+                }
             }
         }
     }
@@ -205,7 +315,7 @@ impl Tile {
     /// }
     /// ```
     pub fn get_lat_lon(&self, radius: f64) -> LatLon {
-        self.center_point.to_lat_lon(radius)
+        self.relative(&self.center_point).to_lat_lon(radius)
     }
 
     /// Converts a specific boundary point to latitude and longitude coordinates.
@@ -239,7 +349,195 @@ impl Tile {
     pub fn get_boundary_lat_lon(&self, radius: f64, boundary_num: usize) -> Option<LatLon> {
         self.boundary
             .get(boundary_num)
-            .map(|point| point.to_lat_lon(radius))
+            .map(|point| self.relative(point).to_lat_lon(radius))
+    }
+
+    /// Converts the tile center to latitude and longitude on its own sphere.
+    ///
+    /// Like [`Tile::get_lat_lon`] with the stored `sphere_radius`, so the
+    /// radius cannot be mismatched. Use the explicit form when the tile
+    /// coordinates have been scaled after construction.
+    ///
+    /// # Returns
+    ///
+    /// A `LatLon` struct with latitude and longitude in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// assert_eq!(tile.lat_lon(), tile.get_lat_lon(10.0));
+    /// ```
+    pub fn lat_lon(&self) -> LatLon {
+        self.get_lat_lon(self.sphere_radius)
+    }
+
+    /// Converts a boundary point to latitude and longitude on the tile's own sphere.
+    ///
+    /// Like [`Tile::get_boundary_lat_lon`] with the stored `sphere_radius`.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundary_num` - Index of the boundary point (0 to boundary.len()-1)
+    ///
+    /// # Returns
+    ///
+    /// Some(`LatLon`) if the boundary index is valid, None otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// assert_eq!(tile.boundary_lat_lon(0), tile.get_boundary_lat_lon(10.0, 0));
+    /// assert_eq!(tile.boundary_lat_lon(tile.boundary.len()), None);
+    /// ```
+    pub fn boundary_lat_lon(&self, boundary_num: usize) -> Option<LatLon> {
+        self.get_boundary_lat_lon(self.sphere_radius, boundary_num)
+    }
+
+    /// Gets the geographic coordinates of a boundary point, reporting bad indices.
+    ///
+    /// The fallible counterpart of [`Tile::get_boundary_lat_lon`], for callers
+    /// that propagate errors with `?` rather than handle `None`.
+    ///
+    /// # Returns
+    ///
+    /// The coordinates, or [`Error::IndexOutOfRange`](crate::Error::IndexOutOfRange)
+    /// if `boundary_num` is not a valid boundary index
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Error, Hexasphere};
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = hexasphere.tiles.iter().find(|t| t.is_pentagon()).unwrap();
+    ///
+    /// assert!(tile.try_get_boundary_lat_lon(10.0, 0).is_ok());
+    /// assert!(matches!(
+    ///     tile.try_get_boundary_lat_lon(10.0, 6),
+    ///     Err(Error::IndexOutOfRange { index: 6, len: 5 })
+    /// ));
+    /// ```
+    pub fn try_get_boundary_lat_lon(
+        &self,
+        radius: f64,
+        boundary_num: usize,
+    ) -> crate::Result<LatLon> {
+        self.get_boundary_lat_lon(radius, boundary_num)
+            .ok_or(crate::Error::IndexOutOfRange {
+                index: boundary_num,
+                len: self.boundary.len(),
+            })
+    }
+
+    /// Calculates the latitude/longitude box enclosing this tile.
+    ///
+    /// The corners and the center are projected onto the sphere, and the
+    /// edges are treated as great-circle arcs like in [`Tile::contains_point`],
+    /// so the box also covers arcs that bulge toward a pole between their
+    /// corners. Longitudes are measured from the center, which makes tiles
+    /// straddling the antimeridian come out as a narrow wrapping box rather
+    /// than one spanning the whole globe.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere the tile lies on
+    ///
+    /// # Returns
+    ///
+    /// The bounds in degrees. A tile containing a pole extends to ±90° latitude
+    /// and covers every longitude, from -180° to 180°.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Culling**: Skip tiles outside a map view with a cheap box test
+    /// - **Overlays**: Find the raster or tile-map region under a tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    ///
+    /// for tile in &hexasphere.tiles {
+    ///     let bounds = tile.lat_lon_bounds(10.0);
+    ///     assert!(bounds.contains(&tile.get_lat_lon(10.0)));
+    /// }
+    /// ```
+    pub fn lat_lon_bounds(&self, radius: f64) -> LatLonBounds {
+        if let Cow::Owned(tile) = self.centered() {
+            return tile.lat_lon_bounds(radius);
+        }
+        let to_lat_lon = |direction: &Vector3| project_exact(direction, radius).to_lat_lon(radius);
+        let center = to_lat_lon(&Vector3::from(&self.center_point));
+        let corners: Vec<Vector3> = self
+            .boundary
+            .iter()
+            .map(|point| Vector3::from(point).normalize())
+            .collect();
+
+        let (mut min_lat, mut max_lat) = (center.lat, center.lat);
+        // Westernmost and easternmost longitudes, ranked by their offset from
+        // the center in (-180, 180] so the antimeridian does not matter
+        let (mut west, mut east) = ((0.0, center.lon), (0.0, center.lon));
+        for corner in &corners {
+            let lat_lon = to_lat_lon(corner);
+            min_lat = min_lat.min(lat_lon.lat);
+            max_lat = max_lat.max(lat_lon.lat);
+            let offset = 180.0 - (180.0 - (lat_lon.lon - center.lon)).rem_euclid(360.0);
+            if offset < west.0 {
+                west = (offset, lat_lon.lon);
+            }
+            if offset > east.0 {
+                east = (offset, lat_lon.lon);
+            }
+        }
+        for (i, start) in corners.iter().enumerate() {
+            let end = &corners[(i + 1) % corners.len()];
+            for toward in [1.0, -1.0] {
+                if let Some(lat) = arc_extreme_latitude(start, end, toward) {
+                    min_lat = min_lat.min(lat);
+                    max_lat = max_lat.max(lat);
+                }
+            }
+        }
+
+        let north = self.contains_point(&Point {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        });
+        let south = self.contains_point(&Point {
+            x: 0.0,
+            y: -1.0,
+            z: 0.0,
+        });
+        if north || south {
+            return LatLonBounds {
+                min_lat: if south { -90.0 } else { min_lat },
+                max_lat: if north { 90.0 } else { max_lat },
+                min_lon: -180.0,
+                max_lon: 180.0,
+                crosses_antimeridian: false,
+            };
+        }
+
+        // A tile touching the antimeridian from either side does not cross it
+        let min_lon = if west.1 == 180.0 { -180.0 } else { west.1 };
+        let max_lon = if east.1 == -180.0 { 180.0 } else { east.1 };
+        LatLonBounds {
+            min_lat,
+            max_lat,
+            min_lon,
+            max_lon,
+            crosses_antimeridian: min_lon > max_lon,
+        }
     }
 
     /// Creates a smaller version of the tile boundary by scaling toward the center.
@@ -289,6 +587,174 @@ impl Tile {
             .collect()
     }
 
+    /// Creates a scaled boundary whose points lie on the sphere surface.
+    ///
+    /// Like [`Tile::scaled_boundary`], but each scaled point is pushed back out
+    /// onto the sphere of the given radius. Shrunken tiles then hug the sphere
+    /// instead of sinking below it, which matters when drawing them over a
+    /// smooth globe.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - How much to scale down (0.0 = point at center, 1.0 = original size)
+    ///   Automatically clamped to [0.0, 1.0]
+    /// * `radius` - Radius of the sphere to project onto
+    ///
+    /// # Returns
+    ///
+    /// A vector of points at distance `radius` from `sphere_center`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let boundary = hexasphere.tiles[0].scaled_boundary_on_sphere(0.8, 10.0);
+    ///
+    /// for point in &boundary {
+    ///     let distance = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+    ///     assert!((distance - 10.0).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn scaled_boundary_on_sphere(&self, scale: f64, radius: f64) -> Vec<Point> {
+        self.scaled_boundary(scale)
+            .iter()
+            .map(|point| self.placed(project_exact(&Vector3::from(&self.relative(point)), radius)))
+            .collect()
+    }
+
+    /// Samples the tile boundary along great-circle arcs.
+    ///
+    /// Boundary edges are straight chords that cut into the sphere. This method
+    /// projects the corners onto the sphere of the given radius and inserts
+    /// `samples_per_edge` extra points along each great-circle arc between
+    /// neighboring corners, producing a densified ring that follows the curved
+    /// surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere the arcs lie on
+    /// * `samples_per_edge` - Number of points inserted between each pair of corners
+    ///
+    /// # Returns
+    ///
+    /// A ring of `n × (samples_per_edge + 1)` points in the same counter-clockwise
+    /// order as `boundary`, where corner `i` is at index `i × (samples_per_edge + 1)`
+    ///
+    /// # Use Cases
+    ///
+    /// - **Globe rendering**: Draw tile outlines that follow the sphere surface
+    /// - **Curved meshes**: Triangulate tiles that bulge like the sphere does
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// let ring = tile.boundary_arc_points(10.0, 3);
+    /// assert_eq!(ring.len(), tile.boundary.len() * 4);
+    /// ```
+    pub fn boundary_arc_points(&self, radius: f64, samples_per_edge: usize) -> Vec<Point> {
+        let corners: Vec<Vector3> = self
+            .boundary
+            .iter()
+            .map(|point| Vector3::from(&self.relative(point)))
+            .collect();
+        let steps = samples_per_edge + 1;
+        let mut ring = Vec::with_capacity(corners.len() * steps);
+
+        for (i, start) in corners.iter().enumerate() {
+            let end = &corners[(i + 1) % corners.len()];
+            for step in 0..steps {
+                let direction = slerp(start, end, step as f64 / steps as f64);
+                ring.push(self.placed(project_exact(&direction, radius)));
+            }
+        }
+
+        ring
+    }
+
+    /// Triangulates the tile as a fan from its center.
+    ///
+    /// # Returns
+    ///
+    /// A vertex list of the center followed by the boundary points, and one
+    /// triangle per boundary edge indexing into it. Triangles wind
+    /// counter-clockwise seen from outside the sphere, so their normals point
+    /// outward. Tiles with fewer than 3 boundary points have no triangles.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Rendering**: Upload a tile as an indexed triangle mesh
+    /// - **Physics**: Build a collider from the tile's triangles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// let (vertices, triangles) = tile.triangulate();
+    /// assert_eq!(vertices.len(), tile.boundary.len() + 1);
+    /// assert_eq!(triangles.len(), tile.boundary.len());
+    /// assert_eq!(triangles[0], [0, 1, 2]);
+    /// ```
+    pub fn triangulate(&self) -> (Vec<Point>, Vec<[u32; 3]>) {
+        let vertices = std::iter::once(self.center_point.clone())
+            .chain(self.boundary.iter().cloned())
+            .collect();
+        (vertices, fan_triangles(self.boundary.len()))
+    }
+
+    /// Triangulates the tile as a fan over its arc-densified boundary.
+    ///
+    /// Like [`Tile::triangulate`], but the ring is
+    /// [`Tile::boundary_arc_points`] and the center is projected onto the
+    /// sphere too, so the tile follows the curved surface instead of lying
+    /// flat beneath it.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere the vertices are placed on
+    /// * `samples_per_edge` - Number of points inserted along each boundary edge
+    ///
+    /// # Returns
+    ///
+    /// The center followed by the densified ring, and one outward-facing
+    /// triangle per ring segment: `n × (samples_per_edge + 1)` triangles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// let (vertices, triangles) = tile.triangulate_with_arcs(10.0, 3);
+    /// assert_eq!(triangles.len(), tile.boundary.len() * 4);
+    /// assert_eq!(vertices.len(), triangles.len() + 1);
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// - Time complexity: O(n × s) where n = boundary points, s = `samples_per_edge`
+    pub fn triangulate_with_arcs(
+        &self,
+        radius: f64,
+        samples_per_edge: usize,
+    ) -> (Vec<Point>, Vec<[u32; 3]>) {
+        let ring = self.boundary_arc_points(radius, samples_per_edge);
+        let triangles = fan_triangles(ring.len());
+        let center = Vector3::from(&self.relative(&self.center_point));
+        let vertices = std::iter::once(self.placed(project_exact(&center, radius)))
+            .chain(ring)
+            .collect();
+        (vertices, triangles)
+    }
+
     /// Returns true if this is a hexagon (6 sides), false if pentagon (5 sides).
     ///
     /// Hexagons make up the vast majority of tiles (~90%) and are located away
@@ -341,6 +807,203 @@ impl Tile {
         self.boundary.len() == 5
     }
 
+    /// Returns the neighbor that lies across a given boundary edge.
+    ///
+    /// Edge `i` runs from `boundary[i]` to `boundary[(i + 1) % n]`. Because
+    /// `neighbors` is ordered counter-clockwise with the boundary, stepping the
+    /// edge index walks around the tile in the same direction as its winding.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge_index` - Index of the boundary edge (0 to n-1)
+    ///
+    /// # Returns
+    ///
+    /// The index of the adjacent tile in the hexasphere's `tiles` array, or `None`
+    /// if the edge index is out of range or the neighbors were not fully resolved
+    ///
+    /// # Use Cases
+    ///
+    /// - **Directional movement**: Step from tile to tile in a consistent direction
+    /// - **River tracing**: Follow an edge and find the tile on the other side
+    /// - **Hex-grid algorithms**: Port flat hex-grid logic that relies on edge order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// // Walk around the tile, visiting each neighbor in counter-clockwise order
+    /// for edge in 0..tile.boundary.len() {
+    ///     let neighbor = tile.neighbor_across_edge(edge).unwrap();
+    ///     assert!(hexasphere.tiles[neighbor].neighbors.contains(&0));
+    /// }
+    /// assert_eq!(tile.neighbor_across_edge(tile.boundary.len()), None);
+    /// ```
+    pub fn neighbor_across_edge(&self, edge_index: usize) -> Option<usize> {
+        if self.neighbors.len() != self.boundary.len() {
+            return None;
+        }
+        self.neighbors.get(edge_index).copied()
+    }
+
+    /// Finds the boundary edge this tile shares with another tile, by geometry.
+    ///
+    /// Looks for two consecutive boundary points of this tile that also appear
+    /// in `other`'s boundary. Points are rounded on construction, so corners
+    /// shared by adjacent tiles compare equal exactly. Use
+    /// [`crate::Hexasphere::shared_edge`] when the tile indices are known; it
+    /// relies on neighbor order instead and also works for `hex_size < 1.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tile to look for a shared edge with; a tile compared
+    ///   with itself matches its first edge
+    ///
+    /// # Returns
+    ///
+    /// The two shared corners in this tile's counter-clockwise winding, or
+    /// `None` if the tiles do not share an edge (including when `hex_size < 1.0`
+    /// has pulled the boundaries apart)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    /// let neighbor = &hexasphere.tiles[tile.neighbors[0]];
+    ///
+    /// let (start, end) = tile.shared_edge_with(neighbor).unwrap();
+    /// assert_eq!(start, tile.boundary[0]);
+    /// assert_eq!(end, tile.boundary[1]);
+    /// ```
+    pub fn shared_edge_with(&self, other: &Tile) -> Option<(Point, Point)> {
+        let n = self.boundary.len();
+        if n < 2 {
+            return None;
+        }
+
+        (0..n).find_map(|i| {
+            let start = &self.boundary[i];
+            let end = &self.boundary[(i + 1) % n];
+            (other.boundary.contains(start) && other.boundary.contains(end))
+                .then(|| (start.clone(), end.clone()))
+        })
+    }
+
+    /// Tests whether the direction of a point falls inside this tile.
+    ///
+    /// The tile is treated as a spherical polygon: every boundary edge defines a
+    /// great circle (a plane through the sphere's center), and the point is
+    /// inside if it lies on the same side of every one of those planes as the
+    /// tile center. Only the direction of `point` matters, so ray hits slightly
+    /// above or below the surface are handled the same way as points exactly on
+    /// it. Points on an edge count as inside both adjacent tiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to test, in the same coordinate space as the tile
+    ///
+    /// # Returns
+    ///
+    /// `true` if the point's direction lies within the tile boundary
+    ///
+    /// # Use Cases
+    ///
+    /// - **Picking**: Find the tile under a raycast hit on the sphere
+    /// - **Placement**: Check whether an object position belongs to a tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// assert!(tile.contains_point(&tile.center_point));
+    /// // Only the direction matters, so a hit above the surface still counts
+    /// let above = &tile.center_point * 2.0;
+    /// assert!(tile.contains_point(&above));
+    /// ```
+    pub fn contains_point(&self, point: &Point) -> bool {
+        let n = self.boundary.len();
+        if n < 3 {
+            return false;
+        }
+
+        let Some(direction) = Vector3::from(&self.relative(point)).try_normalize() else {
+            return false;
+        };
+        let center = Vector3::from(&self.relative(&self.center_point)).normalize();
+
+        (0..n).all(|i| {
+            let edge_plane = Vector3::from(&self.relative(&self.boundary[i]))
+                .cross(&Vector3::from(&self.relative(&self.boundary[(i + 1) % n])))
+                .normalize();
+            let center_side = edge_plane.dot(&center);
+            let point_side = edge_plane.dot(&direction);
+            point_side * center_side.signum() >= -CONTAINMENT_TOLERANCE
+        })
+    }
+
+    /// Calculates the interior angle at each boundary vertex.
+    ///
+    /// The boundary is projected onto the plane tangent to the sphere at the
+    /// tile center, and each angle is measured there between the edges to the
+    /// previous and next boundary points. Measuring in the tile's own plane
+    /// (rather than at each vertex, where three tiles always share 360°) keeps
+    /// the angles comparable with those of a flat regular polygon.
+    ///
+    /// A regular hexagon has interior angles of 120° (2π/3) and a regular
+    /// pentagon 108° (3π/5); deviation from these values measures angular
+    /// distortion.
+    ///
+    /// # Returns
+    ///
+    /// Interior angles in radians, where entry `i` belongs to `boundary[i]`.
+    /// Angles lie in [0, 2π), so the reflex corners of a non-convex tile (for
+    /// example after relaxing or displacing) exceed π. Empty for tiles with
+    /// fewer than 3 boundary points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+    ///
+    /// for angle in tile.interior_angles() {
+    ///     assert!((angle.to_degrees() - 120.0).abs() < 15.0);
+    /// }
+    /// ```
+    pub fn interior_angles(&self) -> Vec<f64> {
+        let n = self.boundary.len();
+        if n < 3 {
+            return Vec::new();
+        }
+
+        let normal = Vector3::from(&self.relative(&self.center_point)).normalize();
+        (0..n)
+            .map(|i| {
+                let vertex = Vector3::from(&self.boundary[i]);
+                let tangent = |point: &Point| {
+                    let edge = Vector3::from(point) - vertex.clone();
+                    &edge - &(&normal * edge.dot(&normal))
+                };
+                let to_prev = tangent(&self.boundary[(i + n - 1) % n]);
+                let to_next = tangent(&self.boundary[(i + 1) % n]);
+                // The counter-clockwise boundary puts the interior counter-clockwise
+                // from the next edge to the previous one
+                let sin = to_next.cross(&to_prev).dot(&normal);
+                sin.atan2(to_next.dot(&to_prev))
+                    .rem_euclid(std::f64::consts::TAU)
+            })
+            .collect()
+    }
+
     /// Calculate the average distance from center to boundary points (approximates radius).
     ///
     /// This provides a measure of the tile's "size" by calculating how far the boundary
@@ -374,6 +1037,9 @@ impl Tile {
     /// }
     /// ```
     pub fn get_average_radius(&self) -> f64 {
+        if let Some(cache) = &self.cache {
+            return cache.average_radius;
+        }
         if self.boundary.is_empty() {
             return 0.0;
         }
@@ -428,6 +1094,9 @@ impl Tile {
     /// }
     /// ```
     pub fn get_average_edge_length(&self) -> f64 {
+        if let Some(cache) = &self.cache {
+            return cache.average_edge_length;
+        }
         if self.boundary.len() < 2 {
             return 0.0;
         }
@@ -486,6 +1155,9 @@ impl Tile {
     /// println!("This tile covers {:.2}% of sphere", coverage * 100.0);
     /// ```
     pub fn get_area(&self) -> f64 {
+        if let Some(cache) = &self.cache {
+            return cache.area;
+        }
         if self.boundary.len() < 3 {
             return 0.0;
         }
@@ -505,29 +1177,255 @@ impl Tile {
         total_area
     }
 
-    /// Calculate the orientation of this tile for placing a regular hexagon.
+    /// Calculate the area the tile covers when projected onto the sphere.
     ///
-    /// Determines the local coordinate system for this tile, which can be used
+    /// [`Tile::get_area`] measures the flat polygon, which lies beneath the
+    /// sphere and is therefore always a little smaller. This measures the
+    /// spherical polygon obtained by projecting the boundary onto the sphere
+    /// and joining it with great-circle arcs. With `hex_size`
+    /// 1.0 the spherical areas of all tiles add up to the full sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere to project onto
+    ///
+    /// # Returns
+    ///
+    /// The spherical area, or 0.0 for tiles with fewer than 3 boundary points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// assert!(tile.get_spherical_area(10.0) > tile.get_area());
+    /// ```
+    pub fn get_spherical_area(&self, radius: f64) -> f64 {
+        spherical_polygon_area(&self.centered().boundary, radius)
+    }
+
+    /// Get the perimeter of this tile, summing the straight boundary edges.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the chord lengths between consecutive boundary points, or 0.0 if
+    /// fewer than 2 boundary points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// let expected = tile.get_average_edge_length() * tile.boundary.len() as f64;
+    /// assert!((tile.get_perimeter() - expected).abs() < 1e-9);
+    /// ```
+    pub fn get_perimeter(&self) -> f64 {
+        let n = self.boundary.len();
+        if n < 2 {
+            return 0.0;
+        }
+
+        (0..n)
+            .map(|i| self.boundary[i].distance_to(&self.boundary[(i + 1) % n]))
+            .sum()
+    }
+
+    /// Calculate the perimeter the tile has when projected onto the sphere.
+    ///
+    /// The spherical counterpart of [`Tile::get_perimeter`]: boundary points are
+    /// projected onto the sphere and joined with great-circle arcs, so the
+    /// result is always a little longer than the chord perimeter.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere to project onto
+    ///
+    /// # Returns
+    ///
+    /// Sum of the great-circle arc lengths, or 0.0 if fewer than 2 boundary points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// assert!(tile.get_spherical_perimeter(101f64.sqrt()) > tile.get_perimeter());
+    /// ```
+    pub fn get_spherical_perimeter(&self, radius: f64) -> f64 {
+        let n = self.boundary.len();
+        if n < 2 {
+            return 0.0;
+        }
+
+        let boundary = &self.centered().boundary;
+        (0..n)
+            .map(|i| great_circle_distance(&boundary[i], &boundary[(i + 1) % n], radius))
+            .sum()
+    }
+
+    /// Calculate the isoperimetric compactness of this tile.
+    ///
+    /// Compactness is `4π·area / perimeter²`, using [`Tile::get_perimeter`] and
+    /// the area enclosed by the boundary polygon itself. [`Tile::get_area`] is
+    /// not used: it fans out from the center point, which sits above the
+    /// boundary, and would let real tiles outscore a flat regular polygon.
+    /// A circle scores 1.0 and every polygon scores less; squashed tiles score
+    /// noticeably less than regular ones with the same number of sides.
+    ///
+    /// # Returns
+    ///
+    /// The compactness score, or 0.0 for tiles without a perimeter
+    ///
+    /// # Reference Values
+    ///
+    /// - **Regular hexagon**: `π/(2√3)` ≈ 0.9069
+    /// - **Regular pentagon**: `π/(5·tan(π/5))` ≈ 0.8648
+    ///
+    /// # Use Cases
+    ///
+    /// - Detecting badly squashed tiles near the pentagons
+    /// - Comparing shape quality across subdivision levels
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let regular_hexagon = std::f64::consts::PI / (2.0 * 3f64.sqrt());
+    ///
+    /// for (_, tile) in hexasphere.hexagons() {
+    ///     assert!(tile.compactness() <= regular_hexagon + 1e-9);
+    /// }
+    /// ```
+    pub fn compactness(&self) -> f64 {
+        let perimeter = self.get_perimeter();
+        if perimeter <= 0.0 {
+            return 0.0;
+        }
+
+        // Half the length of the vector area, exact for planar polygons
+        let n = self.boundary.len();
+        let vector_area = (0..n).fold(Vector3::new(0.0, 0.0, 0.0), |sum, i| {
+            let start = Vector3::from(&self.boundary[i]);
+            let end = Vector3::from(&self.boundary[(i + 1) % n]);
+            sum + start.cross(&end)
+        });
+        let area = 0.5 * vector_area.length();
+
+        4.0 * std::f64::consts::PI * area / (perimeter * perimeter)
+    }
+
+    /// Calculate the area-weighted centroid of the boundary polygon.
+    ///
+    /// `center_point` is the geodesic vertex the tile was built around, not
+    /// the center of mass of its polygon. On distorted tiles the two drift
+    /// apart, which matters when placing labels or physics bodies. The polygon
+    /// is split into triangles fanning out from the mean boundary point, each
+    /// weighted by its area, and the result is projected back onto the sphere
+    /// at the distance of `center_point` from `sphere_center`.
+    ///
+    /// # Returns
+    ///
+    /// The centroid on the tile's sphere, or `center_point` for tiles with
+    /// fewer than 3 boundary points or no area
+    ///
+    /// # Use Cases
+    ///
+    /// - **Label placement**: Put text at the visual middle of the tile
+    /// - **Physics**: Center of mass for a tile-shaped rigid body
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let centroid = hexasphere.tiles[20].boundary_centroid();
+    ///
+    /// let distance = (centroid.x.powi(2) + centroid.y.powi(2) + centroid.z.powi(2)).sqrt();
+    /// assert!((distance - 10.0).abs() < 1e-6);
+    /// ```
+    pub fn boundary_centroid(&self) -> Point {
+        let n = self.boundary.len();
+        if n < 3 {
+            return self.center_point.clone();
+        }
+
+        let corners: Vec<Vector3> = self.boundary.iter().map(Vector3::from).collect();
+        let hub = corners
+            .iter()
+            .fold(Vector3::new(0.0, 0.0, 0.0), |sum, corner| &sum + corner)
+            / n as f64;
+
+        let mut weighted = Vector3::new(0.0, 0.0, 0.0);
+        let mut total_area = 0.0;
+        for i in 0..n {
+            let (start, end) = (&corners[i], &corners[(i + 1) % n]);
+            let area = 0.5 * (start - &hub).cross(&(end - &hub)).length();
+            let triangle_centroid = &(&hub + start) + end;
+            weighted = weighted + triangle_centroid * (area / 3.0);
+            total_area += area;
+        }
+        if total_area <= 0.0 {
+            return self.center_point.clone();
+        }
+
+        let radius = Vector3::from(&self.relative(&self.center_point)).length();
+        let centroid = weighted / total_area - Vector3::from(&self.sphere_center);
+        self.placed(project_exact(&centroid, radius))
+    }
+
+    /// Distance between `center_point` and [`Tile::boundary_centroid`].
+    ///
+    /// # Returns
+    ///
+    /// The straight-line offset, close to 0.0 for near-regular tiles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    ///
+    /// // Pentagons are symmetric about their center
+    /// let (_, pentagon) = hexasphere.pentagons().next().unwrap();
+    /// assert!(pentagon.centroid_offset() < 1e-2);
+    /// ```
+    pub fn centroid_offset(&self) -> f64 {
+        Vector3::from(&self.center_point).distance_to(&Vector3::from(&self.boundary_centroid()))
+    }
+
+    /// Calculate the orientation of this tile for placing a regular hexagon.
+    ///
+    /// Determines the local coordinate system for this tile, which can be used
     /// to orient regular hexagons or other objects. The orientation is defined
     /// by three orthogonal unit vectors forming a right-handed coordinate system.
     ///
     /// # Returns
     ///
     /// Some(`TileOrientation`) containing the coordinate system vectors, or None if
-    /// the tile has no boundary points
+    /// the tile has no boundary points, its center is `sphere_center`, or every
+    /// boundary point lies on the line through `sphere_center` and the center
     ///
     /// # Coordinate System Definition
     ///
-    /// - **Right vector**: Points from center toward first boundary vertex
+    /// - **Right vector**: Points from center toward first boundary vertex, or
+    ///   toward the next one if the first lies straight above or below the center
     /// - **Up vector**: Points outward from sphere surface (surface normal)
     /// - **Forward vector**: Perpendicular to both (completes right-handed system)
     ///
     /// # Algorithm
     ///
-    /// 1. Calculate right vector: normalize(first_boundary - center)
-    /// 2. Calculate up vector: normalize(center) (sphere normal)
+    /// 1. Calculate up vector: normalize(center) (sphere normal)
+    /// 2. Calculate right vector: first_boundary - center, projected into the
+    ///    tangent plane and normalized; boundary vertices whose offset is
+    ///    parallel to up are skipped
     /// 3. Calculate forward vector: cross(right, up)
-    /// 4. Recalculate right: cross(up, forward) (ensure orthogonality)
     ///
     /// # Use Cases
     ///
@@ -550,39 +1448,466 @@ impl Tile {
     /// }
     /// ```
     pub fn get_orientation(&self) -> Option<TileOrientation> {
+        if let Some(cache) = &self.cache {
+            return cache.orientation.clone();
+        }
         if self.boundary.is_empty() {
             return None;
         }
 
-        // Use the first boundary point to define the "right" direction
-        let first_vertex = &self.boundary[0];
-
-        // Calculate the "right" vector (center to first vertex)
-        let right = Vector3::new(
-            first_vertex.x - self.center_point.x,
-            first_vertex.y - self.center_point.y,
-            first_vertex.z - self.center_point.z,
-        )
-        .normalize();
-
-        // Calculate the "up" vector (normal to sphere surface)
-        // For a sphere centered at origin, this is just the center point normalized
-        let up = Vector3::new(
-            self.center_point.x,
-            self.center_point.y,
-            self.center_point.z,
-        )
-        .normalize();
+        // Calculate the "up" vector (normal to sphere surface), pointing
+        // from the sphere center through the tile center
+        let center = Vector3::from(&self.center_point);
+        let up = Vector3::from(&self.relative(&self.center_point)).try_normalize()?;
+
+        // Calculate the "right" vector: center to the first boundary vertex,
+        // flattened into the tangent plane so the basis is orthonormal. A vertex
+        // straight above or below the center has no tangent direction, so the
+        // next one stands in for it.
+        let right = self.boundary.iter().find_map(|vertex| {
+            let offset = (&Vector3::from(vertex) - &center).try_normalize()?;
+            if offset.cross(&up).length() < PARALLEL_TOLERANCE {
+                return None;
+            }
+            offset.reject_from(&up).try_normalize()
+        })?;
 
         // Calculate the "forward" vector (cross product of right and up)
         let forward = right.cross(&up).normalize();
 
-        // Recalculate right to ensure orthogonality (cross product of up and forward)
-        let right = up.cross(&forward).normalize();
-
         Some(TileOrientation { right, up, forward })
     }
 
+    /// Calculates how far the tile's local frame is twisted away from north.
+    ///
+    /// North is the direction of increasing latitude (toward +Y, see
+    /// [`Point::to_lat_lon`]) projected into the tile's tangent plane. The
+    /// angle is measured from the `right` vector of [`Tile::get_orientation`]
+    /// to north, positive counter-clockwise when looking down at the tile from
+    /// outside the sphere (a right-handed rotation about `up`).
+    ///
+    /// # Returns
+    ///
+    /// The signed angle in radians in `(-π, π]`, or `None` if the tile has no
+    /// boundary or its center sits on a pole where north is undefined
+    ///
+    /// # Use Cases
+    ///
+    /// - **Labels**: Rotate text by this angle so it reads north-up on every tile
+    /// - **Sprites**: Keep decals consistently oriented across the sphere
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// for tile in &hexasphere.tiles {
+    ///     if let Some(angle) = tile.orientation_angle_north() {
+    ///         assert!(angle.abs() <= std::f64::consts::PI);
+    ///     }
+    /// }
+    /// ```
+    pub fn orientation_angle_north(&self) -> Option<f64> {
+        let orientation = self.get_orientation()?;
+        let north = north_tangent(&orientation.up)?;
+
+        let sin = orientation.right.cross(&north).dot(&orientation.up);
+        let cos = orientation.right.dot(&north);
+        Some(sin.atan2(cos))
+    }
+
+    /// Maps the boundary into the unit square for per-tile texturing.
+    ///
+    /// Each boundary point is projected onto the tile's tangent plane from
+    /// [`Tile::get_orientation`] and divided by [`Tile::get_average_radius`].
+    /// The center sits implicitly at (0.5, 0.5) and the first boundary point
+    /// lies along +u, so a regular hexagon lands on the corners of the
+    /// standard atlas hexagon `(0.5 + 0.5·cos(i·60°), 0.5 + 0.5·sin(i·60°))`.
+    ///
+    /// # Returns
+    ///
+    /// One `(u, v)` pair per boundary point, counter-clockwise like the
+    /// boundary itself, or an empty vector if the tile has no boundary
+    ///
+    /// # Irregular Tiles
+    ///
+    /// Corners further from the center than average map slightly outside the
+    /// unit square; the distortion is the real shape of the tile.
+    ///
+    /// # Use Cases
+    ///
+    /// - **Texture atlases**: Sample one hexagon (or pentagon) texture per tile
+    /// - **Decals**: Place per-tile art without seams between tiles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// let uvs = tile.local_uvs();
+    /// assert_eq!(uvs.len(), tile.boundary.len());
+    ///
+    /// // The first corner points along +u
+    /// assert!(uvs[0].0 > 0.9 && (uvs[0].1 - 0.5).abs() < 1e-9);
+    /// ```
+    pub fn local_uvs(&self) -> Vec<(f64, f64)> {
+        let Some(orientation) = self.get_orientation() else {
+            return Vec::new();
+        };
+        let radius = self.get_average_radius();
+        if radius <= 0.0 {
+            return vec![(0.5, 0.5); self.boundary.len()];
+        }
+
+        // Counter-clockwise around `up` runs from `right` toward `up × right`
+        let across = orientation.up.cross(&orientation.right);
+        let center = Vector3::from(&self.center_point);
+
+        self.boundary
+            .iter()
+            .map(|point| {
+                let offset = &Vector3::from(point) - &center;
+                (
+                    0.5 + 0.5 * offset.dot(&orientation.right) / radius,
+                    0.5 + 0.5 * offset.dot(&across) / radius,
+                )
+            })
+            .collect()
+    }
+
+    /// Projects the boundary into the tile's local 2D frame.
+    ///
+    /// Each boundary point is projected orthogonally onto the tangent plane at
+    /// `center_point`, using the [`Tile::get_orientation`] basis: `x` runs
+    /// along `right` and `y` along `up × right` (which is `-forward`). With
+    /// that choice of `y` the polygon keeps the counter-clockwise order of the
+    /// boundary, so its signed area is positive. The center is the origin and
+    /// the first boundary point lies on the positive `x` axis.
+    ///
+    /// # Returns
+    ///
+    /// One `(x, y)` pair per boundary point in world units, or an empty vector
+    /// if the tile has no boundary
+    ///
+    /// # Use Cases
+    ///
+    /// - **2D algorithms**: Point-in-polygon tests, insetting, straight skeletons
+    /// - **Labels**: Fit text into the tile's outline
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let tile = &hexasphere.tiles[3];
+    ///
+    /// let polygon = tile.to_local_2d();
+    /// let doubled_area: f64 = (0..polygon.len())
+    ///     .map(|i| {
+    ///         let ((x1, y1), (x2, y2)) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+    ///         x1 * y2 - x2 * y1
+    ///     })
+    ///     .sum();
+    /// assert!(doubled_area > 0.0);
+    /// ```
+    pub fn to_local_2d(&self) -> Vec<(f64, f64)> {
+        let Some(orientation) = self.get_orientation() else {
+            return Vec::new();
+        };
+        let across = orientation.up.cross(&orientation.right);
+        let center = Vector3::from(&self.center_point);
+
+        self.boundary
+            .iter()
+            .map(|point| {
+                let offset = &Vector3::from(point) - &center;
+                (offset.dot(&orientation.right), offset.dot(&across))
+            })
+            .collect()
+    }
+
+    /// Maps a point of the local 2D frame back onto the tile.
+    ///
+    /// The inverse of [`Tile::to_local_2d`]. Boundary points are not
+    /// coplanar with the center, so instead of lifting `(x, y)` onto the
+    /// tangent plane it is lifted onto the tile's own surface: the fan of
+    /// triangles from [`Tile::triangulate`]. The triangle whose 2D wedge
+    /// contains `(x, y)` is interpolated linearly, which returns boundary
+    /// points exactly and keeps every other point on the rendered tile.
+    /// Points outside the polygon are extrapolated from the wedge they fall in.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Distance along the local `right` axis
+    /// * `y` - Distance along the local `up × right` axis
+    ///
+    /// # Returns
+    ///
+    /// The point in world space, unrounded; the center point if the tile has
+    /// no boundary
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let tile = &hexasphere.tiles[3];
+    ///
+    /// let (x, y) = tile.to_local_2d()[2];
+    /// assert!(tile.from_local_2d(x, y).distance_to(&tile.boundary[2]) < 1e-9);
+    /// assert_eq!(tile.from_local_2d(0.0, 0.0), tile.center_point);
+    /// ```
+    pub fn from_local_2d(&self, x: f64, y: f64) -> Point {
+        let ring = self.to_local_2d();
+        let count = ring.len();
+        let center = Vector3::from(&self.center_point);
+        if count < 3 {
+            return self.center_point.clone();
+        }
+
+        // The wedge between corners i and i + 1 that (x, y) lies in, or the
+        // one it misses by the least
+        let cross = |(ax, ay): (f64, f64), (bx, by): (f64, f64)| ax * by - ay * bx;
+        let wedge = (0..count)
+            .max_by(|&a, &b| {
+                let margin =
+                    |i: usize| cross(ring[i], (x, y)).min(cross((x, y), ring[(i + 1) % count]));
+                margin(a).total_cmp(&margin(b))
+            })
+            .unwrap_or(0);
+
+        // Solve (x, y) = s·ring[i] + t·ring[i + 1]
+        let (a, b) = (ring[wedge], ring[(wedge + 1) % count]);
+        let determinant = cross(a, b);
+        let offset = if determinant.abs() > f64::EPSILON {
+            let s = cross((x, y), b) / determinant;
+            let t = cross(a, (x, y)) / determinant;
+            let to_a = &Vector3::from(&self.boundary[wedge]) - &center;
+            let to_b = &Vector3::from(&self.boundary[(wedge + 1) % count]) - &center;
+            to_a * s + to_b * t
+        } else {
+            let Some(orientation) = self.get_orientation() else {
+                return self.center_point.clone();
+            };
+            let across = orientation.up.cross(&orientation.right);
+            &orientation.right * x + across * y
+        };
+
+        let point = center + offset;
+        Point {
+            x: point.x,
+            y: point.y,
+            z: point.z,
+        }
+    }
+
+    /// Maps a point of the local 2D frame back onto the sphere.
+    ///
+    /// Like [`Tile::from_local_2d`], with the result pushed out onto the
+    /// sphere of the given radius along its direction from `sphere_center`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Distance along the local `right` axis
+    /// * `y` - Distance along the local `up × right` axis
+    /// * `radius` - Radius of the sphere to project onto
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, Vector3};
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let point = hexasphere.tiles[3].from_local_2d_on_sphere(0.2, 0.1, 10.0);
+    /// assert!((Vector3::from(&point).length() - 10.0).abs() < 1e-9);
+    /// ```
+    pub fn from_local_2d_on_sphere(&self, x: f64, y: f64, radius: f64) -> Point {
+        let point = self.relative(&self.from_local_2d(x, y));
+        self.placed(project_exact(&Vector3::from(&point), radius))
+    }
+
+    /// Maps parametric `(u, v)` coordinates to a position inside the tile.
+    ///
+    /// The unit square is first mapped onto the unit disc with the
+    /// concentric (Shirley–Chiu) mapping, then each ray of the disc is
+    /// stretched to reach the tile outline in its local 2D frame
+    /// ([`Tile::to_local_2d`]). The whole square therefore covers the tile
+    /// without clipping: `(0.5, 0.5)` is the tile center, the edges of the
+    /// square trace the boundary and nearby parameters stay nearby. The
+    /// result is pushed onto the sphere of radius `sphere_radius`.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - First parameter, clamped to `[0, 1]`
+    /// * `v` - Second parameter, clamped to `[0, 1]`
+    ///
+    /// # Returns
+    ///
+    /// The point on the sphere, unrounded; the center point if the tile has
+    /// fewer than 3 boundary points
+    ///
+    /// # Use Cases
+    ///
+    /// - **Object placement**: Position buildings or props inside a tile
+    /// - **Texturing**: Map a square texture onto a tile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let tile = &hexasphere.tiles[3];
+    ///
+    /// let point = tile.interpolate(0.25, 0.7);
+    /// assert!(tile.contains_point(&point));
+    /// assert!(tile.interpolate(0.5, 0.5).distance_to(&tile.center_point) < 1e-9);
+    /// ```
+    pub fn interpolate(&self, u: f64, v: f64) -> Point {
+        let ring = self.to_local_2d();
+        if ring.len() < 3 {
+            return self.center_point.clone();
+        }
+
+        let (a, b) = (2.0 * u.clamp(0.0, 1.0) - 1.0, 2.0 * v.clamp(0.0, 1.0) - 1.0);
+        let (radius, angle) = if a == 0.0 && b == 0.0 {
+            (0.0, 0.0)
+        } else if a.abs() > b.abs() {
+            (a, std::f64::consts::FRAC_PI_4 * b / a)
+        } else {
+            (
+                b,
+                std::f64::consts::FRAC_PI_2 - std::f64::consts::FRAC_PI_4 * a / b,
+            )
+        };
+        let (sin, cos) = angle.sin_cos();
+        let (x, y) = (radius * cos, radius * sin);
+        let reach = radial_extent(&ring, (x, y));
+
+        self.from_local_2d_on_sphere(x * reach, y * reach, self.sphere_radius)
+    }
+
+    /// Spreads up to `n` points over the tile in a hexagonal packing.
+    ///
+    /// Points are laid out on a triangular lattice in the local 2D frame,
+    /// aligned with the `right` axis and centered on the tile center, with
+    /// the spacing chosen so that as many points as possible up to `n` fall
+    /// strictly inside the outline. They are then pushed onto the sphere of
+    /// radius `sphere_radius`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Maximum number of points
+    ///
+    /// # Returns
+    ///
+    /// Between 1 and `n` points inside the tile, nearest the center first;
+    /// empty if `n` is 0 or the tile has fewer than 3 boundary points
+    ///
+    /// # Use Cases
+    ///
+    /// - **City layout**: Evenly spaced building lots on a tile
+    /// - **Vegetation**: Scatter trees without overlaps
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+    /// let tile = &hexasphere.tiles[3];
+    ///
+    /// let lots = tile.sample_grid(12);
+    /// assert!(!lots.is_empty() && lots.len() <= 12);
+    /// assert!(lots.iter().all(|point| tile.contains_point(point)));
+    /// ```
+    pub fn sample_grid(&self, n: usize) -> Vec<Point> {
+        let ring = self.to_local_2d();
+        if n == 0 || ring.len() < 3 {
+            return Vec::new();
+        }
+
+        // Each lattice point covers a rhombus of area (√3 / 2) s²
+        let area = (0..ring.len())
+            .map(|i| {
+                let ((x1, y1), (x2, y2)) = (ring[i], ring[(i + 1) % ring.len()]);
+                x1 * y2 - x2 * y1
+            })
+            .sum::<f64>()
+            .abs()
+            / 2.0;
+        let extent = ring.iter().map(|&(x, y)| x.hypot(y)).fold(0.0, f64::max);
+        let mut spacing = (2.0 * area / (3f64.sqrt() * n as f64)).sqrt();
+
+        let mut points = vec![(0.0, 0.0)];
+        while spacing > 0.0 && spacing <= 2.0 * extent {
+            let row_height = spacing * 3f64.sqrt() / 2.0;
+            let rows = (extent / row_height).ceil() as i64;
+            let columns = (extent / spacing).ceil() as i64 + 1;
+            let lattice: Vec<(f64, f64)> = (-rows..=rows)
+                .flat_map(|row| {
+                    let shift = if row % 2 == 0 { 0.0 } else { spacing / 2.0 };
+                    (-columns..=columns).map(move |column| {
+                        (column as f64 * spacing + shift, row as f64 * row_height)
+                    })
+                })
+                .filter(|&(x, y)| (x == 0.0 && y == 0.0) || radial_extent(&ring, (x, y)) > 1.0)
+                .collect();
+            if lattice.len() <= n {
+                points = lattice;
+                break;
+            }
+            spacing *= 1.05;
+        }
+
+        points.sort_by(|a, b| (a.0.hypot(a.1)).total_cmp(&b.0.hypot(b.1)));
+        points
+            .into_iter()
+            .map(|(x, y)| self.from_local_2d_on_sphere(x, y, self.sphere_radius))
+            .collect()
+    }
+
+    /// Maps the boundary to equirectangular texture coordinates.
+    ///
+    /// `u = (lon + 180) / 360` and `v = (lat + 90) / 180`, with latitude and
+    /// longitude as in [`Point::to_lat_lon`], so a standard world map wraps
+    /// the whole sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere the tile lies on
+    ///
+    /// # Returns
+    ///
+    /// One `(u, v)` pair per boundary point
+    ///
+    /// # Antimeridian Seam
+    ///
+    /// A tile straddling ±180° longitude would otherwise be interpolated
+    /// across the whole texture. Its vertices on the western side (small `u`)
+    /// are shifted by +1 instead, so `u` may reach up to 1.5 for those tiles.
+    /// Use a repeating sampler in `u` to render them correctly. Everything
+    /// else stays within [0, 1].
+    ///
+    /// # Use Cases
+    ///
+    /// - **Globe textures**: Drape a world map or satellite image over the tiles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    /// for tile in &hexasphere.tiles {
+    ///     for (u, v) in tile.latlon_uvs(10.0) {
+    ///         assert!((0.0..1.5).contains(&u));
+    ///         assert!((0.0..=1.0).contains(&v));
+    ///     }
+    /// }
+    /// ```
+    pub fn latlon_uvs(&self, radius: f64) -> Vec<(f64, f64)> {
+        equirectangular_uvs(self.centered().boundary.iter(), radius)
+    }
+
     /// Get the best regular hexagon parameters for this tile.
     ///
     /// Calculates the position, size, and orientation for a regular hexagon that
@@ -641,15 +1966,266 @@ impl Tile {
             return None; // Only works for hexagons
         }
 
-        let orientation = self.get_orientation()?;
-        let radius = self.get_average_radius();
+        let orientation = self.get_orientation()?;
+        let radius = self.get_average_radius();
+
+        Some(RegularHexagonParams {
+            center: self.center_point.clone(),
+            radius,
+            orientation,
+        })
+    }
+
+    /// Get the best regular pentagon parameters for this tile.
+    ///
+    /// The pentagon counterpart of [`Tile::get_regular_hexagon_params`]. Only
+    /// works for the 12 pentagonal tiles; returns None for hexagons.
+    ///
+    /// # Returns
+    ///
+    /// Some(`RegularPentagonParams`) if this is a pentagon, None if it's a hexagon
+    ///
+    /// # Parameter Calculation
+    ///
+    /// - **Center**: Uses the tile's center point
+    /// - **Radius**: Uses the average distance from center to boundary points
+    /// - **Orientation**: Uses the tile's computed orientation vectors
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+    /// for tile in &hexasphere.tiles {
+    ///     if let Some(pent_params) = tile.get_regular_pentagon_params() {
+    ///         let vertices = pent_params.generate_vertices();
+    ///         assert_eq!(vertices.len(), 5);
+    ///     }
+    /// }
+    /// ```
+    pub fn get_regular_pentagon_params(&self) -> Option<RegularPentagonParams> {
+        if !self.is_pentagon() {
+            return None; // Only works for pentagons
+        }
+
+        let orientation = self.get_orientation()?;
+        let radius = self.get_average_radius();
+
+        Some(RegularPentagonParams {
+            center: self.center_point.clone(),
+            radius,
+            orientation,
+        })
+    }
+
+    /// Computes and stores this tile's derived values.
+    ///
+    /// Afterwards [`Tile::get_orientation`], [`Tile::get_average_radius`],
+    /// [`Tile::get_average_edge_length`] and [`Tile::get_area`] return the
+    /// stored values instead of recomputing them on every call.
+    ///
+    /// The cache is not updated when `center_point` or `boundary` change.
+    /// [`Hexasphere::rotate`](crate::Hexasphere::rotate),
+    /// [`Hexasphere::displace`](crate::Hexasphere::displace) and
+    /// [`Hexasphere::relax`](crate::Hexasphere::relax) clear it; after editing
+    /// those fields by hand, call [`Tile::clear_cache`] or this method again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let mut tile = hexasphere.tiles[3].clone();
+    /// let area = tile.get_area();
+    ///
+    /// tile.precompute_cache();
+    /// assert!(tile.is_cached());
+    /// assert_eq!(tile.get_area(), area);
+    /// ```
+    pub fn precompute_cache(&mut self) {
+        self.cache = None;
+        self.cache = Some(Box::new(TileCache {
+            orientation: self.get_orientation(),
+            average_radius: self.get_average_radius(),
+            average_edge_length: self.get_average_edge_length(),
+            area: self.get_area(),
+        }));
+    }
+
+    /// Discards the values stored by [`Tile::precompute_cache`].
+    pub fn clear_cache(&mut self) {
+        self.cache = None;
+    }
+
+    /// Returns whether [`Tile::precompute_cache`] has stored derived values.
+    pub fn is_cached(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// Heap bytes held by the cache, for [`crate::Hexasphere::memory_estimate`].
+    pub(crate) fn cache_size(&self) -> usize {
+        if self.cache.is_some() {
+            std::mem::size_of::<TileCache>()
+        } else {
+            0
+        }
+    }
+
+    /// `point` relative to `sphere_center`, without `Point::new` rounding.
+    pub(crate) fn relative(&self, point: &Point) -> Point {
+        Point {
+            x: point.x - self.sphere_center.x,
+            y: point.y - self.sphere_center.y,
+            z: point.z - self.sphere_center.z,
+        }
+    }
 
-        Some(RegularHexagonParams {
-            center: self.center_point.clone(),
-            radius,
-            orientation,
+    /// The inverse of [`Tile::relative`]: moves a point given relative to
+    /// `sphere_center` back into place.
+    fn placed(&self, point: Point) -> Point {
+        Point {
+            x: point.x + self.sphere_center.x,
+            y: point.y + self.sphere_center.y,
+            z: point.z + self.sphere_center.z,
+        }
+    }
+
+    /// Moves a tile built around the origin onto a sphere centered on `center`.
+    ///
+    /// Every point is offset without rounding; `neighbor_ids` are left to the
+    /// caller, which knows the moved neighbor centers.
+    pub(crate) fn move_sphere_to(&mut self, center: &Point) {
+        let offset = |point: &mut Point| {
+            point.x += center.x;
+            point.y += center.y;
+            point.z += center.z;
+        };
+        offset(&mut self.center_point);
+        self.boundary.iter_mut().for_each(offset);
+        self.sphere_center = center.clone();
+        self.cache = None;
+    }
+
+    /// The tile moved so its sphere is centered on the origin; borrowed when
+    /// it already is, so origin-centered tiles pay nothing.
+    fn centered(&self) -> Cow<'_, Tile> {
+        if self.sphere_center == Point::ORIGIN {
+            return Cow::Borrowed(self);
+        }
+        let mut tile = self.clone();
+        tile.center_point = self.relative(&self.center_point);
+        tile.boundary = self
+            .boundary
+            .iter()
+            .map(|point| self.relative(point))
+            .collect();
+        tile.sphere_center = Point::ORIGIN;
+        Cow::Owned(tile)
+    }
+}
+
+/// How far along `direction` the ray from the origin meets the outline
+/// `ring`, in multiples of `direction`; 0.0 if it misses every edge.
+fn radial_extent(ring: &[(f64, f64)], direction: (f64, f64)) -> f64 {
+    let cross = |(ax, ay): (f64, f64), (bx, by): (f64, f64)| ax * by - ay * bx;
+    (0..ring.len())
+        .filter_map(|i| {
+            let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+            let edge = (b.0 - a.0, b.1 - a.1);
+            let denominator = cross(direction, edge);
+            if denominator.abs() <= f64::EPSILON {
+                return None;
+            }
+            let t = cross(a, edge) / denominator;
+            let s = cross(a, direction) / denominator;
+            (t > 0.0 && (-1e-12..=1.0 + 1e-12).contains(&s)).then_some(t)
+        })
+        .fold(None, |nearest: Option<f64>, t| {
+            Some(nearest.map_or(t, |n| n.min(t)))
+        })
+        .unwrap_or(0.0)
+}
+
+/// Sine of the angle below which a boundary vertex counts as lying straight
+/// above or below the tile center in [`Tile::get_orientation`].
+const PARALLEL_TOLERANCE: f64 = 1e-9;
+
+/// Slack for points lying on a shared edge, as a sine of the angular distance.
+const CONTAINMENT_TOLERANCE: f64 = 1e-9;
+
+/// Fan triangles from vertex 0 over a ring stored at indices `1..=ring_len`.
+fn fan_triangles(ring_len: usize) -> Vec<[u32; 3]> {
+    if ring_len < 3 {
+        return Vec::new();
+    }
+    let ring_len = ring_len as u32;
+    (0..ring_len)
+        .map(|i| [0, 1 + i, 1 + (i + 1) % ring_len])
+        .collect()
+}
+
+/// Scales a direction to the given radius without `Point::new` rounding, so
+/// the result lies exactly on the sphere.
+fn project_exact(direction: &Vector3, radius: f64) -> Point {
+    let unit = direction.normalize();
+    Point {
+        x: unit.x * radius,
+        y: unit.y * radius,
+        z: unit.z * radius,
+    }
+}
+
+/// Latitude in degrees of the point of the arc `start → end` (unit vectors)
+/// closest to the north pole (`toward = 1.0`) or south pole (`toward = -1.0`),
+/// if that point lies strictly inside the arc rather than at a corner.
+fn arc_extreme_latitude(start: &Vector3, end: &Vector3, toward: f64) -> Option<f64> {
+    let normal = start.cross(end);
+    if normal.length() < 1e-12 {
+        return None;
+    }
+    let normal = normal.normalize();
+    let pole = Vector3::new(0.0, toward, 0.0);
+    let extreme = &pole - &(&normal * pole.dot(&normal));
+    if extreme.length() < 1e-12 {
+        return None;
+    }
+    let extreme = extreme.normalize();
+
+    let inside = start.cross(&extreme).dot(&normal) > 0.0 && extreme.cross(end).dot(&normal) > 0.0;
+    inside.then(|| extreme.y.clamp(-1.0, 1.0).asin().to_degrees())
+}
+
+/// Equirectangular UVs for a ring of points, unwrapped across the antimeridian.
+///
+/// If the points span more than half the texture in `u`, they straddle the
+/// seam and every point with `u < 0.5` is shifted by +1.
+pub(crate) fn equirectangular_uvs<'a>(
+    points: impl Iterator<Item = &'a Point>,
+    radius: f64,
+) -> Vec<(f64, f64)> {
+    let mut uvs: Vec<(f64, f64)> = points
+        .map(|point| {
+            // Same convention as `Point::to_lat_lon`, clamped against rounding
+            let lat = (point.y / radius).clamp(-1.0, 1.0).asin().to_degrees();
+            let lon = point.x.atan2(point.z).to_degrees();
+            ((lon + 180.0) / 360.0, (lat + 90.0) / 180.0)
         })
+        .collect();
+
+    let (min, max) = uvs
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &(u, _)| {
+            (min.min(u), max.max(u))
+        });
+    if max - min > 0.5 {
+        for uv in &mut uvs {
+            if uv.0 < 0.5 {
+                uv.0 += 1.0;
+            }
+        }
     }
+
+    uvs
 }
 
 impl std::fmt::Display for Tile {
@@ -684,7 +2260,9 @@ impl std::fmt::Display for Tile {
 
 #[cfg(test)]
 mod tests {
+    use crate::geometry::{Point, Vector3};
     use crate::hexasphere::core::Hexasphere;
+    use crate::tile::{Tile, TileOrientation};
 
     #[test]
     fn test_thick_tiles() {
@@ -708,6 +2286,162 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_thick_tile_indices_reference_matching_vertices() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+        for thick_tile in hexasphere.create_thick_tiles(0.5) {
+            let sides = thick_tile.outer_boundary.len();
+            let mesh = thick_tile.generate_all_vertices();
+
+            assert_eq!(mesh.vertices.len(), 2 * sides + 2);
+            assert!(mesh.indices.iter().all(|&i| i < mesh.vertices.len()));
+
+            // Outer face fan: center (0) plus the outer boundary (1..=sides)
+            for triangle in mesh.indices[..3 * sides].chunks(3) {
+                assert_eq!(triangle[0], 0);
+                assert!((1..=sides).contains(&triangle[1]));
+                assert!((1..=sides).contains(&triangle[2]));
+            }
+
+            // Every side wall quad must join an outer edge to an inner edge
+            for triangle in mesh.indices[6 * sides..].chunks(3) {
+                let outer = triangle.iter().filter(|&&i| (1..=sides).contains(&i));
+                let inner = triangle.iter().filter(|&&i| i > sides + 1);
+                assert_eq!(outer.count() + inner.count(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighbors_aligned_with_boundary_edges() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let close =
+            |a: &crate::geometry::Point, b: &crate::geometry::Point| a.distance_to(b) < 0.01;
+
+        for (index, tile) in hexasphere.tiles.iter().enumerate() {
+            let sides = tile.boundary.len();
+            assert_eq!(tile.neighbors.len(), sides);
+
+            for edge in 0..sides {
+                let neighbor = &hexasphere.tiles[tile.neighbor_across_edge(edge).unwrap()];
+                let start = &tile.boundary[edge];
+                let end = &tile.boundary[(edge + 1) % sides];
+
+                // Both edge endpoints must also be corners of the neighbor
+                assert!(neighbor.boundary.iter().any(|p| close(p, start)));
+                assert!(neighbor.boundary.iter().any(|p| close(p, end)));
+                assert!(neighbor.neighbors.contains(&index));
+            }
+            assert_eq!(tile.neighbor_across_edge(sides), None);
+        }
+    }
+
+    #[test]
+    fn test_tile_ids_match_indices() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+
+        for (index, tile) in hexasphere.tiles.iter().enumerate() {
+            assert_eq!(tile.id, index);
+            assert_eq!(hexasphere.tile(index).map(|t| t.id), Some(index));
+
+            for &neighbor in &tile.neighbors {
+                let neighbor_tile = hexasphere.tile(neighbor).expect("neighbor id should exist");
+                assert_eq!(neighbor_tile.id, neighbor);
+            }
+        }
+        assert!(hexasphere.tile(hexasphere.tiles.len()).is_none());
+    }
+
+    #[test]
+    fn test_boundary_arc_points_on_sphere() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+        for tile in &hexasphere.tiles {
+            let ring = tile.boundary_arc_points(10.0, 4);
+            assert_eq!(ring.len(), tile.boundary.len() * 5);
+
+            for point in &ring {
+                let distance = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+                assert!((distance - 10.0).abs() < 1e-6);
+            }
+
+            // Corners are kept in order, just pushed out onto the sphere
+            for (i, corner) in tile.boundary.iter().enumerate() {
+                let sampled = &ring[i * 5];
+                let length = (corner.x.powi(2) + corner.y.powi(2) + corner.z.powi(2)).sqrt();
+                assert!((sampled.x - corner.x * 10.0 / length).abs() < 1e-9);
+                assert!((sampled.y - corner.y * 10.0 / length).abs() < 1e-9);
+                assert!((sampled.z - corner.z * 10.0 / length).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangulations_are_outward_fans() {
+        let hexasphere = Hexasphere::new(10.0, 3, 0.9);
+
+        for tile in &hexasphere.tiles {
+            for (samples, (vertices, triangles)) in [
+                (0, tile.triangulate()),
+                (0, tile.triangulate_with_arcs(10.0, 0)),
+                (3, tile.triangulate_with_arcs(10.0, 3)),
+            ] {
+                assert_eq!(triangles.len(), tile.boundary.len() * (samples + 1));
+                assert_eq!(vertices.len(), triangles.len() + 1);
+                for triangle in &triangles {
+                    assert!(triangle.iter().all(|&i| (i as usize) < vertices.len()));
+                    let [a, b, c] = triangle.map(|i| Vector3::from(&vertices[i as usize]));
+                    let normal = (&b - &a).cross(&(&c - &a));
+                    assert!(normal.dot(&a) > 0.0, "tile {} winds inward", tile.id);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangulate_degenerate_tile() {
+        let mut tile = Hexasphere::new(1.0, 1, 1.0).tiles[0].clone();
+        tile.boundary.truncate(2);
+
+        let (vertices, triangles) = tile.triangulate();
+        assert_eq!(vertices.len(), 3);
+        assert!(triangles.is_empty());
+    }
+
+    #[test]
+    fn test_scaled_boundary_on_sphere() {
+        let hexasphere = Hexasphere::new(5.0, 3, 1.0);
+
+        for tile in &hexasphere.tiles {
+            let boundary = tile.scaled_boundary_on_sphere(0.7, 5.0);
+            assert_eq!(boundary.len(), tile.boundary.len());
+            for point in &boundary {
+                let distance = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+                assert!((distance - 5.0).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inner_sphere_preserves_topology() {
+        let outer_sphere = Hexasphere::new(10.0, 6, 1.0);
+        let inner_sphere = outer_sphere.create_inner_sphere(5.0);
+
+        for (outer_tile, inner_tile) in outer_sphere.tiles.iter().zip(&inner_sphere.tiles) {
+            assert_eq!(inner_tile.id, outer_tile.id);
+            assert_eq!(inner_tile.neighbors, outer_tile.neighbors);
+            assert_eq!(inner_tile.boundary.len(), outer_tile.boundary.len());
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "inner radius must be finite and positive")]
+    fn test_inner_sphere_rejects_non_positive_radius() {
+        Hexasphere::new(10.0, 2, 1.0).create_inner_sphere(0.0);
+    }
+
     #[test]
     fn test_inner_sphere_creation() {
         let outer_sphere = Hexasphere::new(10.0, 2, 0.8);
@@ -776,6 +2510,32 @@ mod tests {
 
         // Test invalid boundary index
         assert!(tile.get_boundary_lat_lon(1.0, 100).is_none());
+        assert!(tile.boundary_lat_lon(100).is_none());
+        assert!(matches!(
+            tile.try_get_boundary_lat_lon(1.0, 100),
+            Err(crate::Error::IndexOutOfRange { index: 100, .. })
+        ));
+    }
+
+    #[test]
+    fn test_stored_radius_lat_lon_matches_explicit() {
+        for radius in [1.0, 6371.0] {
+            let hexasphere = Hexasphere::new(radius, 3, 0.8);
+            for tile in &hexasphere.tiles {
+                assert_eq!(tile.sphere_radius, radius);
+                assert_eq!(tile.lat_lon(), tile.get_lat_lon(radius));
+                for i in 0..tile.boundary.len() {
+                    assert_eq!(
+                        tile.boundary_lat_lon(i),
+                        tile.get_boundary_lat_lon(radius, i)
+                    );
+                }
+            }
+        }
+
+        // A tile built on its own takes the distance of its center
+        let empty = Tile::new(Point::new(0.0, 0.0, 10.0), &[], 1.0);
+        assert_eq!(empty.sphere_radius, 10.0);
     }
 
     #[test]
@@ -806,6 +2566,69 @@ mod tests {
         );
     }
 
+    fn flat_regular_hexagon() -> Tile {
+        // Unrounded points, so the analytic values hold to machine precision
+        let boundary = (0..6)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * i as f64 / 6.0;
+                Point {
+                    x: angle.cos(),
+                    y: angle.sin(),
+                    z: 10.0,
+                }
+            })
+            .collect();
+        Tile {
+            id: 0,
+            center_point: Point::new(0.0, 0.0, 10.0),
+            boundary,
+            neighbor_ids: Vec::new(),
+            neighbors: Vec::new(),
+            base_face: 0,
+            sphere_center: Point::ORIGIN,
+            sphere_radius: 10.0,
+            cache: None,
+        }
+    }
+
+    #[test]
+    fn test_interior_angles_of_non_convex_tile() {
+        let mut tile = flat_regular_hexagon();
+        for angle in tile.interior_angles() {
+            assert!((angle - std::f64::consts::TAU / 3.0).abs() < 1e-12);
+        }
+
+        // Pushing a corner past its neighbors' chord makes it reflex
+        tile.boundary[0].x = 0.25;
+        let angles = tile.interior_angles();
+        let reflex = std::f64::consts::TAU - 2.0 * (3f64.sqrt() / 2.0).atan2(0.25);
+        assert!((angles[0] - reflex).abs() < 1e-9, "{}", angles[0]);
+        assert!(angles[1] < std::f64::consts::PI && angles[5] < std::f64::consts::PI);
+        let sum: f64 = angles.iter().sum();
+        assert!((sum - 4.0 * std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_perimeter_and_compactness() {
+        let mut tile = flat_regular_hexagon();
+        let regular = std::f64::consts::PI / (2.0 * 3f64.sqrt());
+
+        assert!((tile.get_perimeter() - 6.0).abs() < 1e-12);
+        assert!(tile.get_spherical_perimeter(101f64.sqrt()) > tile.get_perimeter());
+        assert!((tile.compactness() - regular).abs() < 1e-6);
+
+        // Pulling one corner toward the center squashes the tile
+        tile.boundary[0].x = 0.5;
+        assert!(tile.compactness() < regular);
+
+        // Arcs on the sphere are never shorter than the chords beneath them
+        let hexasphere = Hexasphere::new(2.0, 3, 1.0);
+        for tile in &hexasphere.tiles {
+            assert!(tile.get_spherical_perimeter(2.0) >= tile.get_perimeter());
+            assert!(tile.compactness() > 0.0 && tile.compactness() < 1.0);
+        }
+    }
+
     #[test]
     fn test_tile_classification() {
         let hexasphere = Hexasphere::new(1.0, 2, 1.0);
@@ -833,6 +2656,46 @@ mod tests {
         assert_eq!(hexagon_count + pentagon_count, hexasphere.tiles.len());
     }
 
+    #[test]
+    fn test_orientation_skips_vertices_above_the_center() {
+        let mut tile = flat_regular_hexagon();
+
+        // The first vertex sits straight below the center, as if shrunk onto it
+        tile.boundary[0] = Point {
+            x: 0.0,
+            y: 0.0,
+            z: 9.5,
+        };
+        let orientation = tile.get_orientation().unwrap();
+        let expected = Vector3::new(0.5, 3f64.sqrt() / 2.0, 0.0);
+        assert!(orientation.right.distance_to(&expected) < 1e-12);
+        assert!(orientation.forward.is_finite());
+
+        // No vertex gives a direction
+        for point in &mut tile.boundary {
+            *point = tile.center_point.clone();
+        }
+        assert!(tile.get_orientation().is_none());
+
+        let mut at_origin = flat_regular_hexagon();
+        at_origin.center_point = Point::new(0.0, 0.0, 0.0);
+        assert!(at_origin.get_orientation().is_none());
+    }
+
+    #[test]
+    fn test_tiny_tiles_have_orthonormal_frames() {
+        let hexasphere = Hexasphere::new(10.0, 4, 0.01);
+        for tile in &hexasphere.tiles {
+            let TileOrientation { right, up, forward } = tile.get_orientation().unwrap();
+            for axis in [&right, &up, &forward] {
+                assert!((axis.length() - 1.0).abs() < 1e-9, "tile {}", tile.id);
+            }
+            assert!(right.dot(&up).abs() < 1e-9);
+            assert!(right.dot(&forward).abs() < 1e-9);
+            assert!(up.dot(&forward).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_tile_orientation_and_params() {
         let hexasphere = Hexasphere::new(1.0, 2, 1.0);
@@ -922,4 +2785,446 @@ mod tests {
             }
         }
     }
+
+    fn boundary_centroid(tile: &Tile) -> Point {
+        let n = tile.boundary.len() as f64;
+        let sum = tile.boundary.iter().fold([0.0; 3], |acc, p| {
+            [acc[0] + p.x, acc[1] + p.y, acc[2] + p.z]
+        });
+        Point::new(sum[0] / n, sum[1] / n, sum[2] / n)
+    }
+
+    #[test]
+    fn test_boundary_centroid_offsets() {
+        let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+        let pentagons: Vec<usize> = hexasphere.pentagons().map(|(index, _)| index).collect();
+
+        for tile in &hexasphere.tiles {
+            let centroid = tile.boundary_centroid();
+            let distance = (centroid.x.powi(2) + centroid.y.powi(2) + centroid.z.powi(2)).sqrt();
+            assert!((distance - 10.0).abs() < 1e-2);
+        }
+
+        // Symmetric tiles keep their centroid at the geodesic vertex
+        for (_, pentagon) in hexasphere.pentagons() {
+            assert!(pentagon.centroid_offset() < 1e-3);
+        }
+        let most_regular = hexasphere
+            .hexagons()
+            .map(|(_, tile)| tile.centroid_offset())
+            .fold(f64::INFINITY, f64::min);
+        assert!(most_regular < 1e-3);
+
+        // Hexagons squeezed against a pentagon are lopsided
+        for (_, tile) in hexasphere.hexagons() {
+            if tile.neighbors.iter().any(|n| pentagons.contains(n)) {
+                assert!(tile.centroid_offset() > 1e-2, "tile {}", tile.id);
+            }
+        }
+
+        // The synthetic regular hexagon is centered exactly
+        assert!(flat_regular_hexagon().centroid_offset() < 1e-9);
+    }
+
+    #[test]
+    fn test_lat_lon_bounds_cover_the_globe() {
+        use crate::utils::LatLon;
+
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let bounds: Vec<_> = hexasphere
+            .tiles
+            .iter()
+            .map(|tile| tile.lat_lon_bounds(10.0))
+            .collect();
+
+        // Every coordinate is inside the bounds of the tile containing it
+        for lat in (-90..=90).step_by(5) {
+            for lon in (-180..=180).step_by(5) {
+                let lat_lon = LatLon::new(lat as f64, lon as f64);
+                let tile = hexasphere.tile_containing(&lat_lon.to_point(10.0)).unwrap();
+                assert!(bounds[tile].contains(&lat_lon), "{lat}, {lon}");
+            }
+        }
+
+        let polar = bounds
+            .iter()
+            .filter(|b| b.max_lat == 90.0 || b.min_lat == -90.0);
+        assert_eq!(polar.count(), 2);
+    }
+
+    #[test]
+    fn test_lat_lon_bounds_flag_antimeridian() {
+        use crate::utils::LatLon;
+
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let east = LatLon::new(0.0, 179.9);
+        let west = LatLon::new(0.0, -179.9);
+        let tile = hexasphere.tile_containing(&east.to_point(10.0)).unwrap();
+        let bounds = hexasphere.tiles[tile].lat_lon_bounds(10.0);
+
+        assert!(bounds.crosses_antimeridian);
+        assert!(bounds.min_lon > bounds.max_lon);
+        assert!(bounds.contains(&east) && bounds.contains(&west));
+        assert!(!bounds.contains(&LatLon::new(0.0, 0.0)));
+
+        // Neighbors on one side of the antimeridian stay narrow, ordinary boxes
+        for &neighbor in &hexasphere.tiles[tile].neighbors {
+            let neighbor = &hexasphere.tiles[neighbor];
+            let bounds = neighbor.lat_lon_bounds(10.0);
+            if neighbor.get_lat_lon(10.0).lon.abs() < 179.0 {
+                assert!(!bounds.crosses_antimeridian);
+                assert!(bounds.max_lon - bounds.min_lon < 30.0);
+            } else {
+                assert!(bounds.crosses_antimeridian);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tile_contains_own_centroid() {
+        for hex_size in [1.0, 0.6] {
+            let hexasphere = Hexasphere::new(10.0, 4, hex_size);
+
+            for tile in &hexasphere.tiles {
+                let centroid = boundary_centroid(tile);
+                assert!(tile.contains_point(&centroid));
+                assert_eq!(hexasphere.tile_containing(&centroid), Some(tile.id));
+
+                // Centroids never fall inside a neighboring tile
+                for &neighbor in &tile.neighbors {
+                    assert!(!hexasphere.tiles[neighbor].contains_point(&centroid));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_midpoint_between_centers_resolves_to_one_tile() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+
+        for tile in &hexasphere.tiles {
+            for &neighbor in &tile.neighbors {
+                let other = &hexasphere.tiles[neighbor].center_point;
+                let midpoint = Point::new(
+                    (tile.center_point.x + other.x) / 2.0,
+                    (tile.center_point.y + other.y) / 2.0,
+                    (tile.center_point.z + other.z) / 2.0,
+                );
+
+                let found = hexasphere.tile_containing(&midpoint);
+                assert!(
+                    found == Some(tile.id) || found == Some(neighbor),
+                    "midpoint of {} and {} resolved to {:?}",
+                    tile.id,
+                    neighbor,
+                    found
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tile_containing_gaps_and_degenerate_points() {
+        let hexasphere = Hexasphere::new(10.0, 3, 0.5);
+
+        // With hex_size 0.5 the original tile corners are in the gaps
+        let full = Hexasphere::new(10.0, 3, 1.0);
+        for tile in &full.tiles {
+            for corner in &tile.boundary {
+                assert_eq!(hexasphere.tile_containing(corner), None);
+            }
+        }
+
+        let origin = Point::new(0.0, 0.0, 0.0);
+        assert_eq!(hexasphere.tile_containing(&origin), None);
+        assert!(!hexasphere.tiles[0].contains_point(&origin));
+
+        // Antipodal directions are never inside the tile
+        let tile = &hexasphere.tiles[0];
+        assert!(!tile.contains_point(&-&tile.center_point));
+    }
+
+    #[test]
+    fn test_local_uvs_regular_hexagon_hits_atlas_corners() {
+        // A flat regular hexagon around the +Z axis, counter-clockwise from outside
+        let boundary = (0..6)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * i as f64 / 6.0;
+                Point::new(angle.cos(), angle.sin(), 10.0)
+            })
+            .collect();
+        let tile = Tile {
+            id: 0,
+            center_point: Point::new(0.0, 0.0, 10.0),
+            boundary,
+            neighbor_ids: Vec::new(),
+            neighbors: Vec::new(),
+            base_face: 0,
+            sphere_center: Point::ORIGIN,
+            sphere_radius: 10.0,
+            cache: None,
+        };
+
+        for (i, (u, v)) in tile.local_uvs().into_iter().enumerate() {
+            let angle = std::f64::consts::TAU * i as f64 / 6.0;
+            assert!((u - (0.5 + 0.5 * angle.cos())).abs() < 1e-3);
+            assert!((v - (0.5 + 0.5 * angle.sin())).abs() < 1e-3);
+        }
+
+        // Generated hexagons are close to regular at higher subdivisions
+        let hexasphere = Hexasphere::new(10.0, 8, 1.0);
+        for tile in hexasphere.tiles.iter().filter(|t| t.is_hexagon()) {
+            for (i, (u, v)) in tile.local_uvs().into_iter().enumerate() {
+                let angle = std::f64::consts::TAU * i as f64 / 6.0;
+                let error = (u - (0.5 + 0.5 * angle.cos())).hypot(v - (0.5 + 0.5 * angle.sin()));
+                assert!(
+                    error < 0.15,
+                    "tile {} corner {} off by {}",
+                    tile.id,
+                    i,
+                    error
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_local_2d_round_trips_boundary() {
+        for divisions in [2, 3, 5] {
+            let hexasphere = Hexasphere::new(10.0, divisions, 1.0);
+            for tile in &hexasphere.tiles {
+                let polygon = tile.to_local_2d();
+                assert_eq!(polygon.len(), tile.boundary.len());
+
+                let tolerance = 0.01 * tile.get_average_radius();
+                for (&(x, y), corner) in polygon.iter().zip(&tile.boundary) {
+                    assert!(tile.from_local_2d(x, y).distance_to(corner) < tolerance);
+                }
+
+                // Shoelace formula: counter-clockwise rings have positive area
+                let doubled_area: f64 = (0..polygon.len())
+                    .map(|i| {
+                        let ((x1, y1), (x2, y2)) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                        x1 * y2 - x2 * y1
+                    })
+                    .sum();
+                assert!(doubled_area > 0.0, "tile {}", tile.id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_local_2d_stays_consistent_inside() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        let tile = &hexasphere.tiles[20];
+        let orientation = tile.get_orientation().unwrap();
+        let across = orientation.up.cross(&orientation.right);
+        let center = Vector3::from(&tile.center_point);
+
+        // Lifted points project back onto where they came from
+        let polygon = tile.to_local_2d();
+        for i in 0..polygon.len() {
+            let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+            let (x, y) = (0.3 * a.0 + 0.4 * b.0, 0.3 * a.1 + 0.4 * b.1);
+            let lifted = &Vector3::from(&tile.from_local_2d(x, y)) - &center;
+            assert!((lifted.dot(&orientation.right) - x).abs() < 1e-9);
+            assert!((lifted.dot(&across) - y).abs() < 1e-9);
+            assert!(tile.contains_point(&tile.from_local_2d_on_sphere(x, y, 10.0)));
+        }
+
+        let empty = Tile::new(Point::new(0.0, 0.0, 10.0), &[], 1.0);
+        assert!(empty.to_local_2d().is_empty());
+        assert_eq!(empty.from_local_2d(1.0, 2.0), empty.center_point);
+    }
+
+    #[test]
+    fn test_interpolate_covers_the_tile() {
+        let hexasphere = Hexasphere::new(10.0, 4, 0.9);
+        for tile in hexasphere.tiles.iter().step_by(7) {
+            let middle = tile.interpolate(0.5, 0.5);
+            let centroid = tile.boundary_centroid();
+            assert!(middle.distance_to(&centroid) < 0.1 * tile.get_average_radius());
+
+            for i in 0..=10 {
+                for j in 0..=10 {
+                    let point = tile.interpolate(i as f64 / 10.0, j as f64 / 10.0);
+                    assert!(tile.contains_point(&point), "({i}, {j}) outside");
+                    assert!((Vector3::from(&point).length() - 10.0).abs() < 1e-9);
+                }
+            }
+
+            // The edges of the square trace the outline: on the sphere, the
+            // great circle through one boundary edge
+            let rim = Vector3::from(&tile.interpolate(1.0, 0.5)).normalize();
+            let nearest_edge = (0..tile.boundary.len())
+                .map(|k| {
+                    let next = &tile.boundary[(k + 1) % tile.boundary.len()];
+                    let plane = Vector3::from(&tile.boundary[k])
+                        .cross(&Vector3::from(next))
+                        .normalize();
+                    plane.dot(&rim).abs()
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert!(nearest_edge < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_sample_grid_fills_the_tile_up_to_n() {
+        let hexasphere = Hexasphere::new(10.0, 4, 1.0);
+        for tile in hexasphere.tiles.iter().step_by(11) {
+            for n in [1, 7, 20, 50] {
+                let points = tile.sample_grid(n);
+                assert!(!points.is_empty() && points.len() <= n);
+                assert!(points.len() * 3 >= n, "{} of {n}", points.len());
+                assert!(points.iter().all(|point| tile.contains_point(point)));
+            }
+            assert!(tile.sample_grid(1)[0].distance_to(&tile.center_point) < 1e-9);
+            assert!(tile.sample_grid(0).is_empty());
+        }
+
+        let empty = Tile::new(Point::new(0.0, 0.0, 10.0), &[], 1.0);
+        assert!(empty.sample_grid(5).is_empty());
+        assert_eq!(empty.interpolate(0.2, 0.3), empty.center_point);
+    }
+
+    #[test]
+    fn test_latlon_uvs_in_unit_square_except_seam() {
+        let hexasphere = Hexasphere::new(10.0, 5, 1.0);
+        let mut wrapped = 0;
+
+        for tile in &hexasphere.tiles {
+            let uvs = tile.latlon_uvs(10.0);
+            assert_eq!(uvs.len(), tile.boundary.len());
+            assert!(uvs.iter().all(|&(_, v)| (0.0..=1.0).contains(&v)));
+
+            if uvs.iter().any(|&(u, _)| u > 1.0) {
+                // Seam tiles are shifted past 1 but stay contiguous
+                wrapped += 1;
+                assert!(uvs.iter().all(|&(u, _)| (0.5..1.5).contains(&u)));
+            } else {
+                assert!(uvs.iter().all(|&(u, _)| (0.0..=1.0).contains(&u)));
+            }
+
+            let (min, max) = uvs.iter().fold((f64::MAX, f64::MIN), |(lo, hi), &(u, _)| {
+                (lo.min(u), hi.max(u))
+            });
+            let near_pole = tile.get_lat_lon(10.0).lat.abs() > 80.0;
+            assert!(
+                near_pole || max - min < 0.25,
+                "tile {} spans {}",
+                tile.id,
+                max - min
+            );
+        }
+
+        assert!(wrapped > 0, "expected some tiles on the antimeridian");
+    }
+
+    #[test]
+    fn test_near_plane_tiles_keep_outward_winding() {
+        use crate::hexasphere::HexasphereBuilder;
+        use crate::utils::pointing_away_from_origin;
+
+        // The former sign-per-axis test, which reversed these tiles
+        let same_signs =
+            |p: &Point, n: &Point| p.x * n.x >= 0.0 && p.y * n.y >= 0.0 && p.z * n.z >= 0.0;
+        let outward_normal = |tile: &Tile| {
+            let [a, b, c] = [&tile.boundary[1], &tile.boundary[2], &tile.boundary[0]];
+            let (u, v) = (b - a, c - a);
+            Point {
+                x: u.y * v.z - u.z * v.y,
+                y: u.z * v.x - u.x * v.z,
+                z: u.x * v.y - u.y * v.x,
+            }
+        };
+
+        let polar = HexasphereBuilder::new()
+            .radius(10.0)
+            .subdivisions(4)
+            .pentagon_at_poles(true)
+            .build()
+            .unwrap();
+        assert_eq!(polar.validate(), Ok(()));
+
+        let mut misjudged = 0;
+        for tile in &polar.tiles {
+            let normal = outward_normal(tile);
+            assert!(pointing_away_from_origin(&tile.center_point, &normal));
+            if !same_signs(&tile.center_point, &normal) {
+                // Only tiles close to a coordinate plane are affected
+                let c = &tile.center_point;
+                assert!(c.x.abs().min(c.y.abs()).min(c.z.abs()) < 1.0);
+                misjudged += 1;
+            }
+        }
+        assert!(
+            misjudged > 0,
+            "expected near-plane tiles the old test flips"
+        );
+
+        // Tiles of a level-3 sphere straddling the coordinate planes
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        let near_plane = hexasphere.tiles.iter().filter(|tile| {
+            let c = &tile.center_point;
+            c.x.abs().min(c.y.abs()).min(c.z.abs()) < 1.0
+        });
+        for tile in near_plane {
+            assert!(pointing_away_from_origin(
+                &tile.center_point,
+                &outward_normal(tile)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_cached_getters_match_uncached() {
+        let plain = Hexasphere::new(10.0, 3, 0.9);
+        let mut cached = plain.clone();
+        cached.precompute_tile_caches();
+
+        for (a, b) in plain.tiles.iter().zip(&cached.tiles) {
+            assert!(!a.is_cached() && b.is_cached());
+            assert_eq!(a.get_average_radius(), b.get_average_radius());
+            assert_eq!(a.get_average_edge_length(), b.get_average_edge_length());
+            assert_eq!(a.get_area(), b.get_area());
+
+            let (a, b) = (a.get_orientation().unwrap(), b.get_orientation().unwrap());
+            assert_eq!((a.right, a.up, a.forward), (b.right, b.up, b.forward));
+        }
+
+        cached.clear_tile_caches();
+        assert!(cached.tiles.iter().all(|tile| !tile.is_cached()));
+    }
+
+    #[test]
+    fn test_cache_is_used_and_cleared_by_mutations() {
+        let mut hexasphere = Hexasphere::new(10.0, 3, 1.0);
+        hexasphere.precompute_tile_caches();
+
+        // A stale cache keeps answering until it is cleared
+        let mut tile = hexasphere.tiles[4].clone();
+        let area = tile.get_area();
+        tile.boundary.truncate(2);
+        assert_eq!(tile.get_area(), area);
+        tile.clear_cache();
+        assert_eq!(tile.get_area(), 0.0);
+
+        let displaced = hexasphere.displace(|_, _| 1.0);
+        assert!(displaced.tiles.iter().all(|tile| !tile.is_cached()));
+        assert!(hexasphere.tiles.iter().all(|tile| tile.is_cached()));
+
+        let mut rotated = hexasphere.clone();
+        rotated.rotate(&crate::geometry::Vector3::new(0.0, 1.0, 0.0), 0.3);
+        assert!(rotated.tiles.iter().all(|tile| !tile.is_cached()));
+
+        let mut relaxed = hexasphere.clone();
+        relaxed.relax(1);
+        assert!(relaxed.tiles.iter().all(|tile| !tile.is_cached()));
+        for tile in &relaxed.tiles {
+            let mut fresh = tile.clone();
+            fresh.precompute_cache();
+            assert_eq!(fresh.get_area(), tile.get_area());
+        }
+    }
 }
@@ -9,4 +9,4 @@ pub mod thick_tile;
 
 pub use core::Tile;
 pub use orientation::TileOrientation;
-pub use thick_tile::{ThickTile, ThickTileVertices};
+pub use thick_tile::{ExtrusionMode, ThickTile, ThickTileMesh, ThickTileVertices};
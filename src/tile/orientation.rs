@@ -40,7 +40,9 @@ impl TileOrientation {
     /// Converts the orientation to a 3×3 rotation matrix in row-major order.
     ///
     /// The rotation matrix can be used to transform vectors from local tile coordinates
-    /// to world coordinates. Each row represents one of the orientation vectors.
+    /// to world coordinates. Each column holds one of the orientation vectors. Use
+    /// [`to_rotation_matrix_column_major`](Self::to_rotation_matrix_column_major) when
+    /// feeding OpenGL, WebGPU or glam, which store matrices column by column.
     ///
     /// # Returns
     ///
@@ -78,7 +80,9 @@ impl TileOrientation {
     /// Converts the orientation to a 4×4 transformation matrix with translation.
     ///
     /// This combines the rotation matrix with a translation vector to create a complete
-    /// transformation matrix suitable for 3D graphics pipelines.
+    /// transformation matrix. The layout is row-major; most graphics APIs want
+    /// [`to_transform_matrix_column_major`](Self::to_transform_matrix_column_major)
+    /// instead.
     ///
     /// # Arguments
     ///
@@ -102,7 +106,7 @@ impl TileOrientation {
     /// # let tile = &hexasphere.tiles[0];
     /// # let orientation = tile.get_orientation().unwrap();
     /// let transform = orientation.to_transform_matrix(&tile.center_point);
-    /// // Use with 3D engines like Bevy, Three.js, etc.
+    /// // Row-major: element [3] is translation.x
     /// ```
     pub fn to_transform_matrix(&self, translation: &Point) -> [f64; 16] {
         [
@@ -124,6 +128,230 @@ impl TileOrientation {
             1.0,
         ]
     }
+
+    /// Converts the orientation to a 3×3 rotation matrix in column-major order.
+    ///
+    /// This is the same matrix as [`to_rotation_matrix`](Self::to_rotation_matrix)
+    /// laid out the way OpenGL, WebGPU and glam store matrices: each group of
+    /// three elements is one column, i.e. one orientation vector.
+    ///
+    /// # Returns
+    ///
+    /// A 9-element array, one column after another:
+    /// ```text
+    /// [right.x,   right.y,   right.z,
+    ///  up.x,      up.y,      up.z,
+    ///  forward.x, forward.y, forward.z]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::tile::TileOrientation;
+    /// let orientation = TileOrientation::default();
+    /// let matrix = orientation.to_rotation_matrix_column_major();
+    ///
+    /// // The first column is the right vector
+    /// assert_eq!(&matrix[0..3], &[1.0, 0.0, 0.0]);
+    /// ```
+    pub fn to_rotation_matrix_column_major(&self) -> [f64; 9] {
+        [
+            self.right.x,
+            self.right.y,
+            self.right.z,
+            self.up.x,
+            self.up.y,
+            self.up.z,
+            self.forward.x,
+            self.forward.y,
+            self.forward.z,
+        ]
+    }
+
+    /// Converts the orientation to a row-major 3×3 rotation matrix of `f32`.
+    ///
+    /// Same layout as [`to_rotation_matrix`](Self::to_rotation_matrix), narrowed
+    /// to single precision.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::tile::TileOrientation;
+    /// let matrix: [f32; 9] = TileOrientation::default().to_rotation_matrix_f32();
+    /// assert_eq!(matrix[5], 1.0); // row 1, column 2: forward.y
+    /// ```
+    pub fn to_rotation_matrix_f32(&self) -> [f32; 9] {
+        self.to_rotation_matrix().map(|value| value as f32)
+    }
+
+    /// Converts the orientation to a column-major 3×3 rotation matrix of `f32`.
+    ///
+    /// Same layout as
+    /// [`to_rotation_matrix_column_major`](Self::to_rotation_matrix_column_major),
+    /// narrowed to single precision. This is what GPU uniform buffers expect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::tile::TileOrientation;
+    /// let matrix: [f32; 9] = TileOrientation::default().to_rotation_matrix_column_major_f32();
+    /// assert_eq!(matrix[7], 1.0); // column 2, row 1: forward.y
+    /// ```
+    pub fn to_rotation_matrix_column_major_f32(&self) -> [f32; 9] {
+        self.to_rotation_matrix_column_major()
+            .map(|value| value as f32)
+    }
+
+    /// Converts the orientation to a 4×4 transformation matrix in column-major order.
+    ///
+    /// This is the same matrix as [`to_transform_matrix`](Self::to_transform_matrix)
+    /// laid out the way OpenGL, WebGPU and glam store matrices. Multiplying it by a
+    /// local-space column vector `(x, y, z, 1)` gives
+    /// `translation + x·right + y·up + z·forward`, so a flat polygon in the local
+    /// XZ plane ends up lying on the tile.
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The position where the transformed object should be placed
+    ///
+    /// # Returns
+    ///
+    /// A 16-element array, one column after another:
+    /// ```text
+    /// [right.x,       right.y,       right.z,       0.0,
+    ///  up.x,          up.y,          up.z,          0.0,
+    ///  forward.x,     forward.y,     forward.z,     0.0,
+    ///  translation.x, translation.y, translation.z, 1.0]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Hexasphere;
+    /// # let hexasphere = Hexasphere::new(10.0, 2, 0.8);
+    /// # let tile = &hexasphere.tiles[0];
+    /// # let orientation = tile.get_orientation().unwrap();
+    /// let transform = orientation.to_transform_matrix_column_major(&tile.center_point);
+    ///
+    /// // The last column holds the translation
+    /// assert_eq!(transform[12], tile.center_point.x);
+    /// ```
+    pub fn to_transform_matrix_column_major(&self, translation: &Point) -> [f64; 16] {
+        [
+            self.right.x,
+            self.right.y,
+            self.right.z,
+            0.0,
+            self.up.x,
+            self.up.y,
+            self.up.z,
+            0.0,
+            self.forward.x,
+            self.forward.y,
+            self.forward.z,
+            0.0,
+            translation.x,
+            translation.y,
+            translation.z,
+            1.0,
+        ]
+    }
+
+    /// Converts the orientation to a row-major 4×4 transformation matrix of `f32`.
+    ///
+    /// Same layout as [`to_transform_matrix`](Self::to_transform_matrix), narrowed
+    /// to single precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The position where the transformed object should be placed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Point, tile::TileOrientation};
+    /// let translation = Point::new(2.0, 3.0, 4.0);
+    /// let matrix: [f32; 16] = TileOrientation::default().to_transform_matrix_f32(&translation);
+    /// assert_eq!(matrix[3], 2.0); // row 0, column 3: translation.x
+    /// ```
+    pub fn to_transform_matrix_f32(&self, translation: &Point) -> [f32; 16] {
+        self.to_transform_matrix(translation)
+            .map(|value| value as f32)
+    }
+
+    /// Converts the orientation to a column-major 4×4 transformation matrix of `f32`.
+    ///
+    /// Same layout as
+    /// [`to_transform_matrix_column_major`](Self::to_transform_matrix_column_major),
+    /// narrowed to single precision. This is the layout to upload as a model
+    /// matrix or per-instance transform.
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The position where the transformed object should be placed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Point, tile::TileOrientation};
+    /// let translation = Point::new(2.0, 3.0, 4.0);
+    /// let matrix = TileOrientation::default().to_transform_matrix_column_major_f32(&translation);
+    /// assert_eq!(&matrix[12..16], &[2.0, 3.0, 4.0, 1.0]);
+    /// ```
+    pub fn to_transform_matrix_column_major_f32(&self, translation: &Point) -> [f32; 16] {
+        self.to_transform_matrix_column_major(translation)
+            .map(|value| value as f32)
+    }
+
+    /// Builds the orientation at `center` whose `right` vector points north.
+    ///
+    /// North is the direction of increasing latitude, i.e. toward +Y (see
+    /// [`Point::to_lat_lon`]), projected into the tangent plane. Decals and
+    /// labels placed with this orientation read the same way on every tile,
+    /// independent of where each tile's first boundary vertex happens to lie.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Point on the sphere (the tile center) whose direction is `up`
+    ///
+    /// # Returns
+    ///
+    /// The north-aligned orientation, or `None` at the poles where north is
+    /// undefined (the center lies within about 0.00006° of the Y axis) and
+    /// for a center at the origin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::Point;
+    /// # use geotiles::tile::TileOrientation;
+    /// // On the equator north is straight up the Y axis
+    /// let orientation = TileOrientation::with_north_alignment(&Point::new(0.0, 0.0, 10.0)).unwrap();
+    /// assert!((orientation.right.y - 1.0).abs() < 1e-9);
+    ///
+    /// // North is undefined at the poles
+    /// assert!(TileOrientation::with_north_alignment(&Point::new(0.0, 10.0, 0.0)).is_none());
+    /// ```
+    pub fn with_north_alignment(center: &Point) -> Option<Self> {
+        let up = Vector3::from(center).try_normalize()?;
+        let right = north_tangent(&up)?;
+        let forward = right.cross(&up).normalize();
+
+        Some(Self { right, up, forward })
+    }
+}
+
+/// Unit vector pointing north in the tangent plane with normal `up`.
+///
+/// Returns `None` when `up` is (anti)parallel to the Y axis.
+pub(crate) fn north_tangent(up: &Vector3) -> Option<Vector3> {
+    const POLE_TOLERANCE: f64 = 1e-6;
+
+    let tangent = Vector3::new(0.0, 1.0, 0.0).reject_from(up);
+    if tangent.length() < POLE_TOLERANCE {
+        return None;
+    }
+    Some(tangent.normalize())
 }
 
 impl Default for TileOrientation {
@@ -142,6 +370,16 @@ impl Default for TileOrientation {
     }
 }
 
+/// A right-handed basis with distinct components, so a transposed matrix
+/// conversion is detectable.
+#[cfg(all(test, any(feature = "glam", feature = "mint")))]
+pub(crate) fn sample_orientation() -> TileOrientation {
+    let up = Vector3::new(1.0, 2.0, 2.0).normalize();
+    let right = Vector3::new(2.0, -2.0, 1.0).normalize();
+    let forward = right.cross(&up);
+    TileOrientation { right, up, forward }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,4 +596,165 @@ mod tests {
             assert_eq!(matrix1[i], matrix2[i]);
         }
     }
+
+    #[test]
+    fn test_north_alignment_on_hand_picked_points() {
+        // On the equator north is +Y and the equator tangent is horizontal
+        for center in [
+            Point::new(10.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, -10.0),
+            Point::new(7.071, 0.0, 7.071),
+        ] {
+            let orientation = TileOrientation::with_north_alignment(&center).unwrap();
+            let up = Vector3::from(&center).normalize();
+            let east = Vector3::new(0.0, 1.0, 0.0).cross(&up).normalize();
+
+            assert!((orientation.right.y - 1.0).abs() < 1e-9);
+            assert!(orientation.right.dot(&east).abs() < 1e-9);
+            assert!(orientation.right.dot(&orientation.up).abs() < 1e-9);
+            assert!(orientation.forward.dot(&orientation.up).abs() < 1e-9);
+        }
+
+        // At 45° latitude north tilts back toward the axis
+        let orientation =
+            TileOrientation::with_north_alignment(&Point::new(1.0, 1.0, 0.0)).unwrap();
+        let expected = Vector3::new(-1.0, 1.0, 0.0).normalize();
+        assert!(orientation.right.dot(&expected) > 1.0 - 1e-9);
+
+        // Undefined at both poles
+        assert!(TileOrientation::with_north_alignment(&Point::new(0.0, 5.0, 0.0)).is_none());
+        assert!(TileOrientation::with_north_alignment(&Point::new(0.0, -5.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_orientation_angle_north_rotates_right_onto_north() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+
+        for tile in &hexasphere.tiles {
+            let angle = tile.orientation_angle_north().unwrap();
+            let orientation = tile.get_orientation().unwrap();
+            let north = TileOrientation::with_north_alignment(&tile.center_point)
+                .unwrap()
+                .right;
+
+            // Rotating right about up by the angle lands on north
+            let side = orientation.up.cross(&orientation.right);
+            let rotated = orientation.right.clone() * angle.cos() + side * angle.sin();
+            assert!(rotated.dot(&north) > 1.0 - 1e-9, "tile {}", tile.id);
+        }
+    }
+
+    #[test]
+    fn test_orientation_angle_north_at_pole() {
+        // Even subdivisions put a vertex on the Y axis
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let pole = hexasphere
+            .tiles
+            .iter()
+            .find(|tile| tile.center_point.x == 0.0 && tile.center_point.z == 0.0)
+            .unwrap();
+
+        assert!(pole.get_orientation().is_some());
+        assert_eq!(pole.orientation_angle_north(), None);
+    }
+
+    /// Multiplies a column-major 4×4 matrix by the column vector `(x, y, z, 1)`.
+    fn apply_column_major(matrix: &[f64; 16], local: [f64; 3]) -> [f64; 3] {
+        let mut world = [0.0; 3];
+        for (row, value) in world.iter_mut().enumerate() {
+            *value = matrix[row] * local[0]
+                + matrix[4 + row] * local[1]
+                + matrix[8 + row] * local[2]
+                + matrix[12 + row];
+        }
+        world
+    }
+
+    #[test]
+    fn test_column_major_places_hexagon_vertices() {
+        let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+
+        for params in hexasphere.get_regular_hexagon_approximations() {
+            let matrix = params
+                .orientation
+                .to_transform_matrix_column_major(&params.center);
+
+            for (i, expected) in params.generate_vertices().iter().enumerate() {
+                // Local hexagons lie in the XZ plane: x along right, z along forward
+                let angle = i as f64 * std::f64::consts::PI / 3.0;
+                let local = [
+                    params.radius * angle.cos(),
+                    0.0,
+                    params.radius * angle.sin(),
+                ];
+                let world = apply_column_major(&matrix, local);
+
+                // generate_vertices rounds to three decimals
+                assert!((world[0] - expected.x).abs() < 1e-3);
+                assert!((world[1] - expected.y).abs() < 1e-3);
+                assert!((world[2] - expected.z).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_column_major_is_transpose_of_row_major() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let tile = &hexasphere.tiles[7];
+        let orientation = tile.get_orientation().unwrap();
+
+        let rows = orientation.to_transform_matrix(&tile.center_point);
+        let columns = orientation.to_transform_matrix_column_major(&tile.center_point);
+        for row in 0..4 {
+            for column in 0..4 {
+                assert_eq!(rows[row * 4 + column], columns[column * 4 + row]);
+            }
+        }
+
+        let rows = orientation.to_rotation_matrix();
+        let columns = orientation.to_rotation_matrix_column_major();
+        for row in 0..3 {
+            for column in 0..3 {
+                assert_eq!(rows[row * 3 + column], columns[column * 3 + row]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_matrices_match_f64_layouts() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let tile = &hexasphere.tiles[3];
+        let orientation = tile.get_orientation().unwrap();
+        let center = &tile.center_point;
+
+        let pairs: [(Vec<f64>, Vec<f32>); 4] = [
+            (
+                orientation.to_rotation_matrix().to_vec(),
+                orientation.to_rotation_matrix_f32().to_vec(),
+            ),
+            (
+                orientation.to_rotation_matrix_column_major().to_vec(),
+                orientation.to_rotation_matrix_column_major_f32().to_vec(),
+            ),
+            (
+                orientation.to_transform_matrix(center).to_vec(),
+                orientation.to_transform_matrix_f32(center).to_vec(),
+            ),
+            (
+                orientation
+                    .to_transform_matrix_column_major(center)
+                    .to_vec(),
+                orientation
+                    .to_transform_matrix_column_major_f32(center)
+                    .to_vec(),
+            ),
+        ];
+
+        for (wide, narrow) in &pairs {
+            assert_eq!(wide.len(), narrow.len());
+            for (&a, &b) in wide.iter().zip(narrow) {
+                assert_eq!(a as f32, b);
+            }
+        }
+    }
 }
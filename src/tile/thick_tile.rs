@@ -3,12 +3,33 @@
 use super::core::Tile;
 use crate::geometry::{Point, Vector3};
 
+/// Where a thick tile's slab lies relative to the sphere surface.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{ExtrusionMode, Hexasphere};
+/// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+/// let plates = hexasphere.create_thick_tiles_with(ExtrusionMode::Outward, |_, _| 0.5);
+/// assert_eq!(plates.len(), hexasphere.tiles.len());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExtrusionMode {
+    /// Outer face on the surface, inner face `thickness` below it (the original behavior)
+    #[default]
+    Inward,
+    /// Inner face on the surface, outer face `thickness` above it
+    Outward,
+    /// Slab centered on the surface, half the thickness on each side
+    Centered,
+}
+
 /// A thick 3D tile with both inner and outer surfaces.
 ///
 /// This struct represents a tile that has been extruded to create thickness,
 /// suitable for 3D visualization, physics simulation, or manufacturing applications.
-/// The thickness is applied uniformly inward from the original sphere surface,
-/// maintaining the same center point but creating an inner boundary.
+/// By default the thickness is applied uniformly inward from the original sphere
+/// surface; see [`ExtrusionMode`] for raised and centered slabs.
 ///
 /// # Structure
 ///
@@ -42,10 +63,15 @@ pub struct ThickTile {
     pub inner_boundary: Vec<Point>,
     /// Center point of the tile (on the outer surface)
     pub center_point: Point,
+    /// Center of the sphere the tile was extruded from; the surface normal
+    /// points away from it
+    pub sphere_center: Point,
     /// Thickness of the tile (distance between outer and inner surfaces)
     pub thickness: f64,
     /// Whether this tile has 6 sides (hexagon) or 5 sides (pentagon)
     pub is_hexagon: bool,
+    /// Size of the chamfer around the outer face; 0.0 for straight walls
+    pub bevel_size: f64,
 }
 
 impl ThickTile {
@@ -83,32 +109,150 @@ impl ThickTile {
     /// assert_eq!(thick_tile.outer_boundary.len(), thick_tile.inner_boundary.len());
     /// ```
     pub fn from_surface_tile(surface_tile: &Tile, thickness: f64) -> Self {
-        let normal = Vector3::new(
-            surface_tile.center_point.x,
-            surface_tile.center_point.y,
-            surface_tile.center_point.z,
-        )
-        .normalize();
-
-        let inner_boundary = surface_tile
-            .boundary
-            .iter()
-            .map(|point| {
-                Point::new(
-                    point.x - normal.x * thickness,
-                    point.y - normal.y * thickness,
-                    point.z - normal.z * thickness,
-                )
-            })
-            .collect();
+        Self::from_surface_tile_with_mode(surface_tile, thickness, ExtrusionMode::Inward)
+    }
+
+    /// Creates a thick tile by extruding a surface tile in the given direction.
+    ///
+    /// Every boundary point is offset along the tile's surface normal, exactly
+    /// as in [`ThickTile::from_surface_tile`], but the slab can sit below,
+    /// above or across the sphere surface. Regardless of the mode,
+    /// `outer_boundary` and `center_point` are always on the ring farther from
+    /// the origin, so [`ThickTile::generate_all_vertices`] keeps its winding.
+    ///
+    /// # Arguments
+    ///
+    /// * `surface_tile` - The original 2D tile to extrude
+    /// * `thickness` - Distance between the outer and inner rings
+    /// * `mode` - Where the slab lies relative to the surface, see [`ExtrusionMode`]
+    ///
+    /// # Returns
+    ///
+    /// A new `ThickTile` with both outer and inner boundaries
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{ExtrusionMode, Hexasphere, ThickTile};
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let tile = &hexasphere.tiles[0];
+    ///
+    /// // A terrain plate raised above the surface
+    /// let plate = ThickTile::from_surface_tile_with_mode(tile, 0.5, ExtrusionMode::Outward);
+    /// assert_eq!(plate.inner_boundary, tile.boundary);
+    /// ```
+    pub fn from_surface_tile_with_mode(
+        surface_tile: &Tile,
+        thickness: f64,
+        mode: ExtrusionMode,
+    ) -> Self {
+        let normal = Vector3::from(&surface_tile.relative(&surface_tile.center_point)).normalize();
+        let (outer_offset, inner_offset) = match mode {
+            ExtrusionMode::Inward => (0.0, -thickness),
+            ExtrusionMode::Outward => (thickness, 0.0),
+            ExtrusionMode::Centered => (thickness / 2.0, -thickness / 2.0),
+        };
+
+        let offset_ring = |distance: f64| -> Vec<Point> {
+            if distance == 0.0 {
+                return surface_tile.boundary.clone();
+            }
+            let offset = &normal * distance;
+            surface_tile
+                .boundary
+                .iter()
+                .map(|point| Point::from(Vector3::from(point) + offset.clone()))
+                .collect()
+        };
+
+        let center_point = if outer_offset == 0.0 {
+            surface_tile.center_point.clone()
+        } else {
+            Point::from(Vector3::from(&surface_tile.center_point) + &normal * outer_offset)
+        };
 
         Self {
-            outer_boundary: surface_tile.boundary.clone(),
-            inner_boundary,
-            center_point: surface_tile.center_point.clone(),
+            outer_boundary: offset_ring(outer_offset),
+            inner_boundary: offset_ring(inner_offset),
+            center_point,
+            sphere_center: surface_tile.sphere_center.clone(),
             thickness,
             is_hexagon: surface_tile.is_hexagon(),
+            bevel_size: 0.0,
+        }
+    }
+
+    /// Chamfers the rim of the outer face.
+    ///
+    /// The outer face is inset toward the tile center by `bevel_size` (using
+    /// the same math as [`Tile::scaled_boundary`]) and the original outer ring
+    /// is lowered by `bevel_size` along the surface normal. A ring of sloped
+    /// quads joins the two, so neighboring tiles no longer meet in coincident
+    /// sharp edges and shading picks up a highlight along each rim.
+    ///
+    /// # Arguments
+    ///
+    /// * `bevel_size` - Inset and drop of the chamfer, clamped to `[0, thickness]`
+    ///
+    /// # Returns
+    ///
+    /// The same tile with `bevel_size` set; the stored rings are unchanged
+    /// and the chamfer is applied by the mesh generators
+    ///
+    /// # Mesh Size
+    ///
+    /// For a tile with `n` sides, [`ThickTile::generate_all_vertices`] grows
+    /// from `2n + 2` vertices and `4n` triangles to `3n + 2` vertices and `6n`
+    /// triangles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, ThickTile};
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let hexagon = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+    ///
+    /// let thick_tile = ThickTile::from_surface_tile(hexagon, 0.5).with_bevel(0.1);
+    /// let mesh_data = thick_tile.generate_all_vertices();
+    ///
+    /// assert_eq!(mesh_data.vertices.len(), 20);
+    /// assert_eq!(mesh_data.indices.len() / 3, 36);
+    /// ```
+    pub fn with_bevel(mut self, bevel_size: f64) -> Self {
+        self.bevel_size = bevel_size.clamp(0.0, self.thickness.max(0.0));
+        self
+    }
+
+    /// Rings of the tile from the outer face down to the inner face.
+    ///
+    /// Without a bevel this is `[outer, inner]`; with one, the outer face is
+    /// the inset ring and the lowered original rim sits in between.
+    fn rings(&self) -> Vec<Vec<Point>> {
+        if self.bevel_size <= 0.0 || self.outer_boundary.is_empty() {
+            return vec![self.outer_boundary.clone(), self.inner_boundary.clone()];
         }
+
+        let average_radius = self
+            .outer_boundary
+            .iter()
+            .map(|point| self.center_point.distance_to(point))
+            .sum::<f64>()
+            / self.outer_boundary.len() as f64;
+        let scale = (1.0 - self.bevel_size / average_radius).max(0.0);
+        let inset = self
+            .outer_boundary
+            .iter()
+            .map(|point| self.center_point.segment(point, scale))
+            .collect();
+
+        let drop = self.get_normal() * self.bevel_size;
+        let lowered = self
+            .outer_boundary
+            .iter()
+            .map(|point| Point::from(Vector3::from(point) - drop.clone()))
+            .collect();
+
+        vec![inset, lowered, self.inner_boundary.clone()]
     }
 
     /// Generates complete mesh data for the thick tile including all faces and sides.
@@ -136,6 +280,8 @@ impl ThickTile {
     /// 2. Outer boundary points (indices 1 to N)
     /// 3. Inner center point (index N+1)
     /// 4. Inner boundary points (indices N+2 to 2N+1)
+    /// 5. With a bevel ([`ThickTile::with_bevel`]), the lowered outer rim
+    ///    (indices 2N+2 to 3N+1); the outer face then uses the inset ring
     ///
     /// # Examples
     ///
@@ -155,23 +301,35 @@ impl ThickTile {
     /// }
     /// ```
     pub fn generate_all_vertices(&self) -> ThickTileVertices {
+        let rings = self.rings();
+        let sides = self.outer_boundary.len();
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
-        let mut vertex_count = 0;
 
         // Add outer face vertices (as triangle fan from center)
         vertices.push(self.center_point.clone()); // Center vertex
-        vertex_count += 1;
+        let outer_boundary_start = vertices.len();
+        vertices.extend(rings[0].iter().cloned());
 
-        for point in &self.outer_boundary {
-            vertices.push(point.clone());
+        // Add inner face vertices
+        let inner_center =
+            Point::from(Vector3::from(&self.center_point) - self.get_normal() * self.thickness);
+        let inner_center_idx = vertices.len();
+        vertices.push(inner_center);
+        let inner_boundary_start = vertices.len();
+        vertices.extend(rings[rings.len() - 1].iter().cloned());
+
+        // Bevel rings between the two faces come last
+        let mut ring_starts = vec![outer_boundary_start];
+        for ring in &rings[1..rings.len() - 1] {
+            ring_starts.push(vertices.len());
+            vertices.extend(ring.iter().cloned());
         }
-        let outer_boundary_start = vertices.len();
-        vertex_count += self.outer_boundary.len();
+        ring_starts.push(inner_boundary_start);
 
         // Create outer face triangles
-        for i in 0..self.outer_boundary.len() {
-            let next_i = (i + 1) % self.outer_boundary.len();
+        for i in 0..sides {
+            let next_i = (i + 1) % sides;
             indices.extend_from_slice(&[
                 0, // Center
                 outer_boundary_start + i,
@@ -179,26 +337,9 @@ impl ThickTile {
             ]);
         }
 
-        // Add inner face vertices
-        let inner_center = Point::new(
-            self.center_point.x - self.get_normal().x * self.thickness,
-            self.center_point.y - self.get_normal().y * self.thickness,
-            self.center_point.z - self.get_normal().z * self.thickness,
-        );
-
-        vertices.push(inner_center);
-        let inner_center_idx = vertex_count;
-        vertex_count += 1;
-
-        for point in &self.inner_boundary {
-            vertices.push(point.clone());
-        }
-        let inner_boundary_start = vertex_count;
-        // vertex_count += self.inner_boundary.len();
-
         // Create inner face triangles (reversed winding for inward-facing normal)
-        for i in 0..self.inner_boundary.len() {
-            let next_i = (i + 1) % self.inner_boundary.len();
+        for i in 0..sides {
+            let next_i = (i + 1) % sides;
             indices.extend_from_slice(&[
                 inner_center_idx,              // Center
                 inner_boundary_start + next_i, // Reversed order
@@ -206,29 +347,122 @@ impl ThickTile {
             ]);
         }
 
-        // Create side faces (quads as two triangles each)
-        for i in 0..self.outer_boundary.len() {
-            let next_i = (i + 1) % self.outer_boundary.len();
+        // Create side faces (quads as two triangles each), band by band from the top
+        for band in ring_starts.windows(2) {
+            let (upper_start, lower_start) = (band[0], band[1]);
+            for i in 0..sides {
+                let next_i = (i + 1) % sides;
 
-            let outer_curr = outer_boundary_start + i;
-            let outer_next = outer_boundary_start + next_i;
-            let inner_curr = inner_boundary_start + i;
-            let inner_next = inner_boundary_start + next_i;
+                let outer_curr = upper_start + i;
+                let outer_next = upper_start + next_i;
+                let inner_curr = lower_start + i;
+                let inner_next = lower_start + next_i;
 
-            // First triangle of quad
-            indices.extend_from_slice(&[outer_curr, inner_curr, outer_next]);
-            // Second triangle of quad
-            indices.extend_from_slice(&[outer_next, inner_curr, inner_next]);
+                // First triangle of quad
+                indices.extend_from_slice(&[outer_curr, inner_curr, outer_next]);
+                // Second triangle of quad
+                indices.extend_from_slice(&[outer_next, inner_curr, inner_next]);
+            }
         }
 
         ThickTileVertices { vertices, indices }
     }
 
+    /// Generates a render-ready mesh with per-vertex normals and `u32` indices.
+    ///
+    /// Unlike [`ThickTile::generate_all_vertices`], vertices are duplicated
+    /// wherever faces meet at a hard edge, so every vertex carries the normal
+    /// of the face it belongs to:
+    ///
+    /// - **Outer face**: The surface normal at the tile center
+    /// - **Inner face**: The negated surface normal
+    /// - **Side walls**: Each wall quad's own normal, pointing away from the tile center
+    ///
+    /// Winding matches [`ThickTile::generate_all_vertices`], so triangle
+    /// normals agree with the vertex normals.
+    ///
+    /// # Returns
+    ///
+    /// A [`ThickTileMesh`] with flat `f32` arrays. For a tile with `n` sides
+    /// it holds `6n + 2` vertices and `4n` triangles, or `10n + 2` vertices
+    /// and `6n` triangles with a bevel.
+    ///
+    /// # Mesh Structure
+    ///
+    /// 1. Outer center and outer boundary (indices 0 to n)
+    /// 2. Inner center and inner boundary (indices n+1 to 2n+1)
+    /// 3. Four vertices per side wall: outer i, outer i+1, inner i, inner i+1,
+    ///    with the bevel quads (if any) before the walls
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::{Hexasphere, ThickTile};
+    /// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+    /// let thick_tile = ThickTile::from_surface_tile(&hexasphere.tiles[0], 0.5);
+    /// let mesh = thick_tile.generate_mesh();
+    ///
+    /// let n = thick_tile.outer_boundary.len();
+    /// assert_eq!(mesh.vertex_count(), 6 * n + 2);
+    /// assert_eq!(mesh.indices.len(), 12 * n);
+    /// assert_eq!(mesh.normals.len(), mesh.positions.len());
+    /// ```
+    pub fn generate_mesh(&self) -> ThickTileMesh {
+        let rings = self.rings();
+        let mut mesh = ThickTileMesh::default();
+        let normal = self.get_normal();
+        let inward = &normal * -1.0;
+        let n = self.outer_boundary.len() as u32;
+
+        let inner_center =
+            Point::from(Vector3::from(&self.center_point) - &normal * self.thickness);
+        let faces = [
+            (&self.center_point, &rings[0], &normal),
+            (&inner_center, &rings[rings.len() - 1], &inward),
+        ];
+        for (center, boundary, face_normal) in faces {
+            mesh.push_vertex(center, face_normal);
+            for point in boundary {
+                mesh.push_vertex(point, face_normal);
+            }
+        }
+
+        for i in 0..n {
+            let next = (i + 1) % n;
+            // Outer face counter-clockwise, inner face reversed
+            mesh.indices.extend([0, 1 + i, 1 + next]);
+            mesh.indices.extend([n + 1, n + 2 + next, n + 2 + i]);
+        }
+
+        for band in rings.windows(2) {
+            let (upper, lower) = (&band[0], &band[1]);
+            for i in 0..upper.len() {
+                let next = (i + 1) % upper.len();
+                let corners = [&upper[i], &upper[next], &lower[i], &lower[next]];
+                let [outer_curr, outer_next, inner_curr, _] = corners.map(Vector3::from);
+                let wall_normal = (&inner_curr - &outer_curr)
+                    .cross(&(&outer_next - &outer_curr))
+                    .normalize();
+
+                let start = mesh.vertex_count() as u32;
+                for corner in corners {
+                    mesh.push_vertex(corner, &wall_normal);
+                }
+                let [outer_curr, outer_next, inner_curr, inner_next] =
+                    [start, start + 1, start + 2, start + 3];
+                mesh.indices.extend([outer_curr, inner_curr, outer_next]);
+                mesh.indices.extend([outer_next, inner_curr, inner_next]);
+            }
+        }
+
+        mesh
+    }
+
     /// Calculates the surface normal vector for this tile.
     ///
-    /// For a tile on a sphere centered at the origin, the surface normal
-    /// is simply the normalized vector from the origin to the tile center.
-    /// This vector points directly outward from the sphere surface.
+    /// The surface normal is the normalized vector from the sphere center to
+    /// the tile center, which for a sphere at the origin is just the tile
+    /// center itself. This vector points directly outward from the sphere surface.
     ///
     /// # Returns
     ///
@@ -252,12 +486,7 @@ impl ThickTile {
     /// assert!((magnitude - 1.0).abs() < 0.001); // Should be unit vector
     /// ```
     fn get_normal(&self) -> Vector3 {
-        Vector3::new(
-            self.center_point.x,
-            self.center_point.y,
-            self.center_point.z,
-        )
-        .normalize()
+        (Vector3::from(&self.center_point) - Vector3::from(&self.sphere_center)).normalize()
     }
 
     /// Generates vertices for just the side walls of the thick tile.
@@ -360,3 +589,254 @@ pub struct ThickTileVertices {
     /// Triangle indices (every 3 consecutive indices form one triangle)
     pub indices: Vec<usize>,
 }
+
+/// Thick tile mesh with per-vertex normals, in flat GPU-friendly arrays.
+///
+/// Produced by [`ThickTile::generate_mesh`]. Positions and normals store
+/// three `f32` components per vertex (`[x0, y0, z0, x1, ...]`), ready to be
+/// uploaded as vertex buffers without conversion.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Hexasphere, ThickTile};
+/// let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+/// let mesh = ThickTile::from_surface_tile(&hexasphere.tiles[0], 0.5).generate_mesh();
+///
+/// // Normal of the first vertex (the outer center)
+/// let normal = &mesh.normals[0..3];
+/// let length = normal.iter().map(|c| c * c).sum::<f32>().sqrt();
+/// assert!((length - 1.0).abs() < 1e-5);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThickTileMesh {
+    /// Vertex positions, three components per vertex
+    pub positions: Vec<f32>,
+    /// Unit vertex normals, three components per vertex
+    pub normals: Vec<f32>,
+    /// Triangle indices (every 3 consecutive indices form one triangle)
+    pub indices: Vec<u32>,
+}
+
+impl ThickTileMesh {
+    /// Returns the number of vertices in the mesh.
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len() / 3
+    }
+
+    fn push_vertex(&mut self, position: &Point, normal: &Vector3) {
+        self.positions
+            .extend([position.x as f32, position.y as f32, position.z as f32]);
+        self.normals
+            .extend([normal.x as f32, normal.y as f32, normal.z as f32]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExtrusionMode, ThickTile};
+    use crate::geometry::Vector3;
+    use crate::hexasphere::core::Hexasphere;
+
+    fn vector(values: &[f32], index: u32) -> Vector3 {
+        let i = index as usize * 3;
+        Vector3::new(values[i] as f64, values[i + 1] as f64, values[i + 2] as f64)
+    }
+
+    #[test]
+    fn test_generate_mesh_normals() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let hexagon = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+        let pentagon = hexasphere.tiles.iter().find(|t| t.is_pentagon()).unwrap();
+
+        for tile in [hexagon, pentagon] {
+            let thick_tile = ThickTile::from_surface_tile(tile, 1.0);
+            let mesh = thick_tile.generate_mesh();
+            let n = tile.boundary.len() as u32;
+            let up = Vector3::from(&tile.center_point).normalize();
+
+            assert_eq!(mesh.vertex_count() as u32, 6 * n + 2);
+            assert_eq!(mesh.indices.len() as u32, 12 * n);
+
+            for index in 0..mesh.vertex_count() as u32 {
+                let normal = vector(&mesh.normals, index);
+                assert!((normal.dot(&normal).sqrt() - 1.0).abs() < 1e-5);
+
+                let along_up = normal.dot(&up);
+                if index <= n {
+                    assert!(along_up > 0.999, "outer face normal points outward");
+                } else if index <= 2 * n + 1 {
+                    assert!(along_up < -0.999, "inner face normal points inward");
+                } else {
+                    // Walls face sideways, away from the tile center
+                    assert!(along_up.abs() < 0.2);
+                    let position = vector(&mesh.positions, index);
+                    let center = Vector3::from(&tile.center_point);
+                    assert!(normal.dot(&(position - center)) > 0.0);
+                }
+            }
+
+            // Triangle winding agrees with the vertex normals
+            for triangle in mesh.indices.chunks(3) {
+                let [a, b, c] = [0, 1, 2].map(|k| vector(&mesh.positions, triangle[k]));
+                let face_normal = (&b - &a).cross(&(&c - &a));
+                assert!(face_normal.dot(&vector(&mesh.normals, triangle[0])) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_mesh_matches_generate_all_vertices() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let thick_tile = ThickTile::from_surface_tile(&hexasphere.tiles[3], 0.5);
+        let mesh = thick_tile.generate_mesh();
+        let old = thick_tile.generate_all_vertices();
+
+        // Cap vertices keep the layout of generate_all_vertices
+        for (index, point) in old.vertices.iter().enumerate() {
+            let offset = vector(&mesh.positions, index as u32) - Vector3::from(point);
+            assert!(offset.dot(&offset).sqrt() < 1e-5);
+        }
+        assert_eq!(mesh.indices.len(), old.indices.len());
+    }
+
+    fn radius(point: &crate::geometry::Point) -> f64 {
+        let v = Vector3::from(point);
+        v.dot(&v).sqrt()
+    }
+
+    #[test]
+    fn test_extrusion_modes_place_rings() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let tile = &hexasphere.tiles[5];
+        let thickness = 1.0;
+
+        // (mode, outer center radius, inner center radius)
+        let cases = [
+            (ExtrusionMode::Inward, 10.0, 9.0),
+            (ExtrusionMode::Outward, 11.0, 10.0),
+            (ExtrusionMode::Centered, 10.5, 9.5),
+        ];
+        for (mode, outer, inner) in cases {
+            let thick_tile = ThickTile::from_surface_tile_with_mode(tile, thickness, mode);
+            assert!(
+                (radius(&thick_tile.center_point) - outer).abs() < 0.01,
+                "{:?}",
+                mode
+            );
+
+            for (o, i) in thick_tile
+                .outer_boundary
+                .iter()
+                .zip(&thick_tile.inner_boundary)
+            {
+                assert!(
+                    radius(o) > radius(i),
+                    "{:?}: outer ring is farther out",
+                    mode
+                );
+                assert!((o.distance_to(i) - thickness).abs() < 0.01);
+            }
+
+            // Inner center sits one thickness below the outer center
+            let mesh = thick_tile.generate_all_vertices();
+            let n = tile.boundary.len();
+            assert!((radius(&mesh.vertices[n + 1]) - inner).abs() < 0.01);
+
+            // Outer cap triangles face away from the origin
+            for triangle in mesh.indices[..3 * n].chunks(3) {
+                let [a, b, c] = [0, 1, 2].map(|k| Vector3::from(&mesh.vertices[triangle[k]]));
+                let face_normal = (&b - &a).cross(&(&c - &a));
+                assert!(face_normal.dot(&a) > 0.0, "{:?}", mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_thick_tiles_with_per_tile_thickness() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let thick_tiles = hexasphere
+            .create_thick_tiles_with(ExtrusionMode::Outward, |index, _| index as f64 * 0.01);
+
+        assert_eq!(thick_tiles.len(), hexasphere.tiles.len());
+        for (index, thick_tile) in thick_tiles.iter().enumerate() {
+            assert_eq!(thick_tile.thickness, index as f64 * 0.01);
+            assert_eq!(thick_tile.inner_boundary, hexasphere.tiles[index].boundary);
+        }
+
+        let inward = hexasphere.create_thick_tiles_with(ExtrusionMode::Inward, |_, _| 0.5);
+        let classic = hexasphere.create_thick_tiles(0.5);
+        for (a, b) in inward.iter().zip(&classic) {
+            assert_eq!(a.outer_boundary, b.outer_boundary);
+            assert_eq!(a.inner_boundary, b.inner_boundary);
+        }
+    }
+
+    #[test]
+    fn test_bevel_counts_and_radii() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let hexagon = hexasphere.tiles.iter().find(|t| t.is_hexagon()).unwrap();
+        let pentagon = hexasphere.tiles.iter().find(|t| t.is_pentagon()).unwrap();
+        let bevel = 0.2;
+
+        for tile in [hexagon, pentagon] {
+            let n = tile.boundary.len();
+            let thick_tile = ThickTile::from_surface_tile(tile, 1.0).with_bevel(bevel);
+
+            let mesh_data = thick_tile.generate_all_vertices();
+            assert_eq!(mesh_data.vertices.len(), 3 * n + 2);
+            assert_eq!(mesh_data.indices.len(), 3 * 6 * n);
+            assert!(mesh_data
+                .indices
+                .iter()
+                .all(|&i| i < mesh_data.vertices.len()));
+
+            let mesh = thick_tile.generate_mesh();
+            assert_eq!(mesh.vertex_count(), 10 * n + 2);
+            assert_eq!(mesh.indices.len(), 3 * 6 * n);
+
+            // Inset top ring and lowered rim stay within the bevel band,
+            // measured as height along the tile normal
+            let up = Vector3::from(&tile.center_point).normalize();
+            let height = |point: &crate::geometry::Point| Vector3::from(point).dot(&up);
+            let top = tile
+                .boundary
+                .iter()
+                .map(height)
+                .fold(height(&tile.center_point), f64::max);
+            let rim = tile.boundary.iter().map(height).fold(f64::MAX, f64::min);
+            let bevel_ring = mesh_data.vertices[1..=n]
+                .iter()
+                .chain(&mesh_data.vertices[2 * n + 2..]);
+            for point in bevel_ring {
+                let h = height(point);
+                assert!(h <= top + 1e-3 && h >= rim - bevel - 1e-3, "{}", h);
+            }
+
+            // Every triangle still faces away from the tile's interior
+            let interior =
+                Vector3::from(&tile.center_point).normalize() * (radius(&tile.center_point) - 0.5);
+            for triangle in mesh_data.indices.chunks(3) {
+                let [a, b, c] = [0, 1, 2].map(|k| Vector3::from(&mesh_data.vertices[triangle[k]]));
+                let face_normal = (&b - &a).cross(&(&c - &a));
+                assert!(face_normal.dot(&(&a - &interior)) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bevel_is_clamped_and_zero_is_unchanged() {
+        let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+        let tile = &hexasphere.tiles[0];
+
+        let plain = ThickTile::from_surface_tile(tile, 0.5);
+        let zero = plain.clone().with_bevel(0.0);
+        assert_eq!(
+            zero.generate_all_vertices().vertices,
+            plain.generate_all_vertices().vertices
+        );
+
+        assert_eq!(plain.clone().with_bevel(2.0).bevel_size, 0.5);
+        assert_eq!(plain.with_bevel(-1.0).bevel_size, 0.0);
+    }
+}
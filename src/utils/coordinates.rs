@@ -1,19 +1,549 @@
 //! Coordinate system utilities and conversions.
+
+use crate::geometry::{Point, Vector3};
+
 /// Latitude and longitude coordinates in degrees.
 ///
 /// Used for converting 3D sphere coordinates to geographic coordinates,
 /// which can be useful for mapping applications or coordinate system conversions.
 ///
+/// # Coordinate Convention
+///
+/// The sphere is Y-up, matching [`Point::to_lat_lon`]:
+///
+/// - **Latitude**: Angle above the XZ plane, toward +Y
+/// - **Longitude**: Angle around the Y axis, 0° along +Z and 90° along +X
+///
 /// # Examples
 ///
 /// ```rust
 /// # use geotiles::LatLon;
 /// let lat_lon = LatLon { lat: 40.7128, lon: -74.0060 }; // New York City
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct LatLon {
     /// Latitude in degrees, ranging from -90 (South Pole) to +90 (North Pole)
     pub lat: f64,
     /// Longitude in degrees, ranging from -180 to +180
     pub lon: f64,
 }
+
+impl LatLon {
+    /// Creates a coordinate, normalizing it into the canonical ranges.
+    ///
+    /// # Arguments
+    ///
+    /// * `lat` - Latitude in degrees; clamped to [-90, 90]
+    /// * `lon` - Longitude in degrees; wrapped into (-180, 180]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::LatLon;
+    /// let wrapped = LatLon::new(10.0, 190.0);
+    /// assert_eq!(wrapped.lon, -170.0);
+    ///
+    /// let clamped = LatLon::new(95.0, -180.0);
+    /// assert_eq!(clamped.lat, 90.0);
+    /// assert_eq!(clamped.lon, 180.0);
+    /// ```
+    pub fn new(lat: f64, lon: f64) -> Self {
+        let lon = lon.rem_euclid(360.0);
+        let lon = if lon > 180.0 { lon - 360.0 } else { lon };
+
+        Self {
+            lat: lat.clamp(-90.0, 90.0),
+            lon,
+        }
+    }
+
+    /// Converts the coordinate to a point on a sphere of the given radius.
+    ///
+    /// This is the exact inverse of [`Point::to_lat_lon`]. The result is not
+    /// rounded to 3 decimals like [`Point::new`], so round trips are accurate
+    /// to floating-point precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere
+    ///
+    /// # Returns
+    ///
+    /// The point at this latitude and longitude, `radius` from the origin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::LatLon;
+    /// // The north pole is +Y
+    /// let pole = LatLon::new(90.0, 0.0).to_point(10.0);
+    /// assert!((pole.y - 10.0).abs() < 1e-9);
+    ///
+    /// // Round trip through the crate's own convention
+    /// let paris = LatLon::new(48.8566, 2.3522);
+    /// let back = paris.to_point(6371.0).to_lat_lon(6371.0);
+    /// assert!((back.lat - paris.lat).abs() < 1e-9);
+    /// assert!((back.lon - paris.lon).abs() < 1e-9);
+    /// ```
+    pub fn to_point(&self, radius: f64) -> Point {
+        let (lat, lon) = (self.lat.to_radians(), self.lon.to_radians());
+
+        Point {
+            x: radius * lat.cos() * lon.sin(),
+            y: radius * lat.sin(),
+            z: radius * lat.cos() * lon.cos(),
+        }
+    }
+
+    /// Calculates the great-circle distance to another coordinate.
+    ///
+    /// Uses the haversine formula, which stays accurate for both very short
+    /// and nearly antipodal distances.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The destination coordinate
+    /// * `radius` - Radius of the sphere
+    ///
+    /// # Returns
+    ///
+    /// The distance along the sphere surface, in the same units as `radius`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::LatLon;
+    /// let london = LatLon::new(51.5074, -0.1278);
+    /// let new_york = LatLon::new(40.7128, -74.0060);
+    ///
+    /// let km = london.distance_to(&new_york, 6371.0);
+    /// assert!((km - 5570.0).abs() < 10.0);
+    /// ```
+    pub fn distance_to(&self, other: &LatLon, radius: f64) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.lon - self.lon).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        let a = a.clamp(0.0, 1.0);
+
+        2.0 * radius * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+
+    /// Calculates the initial bearing of the great circle toward another coordinate.
+    ///
+    /// The bearing is the compass direction at the start of the path: 0° is
+    /// north, 90° is east. It generally changes along the way, except on
+    /// meridians and the equator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The destination coordinate
+    ///
+    /// # Returns
+    ///
+    /// The bearing in degrees in [0, 360). Identical and antipodal points
+    /// have no unique direction, so both return 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::LatLon;
+    /// // Crossing the antimeridian eastward along the equator
+    /// let bearing = LatLon::new(0.0, 179.0).initial_bearing_to(&LatLon::new(0.0, -179.0));
+    /// assert!((bearing - 90.0).abs() < 1e-9);
+    /// ```
+    pub fn initial_bearing_to(&self, other: &LatLon) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lon = (other.lon - self.lon).to_radians();
+
+        let y = d_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+        if y.hypot(x) < BEARING_TOLERANCE {
+            return 0.0;
+        }
+
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Travels along a great circle from this coordinate.
+    ///
+    /// The inverse of [`LatLon::distance_to`] and [`LatLon::initial_bearing_to`]:
+    /// starting here and heading `bearing` for `distance` arrives at the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `bearing` - Initial compass direction in degrees (0° north, 90° east)
+    /// * `distance` - Distance to travel, in the same units as `radius`
+    /// * `radius` - Radius of the sphere
+    ///
+    /// # Returns
+    ///
+    /// The destination, normalized like [`LatLon::new`]
+    ///
+    /// # Use Cases
+    ///
+    /// - **Dead reckoning**: Move a unit a given distance in a given direction
+    /// - **Range rings**: Sample points at a fixed distance around a location
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use geotiles::LatLon;
+    /// let start = LatLon::new(0.0, 0.0);
+    ///
+    /// // A quarter of the way around the sphere heading north reaches the pole
+    /// let radius = 10.0;
+    /// let quarter = std::f64::consts::FRAC_PI_2 * radius;
+    /// let pole = start.destination(0.0, quarter, radius);
+    /// assert!((pole.lat - 90.0).abs() < 1e-9);
+    /// ```
+    pub fn destination(&self, bearing: f64, distance: f64, radius: f64) -> LatLon {
+        let lat1 = self.lat.to_radians();
+        let bearing = bearing.to_radians();
+        let angle = distance / radius;
+
+        let sin_lat2 = lat1.sin() * angle.cos() + lat1.cos() * angle.sin() * bearing.cos();
+        let lat2 = sin_lat2.clamp(-1.0, 1.0).asin();
+        let d_lon =
+            (bearing.sin() * angle.sin() * lat1.cos()).atan2(angle.cos() - lat1.sin() * sin_lat2);
+
+        LatLon::new(lat2.to_degrees(), self.lon + d_lon.to_degrees())
+    }
+}
+
+/// Which axis points up in exported geometry.
+///
+/// The crate is Y-up (see [`LatLon`]), but Blender, most CAD packages and
+/// many GIS tools are Z-up, where Y-up files arrive lying on their side.
+/// [`AxisConvention::ZUp`] rotates the sphere a quarter turn about the X
+/// axis, `(x, y, z) → (x, -z, y)`, the same mapping Blender applies when it
+/// imports a Y-up file. A rotation keeps handedness, so triangles stay wound
+/// counter-clockwise seen from outside; merely swapping Y and Z would mirror
+/// the mesh and turn every face inside out.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{AxisConvention, Point};
+/// let north_pole = Point::new(0.0, 10.0, 0.0);
+/// let converted = AxisConvention::ZUp.convert(&north_pole);
+/// assert_eq!(converted, Point::new(0.0, 0.0, 10.0));
+/// assert_eq!(AxisConvention::ZUp.restore(&converted), north_pole);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AxisConvention {
+    /// The crate's own convention: +Y is north, unchanged on export
+    #[default]
+    YUp,
+    /// +Z is north, for Blender and other Z-up tools
+    ZUp,
+}
+
+impl AxisConvention {
+    /// Converts a point from the crate's Y-up space into this convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - A point in the crate's coordinate space
+    ///
+    /// # Returns
+    ///
+    /// The same point expressed in this convention, without extra rounding
+    pub fn convert(&self, point: &Point) -> Point {
+        let [x, y, z] = self.convert_xyz([point.x, point.y, point.z]);
+        Point { x, y, z }
+    }
+
+    /// Converts a point from this convention back into the crate's Y-up space.
+    ///
+    /// The inverse of [`AxisConvention::convert`].
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - A point expressed in this convention
+    ///
+    /// # Returns
+    ///
+    /// The same point in the crate's coordinate space
+    pub fn restore(&self, point: &Point) -> Point {
+        let [x, y, z] = match self {
+            AxisConvention::YUp => [point.x, point.y, point.z],
+            AxisConvention::ZUp => [point.x, point.z, negate(point.y)],
+        };
+        Point { x, y, z }
+    }
+
+    /// Converts a direction, such as a normal, into this convention.
+    pub(crate) fn convert_vector(&self, vector: &Vector3) -> Vector3 {
+        let [x, y, z] = self.convert_xyz([vector.x, vector.y, vector.z]);
+        Vector3::new(x, y, z)
+    }
+
+    /// Converts raw coordinates into this convention.
+    pub(crate) fn convert_xyz(&self, [x, y, z]: [f64; 3]) -> [f64; 3] {
+        match self {
+            AxisConvention::YUp => [x, y, z],
+            AxisConvention::ZUp => [x, negate(z), y],
+        }
+    }
+}
+
+/// Negates without producing `-0.0`, which would print as `-0` in text exports.
+fn negate(value: f64) -> f64 {
+    0.0 - value
+}
+
+/// A latitude/longitude bounding box, as returned by [`crate::Tile::lat_lon_bounds`].
+///
+/// Latitudes run from `min_lat` north to `max_lat`. Longitudes run east from
+/// `min_lon` to `max_lon`; when the box crosses the antimeridian `min_lon` is
+/// greater than `max_lon` (e.g. 170° to -170°), the same convention as
+/// [`crate::Hexasphere::tiles_in_bbox`]. Boxes around a pole span every
+/// longitude, from -180° to 180°.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{LatLon, LatLonBounds};
+/// let pacific = LatLonBounds {
+///     min_lat: -10.0,
+///     max_lat: 10.0,
+///     min_lon: 170.0,
+///     max_lon: -170.0,
+///     crosses_antimeridian: true,
+/// };
+///
+/// assert!(pacific.contains(&LatLon::new(0.0, 179.0)));
+/// assert!(pacific.contains(&LatLon::new(0.0, -175.0)));
+/// assert!(!pacific.contains(&LatLon::new(0.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatLonBounds {
+    /// Southern edge in degrees
+    pub min_lat: f64,
+    /// Northern edge in degrees
+    pub max_lat: f64,
+    /// Western edge in degrees
+    pub min_lon: f64,
+    /// Eastern edge in degrees; less than `min_lon` when crossing the antimeridian
+    pub max_lon: f64,
+    /// Whether the box wraps from 180° to -180° longitude
+    pub crosses_antimeridian: bool,
+}
+
+impl LatLonBounds {
+    /// Tests whether a coordinate lies inside the box, edges included.
+    ///
+    /// Longitudes are compared modulo 360°, so -180° and 180° are the same
+    /// meridian. Coordinates at a pole match any longitude.
+    ///
+    /// # Arguments
+    ///
+    /// * `lat_lon` - The coordinate to test, in degrees
+    ///
+    /// # Returns
+    ///
+    /// `true` if the coordinate is within both the latitude and longitude range
+    pub fn contains(&self, lat_lon: &LatLon) -> bool {
+        if !(self.min_lat..=self.max_lat).contains(&lat_lon.lat) {
+            return false;
+        }
+        if lat_lon.lat.abs() >= 90.0 {
+            return true;
+        }
+
+        // Degrees east of the western edge, compared with the box's width
+        let width = (self.max_lon - self.min_lon).rem_euclid(360.0);
+        let width = if width == 0.0 && self.max_lon != self.min_lon {
+            360.0
+        } else {
+            width
+        };
+        (lat_lon.lon - self.min_lon).rem_euclid(360.0) <= width
+    }
+}
+
+/// Below this magnitude the bearing direction vector is treated as zero.
+const BEARING_TOLERANCE: f64 = 1e-12;
+
+#[cfg(test)]
+mod tests {
+    use super::{AxisConvention, LatLon, LatLonBounds};
+    use crate::geometry::Point;
+
+    #[test]
+    fn test_axis_convention_round_trips() {
+        let point = Point {
+            x: 1.25,
+            y: -2.5,
+            z: 0.0,
+        };
+        for axes in [AxisConvention::YUp, AxisConvention::ZUp] {
+            assert_eq!(axes.restore(&axes.convert(&point)), point);
+        }
+        assert_eq!(AxisConvention::YUp.convert(&point), point);
+
+        // North stays north, and no negative zero sneaks into the output
+        let converted = AxisConvention::ZUp.convert(&point);
+        assert_eq!([converted.x, converted.y, converted.z], [1.25, 0.0, -2.5]);
+        assert!(converted.y.is_sign_positive());
+
+        // A rotation, not a mirror: right-handed bases stay right-handed
+        let x = AxisConvention::ZUp.convert_xyz([1.0, 0.0, 0.0]);
+        let y = AxisConvention::ZUp.convert_xyz([0.0, 1.0, 0.0]);
+        let z = AxisConvention::ZUp.convert_xyz([0.0, 0.0, 1.0]);
+        let cross = [
+            x[1] * y[2] - x[2] * y[1],
+            x[2] * y[0] - x[0] * y[2],
+            x[0] * y[1] - x[1] * y[0],
+        ];
+        assert_eq!(cross, z);
+    }
+
+    #[test]
+    fn test_lat_lon_bounds_contains_wraps_longitude() {
+        let bounds = |min_lon, max_lon| LatLonBounds {
+            min_lat: -10.0,
+            max_lat: 10.0,
+            min_lon,
+            max_lon,
+            crosses_antimeridian: min_lon > max_lon,
+        };
+
+        let plain = bounds(10.0, 20.0);
+        assert!(plain.contains(&LatLon::new(0.0, 15.0)));
+        assert!(!plain.contains(&LatLon::new(0.0, 25.0)));
+        assert!(!plain.contains(&LatLon::new(11.0, 15.0)));
+
+        let wrapping = bounds(170.0, -170.0);
+        assert!(wrapping.contains(&LatLon {
+            lat: 0.0,
+            lon: -180.0
+        }));
+        assert!(wrapping.contains(&LatLon::new(0.0, 180.0)));
+        assert!(!wrapping.contains(&LatLon::new(0.0, 160.0)));
+
+        let everywhere = bounds(-180.0, 180.0);
+        assert!(everywhere.contains(&LatLon::new(0.0, 0.0)));
+        assert!(everywhere.contains(&LatLon {
+            lat: 0.0,
+            lon: -180.0
+        }));
+
+        let polar = LatLonBounds {
+            max_lat: 90.0,
+            ..plain
+        };
+        assert!(polar.contains(&LatLon::new(90.0, 100.0)));
+    }
+
+    #[test]
+    fn test_lat_lon_new_normalizes() {
+        assert_eq!(LatLon::new(0.0, 540.0).lon, 180.0);
+        assert_eq!(LatLon::new(0.0, -540.0).lon, 180.0);
+        assert_eq!(LatLon::new(0.0, 359.0).lon, -1.0);
+        assert_eq!(LatLon::new(0.0, -45.0).lon, -45.0);
+        assert_eq!(LatLon::new(-120.0, 0.0).lat, -90.0);
+    }
+
+    #[test]
+    fn test_lat_lon_round_trips() {
+        let radius = 7.5;
+
+        for lat_step in -18..=18 {
+            for lon_step in -35..=36 {
+                let lat_lon = LatLon::new(lat_step as f64 * 5.0, lon_step as f64 * 5.0);
+                let point = lat_lon.to_point(radius);
+
+                let length = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+                assert!((length - radius).abs() < 1e-9 * radius);
+
+                // Point -> lat/lon -> point
+                let again = point.to_lat_lon(radius).to_point(radius);
+                let error = ((again.x - point.x).powi(2)
+                    + (again.y - point.y).powi(2)
+                    + (again.z - point.z).powi(2))
+                .sqrt();
+                assert!(error < 1e-6 * radius, "{:?}", lat_lon);
+
+                // Lat/lon -> point -> lat/lon, away from the poles where lon is arbitrary
+                if lat_lon.lat.abs() < 90.0 {
+                    let back = point.to_lat_lon(radius);
+                    assert!((back.lat - lat_lon.lat).abs() < 1e-6, "{:?}", lat_lon);
+                    let lon_error = (back.lon - lat_lon.lon).rem_euclid(360.0);
+                    assert!(lon_error.min(360.0 - lon_error) < 1e-6, "{:?}", lat_lon);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lat_lon_distance_known_values() {
+        let earth = 6371.0;
+        let london = LatLon::new(51.5074, -0.1278);
+        let new_york = LatLon::new(40.7128, -74.0060);
+
+        // Published great-circle distance is ~5570 km
+        let distance = london.distance_to(&new_york, earth);
+        assert!((distance - 5570.0).abs() / 5570.0 < 0.005, "{}", distance);
+        assert!((new_york.distance_to(&london, earth) - distance).abs() < 1e-9);
+        assert_eq!(london.distance_to(&london, earth), 0.0);
+
+        // Antipodal points are half a circumference apart
+        let antipode = LatLon::new(-51.5074, 179.8722);
+        let half = std::f64::consts::PI * earth;
+        assert!((london.distance_to(&antipode, earth) - half).abs() < 1e-6);
+
+        // Two degrees of equator across the antimeridian, not 358
+        let span = LatLon::new(0.0, 179.0).distance_to(&LatLon::new(0.0, -179.0), earth);
+        assert!((span - 2f64.to_radians() * earth).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lat_lon_bearing_edge_cases() {
+        let london = LatLon::new(51.5074, -0.1278);
+        let new_york = LatLon::new(40.7128, -74.0060);
+
+        // Westbound out of London, a little north of west
+        let bearing = london.initial_bearing_to(&new_york);
+        assert!((bearing - 288.3).abs() < 0.5, "{}", bearing);
+
+        assert_eq!(london.initial_bearing_to(&london), 0.0);
+        assert_eq!(
+            LatLon::new(0.0, 0.0).initial_bearing_to(&LatLon::new(0.0, 180.0)),
+            0.0
+        );
+        assert!(
+            (LatLon::new(10.0, 0.0).initial_bearing_to(&LatLon::new(-10.0, 0.0)) - 180.0).abs()
+                < 1e-9
+        );
+        assert!(
+            (LatLon::new(0.0, -179.0).initial_bearing_to(&LatLon::new(0.0, 179.0)) - 270.0).abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_lat_lon_destination_inverts_distance_and_bearing() {
+        let radius = 6371.0;
+        let starts = [
+            LatLon::new(51.5074, -0.1278),
+            LatLon::new(-33.8688, 151.2093),
+            LatLon::new(0.0, 179.5),
+        ];
+
+        for start in starts {
+            for bearing in [0.0, 45.0, 90.0, 135.0, 200.0, 315.0] {
+                for distance in [1.0, 500.0, 5000.0] {
+                    let end = start.destination(bearing, distance, radius);
+                    assert!(end.lon > -180.0 && end.lon <= 180.0);
+                    assert!((start.distance_to(&end, radius) - distance).abs() < 1e-6);
+
+                    let error = (start.initial_bearing_to(&end) - bearing).rem_euclid(360.0);
+                    assert!(error.min(360.0 - error) < 1e-6, "{:?} {}", start, bearing);
+                }
+            }
+        }
+    }
+}
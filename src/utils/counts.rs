@@ -0,0 +1,172 @@
+//! Exact element counts of the Goldberg polyhedra the crate builds.
+//!
+//! A hexasphere with `num_divisions = d` is the Goldberg polyhedron GP(d, 0),
+//! whose triangulation number is `T = d²`. Euler's formula fixes every count
+//! from `T` alone, so these functions let callers size buffers before
+//! construction. `d = 0` produces the same 12-tile dodecahedron as `d = 1`.
+
+/// Triangulation number `T = d²` of the sphere built with `num_divisions`.
+fn triangulation_number(num_divisions: usize) -> usize {
+    let d = num_divisions.max(1);
+    d.saturating_mul(d)
+}
+
+/// Predicts how many tiles `Hexasphere::new` creates for `num_divisions`.
+///
+/// # Arguments
+///
+/// * `num_divisions` - The subdivision level passed to `Hexasphere::new`
+///
+/// # Returns
+///
+/// `10·d² + 2` tiles (saturating), where `d = max(num_divisions, 1)`
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::utils::tile_count;
+/// # use geotiles::Hexasphere;
+/// assert_eq!(tile_count(0), 12);
+/// assert_eq!(tile_count(3), 92);
+/// assert_eq!(Hexasphere::new(1.0, 3, 1.0).tiles.len(), tile_count(3));
+/// ```
+///
+/// # Use Cases
+///
+/// - **Memory planning**: Pre-allocating per-tile buffers before construction
+/// - **Level selection**: Choosing the subdivision level for a tile budget
+///
+/// # Performance
+///
+/// - Time complexity: O(1)
+pub fn tile_count(num_divisions: usize) -> usize {
+    triangulation_number(num_divisions)
+        .saturating_mul(10)
+        .saturating_add(2)
+}
+
+/// Predicts how many hexagonal tiles `Hexasphere::new` creates.
+///
+/// Every Goldberg polyhedron has exactly 12 pentagons; all other tiles are hexagons.
+///
+/// # Arguments
+///
+/// * `num_divisions` - The subdivision level passed to `Hexasphere::new`
+///
+/// # Returns
+///
+/// `10·(d² - 1)` hexagons, where `d = max(num_divisions, 1)`
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::utils::hexagon_count;
+/// assert_eq!(hexagon_count(1), 0);
+/// assert_eq!(hexagon_count(3), 80);
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(1)
+pub fn hexagon_count(num_divisions: usize) -> usize {
+    tile_count(num_divisions).saturating_sub(12)
+}
+
+/// Predicts how many distinct tile edges `Hexasphere::new` creates.
+///
+/// Each edge is shared by exactly two tiles and counted once.
+///
+/// # Arguments
+///
+/// * `num_divisions` - The subdivision level passed to `Hexasphere::new`
+///
+/// # Returns
+///
+/// `30·d²` edges (saturating), where `d = max(num_divisions, 1)`
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::utils::edge_count;
+/// assert_eq!(edge_count(1), 30);
+/// assert_eq!(edge_count(3), 270);
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(1)
+pub fn edge_count(num_divisions: usize) -> usize {
+    triangulation_number(num_divisions).saturating_mul(30)
+}
+
+/// Predicts how many distinct tile corners `Hexasphere::new` creates.
+///
+/// Each corner is shared by exactly three tiles and counted once; there is
+/// one corner per triangle of the underlying geodesic mesh.
+///
+/// # Arguments
+///
+/// * `num_divisions` - The subdivision level passed to `Hexasphere::new`
+///
+/// # Returns
+///
+/// `20·d²` vertices (saturating), where `d = max(num_divisions, 1)`
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::utils::vertex_count;
+/// assert_eq!(vertex_count(1), 20);
+/// assert_eq!(vertex_count(3), 180);
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(1)
+pub fn vertex_count(num_divisions: usize) -> usize {
+    triangulation_number(num_divisions).saturating_mul(20)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+    use crate::hexasphere::Hexasphere;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_counts_match_constructed_spheres() {
+        for divisions in 0..=4 {
+            let (hexasphere, mesh) = Hexasphere::new_with_mesh(1.0, divisions, 1.0);
+            let corners: HashSet<&Point> = hexasphere
+                .tiles
+                .iter()
+                .flat_map(|tile| tile.boundary.iter())
+                .collect();
+            let edge_ends: usize = hexasphere.tiles.iter().map(|t| t.boundary.len()).sum();
+
+            assert_eq!(
+                hexasphere.tiles.len(),
+                tile_count(divisions),
+                "d={divisions}"
+            );
+            assert_eq!(hexasphere.hexagons().count(), hexagon_count(divisions));
+            assert_eq!(hexasphere.pentagons().count(), 12);
+            assert_eq!(edge_ends / 2, edge_count(divisions), "d={divisions}");
+            assert_eq!(corners.len(), vertex_count(divisions), "d={divisions}");
+            assert_eq!(mesh.triangles.len(), vertex_count(divisions));
+            assert_eq!(mesh.vertices.len(), tile_count(divisions));
+            assert_eq!(
+                tile_count(divisions) + vertex_count(divisions) - edge_count(divisions),
+                2
+            );
+        }
+    }
+
+    #[test]
+    fn test_counts_saturate() {
+        assert_eq!(tile_count(usize::MAX), usize::MAX);
+        assert_eq!(edge_count(usize::MAX), usize::MAX);
+        assert_eq!(vertex_count(usize::MAX), usize::MAX);
+    }
+}
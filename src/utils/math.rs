@@ -1,6 +1,7 @@
 //! Mathematical helper functions.
 
-use crate::geometry::{Face, Point, Vector3};
+use crate::geometry::{Face, Point, Vector3, VertexPool};
+use crate::utils::LatLon;
 use std::collections::HashMap;
 
 // Helper functions
@@ -70,8 +71,8 @@ use std::collections::HashMap;
 /// - Space complexity: O(1) - only creates temporary vectors
 /// - Very fast: Just basic arithmetic operations
 pub fn calculate_surface_normal(p1: &Point, p2: &Point, p3: &Point) -> Point {
-    let u = Point::new(p2.x - p1.x, p2.y - p1.y, p2.z - p1.z);
-    let v = Point::new(p3.x - p1.x, p3.y - p1.y, p3.z - p1.z);
+    let u = p2 - p1;
+    let v = p3 - p1;
 
     Point::new(
         u.y * v.z - u.z * v.y,
@@ -84,7 +85,8 @@ pub fn calculate_surface_normal(p1: &Point, p2: &Point, p3: &Point) -> Point {
 ///
 /// Determines whether a vector is pointing "outward" from the sphere surface
 /// by checking if the vector and point have the same general direction from
-/// the origin. Used for ensuring tile boundaries have consistent outward orientation.
+/// the origin, i.e. `dot(normalize(point), normalize(vector)) > 0`. Used for
+/// ensuring tile boundaries have consistent outward orientation.
 ///
 /// # Arguments
 ///
@@ -97,16 +99,19 @@ pub fn calculate_surface_normal(p1: &Point, p2: &Point, p3: &Point) -> Point {
 ///
 /// # Algorithm
 ///
-/// For each coordinate axis, checks if the point and vector have the same sign:
-/// - If point.x and vector.x are both positive or both negative: ✓
-/// - If they have opposite signs: ✗
-/// - Returns `true` only if ALL three axes agree
+/// Checks the sign of the dot product of `point` and `vector`. Normalizing
+/// either one does not change the sign, so no normalization is performed.
 ///
 /// # Geometric Interpretation
 ///
-/// - **True**: Vector points "outward" from the sphere center
-/// - **False**: Vector points "inward" toward the sphere center
-/// - **Edge case**: Returns `false` if any component has opposite signs
+/// - **True**: The angle between vector and point is below 90°, so the
+///   vector points "outward" from the sphere center
+/// - **False**: The vector points "inward" or is tangent to the sphere, or
+///   either argument is the zero vector
+///
+/// Earlier versions compared signs axis by axis, which misjudged outward
+/// normals with a small component of opposite sign to the point's, as
+/// happens for tiles near the coordinate planes.
 ///
 /// # Use Cases
 ///
@@ -115,12 +120,6 @@ pub fn calculate_surface_normal(p1: &Point, p2: &Point, p3: &Point) -> Point {
 /// - **Surface normal validation**: Checking calculated normals are correct
 /// - **Rendering consistency**: Maintaining proper face orientation
 ///
-/// # Limitations
-///
-/// This is a simplified heuristic that works well for points on a sphere
-/// centered at the origin. For more complex geometries, a proper dot product
-/// test would be more accurate: `dot(normalize(point), normalize(vector)) > 0`
-///
 /// # Examples
 ///
 /// ```rust
@@ -135,12 +134,47 @@ pub fn calculate_surface_normal(p1: &Point, p2: &Point, p3: &Point) -> Point {
 /// let inward = Point::new(-1.0, -1.0, -1.0); // Opposite direction
 /// assert_eq!(pointing_away_from_origin(&center, &inward), false);
 ///
-/// // Mixed directions (fails test)
-/// let mixed = Point::new(1.0, -1.0, 1.0);   // Some components opposite
-/// assert_eq!(pointing_away_from_origin(&center, &mixed), false);
+/// // Tilted but still within 90° of the point's direction
+/// let tilted = Point::new(1.0, -1.0, 1.0);
+/// assert_eq!(pointing_away_from_origin(&center, &tilted), true);
+///
+/// // Tangent to the sphere
+/// let tangent = Point::new(1.0, -1.0, 0.0);
+/// assert_eq!(pointing_away_from_origin(&center, &tangent), false);
 /// ```
 pub fn pointing_away_from_origin(point: &Point, vector: &Point) -> bool {
-    (point.x * vector.x) >= 0.0 && (point.y * vector.y) >= 0.0 && (point.z * vector.z) >= 0.0
+    Vector3::from(point).dot(&Vector3::from(vector)) > 0.0
+}
+
+/// How new vertices are placed between two existing ones during subdivision.
+///
+/// # Variants
+///
+/// - **Linear**: Interpolate along the straight chord, then project. Vertices
+///   bunch up towards the middle of each icosahedron face, so tiles there are
+///   smaller than tiles near the pentagons.
+/// - **Slerp**: Interpolate along the great-circle arc through both vertices.
+///   Arc lengths are divided evenly, which spreads vertices more uniformly and
+///   reduces tile size variation.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{HexasphereBuilder, SubdivisionMode};
+/// let hexasphere = HexasphereBuilder::new()
+///     .subdivisions(3)
+///     .subdivision_mode(SubdivisionMode::Slerp)
+///     .build()
+///     .unwrap();
+/// assert_eq!(hexasphere.tiles.len(), 92);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubdivisionMode {
+    /// Straight-line interpolation between vertices (the original behavior)
+    #[default]
+    Linear,
+    /// Great-circle interpolation between vertices
+    Slerp,
 }
 
 /// Subdivides a triangular face into smaller triangular faces recursively.
@@ -244,6 +278,64 @@ pub fn subdivide_face(
     num_divisions: usize,
     points: &mut HashMap<Point, Point>,
     face_id: &mut usize,
+) -> Vec<Face> {
+    subdivide_face_with_mode(
+        face,
+        num_divisions,
+        SubdivisionMode::Linear,
+        points,
+        face_id,
+    )
+}
+
+/// Subdivides a triangular face, placing new vertices according to `mode`.
+///
+/// Identical to [`subdivide_face`] for [`SubdivisionMode::Linear`]. With
+/// [`SubdivisionMode::Slerp`] both the face edges and the rows across the
+/// face interior are divided along great-circle arcs, so the new vertices are
+/// already spread evenly once projected onto the sphere.
+///
+/// # Arguments
+///
+/// * `face` - The triangular face to subdivide
+/// * `num_divisions` - Number of subdivisions per edge
+/// * `mode` - How intermediate vertices are interpolated
+/// * `points` - HashMap for point deduplication and storage
+/// * `face_id` - Mutable counter for assigning unique face IDs
+///
+/// # Returns
+///
+/// A vector of `num_divisions²` new faces (the original face if `num_divisions` is 0)
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Face, Point, SubdivisionMode};
+/// # use geotiles::utils::subdivide_face_with_mode;
+/// # use std::collections::HashMap;
+/// let face = Face::new(0,
+///     Point::new(100.0, 0.0, 0.0),
+///     Point::new(0.0, 100.0, 0.0),
+///     Point::new(0.0, 0.0, 100.0)
+/// );
+/// let mut points = HashMap::new();
+/// let mut face_id = 1;
+///
+/// let faces = subdivide_face_with_mode(face, 2, SubdivisionMode::Slerp, &mut points, &mut face_id);
+/// assert_eq!(faces.len(), 4);
+///
+/// // Slerped vertices keep the distance of the corners from the origin
+/// for point in points.keys() {
+///     let distance = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+///     assert!((distance - 100.0).abs() < 0.01);
+/// }
+/// ```
+pub fn subdivide_face_with_mode(
+    face: Face,
+    num_divisions: usize,
+    mode: SubdivisionMode,
+    points: &mut HashMap<Point, Point>,
+    face_id: &mut usize,
 ) -> Vec<Face> {
     let mut new_faces = Vec::new();
 
@@ -252,13 +344,25 @@ pub fn subdivide_face(
         return vec![face];
     }
 
-    let left = subdivide_edge(&face.points[0], &face.points[1], num_divisions, points);
-    let right = subdivide_edge(&face.points[0], &face.points[2], num_divisions, points);
+    let left = subdivide_edge_with_mode(
+        &face.points[0],
+        &face.points[1],
+        num_divisions,
+        mode,
+        points,
+    );
+    let right = subdivide_edge_with_mode(
+        &face.points[0],
+        &face.points[2],
+        num_divisions,
+        mode,
+        points,
+    );
 
     let mut prev_row = vec![face.points[0].clone()];
 
     for i in 1..=num_divisions {
-        let current_row = subdivide_edge(&left[i], &right[i], i, points);
+        let current_row = subdivide_edge_with_mode(&left[i], &right[i], i, mode, points);
 
         // Create faces between rows
         for j in 0..i {
@@ -377,21 +481,194 @@ pub fn subdivide_edge(
     p2: &Point,
     count: usize,
     points: &mut HashMap<Point, Point>,
+) -> Vec<Point> {
+    subdivide_edge_with_mode(p1, p2, count, SubdivisionMode::Linear, points)
+}
+
+/// Subdivides an edge, placing intermediate points according to `mode`.
+///
+/// Identical to [`subdivide_edge`] for [`SubdivisionMode::Linear`]. With
+/// [`SubdivisionMode::Slerp`] the points are spaced evenly along the
+/// great-circle arc from `p1` to `p2`, and their distance from the origin is
+/// interpolated linearly between the endpoint distances.
+///
+/// # Arguments
+///
+/// * `p1` - Starting point of the edge
+/// * `p2` - Ending point of the edge
+/// * `count` - Number of segments to create
+/// * `mode` - How intermediate points are interpolated
+/// * `points` - HashMap for point deduplication and storage
+///
+/// # Returns
+///
+/// `count + 1` points from `p1` to `p2`, including both endpoints
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Point, SubdivisionMode};
+/// # use geotiles::utils::subdivide_edge_with_mode;
+/// # use std::collections::HashMap;
+/// let mut points = HashMap::new();
+/// let start = Point::new(10.0, 0.0, 0.0);
+/// let end = Point::new(0.0, 10.0, 0.0);
+///
+/// let arc = subdivide_edge_with_mode(&start, &end, 2, SubdivisionMode::Slerp, &mut points);
+/// // The midpoint lies on the arc, not on the chord
+/// assert_eq!(arc[1], Point::new(7.071, 7.071, 0.0));
+/// ```
+pub fn subdivide_edge_with_mode(
+    p1: &Point,
+    p2: &Point,
+    count: usize,
+    mode: SubdivisionMode,
+    points: &mut HashMap<Point, Point>,
 ) -> Vec<Point> {
     let mut result = Vec::new();
     result.push(get_or_insert_point(p1.clone(), points));
 
     for i in 1..count {
         let t = i as f64 / count as f64;
-        let new_point = Point::new(
+        result.push(get_or_insert_point(interpolate(p1, p2, t, mode), points));
+    }
+
+    result.push(get_or_insert_point(p2.clone(), points));
+    result
+}
+
+/// Places the point a fraction `t` of the way from `p1` to `p2`.
+fn interpolate(p1: &Point, p2: &Point, t: f64, mode: SubdivisionMode) -> Point {
+    match mode {
+        SubdivisionMode::Linear => Point::new(
             p1.x * (1.0 - t) + p2.x * t,
             p1.y * (1.0 - t) + p2.y * t,
             p1.z * (1.0 - t) + p2.z * t,
-        );
-        result.push(get_or_insert_point(new_point, points));
+        ),
+        SubdivisionMode::Slerp => {
+            let (from, to) = (Vector3::from(p1), Vector3::from(p2));
+            let length = from.dot(&from).sqrt() * (1.0 - t) + to.dot(&to).sqrt() * t;
+            Point::from(slerp(&from, &to, t) * length)
+        }
     }
+}
 
-    result.push(get_or_insert_point(p2.clone(), points));
+/// Subdivides a triangle given as vertex pool indices.
+///
+/// Produces the same vertices and triangles, in the same order, as
+/// [`subdivide_face_with_mode`], but stores each vertex once in `pool` and
+/// returns triangles as three `u32` indices instead of owned [`Face`]s.
+///
+/// # Arguments
+///
+/// * `corners` - Pool indices of the triangle corners
+/// * `num_divisions` - Number of subdivisions per edge
+/// * `mode` - How intermediate vertices are interpolated
+/// * `pool` - Vertex pool holding the corners, extended with new vertices
+///
+/// # Returns
+///
+/// `num_divisions²` triangles (just `corners` if `num_divisions` is 0)
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Point, SubdivisionMode, VertexPool};
+/// # use geotiles::utils::subdivide_face_indexed;
+/// let mut pool = VertexPool::new();
+/// let corners = [
+///     pool.insert(Point::new(100.0, 0.0, 0.0)),
+///     pool.insert(Point::new(0.0, 100.0, 0.0)),
+///     pool.insert(Point::new(0.0, 0.0, 100.0)),
+/// ];
+///
+/// let triangles = subdivide_face_indexed(corners, 2, SubdivisionMode::Linear, &mut pool);
+/// assert_eq!(triangles.len(), 4);
+/// assert_eq!(pool.len(), 6); // 3 corners + 3 edge midpoints
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(n²) where n = num_divisions
+/// - Space complexity: 12 bytes per triangle plus one pool entry per new vertex
+pub fn subdivide_face_indexed(
+    corners: [u32; 3],
+    num_divisions: usize,
+    mode: SubdivisionMode,
+    pool: &mut VertexPool,
+) -> Vec<[u32; 3]> {
+    if num_divisions == 0 {
+        return vec![corners];
+    }
+
+    let [a, b, c] = corners;
+    let left = subdivide_edge_indexed(a, b, num_divisions, mode, pool);
+    let right = subdivide_edge_indexed(a, c, num_divisions, mode, pool);
+
+    let mut triangles = Vec::with_capacity(num_divisions * num_divisions);
+    let mut prev_row = vec![a];
+
+    for i in 1..=num_divisions {
+        let current_row = subdivide_edge_indexed(left[i], right[i], i, mode, pool);
+
+        for j in 0..i {
+            triangles.push([prev_row[j], current_row[j], current_row[j + 1]]);
+            if j > 0 {
+                triangles.push([prev_row[j - 1], prev_row[j], current_row[j]]);
+            }
+        }
+
+        prev_row = current_row;
+    }
+
+    triangles
+}
+
+/// Subdivides an edge given as vertex pool indices.
+///
+/// The index counterpart of [`subdivide_edge_with_mode`].
+///
+/// # Arguments
+///
+/// * `a` - Pool index of the starting point
+/// * `b` - Pool index of the ending point
+/// * `count` - Number of segments to create
+/// * `mode` - How intermediate points are interpolated
+/// * `pool` - Vertex pool holding the endpoints, extended with new points
+///
+/// # Returns
+///
+/// `count + 1` pool indices from `a` to `b`, including both endpoints
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{Point, SubdivisionMode, VertexPool};
+/// # use geotiles::utils::subdivide_edge_indexed;
+/// let mut pool = VertexPool::new();
+/// let start = pool.insert(Point::new(0.0, 0.0, 0.0));
+/// let end = pool.insert(Point::new(3.0, 0.0, 0.0));
+///
+/// let edge = subdivide_edge_indexed(start, end, 3, SubdivisionMode::Linear, &mut pool);
+/// assert_eq!(edge.len(), 4);
+/// assert_eq!(pool.get(edge[1]), &Point::new(1.0, 0.0, 0.0));
+/// ```
+pub fn subdivide_edge_indexed(
+    a: u32,
+    b: u32,
+    count: usize,
+    mode: SubdivisionMode,
+    pool: &mut VertexPool,
+) -> Vec<u32> {
+    let (p1, p2) = (pool.get(a).clone(), pool.get(b).clone());
+
+    let mut result = Vec::with_capacity(count + 1);
+    result.push(a);
+    for i in 1..count {
+        let t = i as f64 / count as f64;
+        result.push(pool.insert(interpolate(&p1, &p2, t, mode)));
+    }
+    result.push(b);
     result
 }
 
@@ -720,6 +997,15 @@ pub fn find_projected_point(
 /// - Space complexity: O(n) for temporary data structures
 /// - Geometric calculations: Angle computation or edge comparison overhead
 pub fn sort_faces_around_point(faces: &mut [Face], point: &Point) {
+    sort_faces_around_point_with_precision(faces, point, 3);
+}
+
+/// Like [`sort_faces_around_point`], rounding face centroids to `decimals` places.
+pub(crate) fn sort_faces_around_point_with_precision(
+    faces: &mut [Face],
+    point: &Point,
+    decimals: u32,
+) {
     if faces.len() <= 2 {
         return; // No sorting needed for 0, 1, or 2 faces
     }
@@ -727,21 +1013,19 @@ pub fn sort_faces_around_point(faces: &mut [Face], point: &Point) {
     // Calculate a reference direction vector from the point to establish a consistent ordering
     let reference_direction = if let Some(face) = faces.first() {
         // Use the direction to the centroid of the first face as reference
-        let centroid = face.calculate_centroid();
-        Vector3::new(
-            centroid.x - point.x,
-            centroid.y - point.y,
-            centroid.z - point.z,
-        )
-        .normalize()
+        let centroid = face.centroid_with_precision(decimals);
+        (Vector3::from(&centroid) - Vector3::from(point)).normalize()
     } else {
         return;
     };
 
     // Calculate the "up" direction (normal to the sphere surface at this point)
-    let up_direction = Vector3::new(point.x, point.y, point.z).normalize();
+    let up_direction = Vector3::from(point).normalize();
 
-    // Create a coordinate system for angular sorting
+    // Create a coordinate system for angular sorting. The reference is
+    // deliberately not flattened with `Vector3::reject_from`: that shifts
+    // faces near ±180° across the atan2 seam and would change which corner
+    // existing tile boundaries start at.
     let right_direction = reference_direction;
     let forward_direction = up_direction.cross(&right_direction).normalize();
 
@@ -750,13 +1034,8 @@ pub fn sort_faces_around_point(faces: &mut [Face], point: &Point) {
         .iter()
         .enumerate()
         .map(|(index, face)| {
-            let centroid = face.calculate_centroid();
-            let direction = Vector3::new(
-                centroid.x - point.x,
-                centroid.y - point.y,
-                centroid.z - point.z,
-            )
-            .normalize();
+            let centroid = face.centroid_with_precision(decimals);
+            let direction = (Vector3::from(&centroid) - Vector3::from(point)).normalize();
 
             // Project direction onto the tangent plane and calculate angle
             let x_component = direction.dot(&right_direction);
@@ -863,16 +1142,340 @@ pub fn sort_faces_around_point(faces: &mut [Face], point: &Point) {
 /// - Very efficient for repeated area calculations
 pub fn triangle_area(p1: &Point, p2: &Point, p3: &Point) -> f64 {
     // Using cross product to calculate triangle area
-    let v1 = Vector3::new(p2.x - p1.x, p2.y - p1.y, p2.z - p1.z);
-    let v2 = Vector3::new(p3.x - p1.x, p3.y - p1.y, p3.z - p1.z);
+    let v1 = Vector3::from(p2) - Vector3::from(p1);
+    let v2 = Vector3::from(p3) - Vector3::from(p1);
     let cross = v1.cross(&v2);
     0.5 * (cross.x.powi(2) + cross.y.powi(2) + cross.z.powi(2)).sqrt()
 }
 
+/// Calculate the area of the spherical triangle spanned by three directions.
+///
+/// Each point is treated as a direction from the origin and projected onto
+/// the sphere of the given radius; the result is the area of the triangle
+/// bounded by great-circle arcs between them. Uses the Van Oosterom–Strackee
+/// formula for the solid angle, which stays accurate for tiny triangles.
+///
+/// # Arguments
+///
+/// * `p1`, `p2`, `p3` - Triangle corners; only their directions matter
+/// * `radius` - Radius of the sphere
+///
+/// # Returns
+///
+/// The (non-negative) spherical area, or 0.0 if a corner is at the origin
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Point;
+/// # use geotiles::utils::spherical_triangle_area;
+/// // One octant of the sphere covers an eighth of its surface
+/// let area = spherical_triangle_area(
+///     &Point::new(1.0, 0.0, 0.0),
+///     &Point::new(0.0, 1.0, 0.0),
+///     &Point::new(0.0, 0.0, 1.0),
+///     2.0,
+/// );
+/// assert!((area - 4.0 * std::f64::consts::PI * 4.0 / 8.0).abs() < 1e-12);
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(1) - constant time calculation
+/// - Space complexity: O(1)
+pub fn spherical_triangle_area(p1: &Point, p2: &Point, p3: &Point, radius: f64) -> f64 {
+    let [a, b, c] = [p1, p2, p3].map(|point| Vector3::from(point).normalize());
+    signed_solid_angle(&a, &b, &c).abs() * radius * radius
+}
+
+/// Solid angle of the spherical triangle spanned by three unit vectors.
+///
+/// Positive when the corners run counter-clockwise seen from outside the
+/// sphere, negative when clockwise, and 0.0 when they lie on one great circle.
+fn signed_solid_angle(a: &Vector3, b: &Vector3, c: &Vector3) -> f64 {
+    let triple = a.dot(&b.cross(c));
+    let denominator = 1.0 + a.dot(b) + b.dot(c) + c.dot(a);
+
+    // Corners spread around one great circle would otherwise round to a
+    // hemisphere of either sign
+    if denominator <= 0.0 && triple.abs() < 1e-12 {
+        return 0.0;
+    }
+    2.0 * triple.atan2(denominator)
+}
+
+/// Calculate the area of a polygon drawn on the sphere with great-circle edges.
+///
+/// Each point is treated as a direction from the origin; consecutive points
+/// are joined by great-circle arcs and the last is joined back to the first.
+/// The area is summed from signed triangles fanning out from the mean corner
+/// direction to every edge, so non-convex polygons are measured correctly.
+///
+/// # Arguments
+///
+/// * `points` - Polygon corners in order, either winding; only their directions matter
+/// * `radius` - Radius of the sphere
+///
+/// # Returns
+///
+/// The (non-negative) spherical area, or 0.0 for fewer than 3 points.
+/// Polygons must not intersect themselves and must cover less than a hemisphere.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Point;
+/// # use geotiles::utils::spherical_polygon_area;
+/// // Two adjacent octants form a lune covering a quarter of the sphere
+/// let lune = [
+///     Point::new(0.0, 1.0, 0.0),
+///     Point::new(1.0, 0.0, 0.0),
+///     Point::new(0.0, -1.0, 0.0),
+///     Point::new(0.0, 0.0, 1.0),
+/// ];
+/// let area = spherical_polygon_area(&lune, 1.0);
+/// assert!((area - std::f64::consts::PI).abs() < 1e-12);
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(n) where n = number of points
+/// - Space complexity: O(n)
+pub fn spherical_polygon_area(points: &[Point], radius: f64) -> f64 {
+    if points.len() < 3 {
+        return 0.0;
+    }
+
+    let corners: Vec<Vector3> = points
+        .iter()
+        .map(|point| Vector3::from(point).normalize())
+        .collect();
+    let sum = corners
+        .iter()
+        .fold(Vector3::new(0.0, 0.0, 0.0), |sum, corner| &sum + corner);
+    let hub = if sum.length() > 1e-9 {
+        sum.normalize()
+    } else {
+        corners[0].clone()
+    };
+
+    let solid_angle: f64 = (0..corners.len())
+        .map(|i| signed_solid_angle(&hub, &corners[i], &corners[(i + 1) % corners.len()]))
+        .sum();
+    solid_angle.abs() * radius * radius
+}
+
+/// Calculate the length of the shorter great-circle arc between two directions.
+///
+/// # Arguments
+///
+/// * `a`, `b` - Arc ends; only their directions matter
+/// * `radius` - Radius of the sphere
+///
+/// # Returns
+///
+/// The arc length, between 0.0 and `π × radius`, or 0.0 if either point
+/// is at the origin
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Point;
+/// # use geotiles::utils::great_circle_distance;
+/// let quarter = great_circle_distance(&Point::new(2.0, 0.0, 0.0), &Point::new(0.0, 0.0, 5.0), 10.0);
+/// assert!((quarter - 5.0 * std::f64::consts::PI).abs() < 1e-12);
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(1)
+/// - Accurate for tiny and nearly antipodal arcs alike, unlike `acos` of the dot product
+pub fn great_circle_distance(a: &Point, b: &Point, radius: f64) -> f64 {
+    a.angle_between(b) * radius
+}
+
+/// Find the point halfway along the great-circle arc between two directions.
+///
+/// # Arguments
+///
+/// * `a`, `b` - Arc ends; only their directions matter
+/// * `radius` - Radius of the sphere the midpoint is placed on
+///
+/// # Returns
+///
+/// The midpoint, exactly `radius` from the origin and not rounded like
+/// [`Point::new`]. Identical directions give that direction; antipodal ones
+/// follow the fixed great circle chosen by [`slerp`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Point;
+/// # use geotiles::utils::arc_midpoint;
+/// let mid = arc_midpoint(&Point::new(1.0, 0.0, 0.0), &Point::new(0.0, 1.0, 0.0), 2.0);
+/// assert!((mid.x - 2f64.sqrt()).abs() < 1e-12);
+/// assert!((mid.y - 2f64.sqrt()).abs() < 1e-12);
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(1)
+pub fn arc_midpoint(a: &Point, b: &Point, radius: f64) -> Point {
+    a.slerp(b, 0.5, radius)
+}
+
+/// Spherical linear interpolation between two directions.
+///
+/// Moves along the great-circle arc from `from` to `to` at constant angular
+/// speed, unlike linear interpolation along the chord which speeds up in the
+/// middle and cuts beneath the sphere surface.
+///
+/// # Arguments
+///
+/// * `from` - Start direction (need not be normalized)
+/// * `to` - End direction (need not be normalized)
+/// * `t` - Interpolation factor (0.0 = `from`, 1.0 = `to`)
+///
+/// # Returns
+///
+/// A unit vector on the arc between the two normalized directions
+///
+/// # Degenerate Arcs
+///
+/// - **Nearly identical** directions fall back to normalized linear
+///   interpolation, avoiding the division by `sin(0)`
+/// - **Nearly antipodal** directions have no unique great circle; the arc is
+///   taken through a fixed direction perpendicular to `from`, chosen from the
+///   coordinate axis least aligned with it, so results are stable
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::Vector3;
+/// # use geotiles::utils::slerp;
+/// let x = Vector3::new(1.0, 0.0, 0.0);
+/// let y = Vector3::new(0.0, 1.0, 0.0);
+///
+/// // Halfway along the quarter circle is at 45 degrees
+/// let mid = slerp(&x, &y, 0.5);
+/// assert!((mid.x - mid.y).abs() < 1e-12);
+/// assert!((mid.x.powi(2) + mid.y.powi(2) - 1.0).abs() < 1e-12);
+/// ```
+pub fn slerp(from: &Vector3, to: &Vector3, t: f64) -> Vector3 {
+    let a = from.normalize();
+    let b = to.normalize();
+    let cos_theta = (a.x * b.x + a.y * b.y + a.z * b.z).clamp(-1.0, 1.0);
+    let theta = cos_theta.acos();
+
+    let (weight_a, weight_b) = if theta.sin().abs() < 1e-9 {
+        if cos_theta < 0.0 {
+            return antipodal_slerp(&a, t);
+        }
+        (1.0 - t, t)
+    } else {
+        let sin_theta = theta.sin();
+        (
+            ((1.0 - t) * theta).sin() / sin_theta,
+            (t * theta).sin() / sin_theta,
+        )
+    };
+
+    Vector3::new(
+        weight_a * a.x + weight_b * b.x,
+        weight_a * a.y + weight_b * b.y,
+        weight_a * a.z + weight_b * b.z,
+    )
+    .normalize()
+}
+
+/// Rotates `from` by `t × π` toward a fixed direction perpendicular to it.
+fn antipodal_slerp(from: &Vector3, t: f64) -> Vector3 {
+    let axis = if from.x.abs() <= from.y.abs() && from.x.abs() <= from.z.abs() {
+        Vector3::new(1.0, 0.0, 0.0)
+    } else if from.y.abs() <= from.z.abs() {
+        Vector3::new(0.0, 1.0, 0.0)
+    } else {
+        Vector3::new(0.0, 0.0, 1.0)
+    };
+    let perpendicular = from.cross(&axis).normalize();
+    let angle = t * std::f64::consts::PI;
+
+    &(from * angle.cos()) + &(&perpendicular * angle.sin())
+}
+
+/// Tests whether a direction lies inside a polygon drawn on the sphere.
+///
+/// The ring's consecutive vertices are joined by great-circle arcs, with the
+/// last vertex joined back to the first. It may run clockwise or
+/// counter-clockwise: of the two regions it bounds, the smaller one is the
+/// inside. Rings crossing the antimeridian or enclosing a pole need no special
+/// handling, as the test works on unit vectors rather than raw degrees.
+///
+/// Internally this sums the signed solid angles of the triangles fanning from
+/// the antipode of `direction` to every ring edge. The sum is near ±4π minus
+/// the polygon's area when `direction` is inside and at most the polygon's
+/// area otherwise.
+///
+/// # Arguments
+///
+/// * `direction` - The point to test; only its direction matters
+/// * `ring` - Polygon vertices in degrees; a repeated closing vertex is allowed
+///
+/// # Returns
+///
+/// `true` if `direction` is inside the polygon. Rings with fewer than three
+/// vertices contain nothing, and points exactly on an edge may go either way.
+///
+/// # Polygon Size
+///
+/// The polygon must cover less than a hemisphere, as larger ones are taken to
+/// mean their complement.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geotiles::{LatLon, Vector3};
+/// # use geotiles::utils::point_in_spherical_polygon;
+/// // A cap around the north pole
+/// let ring: Vec<LatLon> = (0..8).map(|i| LatLon::new(70.0, i as f64 * 45.0)).collect();
+///
+/// let pole = Vector3::new(0.0, 1.0, 0.0);
+/// let equator = Vector3::new(1.0, 0.0, 0.0);
+/// assert!(point_in_spherical_polygon(&pole, &ring));
+/// assert!(!point_in_spherical_polygon(&equator, &ring));
+/// ```
+///
+/// # Performance
+///
+/// - Time complexity: O(n) where n = ring vertices
+/// - Space complexity: O(n)
+pub fn point_in_spherical_polygon(direction: &Vector3, ring: &[LatLon]) -> bool {
+    let vertices: Vec<Vector3> = ring
+        .iter()
+        .map(|lat_lon| Vector3::from(&lat_lon.to_point(1.0)))
+        .collect();
+    ring_contains(direction, &vertices)
+}
+
+/// [`point_in_spherical_polygon`] for a ring already converted to unit vectors.
+pub(crate) fn ring_contains(direction: &Vector3, vertices: &[Vector3]) -> bool {
+    if vertices.len() < 3 {
+        return false;
+    }
+    let apex = -direction.normalize();
+
+    let solid_angle: f64 = (0..vertices.len())
+        .map(|i| signed_solid_angle(&apex, &vertices[i], &vertices[(i + 1) % vertices.len()]))
+        .sum();
+
+    solid_angle.abs() > 2.0 * std::f64::consts::PI
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::geometry::{Face, Point};
+    use std::f64::consts::PI;
 
     #[test]
     fn test_sort_faces_around_point() {
@@ -972,4 +1575,195 @@ mod tests {
         sort_faces_around_point(&mut two_faces, &center);
         assert_eq!(two_faces.len(), 2);
     }
+
+    #[test]
+    fn test_indexed_subdivision_matches_faces() {
+        let corners = [
+            Point::new(1000.0, 1618.034, 0.0),
+            Point::new(-1000.0, 1618.034, 0.0),
+            Point::new(0.0, 1000.0, 1618.034),
+        ];
+
+        for mode in [SubdivisionMode::Linear, SubdivisionMode::Slerp] {
+            let face = Face::new(
+                0,
+                corners[0].clone(),
+                corners[1].clone(),
+                corners[2].clone(),
+            );
+            let mut points = HashMap::new();
+            let mut face_id = 1;
+            let faces = subdivide_face_with_mode(face, 5, mode, &mut points, &mut face_id);
+
+            let mut pool = VertexPool::new();
+            let indices = corners.clone().map(|corner| pool.insert(corner));
+            let triangles = subdivide_face_indexed(indices, 5, mode, &mut pool);
+
+            assert_eq!(pool.len(), points.len());
+            assert_eq!(triangles.len(), faces.len());
+            for (triangle, face) in triangles.iter().zip(&faces) {
+                let vertices = triangle.map(|index| pool.get(index).clone());
+                assert_eq!(vertices, face.points);
+            }
+        }
+    }
+
+    #[test]
+    fn test_point_in_spherical_polygon_degenerate_rings() {
+        let direction = Vector3::new(0.0, 0.0, 1.0);
+        let around = |lat: f64| {
+            [
+                LatLon::new(-lat, -lat),
+                LatLon::new(-lat, lat),
+                LatLon::new(lat, lat),
+                LatLon::new(lat, -lat),
+            ]
+        };
+
+        assert!(!point_in_spherical_polygon(&direction, &[]));
+        assert!(!point_in_spherical_polygon(&direction, &around(5.0)[..2]));
+        assert!(point_in_spherical_polygon(&direction, &around(5.0)));
+        assert!(point_in_spherical_polygon(
+            &(&direction * 7.0),
+            &around(0.1)
+        ));
+        assert!(!point_in_spherical_polygon(
+            &(&direction * -1.0),
+            &around(5.0)
+        ));
+    }
+
+    #[test]
+    fn test_octants_cover_the_sphere() {
+        let axis = |i: usize, sign: f64| {
+            let mut coordinates = [0.0; 3];
+            coordinates[i] = sign;
+            Point::new(coordinates[0], coordinates[1], coordinates[2])
+        };
+
+        let mut triangle_sum = 0.0;
+        let mut polygon_sum = 0.0;
+        for sx in [1.0, -1.0] {
+            for sy in [1.0, -1.0] {
+                for sz in [1.0, -1.0] {
+                    let corners = [axis(0, sx), axis(1, sy), axis(2, sz)];
+                    let area = spherical_triangle_area(&corners[0], &corners[1], &corners[2], 1.0);
+                    assert!((area - PI / 2.0).abs() < 1e-12);
+                    triangle_sum += area;
+                    polygon_sum += spherical_polygon_area(&corners, 1.0);
+                }
+            }
+        }
+
+        assert!((triangle_sum - 4.0 * PI).abs() < 1e-12);
+        assert!((polygon_sum - 4.0 * PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_degenerate_spherical_inputs() {
+        let x = Point::new(1.0, 0.0, 0.0);
+        let y = Point::new(0.0, 1.0, 0.0);
+        let origin = Point::new(0.0, 0.0, 0.0);
+        let on_equator = |degrees: f64| {
+            let (sin, cos) = degrees.to_radians().sin_cos();
+            Point {
+                x: cos,
+                y: sin,
+                z: 0.0,
+            }
+        };
+        let tilted = |degrees: f64| {
+            let (sin, cos) = degrees.to_radians().sin_cos();
+            Point {
+                x: cos,
+                y: 0.6 * sin,
+                z: 0.8 * sin,
+            }
+        };
+
+        // Corners on one great circle, whether bunched or spread around it
+        for corners in [
+            [x.clone(), y.clone(), Point::new(2.0, 2.0, 0.0)],
+            [on_equator(0.0), on_equator(135.0), on_equator(270.0)],
+            [tilted(10.0), tilted(130.0), tilted(250.0)],
+            [x.clone(), Point::new(-1.0, 0.0, 0.0), y.clone()],
+            [x.clone(), origin.clone(), y.clone()],
+        ] {
+            let area = spherical_triangle_area(&corners[0], &corners[1], &corners[2], 3.0);
+            assert_eq!(area, 0.0, "{corners:?}");
+            assert_eq!(spherical_polygon_area(&corners, 3.0), 0.0, "{corners:?}");
+        }
+        assert_eq!(spherical_polygon_area(&[x.clone(), y.clone()], 1.0), 0.0);
+
+        assert_eq!(great_circle_distance(&x, &x, 2.0), 0.0);
+        assert_eq!(great_circle_distance(&x, &origin, 2.0), 0.0);
+        let antipodal = great_circle_distance(&x, &Point::new(-3.0, 0.0, 0.0), 2.0);
+        assert!((antipodal - 2.0 * PI).abs() < 1e-12);
+
+        let same = arc_midpoint(&x, &x, 4.0);
+        assert!(same.angle_between(&x) < 1e-12);
+        let around = arc_midpoint(&x, &Point::new(-1.0, 0.0, 0.0), 4.0);
+        assert!(!around.x.is_nan() && (around.angle_between(&x) - PI / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spherical_polygon_area_of_non_convex_polygon() {
+        // A square cap around +Z with one corner pushed inward
+        let ring: Vec<Point> = [
+            (1.0, 1.0),
+            (-1.0, 1.0),
+            (-1.0, -1.0),
+            (0.0, 0.0),
+            (1.0, -1.0),
+        ]
+        .iter()
+        .map(|&(x, y)| Point {
+            x: 0.1 * x,
+            y: 0.1 * y,
+            z: 1.0,
+        })
+        .collect();
+        let square = [
+            ring[0].clone(),
+            ring[1].clone(),
+            ring[2].clone(),
+            ring[4].clone(),
+        ];
+        let notch = spherical_triangle_area(&ring[2], &ring[3], &ring[4], 1.0);
+
+        let expected = spherical_polygon_area(&square, 1.0) - notch;
+        assert!((spherical_polygon_area(&ring, 1.0) - expected).abs() < 1e-12);
+
+        let reversed: Vec<Point> = ring.iter().rev().cloned().collect();
+        assert!((spherical_polygon_area(&reversed, 1.0) - expected).abs() < 1e-12);
+    }
+
+    fn direction() -> impl proptest::strategy::Strategy<Value = Point> {
+        use proptest::prelude::*;
+        (-1.0..1.0f64, -1.0..1.0f64, -1.0..1.0f64)
+            .prop_filter("away from the origin", |(x, y, z)| {
+                x * x + y * y + z * z > 0.01
+            })
+            .prop_map(|(x, y, z)| Point { x, y, z })
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_triangle_and_polygon_areas_agree(a in direction(), b in direction(), c in direction()) {
+            let triangle = spherical_triangle_area(&a, &b, &c, 2.0);
+            let polygon = spherical_polygon_area(&[a, b, c], 2.0);
+            proptest::prop_assert!(!triangle.is_nan());
+            proptest::prop_assert!((0.0..=8.0 * PI).contains(&triangle));
+            proptest::prop_assert!((triangle - polygon).abs() < 1e-9);
+        }
+
+        #[test]
+        fn prop_arc_midpoint_halves_the_distance(a in direction(), b in direction()) {
+            proptest::prop_assume!(a.angle_between(&b) < PI - 1e-3);
+            let mid = arc_midpoint(&a, &b, 5.0);
+            let whole = great_circle_distance(&a, &b, 5.0);
+            proptest::prop_assert!((great_circle_distance(&a, &mid, 5.0) - whole / 2.0).abs() < 1e-9);
+            proptest::prop_assert!((great_circle_distance(&mid, &b, 5.0) - whole / 2.0).abs() < 1e-9);
+        }
+    }
 }
@@ -1,7 +1,9 @@
 //! Utility types and helper functions.
 
 pub mod coordinates;
+pub mod counts;
 pub mod math;
 
-pub use coordinates::LatLon;
+pub use coordinates::{AxisConvention, LatLon, LatLonBounds};
+pub use counts::{edge_count, hexagon_count, tile_count, vertex_count};
 pub use math::*;
@@ -71,3 +71,337 @@ fn test_regular_hexagon_generation() {
         }
     }
 }
+
+#[test]
+fn test_tile_order_is_deterministic() {
+    let first = Hexasphere::new(10.0, 4, 0.9);
+    let second = Hexasphere::new(10.0, 4, 0.9);
+
+    assert_eq!(first.tiles.len(), second.tiles.len());
+    for (a, b) in first.tiles.iter().zip(&second.tiles) {
+        assert_eq!(a.center_point, b.center_point);
+        assert_eq!(a.boundary, b.boundary);
+        assert_eq!(a.neighbors, b.neighbors);
+    }
+
+    // Neighbor indices must refer to tiles that are actually adjacent
+    for (index, tile) in first.tiles.iter().enumerate() {
+        for &neighbor in &tile.neighbors {
+            assert!(first.tiles[neighbor].neighbors.contains(&index));
+        }
+    }
+}
+
+#[test]
+fn test_pentagon_indices_match_tiles() {
+    let spheres = [
+        Hexasphere::new(10.0, 0, 1.0),
+        Hexasphere::new(10.0, 4, 0.9),
+        Hexasphere::new_goldberg(10.0, 3, 1, 1.0),
+        HexasphereBuilder::new()
+            .subdivisions(5)
+            .pentagon_at_poles(true)
+            .build()
+            .unwrap(),
+        Hexasphere::from_bytes(&Hexasphere::new(10.0, 3, 1.0).to_bytes()).unwrap(),
+    ];
+
+    for hexasphere in &spheres {
+        let listed = hexasphere.pentagon_indices();
+        assert_eq!(listed.len(), 12);
+        assert_eq!(hexasphere.pentagon_count(), 12);
+        for (id, tile) in hexasphere.tiles.iter().enumerate() {
+            assert_eq!(tile.is_pentagon(), listed.contains(&id));
+        }
+        assert_eq!(
+            hexasphere.hexagon_count(),
+            hexasphere.tiles.iter().filter(|t| t.is_hexagon()).count()
+        );
+    }
+}
+
+#[test]
+fn test_every_tile_has_a_full_set_of_neighbors() {
+    let spheres = (1..=5)
+        .flat_map(|d| [Hexasphere::new(10.0, d, 1.0), Hexasphere::new(1.0, d, 0.5)])
+        .chain([Hexasphere::new_goldberg(10.0, 2, 1, 1.0)]);
+
+    for hexasphere in spheres {
+        for tile in &hexasphere.tiles {
+            let expected = if tile.is_pentagon() { 5 } else { 6 };
+            assert_eq!(
+                tile.neighbors.len(),
+                expected,
+                "{hexasphere}, tile {}",
+                tile.id
+            );
+
+            // Ids stay aligned with the resolved neighbors
+            for (neighbor, id) in tile.neighbors.iter().zip(&tile.neighbor_ids) {
+                assert_eq!(&hexasphere.tiles[*neighbor].center_point.to_string(), id);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tile_iterators() {
+    let hexasphere = Hexasphere::new(10.0, 2, 1.0);
+
+    assert_eq!(hexasphere.iter().count(), 42);
+    assert_eq!(hexasphere.hexagons().count(), 30);
+    assert_eq!(hexasphere.hexagons().count(), hexasphere.hexagon_count());
+
+    let pentagons: Vec<usize> = hexasphere.pentagons().map(|(index, _)| index).collect();
+    assert_eq!(pentagons, hexasphere.pentagon_indices());
+
+    for (index, tile) in hexasphere.iter() {
+        assert_eq!(tile.id, index);
+        assert!(std::ptr::eq(&hexasphere[index], tile));
+    }
+    for (index, tile) in hexasphere.hexagons() {
+        assert!(tile.is_hexagon());
+        assert!(!pentagons.contains(&index));
+    }
+
+    // Orientations stay paired with the tiles they belong to
+    let orientations = hexasphere.get_hexagon_orientations();
+    assert_eq!(orientations.len(), 30);
+    for (index, orientation) in &orientations {
+        let expected = hexasphere[*index].get_orientation().unwrap();
+        assert_eq!(orientation.right, expected.right);
+        assert!(hexasphere[*index].is_hexagon());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_range_panics() {
+    let hexasphere = Hexasphere::new(10.0, 1, 1.0);
+    let _ = &hexasphere[12];
+}
+
+#[test]
+fn test_with_hex_size_matches_construction() {
+    let full = Hexasphere::new(10.0, 3, 1.0);
+    let half = Hexasphere::new(10.0, 3, 0.5);
+
+    // Boundaries are re-derived from the centers, not unscaled, so they match exactly
+    let rescaled = full.with_hex_size(0.5);
+    let restored = half.with_hex_size(1.0);
+    for (id, tile) in rescaled.tiles.iter().enumerate() {
+        assert_eq!(tile.id, id);
+        assert_eq!(tile.center_point, half.tiles[id].center_point);
+        assert_eq!(tile.boundary, half.tiles[id].boundary);
+        assert_eq!(tile.neighbors, half.tiles[id].neighbors);
+        assert_eq!(tile.neighbor_ids, half.tiles[id].neighbor_ids);
+        assert_eq!(restored.tiles[id].boundary, full.tiles[id].boundary);
+    }
+    assert_eq!(rescaled.pentagon_indices(), half.pentagon_indices());
+}
+
+#[test]
+fn test_display_summarizes_without_tiles() {
+    let hexasphere = Hexasphere::new(10.0, 16, 1.0);
+    let summary = hexasphere.to_string();
+
+    assert_eq!(
+        summary,
+        "Hexasphere { radius: 10, tiles: 2562 (2550 hex / 12 pent), subdivision: 16 }"
+    );
+    assert_eq!(hexasphere.subdivisions(), Some(16));
+    assert_eq!(hexasphere.with_hex_size(0.5).subdivisions(), Some(16));
+
+    let soccer_ball = Hexasphere::new_goldberg(10.0, 1, 1, 1.0);
+    assert_eq!(
+        soccer_ball.to_string(),
+        "Hexasphere { radius: 10, tiles: 32 (20 hex / 12 pent) }"
+    );
+}
+
+#[test]
+fn test_memory_estimate_grows_with_tile_count() {
+    let estimates: Vec<usize> = [4, 8, 16]
+        .iter()
+        .map(|&d| Hexasphere::new(10.0, d, 1.0).memory_estimate())
+        .collect();
+
+    // Doubling the subdivisions quadruples the tiles
+    for pair in estimates.windows(2) {
+        let ratio = pair[1] as f64 / pair[0] as f64;
+        assert!((3.5..4.5).contains(&ratio), "ratio {ratio}");
+    }
+
+    let mut cached = Hexasphere::new(10.0, 4, 1.0);
+    cached.precompute_tile_caches();
+    assert!(cached.memory_estimate() > estimates[0]);
+}
+
+#[test]
+fn test_offset_center_matches_translated_origin_sphere() {
+    let offset = Vector3::new(100.0, 0.0, 0.0);
+    let origin = Hexasphere::new(10.0, 3, 0.9);
+    let moved = HexasphereBuilder::new()
+        .radius(10.0)
+        .subdivisions(3)
+        .hex_size(0.9)
+        .center(Point::new(100.0, 0.0, 0.0))
+        .build()
+        .unwrap();
+
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    let translated = |a: &Point, b: &Point| {
+        close(a.x, b.x + offset.x) && close(a.y, b.y + offset.y) && close(a.z, b.z + offset.z)
+    };
+    let same = |a: &Vector3, b: &Vector3| close(a.x, b.x) && close(a.y, b.y) && close(a.z, b.z);
+
+    assert_eq!(moved.center(), &Point::new(100.0, 0.0, 0.0));
+    assert_eq!(moved.tiles.len(), origin.tiles.len());
+    for (tile, base) in moved.tiles.iter().zip(&origin.tiles) {
+        assert_eq!(tile.sphere_center, *moved.center());
+        assert!(translated(&tile.center_point, &base.center_point));
+        assert_eq!(tile.boundary.len(), base.boundary.len());
+        for (point, base_point) in tile.boundary.iter().zip(&base.boundary) {
+            assert!(translated(point, base_point));
+        }
+        assert_eq!(tile.neighbors, base.neighbors);
+
+        let (lat_lon, base_lat_lon) = (tile.get_lat_lon(10.0), base.get_lat_lon(10.0));
+        assert!(close(lat_lon.lat, base_lat_lon.lat) && close(lat_lon.lon, base_lat_lon.lon));
+        assert!(close(tile.get_area(), base.get_area()));
+        assert!(close(
+            tile.get_spherical_area(10.0),
+            base.get_spherical_area(10.0)
+        ));
+
+        let (orientation, base_orientation) = (
+            tile.get_orientation().unwrap(),
+            base.get_orientation().unwrap(),
+        );
+        assert!(same(&orientation.up, &base_orientation.up));
+        assert!(same(&orientation.right, &base_orientation.right));
+    }
+
+    let (stats, base_stats) = (
+        moved.calculate_hexagon_stats(),
+        origin.calculate_hexagon_stats(),
+    );
+    assert!(close(
+        stats.average_hexagon_area,
+        base_stats.average_hexagon_area
+    ));
+    assert!(moved.validate().is_ok());
+
+    // Queries take world positions and find the same tiles
+    for id in [0, 17, 60] {
+        let center = &moved.tiles[id].center_point;
+        assert_eq!(moved.tile_containing(center), Some(id));
+        assert_eq!(TileIndex::new(&moved).tile_containing(center), Some(id));
+    }
+    assert_eq!(moved.tile_containing(moved.center()), None);
+
+    let eye = Point::new(100.0, 0.0, 30.0);
+    let base_eye = Point::new(0.0, 0.0, 30.0);
+    assert_eq!(
+        moved.tiles_visible_from(&eye),
+        origin.tiles_visible_from(&base_eye)
+    );
+
+    let hit = moved.raycast(&eye, &Vector3::new(0.0, 0.0, -1.0)).unwrap();
+    let base_hit = origin
+        .raycast(&base_eye, &Vector3::new(0.0, 0.0, -1.0))
+        .unwrap();
+    assert_eq!(hit.tile_index, base_hit.tile_index);
+    assert!(close(hit.distance, base_hit.distance));
+    assert!(translated(&hit.point, &base_hit.point));
+
+    // Maps are drawn relative to the sphere's own poles, wherever it sits.
+    // Six subdivisions put a tile on each pole, and each must be closed over
+    // its own pole even when the whole sphere is below y = 0.
+    assert_eq!(
+        moved.to_svg_equirectangular(720, 360),
+        origin.to_svg_equirectangular(720, 360)
+    );
+    let lowered = HexasphereBuilder::new()
+        .radius(10.0)
+        .subdivisions(6)
+        .hex_size(0.9)
+        .center(Point::new(0.0, -100.0, 0.0))
+        .build()
+        .unwrap();
+    assert_eq!(
+        lowered.to_svg_equirectangular(720, 360),
+        Hexasphere::new(10.0, 6, 0.9).to_svg_equirectangular(720, 360)
+    );
+}
+
+#[test]
+fn test_rescaled_spheres_keep_stored_radius_in_step() {
+    let hexasphere = Hexasphere::new(10.0, 3, 1.0);
+    let inner = hexasphere.create_inner_sphere(4.0);
+    let shrunk = hexasphere.with_hex_size(0.5);
+
+    for ((tile, small), resized) in hexasphere.tiles.iter().zip(&inner.tiles).zip(&shrunk.tiles) {
+        assert_eq!(small.sphere_radius, 4.0);
+        assert_eq!(small.lat_lon(), small.get_lat_lon(4.0));
+        assert!((small.lat_lon().lat - tile.lat_lon().lat).abs() < 0.1);
+        assert_eq!(resized.sphere_radius, 10.0);
+        assert_eq!(resized.lat_lon(), tile.lat_lon());
+    }
+}
+
+/// A 1 mm sphere, which needs more than the default 3 decimals.
+fn tiny_sphere() -> Hexasphere {
+    HexasphereBuilder::new()
+        .radius(0.001)
+        .subdivisions(3)
+        .vertex_precision(9)
+        .build()
+        .unwrap()
+}
+
+/// Every tile center and every tile's boundary points are distinct.
+fn assert_points_distinct(hexasphere: &Hexasphere) {
+    let centers: std::collections::HashSet<String> = hexasphere
+        .tiles
+        .iter()
+        .map(|tile| tile.center_point.to_string())
+        .collect();
+    assert_eq!(centers.len(), hexasphere.tiles.len());
+    for tile in &hexasphere.tiles {
+        let corners: std::collections::HashSet<String> =
+            tile.boundary.iter().map(Point::to_string).collect();
+        assert_eq!(corners.len(), tile.boundary.len());
+    }
+}
+
+#[test]
+fn test_stored_precision_survives_rebuilding_points() {
+    let tiny = tiny_sphere();
+    assert_eq!(tiny.vertex_precision(), 9);
+
+    let inner = tiny.create_inner_sphere(0.0005);
+    assert_eq!(inner.vertex_precision(), 9);
+    assert_points_distinct(&inner);
+    assert!(inner.validate().is_ok());
+
+    let mut relaxed = tiny.clone();
+    relaxed.relax(2);
+    assert_points_distinct(&relaxed);
+    assert!(relaxed.validate().is_ok());
+
+    let refined = tiny.refined(1);
+    assert_eq!(refined.vertex_precision(), 9);
+    assert_points_distinct(&refined);
+    assert!(refined.validate().is_ok());
+}
+
+#[test]
+fn test_with_hex_size_keeps_vertex_precision() {
+    let tiny = tiny_sphere();
+    let shrunk = tiny.with_hex_size(0.5);
+
+    assert_eq!(shrunk.vertex_precision(), 9);
+    assert_points_distinct(&shrunk);
+    assert!(shrunk.validate().is_ok());
+}